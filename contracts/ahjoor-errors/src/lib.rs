@@ -119,6 +119,8 @@ pub mod rosca {
     pub const SOURCE_GROUP_ALREADY_SPLIT: u32 = 1090;
     pub const SPLIT_ALREADY_CONFIRMED: u32    = 1091;
     pub const SPLIT_NOT_FULLY_CONFIRMED: u32  = 1092;
+    pub const MEMBER_NOT_SUSPENDED: u32       = 1093;
+    pub const SLOT_ALREADY_PAID_OUT: u32      = 1094;
     // ExtError2 variants
     pub const AUCTION_NOT_ENABLED: u32        = 1101;
     pub const AUCTION_NOT_OPEN: u32           = 1102;
@@ -138,6 +140,86 @@ pub mod rosca {
     pub const RECEIPT_NOT_FOUND: u32          = 1116;
     pub const COPAYER_SPLITS_ALREADY_SET: u32 = 1117;
     pub const PROXY_ROUNDS_EXHAUSTED: u32     = 1118;
+    pub const ROUND_ALREADY_COMPLETE: u32     = 1119;
+    pub const MEMBER_AMOUNTS_LENGTH_MISMATCH: u32 = 1120;
+    pub const NO_SURPLUS_BALANCE: u32         = 1121;
+    pub const NOT_SCHEDULED_RECIPIENT: u32    = 1122;
+    pub const EARLY_CLAIM_ALREADY_MADE: u32   = 1123;
+    pub const AUTO_CONTRIBUTE_NOT_ENABLED: u32 = 1124;
+    pub const NO_PENDING_SWAP: u32            = 1125;
+    pub const NOT_INITIALIZED: u32            = 1126;
+    pub const INVALID_TOKEN: u32              = 1127;
+    pub const CYCLE_COMPLETE: u32             = 1128;
+    pub const CONTRIBUTION_NOT_RECORDED: u32  = 1129;
+    pub const MEMBER_SUSPENDED: u32           = 1130;
+    pub const REINIT_COOLDOWN_ACTIVE: u32     = 1131;
+    pub const CONTRACT_ADDRESS_NOT_ALLOWED: u32 = 1132;
+    pub const NOT_WHITELISTED: u32            = 1133;
+    pub const ENROLLMENT_CLOSED: u32          = 1134;
+    pub const NO_DEBT_RECORDED: u32           = 1135;
+    pub const CONTRIBUTIONS_PAUSED: u32       = 1136;
+    pub const DEPOSITS_PAUSED: u32            = 1137;
+    pub const ROUND_NOT_FULLY_PAID: u32       = 1138;
+    pub const INSUFFICIENT_BALANCE: u32       = 1139;
+    pub const PAYOUT_ALREADY_RECEIVED: u32    = 1140;
+    pub const NO_PENDING_PAYOUT: u32          = 1141;
+    pub const PAYOUT_STILL_HELD: u32          = 1142;
+    pub const PAYOUT_DISPUTED: u32            = 1143;
+    pub const NO_OPEN_DISPUTE: u32            = 1144;
+    pub const CYCLE_NOT_COMPLETE: u32         = 1145;
+    pub const ROUND_NOT_FOUND: u32            = 1146;
+    pub const METADATA_TOO_LARGE: u32         = 1147;
+    pub const INVALID_PAYOUT_WEIGHTS: u32     = 1148;
+    pub const ROUND_ALREADY_SETTLED: u32      = 1149;
+    pub const MEMO_TOO_LONG: u32              = 1150;
+    pub const NOT_AN_OPERATOR: u32            = 1151;
+    // ExtError3 variants
+    pub const STREAK_REWARD_ALREADY_CLAIMED: u32 = 1152;
+    pub const REMOVES_SCHEDULED_RECIPIENT: u32 = 1153;
+    pub const CONTRIBUTION_WINDOW_NOT_OPEN: u32 = 1154;
+    pub const INVALID_CONTRIBUTION_WINDOW: u32 = 1155;
+    pub const CANNOT_RESCUE_CONTRIBUTION_TOKEN: u32 = 1156;
+    pub const CONTRIBUTION_BELOW_MINIMUM: u32 = 1157;
+    pub const CONTRIBUTION_ABOVE_MAXIMUM: u32 = 1158;
+    pub const INVALID_CONTRIBUTION_BOUNDS: u32 = 1159;
+    pub const ROUND_ALREADY_CLOSED: u32       = 1160;
+    pub const NO_GUARDIAN_CONFIGURED: u32     = 1161;
+    pub const RECOVERY_DELAY_NOT_ELAPSED: u32 = 1162;
+    pub const POLL_NOT_FOUND: u32             = 1163;
+    pub const POLL_ALREADY_OPEN: u32          = 1164;
+    pub const STALE_PRICE: u32                = 1165;
+    pub const FUNDS_HELD: u32                 = 1166;
+    pub const NO_LATER_TURN: u32              = 1167;
+    pub const TVL_CAP_EXCEEDED: u32           = 1168;
+    pub const PULL_LIMIT_EXCEEDED: u32        = 1169;
+    pub const ROTATION_INCOMPLETE: u32        = 1170;
+    pub const NOTHING_TO_REFUND: u32          = 1171;
+    pub const POT_BELOW_MINIMUM: u32          = 1172;
+    pub const INVALID_SHARES: u32             = 1173;
+    pub const NO_ADMIN_TRANSFER_PROPOSED: u32 = 1174;
+    pub const AUCTION_ALREADY_OPEN: u32       = 1175;
+    pub const BID_ALREADY_COMMITTED: u32      = 1176;
+    pub const REVEAL_NOT_OPEN: u32            = 1177;
+    pub const BID_ALREADY_REVEALED: u32       = 1178;
+    pub const BID_EXCEEDS_DEPOSIT: u32        = 1179;
+    pub const COMMITMENT_MISMATCH: u32        = 1180;
+    pub const INVALID_THRESHOLD: u32          = 1181;
+    pub const CANNOT_VOTE_OWN_REQUEST: u32    = 1182;
+    pub const EMERGENCY_PAYOUT_NOT_FOUND: u32 = 1183;
+    pub const INVALID_EXPIRY: u32             = 1184;
+    pub const NO_DISSOLUTION_VOTE: u32        = 1185;
+    pub const ALREADY_ON_WAITLIST: u32        = 1186;
+    pub const NOT_ON_WAITLIST: u32            = 1187;
+    pub const GROUP_NOT_ACTIVE: u32           = 1188;
+    pub const SNAPSHOT_NOT_FOUND: u32         = 1189;
+    pub const TREASURY_NOT_ENABLED: u32       = 1190;
+    pub const RESERVE_NOT_ENABLED: u32        = 1191;
+    pub const INSUFFICIENT_RESERVE: u32       = 1192;
+    pub const LOAN_NOT_FOUND: u32             = 1193;
+    pub const NOT_THE_BORROWER: u32           = 1194;
+    pub const LOAN_DEFAULTED: u32             = 1195;
+    pub const RANDOMIZATION_NOT_ENABLED: u32  = 1196;
+    pub const PAYOUT_ORDER_FINALIZED: u32     = 1197;
 }
 
 // ---------------------------------------------------------------------------
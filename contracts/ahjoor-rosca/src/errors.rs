@@ -162,6 +162,10 @@ pub enum ExtError {
     SplitAlreadyConfirmed = 91,
     /// Not all members have confirmed; cannot execute split yet (#331).
     SplitNotFullyConfirmed = 92,
+    /// Member is not suspended by the admin.
+    MemberNotSuspended = 93,
+    /// Payout slot has already been paid out, or is being paid this round.
+    SlotAlreadyPaidOut = 94,
     /// Proxy has consumed all authorized rounds (#403).
     ProxyRoundsExhausted = 118,
 }
@@ -204,4 +208,172 @@ pub enum ExtError2 {
     ReceiptNotFound = 116,
     /// Member has already registered co-payer splits; revoke first.
     CopayerSplitsAlreadySet = 117,
+    // 118 is reserved: `ExtError::ProxyRoundsExhausted` already holds it.
+    /// Round has already completed; every active member has paid.
+    RoundAlreadyComplete = 119,
+    /// Per-member contribution amounts do not match the member list length.
+    MemberAmountsLengthMismatch = 120,
+    /// Contract holds no balance beyond what is accounted for.
+    NoSurplusBalance = 121,
+    /// Caller is not the scheduled recipient for the current round.
+    NotScheduledRecipient = 122,
+    /// An early payout has already been claimed for this round.
+    EarlyClaimAlreadyMade = 123,
+    /// Member has no auto-contribute rounds remaining.
+    AutoContributeNotEnabled = 124,
+    /// No pending slot swap with this id is addressed to this member.
+    NoPendingSwap = 125,
+    /// Contract has not been initialized.
+    NotInitialized = 126,
+    /// Token address does not implement the token interface.
+    InvalidToken = 127,
+    /// Every round of the cycle has been paid out; the group is complete.
+    CycleComplete = 128,
+    /// No contribution is recorded for this member in the given round.
+    ContributionNotRecorded = 129,
+    /// Member has been suspended by the admin.
+    MemberSuspended = 130,
+    /// The group was dissolved too recently to be initialized again.
+    ReinitCooldownActive = 131,
+    /// The contract's own address cannot be a member or the admin.
+    ContractAddressNotAllowed = 132,
+    /// Address has not been whitelisted to join this group.
+    NotWhitelisted = 133,
+    /// Self-service joining is closed once the first payout has happened.
+    EnrollmentClosed = 134,
+    /// Member has no recorded debt to settle.
+    NoDebtRecorded = 135,
+    /// Contributions are paused by the admin.
+    ContributionsPaused = 136,
+    /// Security deposits are paused by the admin.
+    DepositsPaused = 137,
+    /// Not every active member has paid for the current round.
+    RoundNotFullyPaid = 138,
+    /// Contributor's token balance does not cover the transfer.
+    InsufficientBalance = 139,
+    /// Member has already received a payout in this finite cycle.
+    PayoutAlreadyReceived = 140,
+    /// No held payout is waiting to be released.
+    NoPendingPayout = 141,
+    /// The payout delay has not elapsed yet.
+    PayoutStillHeld = 142,
+    /// A dispute is open; held payouts cannot be released.
+    PayoutDisputed = 143,
+    /// No dispute is open.
+    NoOpenDispute = 144,
+    /// The current cycle has not completed yet.
+    CycleNotComplete = 145,
+    /// No record exists for the requested round.
+    RoundNotFound = 146,
+    /// Member metadata exceeds the maximum size.
+    MetadataTooLarge = 147,
+    /// Payout weights must give every member a positive weight.
+    InvalidPayoutWeights = 148,
+    /// The round's pot has already been settled.
+    RoundAlreadySettled = 149,
+    /// Contribution memo exceeds the maximum length.
+    MemoTooLong = 150,
+    /// Caller is neither the admin nor an operator.
+    NotAnOperator = 151,
+}
+
+/// Extension error codes 152+ — overflow from ExtError2 (50-variant limit).
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExtError3 {
+    /// Streak reward has already been claimed this round.
+    StreakRewardAlreadyClaimed = 152,
+    /// The member is due the current round's payout and cannot be removed.
+    RemovesScheduledRecipient = 153,
+    /// Contribution window not open yet.
+    ContributionWindowNotOpen = 154,
+    /// Contribution window must open before it closes.
+    InvalidContributionWindow = 155,
+    /// Cannot rescue contribution token.
+    CannotRescueContributionToken = 156,
+    /// Contribution is below the group's minimum.
+    ContributionBelowMinimum = 157,
+    /// Contribution is above the group's maximum.
+    ContributionAboveMaximum = 158,
+    /// Minimum contribution exceeds the maximum.
+    InvalidContributionBounds = 159,
+    /// The round has already been closed.
+    RoundAlreadyClosed = 160,
+    /// No guardian is configured for admin recovery.
+    NoGuardianConfigured = 161,
+    /// The admin has been active too recently for recovery.
+    RecoveryDelayNotElapsed = 162,
+    /// No poll is open under this topic.
+    PollNotFound = 163,
+    /// A poll under this topic is still open.
+    PollAlreadyOpen = 164,
+    /// The oracle price is older than the allowed age.
+    StalePrice = 165,
+    /// Funds are still held in the current base token.
+    FundsHeld = 166,
+    /// No later slot remains in this pass of the payout order.
+    NoLaterTurn = 167,
+    /// TVL cap exceeded: the inflow would push tracked funds above MaxTvl.
+    TvlCapExceeded = 168,
+    /// The pull would take the member's cumulative pulls above their limit.
+    PullLimitExceeded = 169,
+    /// Not every active member has received exactly one payout this cycle.
+    RotationIncomplete = 170,
+    /// The member has no contribution in the current round to refund.
+    NothingToRefund = 171,
+    /// The accumulated pot is below the configured minimum payout pot.
+    PotBelowMinimum = 172,
+    /// Beneficiary shares must each be positive and sum to exactly 10000 bps.
+    InvalidShares = 173,
+    /// No admin transfer has been proposed.
+    NoAdminTransferProposed = 174,
+    /// A sealed slot auction is already open.
+    AuctionAlreadyOpen = 175,
+    /// Bidder has already committed a sealed bid this round.
+    BidAlreadyCommitted = 176,
+    /// The reveal phase of the sealed auction has not opened yet.
+    RevealNotOpen = 177,
+    /// The sealed bid has already been revealed.
+    BidAlreadyRevealed = 178,
+    /// Revealed bid exceeds the committed deposit.
+    BidExceedsDeposit = 179,
+    /// Revealed values do not match the stored commitment.
+    CommitmentMismatch = 180,
+    /// Quorum or approval threshold is out of range.
+    InvalidThreshold = 181,
+    /// A member cannot vote on their own emergency payout request.
+    CannotVoteOwnRequest = 182,
+    /// No emergency payout request exists for this id.
+    EmergencyPayoutNotFound = 183,
+    /// Delegation expiry must be a future ledger.
+    InvalidExpiry = 184,
+    /// No dissolution vote is in progress.
+    NoDissolutionVote = 185,
+    /// Address is already on the waitlist.
+    AlreadyOnWaitlist = 186,
+    /// Address is not on the waitlist.
+    NotOnWaitlist = 187,
+    /// Group is not in the Active state.
+    GroupNotActive = 188,
+    /// No snapshot exists for this id.
+    SnapshotNotFound = 189,
+    /// Treasury rounds are not enabled for this group.
+    TreasuryNotEnabled = 190,
+    /// Emergency reserve is not enabled for this group.
+    ReserveNotEnabled = 191,
+    /// Reserve balance cannot cover the requested loan.
+    InsufficientReserve = 192,
+    /// No loan exists for this id.
+    LoanNotFound = 193,
+    /// Only the borrower can repay this loan.
+    NotTheBorrower = 194,
+    /// Loan has defaulted and can no longer be repaid.
+    LoanDefaulted = 195,
+    /// Payout order randomization is not enabled for this group.
+    RandomizationNotEnabled = 196,
+    /// Payout order has already been finalized.
+    PayoutOrderFinalized = 197,
+    /// Part of the contribution was paid in a token other than the base
+    /// token, so it cannot be refunded.
+    RefundNotInBaseToken = 198,
 }
@@ -1,10 +1,18 @@
-use crate::DistributionType;
-use soroban_sdk::{contractevent, Address, BytesN, Env, Symbol, Vec};
+use crate::{
+    DistributionType, EjectionPolicy, EndOfCyclePolicy, RoscaConfig, RoundSummary, SlotBeneficiary,
+};
+use soroban_sdk::{contractevent, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+/// Layout version of the events below, published as the first topic after
+/// the event name. Bump it whenever an event's topics or data change.
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
 
 /// Event: Rosca initialized
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct RoscaInitialized {
+    #[topic]
+    pub schema_version: u32,
     pub member_count: u32,
     pub contribution_amount: i128,
 }
@@ -13,6 +21,8 @@ pub struct RoscaInitialized {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct GroupActivated {
+    #[topic]
+    pub schema_version: u32,
     pub start_at: u64,
 }
 
@@ -20,6 +30,8 @@ pub struct GroupActivated {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ContributionReceived {
+    #[topic]
+    pub schema_version: u32,
     pub contributor: Address,
     pub round: u32,
     pub token: Address,
@@ -30,6 +42,8 @@ pub struct ContributionReceived {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct SavingsMilestoneReached {
+    #[topic]
+    pub schema_version: u32,
     pub milestone: u32,
     pub total_collected: i128,
 }
@@ -38,14 +52,19 @@ pub struct SavingsMilestoneReached {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct RoundClosed {
+    #[topic]
+    pub schema_version: u32,
     pub round: u32,
     pub defaulters: Vec<Address>,
+    pub summary: RoundSummary,
 }
 
 /// Event: Payout order finalized via randomization (#315)
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct PayoutOrderFinalized {
+    #[topic]
+    pub schema_version: u32,
     pub round: u32,
     pub payout_order: Vec<Address>,
 }
@@ -54,6 +73,8 @@ pub struct PayoutOrderFinalized {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct MemberDefaulted {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub round: u32,
     pub penalty_amount: i128,
@@ -64,6 +85,8 @@ pub struct MemberDefaulted {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct MemberSuspended {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub default_count: u32,
 }
@@ -72,6 +95,8 @@ pub struct MemberSuspended {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct MemberAdded {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub member_count: u32,
 }
@@ -80,6 +105,8 @@ pub struct MemberAdded {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct MemberRemoved {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub member_count: u32,
 }
@@ -88,6 +115,8 @@ pub struct MemberRemoved {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct TokenApproved {
+    #[topic]
+    pub schema_version: u32,
     pub token: Address,
 }
 
@@ -95,6 +124,8 @@ pub struct TokenApproved {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct TokenRemoved {
+    #[topic]
+    pub schema_version: u32,
     pub token: Address,
 }
 
@@ -102,6 +133,8 @@ pub struct TokenRemoved {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ExchangeRateSet {
+    #[topic]
+    pub schema_version: u32,
     pub token: Address,
     pub rate: i128,
 }
@@ -110,6 +143,8 @@ pub struct ExchangeRateSet {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct TokenLimitSet {
+    #[topic]
+    pub schema_version: u32,
     pub token: Address,
     pub limit: i128,
 }
@@ -118,6 +153,8 @@ pub struct TokenLimitSet {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct RewardDeposited {
+    #[topic]
+    pub schema_version: u32,
     pub depositor: Address,
     pub amount: i128,
 }
@@ -126,6 +163,8 @@ pub struct RewardDeposited {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct RewardConfigUpdated {
+    #[topic]
+    pub schema_version: u32,
     pub dist_type: DistributionType,
 }
 
@@ -133,6 +172,8 @@ pub struct RewardConfigUpdated {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct RewardClaimed {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub amount: i128,
 }
@@ -141,6 +182,8 @@ pub struct RewardClaimed {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ProposalCreated {
+    #[topic]
+    pub schema_version: u32,
     pub proposal_id: u32,
     pub creator: Address,
     pub target_member: Address,
@@ -152,6 +195,8 @@ pub struct ProposalCreated {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct VoteCast {
+    #[topic]
+    pub schema_version: u32,
     pub proposal_id: u32,
     pub voter: Address,
     pub vote_for: bool,
@@ -161,6 +206,8 @@ pub struct VoteCast {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct WeightedVoteCast {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub proposal_id: u32,
     pub weight: i128,
@@ -170,6 +217,8 @@ pub struct WeightedVoteCast {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ProposalRejected {
+    #[topic]
+    pub schema_version: u32,
     pub proposal_id: u32,
     pub reason: Symbol,
     pub votes_for: i128,
@@ -180,6 +229,8 @@ pub struct ProposalRejected {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ProposalExecuted {
+    #[topic]
+    pub schema_version: u32,
     pub proposal_id: u32,
     pub proposal_type: u32,
     pub target_member: Address,
@@ -189,6 +240,8 @@ pub struct ProposalExecuted {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct PenaltyAppealApproved {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
 }
 
@@ -196,6 +249,8 @@ pub struct PenaltyAppealApproved {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct QuorumUpdated {
+    #[topic]
+    pub schema_version: u32,
     pub new_quorum: i128,
 }
 
@@ -203,6 +258,8 @@ pub struct QuorumUpdated {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct QuorumConfigUpdated {
+    #[topic]
+    pub schema_version: u32,
     pub proposal_type: crate::ProposalType,
     pub quorum_bps: u32,
 }
@@ -211,6 +268,8 @@ pub struct QuorumConfigUpdated {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct MemberRemovalExecuted {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
 }
 
@@ -218,6 +277,8 @@ pub struct MemberRemovalExecuted {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct DeadlineReminder {
+    #[topic]
+    pub schema_version: u32,
     pub round: u32,
     pub time_remaining: u64,
     pub non_contributors: Vec<Address>,
@@ -228,6 +289,8 @@ pub struct DeadlineReminder {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ContractPaused {
+    #[topic]
+    pub schema_version: u32,
     pub reason: soroban_sdk::String,
 }
 
@@ -235,6 +298,8 @@ pub struct ContractPaused {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ContractResumed {
+    #[topic]
+    pub schema_version: u32,
     pub reason: soroban_sdk::String,
 }
 
@@ -242,6 +307,8 @@ pub struct ContractResumed {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct RoundFinalized {
+    #[topic]
+    pub schema_version: u32,
     pub round: u32,
     pub defaulters: Vec<Address>,
 }
@@ -250,6 +317,8 @@ pub struct RoundFinalized {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ExitRequested {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub round: u32,
 }
@@ -258,6 +327,8 @@ pub struct ExitRequested {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ExitApproved {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub refund_amount: i128,
 }
@@ -266,6 +337,8 @@ pub struct ExitApproved {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ExitRejected {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
 }
 
@@ -273,6 +346,8 @@ pub struct ExitRejected {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct RoundCompleted {
+    #[topic]
+    pub schema_version: u32,
     pub round: u32,
     pub recipient: Address,
     pub payout_amount: i128,
@@ -282,6 +357,8 @@ pub struct RoundCompleted {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct PayoutReinvested {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub round: u32,
     pub amount: i128,
@@ -291,6 +368,8 @@ pub struct PayoutReinvested {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct RoundSkipRequested {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub round: u32,
     pub fee_paid: i128,
@@ -300,6 +379,8 @@ pub struct RoundSkipRequested {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct FeeCollected {
+    #[topic]
+    pub schema_version: u32,
     pub round: u32,
     pub fee_amount: i128,
     pub fee_recipient: Address,
@@ -309,16 +390,31 @@ pub struct FeeCollected {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct PartialContributionReceived {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub round: u32,
     pub amount: i128,
     pub remaining: i128,
 }
 
+/// Event: Partial contribution refunded when the round closed short
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct PartialContributionRefunded {
+    #[topic]
+    pub schema_version: u32,
+    pub member: Address,
+    pub round: u32,
+    pub amount: i128,
+}
+
 /// Event: Suspension threshold configured
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct SuspensionThresholdSet {
+    #[topic]
+    pub schema_version: u32,
     pub max_defaults: u32,
 }
 
@@ -326,6 +422,8 @@ pub struct SuspensionThresholdSet {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct GracePeriodWarning {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub round: u32,
     pub expires_at_ledger: u64,
@@ -335,6 +433,8 @@ pub struct GracePeriodWarning {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ReputationUpdated {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub old_score: i128,
     pub new_score: i128,
@@ -345,6 +445,8 @@ pub struct ReputationUpdated {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct RoundReset {
+    #[topic]
+    pub schema_version: u32,
     pub round: u32,
 }
 
@@ -352,6 +454,8 @@ pub struct RoundReset {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct AdminTransferProposed {
+    #[topic]
+    pub schema_version: u32,
     pub current_admin: Address,
     pub proposed_admin: Address,
 }
@@ -360,6 +464,8 @@ pub struct AdminTransferProposed {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct AdminTransferred {
+    #[topic]
+    pub schema_version: u32,
     pub old_admin: Address,
     pub new_admin: Address,
 }
@@ -368,15 +474,29 @@ pub struct AdminTransferred {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ContractUpgraded {
+    #[topic]
+    pub schema_version: u32,
     pub old_version: u32,
     pub new_version: u32,
     pub by_admin: Address,
 }
 
+/// Event: Storage migrated; missing keys backfilled with defaults
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct StateMigrated {
+    #[topic]
+    pub schema_version: u32,
+    pub version: u32,
+    pub keys_backfilled: u32,
+}
+
 /// Event: Member contribution tier updated
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct MemberTierSet {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub tier_bps: u32,
 }
@@ -387,6 +507,8 @@ pub struct MemberTierSet {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct RoundDeadlineTimestampSet {
+    #[topic]
+    pub schema_version: u32,
     pub round: u32,
     pub timestamp: u64,
 }
@@ -395,12 +517,15 @@ pub struct RoundDeadlineTimestampSet {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct MaxMembersUpdated {
+    #[topic]
+    pub schema_version: u32,
     pub old_max: u32,
     pub new_max: u32,
 }
 
 pub fn emit_rosc_init(e: &Env, member_count: u32, contribution_amount: i128) {
     RoscaInitialized {
+        schema_version: EVENT_SCHEMA_VERSION,
         member_count,
         contribution_amount,
     }
@@ -408,23 +533,24 @@ pub fn emit_rosc_init(e: &Env, member_count: u32, contribution_amount: i128) {
 }
 
 pub fn emit_group_activated(e: &Env, start_at: u64) {
-    GroupActivated { start_at }.publish(e);
+    GroupActivated { schema_version: EVENT_SCHEMA_VERSION, start_at }.publish(e);
 }
 
 pub fn emit_round_deadline_timestamp_set(e: &Env, round: u32, timestamp: u64) {
-    RoundDeadlineTimestampSet { round, timestamp }.publish(e);
+    RoundDeadlineTimestampSet { schema_version: EVENT_SCHEMA_VERSION, round, timestamp }.publish(e);
 }
 
 pub fn emit_max_members_upd(e: &Env, old_max: u32, new_max: u32) {
-    MaxMembersUpdated { old_max, new_max }.publish(e);
+    MaxMembersUpdated { schema_version: EVENT_SCHEMA_VERSION, old_max, new_max }.publish(e);
 }
 
 pub fn emit_member_tier_set(e: &Env, member: Address, tier_bps: u32) {
-    MemberTierSet { member, tier_bps }.publish(e);
+    MemberTierSet { schema_version: EVENT_SCHEMA_VERSION, member, tier_bps }.publish(e);
 }
 
 pub fn emit_contrib(e: &Env, contributor: Address, round: u32, token: Address, amount: i128) {
     ContributionReceived {
+        schema_version: EVENT_SCHEMA_VERSION,
         contributor,
         round,
         token,
@@ -435,18 +561,25 @@ pub fn emit_contrib(e: &Env, contributor: Address, round: u32, token: Address, a
 
 pub fn emit_milestone(e: &Env, milestone: u32, total_collected: i128) {
     SavingsMilestoneReached {
+        schema_version: EVENT_SCHEMA_VERSION,
         milestone,
         total_collected,
     }
     .publish(e);
 }
 
-pub fn emit_closed(e: &Env, round: u32, defaulters: Vec<Address>) {
-    RoundClosed { round, defaulters }.publish(e);
+pub fn emit_closed(e: &Env, round: u32, defaulters: Vec<Address>, summary: RoundSummary) {
+    RoundClosed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round,
+        defaulters,
+        summary,
+    }
+    .publish(e);
 }
 
 pub fn emit_payout_order_finalized(e: &Env, round: u32, payout_order: Vec<Address>) {
-    PayoutOrderFinalized { round, payout_order }.publish(e);
+    PayoutOrderFinalized { schema_version: EVENT_SCHEMA_VERSION, round, payout_order }.publish(e);
 }
 
 pub fn emit_defaulted(
@@ -457,6 +590,7 @@ pub fn emit_defaulted(
     default_count: u32,
 ) {
     MemberDefaulted {
+        schema_version: EVENT_SCHEMA_VERSION,
         member,
         round,
         penalty_amount,
@@ -467,6 +601,7 @@ pub fn emit_defaulted(
 
 pub fn emit_suspended(e: &Env, member: Address, default_count: u32) {
     MemberSuspended {
+        schema_version: EVENT_SCHEMA_VERSION,
         member,
         default_count,
     }
@@ -475,6 +610,7 @@ pub fn emit_suspended(e: &Env, member: Address, default_count: u32) {
 
 pub fn emit_mem_add(e: &Env, member: Address, member_count: u32) {
     MemberAdded {
+        schema_version: EVENT_SCHEMA_VERSION,
         member,
         member_count,
     }
@@ -483,6 +619,7 @@ pub fn emit_mem_add(e: &Env, member: Address, member_count: u32) {
 
 pub fn emit_mem_rmv(e: &Env, member: Address, member_count: u32) {
     MemberRemoved {
+        schema_version: EVENT_SCHEMA_VERSION,
         member,
         member_count,
     }
@@ -490,31 +627,31 @@ pub fn emit_mem_rmv(e: &Env, member: Address, member_count: u32) {
 }
 
 pub fn emit_tok_add(e: &Env, token: Address) {
-    TokenApproved { token }.publish(e);
+    TokenApproved { schema_version: EVENT_SCHEMA_VERSION, token }.publish(e);
 }
 
 pub fn emit_tok_rmv(e: &Env, token: Address) {
-    TokenRemoved { token }.publish(e);
+    TokenRemoved { schema_version: EVENT_SCHEMA_VERSION, token }.publish(e);
 }
 
 pub fn emit_rate_set(e: &Env, token: Address, rate: i128) {
-    ExchangeRateSet { token, rate }.publish(e);
+    ExchangeRateSet { schema_version: EVENT_SCHEMA_VERSION, token, rate }.publish(e);
 }
 
 pub fn emit_lim_set(e: &Env, token: Address, limit: i128) {
-    TokenLimitSet { token, limit }.publish(e);
+    TokenLimitSet { schema_version: EVENT_SCHEMA_VERSION, token, limit }.publish(e);
 }
 
 pub fn emit_rew_dep(e: &Env, depositor: Address, amount: i128) {
-    RewardDeposited { depositor, amount }.publish(e);
+    RewardDeposited { schema_version: EVENT_SCHEMA_VERSION, depositor, amount }.publish(e);
 }
 
 pub fn emit_rew_cfg(e: &Env, dist_type: DistributionType) {
-    RewardConfigUpdated { dist_type }.publish(e);
+    RewardConfigUpdated { schema_version: EVENT_SCHEMA_VERSION, dist_type }.publish(e);
 }
 
 pub fn emit_rew_clm(e: &Env, member: Address, amount: i128) {
-    RewardClaimed { member, amount }.publish(e);
+    RewardClaimed { schema_version: EVENT_SCHEMA_VERSION, member, amount }.publish(e);
 }
 
 pub fn emit_prop_new(
@@ -526,6 +663,7 @@ pub fn emit_prop_new(
     deadline: u64,
 ) {
     ProposalCreated {
+        schema_version: EVENT_SCHEMA_VERSION,
         proposal_id,
         creator,
         target_member,
@@ -537,6 +675,7 @@ pub fn emit_prop_new(
 
 pub fn emit_voted(e: &Env, proposal_id: u32, voter: Address, vote_for: bool) {
     VoteCast {
+        schema_version: EVENT_SCHEMA_VERSION,
         proposal_id,
         voter,
         vote_for,
@@ -546,6 +685,7 @@ pub fn emit_voted(e: &Env, proposal_id: u32, voter: Address, vote_for: bool) {
 
 pub fn emit_weighted_vote_cast(e: &Env, member: Address, proposal_id: u32, weight: i128) {
     WeightedVoteCast {
+        schema_version: EVENT_SCHEMA_VERSION,
         member,
         proposal_id,
         weight,
@@ -561,6 +701,7 @@ pub fn emit_prop_rej(
     votes_against: i128,
 ) {
     ProposalRejected {
+        schema_version: EVENT_SCHEMA_VERSION,
         proposal_id,
         reason,
         votes_for,
@@ -571,6 +712,7 @@ pub fn emit_prop_rej(
 
 pub fn emit_prop_exec(e: &Env, proposal_id: u32, proposal_type: u32, target_member: Address) {
     ProposalExecuted {
+        schema_version: EVENT_SCHEMA_VERSION,
         proposal_id,
         proposal_type,
         target_member,
@@ -579,15 +721,16 @@ pub fn emit_prop_exec(e: &Env, proposal_id: u32, proposal_type: u32, target_memb
 }
 
 pub fn emit_appeal_ok(e: &Env, member: Address) {
-    PenaltyAppealApproved { member }.publish(e);
+    PenaltyAppealApproved { schema_version: EVENT_SCHEMA_VERSION, member }.publish(e);
 }
 
 pub fn emit_rule_upd(e: &Env, new_quorum: i128) {
-    QuorumUpdated { new_quorum }.publish(e);
+    QuorumUpdated { schema_version: EVENT_SCHEMA_VERSION, new_quorum }.publish(e);
 }
 
 pub fn emit_quorum_config_updated(e: &Env, proposal_type: crate::ProposalType, quorum_bps: u32) {
     QuorumConfigUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
         proposal_type,
         quorum_bps,
     }
@@ -595,7 +738,7 @@ pub fn emit_quorum_config_updated(e: &Env, proposal_type: crate::ProposalType, q
 }
 
 pub fn emit_mem_del(e: &Env, member: Address) {
-    MemberRemovalExecuted { member }.publish(e);
+    MemberRemovalExecuted { schema_version: EVENT_SCHEMA_VERSION, member }.publish(e);
 }
 
 pub fn emit_reminder(
@@ -606,6 +749,7 @@ pub fn emit_reminder(
     interval: Symbol,
 ) {
     DeadlineReminder {
+        schema_version: EVENT_SCHEMA_VERSION,
         round,
         time_remaining,
         non_contributors,
@@ -615,23 +759,24 @@ pub fn emit_reminder(
 }
 
 pub fn emit_paused(e: &Env, reason: soroban_sdk::String) {
-    ContractPaused { reason }.publish(e);
+    ContractPaused { schema_version: EVENT_SCHEMA_VERSION, reason }.publish(e);
 }
 
 pub fn emit_resumed(e: &Env, reason: soroban_sdk::String) {
-    ContractResumed { reason }.publish(e);
+    ContractResumed { schema_version: EVENT_SCHEMA_VERSION, reason }.publish(e);
 }
 
 pub fn emit_round_finalized(e: &Env, round: u32, defaulters: Vec<Address>) {
-    RoundFinalized { round, defaulters }.publish(e);
+    RoundFinalized { schema_version: EVENT_SCHEMA_VERSION, round, defaulters }.publish(e);
 }
 
 pub fn emit_exit_req(e: &Env, member: Address, round: u32) {
-    ExitRequested { member, round }.publish(e);
+    ExitRequested { schema_version: EVENT_SCHEMA_VERSION, member, round }.publish(e);
 }
 
 pub fn emit_exit_ok(e: &Env, member: Address, refund_amount: i128) {
     ExitApproved {
+        schema_version: EVENT_SCHEMA_VERSION,
         member,
         refund_amount,
     }
@@ -639,11 +784,12 @@ pub fn emit_exit_ok(e: &Env, member: Address, refund_amount: i128) {
 }
 
 pub fn emit_exit_no(e: &Env, member: Address) {
-    ExitRejected { member }.publish(e);
+    ExitRejected { schema_version: EVENT_SCHEMA_VERSION, member }.publish(e);
 }
 
 pub fn emit_rd_done(e: &Env, round: u32, recipient: Address, payout_amount: i128) {
     RoundCompleted {
+        schema_version: EVENT_SCHEMA_VERSION,
         round,
         recipient,
         payout_amount,
@@ -653,6 +799,7 @@ pub fn emit_rd_done(e: &Env, round: u32, recipient: Address, payout_amount: i128
 
 pub fn emit_payout_reinvested(e: &Env, member: Address, round: u32, amount: i128) {
     PayoutReinvested {
+        schema_version: EVENT_SCHEMA_VERSION,
         member,
         round,
         amount,
@@ -661,11 +808,12 @@ pub fn emit_payout_reinvested(e: &Env, member: Address, round: u32, amount: i128
 }
 
 pub fn emit_reset(e: &Env, round: u32) {
-    RoundReset { round }.publish(e);
+    RoundReset { schema_version: EVENT_SCHEMA_VERSION, round }.publish(e);
 }
 
 pub fn emit_admin_transfer_proposed(e: &Env, current_admin: Address, proposed_admin: Address) {
     AdminTransferProposed {
+        schema_version: EVENT_SCHEMA_VERSION,
         current_admin,
         proposed_admin,
     }
@@ -674,6 +822,7 @@ pub fn emit_admin_transfer_proposed(e: &Env, current_admin: Address, proposed_ad
 
 pub fn emit_admin_transferred(e: &Env, old_admin: Address, new_admin: Address) {
     AdminTransferred {
+        schema_version: EVENT_SCHEMA_VERSION,
         old_admin,
         new_admin,
     }
@@ -682,6 +831,7 @@ pub fn emit_admin_transferred(e: &Env, old_admin: Address, new_admin: Address) {
 
 pub fn emit_contract_upgraded(e: &Env, old_version: u32, new_version: u32, by_admin: Address) {
     ContractUpgraded {
+        schema_version: EVENT_SCHEMA_VERSION,
         old_version,
         new_version,
         by_admin,
@@ -689,8 +839,18 @@ pub fn emit_contract_upgraded(e: &Env, old_version: u32, new_version: u32, by_ad
     .publish(e);
 }
 
+pub fn emit_state_migrated(e: &Env, version: u32, keys_backfilled: u32) {
+    StateMigrated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        version,
+        keys_backfilled,
+    }
+    .publish(e);
+}
+
 pub fn emit_fee_collected(e: &Env, round: u32, fee_amount: i128, fee_recipient: Address) {
     FeeCollected {
+        schema_version: EVENT_SCHEMA_VERSION,
         round,
         fee_amount,
         fee_recipient,
@@ -700,6 +860,7 @@ pub fn emit_fee_collected(e: &Env, round: u32, fee_amount: i128, fee_recipient:
 
 pub fn emit_partial_contribution(e: &Env, member: Address, round: u32, amount: i128, remaining: i128) {
     PartialContributionReceived {
+        schema_version: EVENT_SCHEMA_VERSION,
         member,
         round,
         amount,
@@ -708,12 +869,17 @@ pub fn emit_partial_contribution(e: &Env, member: Address, round: u32, amount: i
     .publish(e);
 }
 
+pub fn emit_partial_refunded(e: &Env, member: Address, round: u32, amount: i128) {
+    PartialContributionRefunded { schema_version: EVENT_SCHEMA_VERSION, member, round, amount }.publish(e);
+}
+
 pub fn emit_suspension_threshold_set(e: &Env, max_defaults: u32) {
-    SuspensionThresholdSet { max_defaults }.publish(e);
+    SuspensionThresholdSet { schema_version: EVENT_SCHEMA_VERSION, max_defaults }.publish(e);
 }
 
 pub fn emit_grace_period_warning(e: &Env, member: Address, round: u32, expires_at_ledger: u64) {
     GracePeriodWarning {
+        schema_version: EVENT_SCHEMA_VERSION,
         member,
         round,
         expires_at_ledger,
@@ -729,6 +895,7 @@ pub fn emit_reputation_updated(
     reason: Symbol,
 ) {
     ReputationUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
         member,
         old_score,
         new_score,
@@ -743,6 +910,8 @@ pub fn emit_reputation_updated(
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct VoteDelegated {
+    #[topic]
+    pub schema_version: u32,
     pub delegator: Address,
     pub delegate: Address,
 }
@@ -751,15 +920,17 @@ pub struct VoteDelegated {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct DelegationRevoked {
+    #[topic]
+    pub schema_version: u32,
     pub delegator: Address,
 }
 
 pub fn emit_vote_delegated(e: &Env, delegator: Address, delegate: Address) {
-    VoteDelegated { delegator, delegate }.publish(e);
+    VoteDelegated { schema_version: EVENT_SCHEMA_VERSION, delegator, delegate }.publish(e);
 }
 
 pub fn emit_delegation_revoked(e: &Env, delegator: Address) {
-    DelegationRevoked { delegator }.publish(e);
+    DelegationRevoked { schema_version: EVENT_SCHEMA_VERSION, delegator }.publish(e);
 }
 
 // --- Auto-Close Round Events ---
@@ -768,12 +939,14 @@ pub fn emit_delegation_revoked(e: &Env, delegator: Address) {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct RoundAutoClosedEarly {
+    #[topic]
+    pub schema_version: u32,
     pub round: u32,
     pub closed_at_ledger: u64,
 }
 
 pub fn emit_round_auto_closed_early(e: &Env, round: u32, closed_at_ledger: u64) {
-    RoundAutoClosedEarly { round, closed_at_ledger }.publish(e);
+    RoundAutoClosedEarly { schema_version: EVENT_SCHEMA_VERSION, round, closed_at_ledger }.publish(e);
 }
 
 // --- Invitation-Based Member Joining Events ---
@@ -782,6 +955,8 @@ pub fn emit_round_auto_closed_early(e: &Env, round: u32, closed_at_ledger: u64)
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct InviteGenerated {
+    #[topic]
+    pub schema_version: u32,
     pub invitee: Address,
     pub expires_at: u64,
 }
@@ -790,15 +965,17 @@ pub struct InviteGenerated {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct InviteRedeemed {
+    #[topic]
+    pub schema_version: u32,
     pub invitee: Address,
 }
 
 pub fn emit_invite_generated(e: &Env, invitee: Address, expires_at: u64) {
-    InviteGenerated { invitee, expires_at }.publish(e);
+    InviteGenerated { schema_version: EVENT_SCHEMA_VERSION, invitee, expires_at }.publish(e);
 }
 
 pub fn emit_invite_redeemed(e: &Env, invitee: Address) {
-    InviteRedeemed { invitee }.publish(e);
+    InviteRedeemed { schema_version: EVENT_SCHEMA_VERSION, invitee }.publish(e);
 }
 
 // --- Admin Multi-Sig Events ---
@@ -807,6 +984,8 @@ pub fn emit_invite_redeemed(e: &Env, invitee: Address) {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct AdminActionProposed {
+    #[topic]
+    pub schema_version: u32,
     pub action_id: u32,
     pub action_type: Symbol,
     pub proposed_by: Address,
@@ -816,6 +995,8 @@ pub struct AdminActionProposed {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct AdminActionApproved {
+    #[topic]
+    pub schema_version: u32,
     pub action_id: u32,
     pub approved_by: Address,
     pub approval_count: u32,
@@ -825,20 +1006,22 @@ pub struct AdminActionApproved {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct AdminActionExecuted {
+    #[topic]
+    pub schema_version: u32,
     pub action_id: u32,
     pub action_type: Symbol,
 }
 
 pub fn emit_admin_action_proposed(e: &Env, action_id: u32, action_type: Symbol, proposed_by: Address) {
-    AdminActionProposed { action_id, action_type, proposed_by }.publish(e);
+    AdminActionProposed { schema_version: EVENT_SCHEMA_VERSION, action_id, action_type, proposed_by }.publish(e);
 }
 
 pub fn emit_admin_action_approved(e: &Env, action_id: u32, approved_by: Address, approval_count: u32) {
-    AdminActionApproved { action_id, approved_by, approval_count }.publish(e);
+    AdminActionApproved { schema_version: EVENT_SCHEMA_VERSION, action_id, approved_by, approval_count }.publish(e);
 }
 
 pub fn emit_admin_action_executed(e: &Env, action_id: u32, action_type: Symbol) {
-    AdminActionExecuted { action_id, action_type }.publish(e);
+    AdminActionExecuted { schema_version: EVENT_SCHEMA_VERSION, action_id, action_type }.publish(e);
 }
 
 // --- Insurance Pool Events ---
@@ -847,6 +1030,8 @@ pub fn emit_admin_action_executed(e: &Env, action_id: u32, action_type: Symbol)
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct InsurancePoolTopUp {
+    #[topic]
+    pub schema_version: u32,
     pub contributor: Address,
     pub amount: i128,
 }
@@ -855,21 +1040,23 @@ pub struct InsurancePoolTopUp {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct InsurancePaidOut {
+    #[topic]
+    pub schema_version: u32,
     pub round: u32,
     pub shortfall: i128,
     pub remaining_pool: i128,
 }
 
 pub fn emit_insurance_top_up(e: &Env, contributor: Address, amount: i128) {
-    InsurancePoolTopUp { contributor, amount }.publish(e);
+    InsurancePoolTopUp { schema_version: EVENT_SCHEMA_VERSION, contributor, amount }.publish(e);
 }
 
 pub fn emit_insurance_paid_out(e: &Env, round: u32, shortfall: i128, remaining_pool: i128) {
-    InsurancePaidOut { round, shortfall, remaining_pool }.publish(e);
+    InsurancePaidOut { schema_version: EVENT_SCHEMA_VERSION, round, shortfall, remaining_pool }.publish(e);
 }
 
 pub fn emit_round_skip_requested(e: &Env, member: Address, round: u32, fee_paid: i128) {
-    RoundSkipRequested { member, round, fee_paid }.publish(e);
+    RoundSkipRequested { schema_version: EVENT_SCHEMA_VERSION, member, round, fee_paid }.publish(e);
 }
 
 // --- Audit Trail Events ---
@@ -878,6 +1065,8 @@ pub fn emit_round_skip_requested(e: &Env, member: Address, round: u32, fee_paid:
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct CycleRecordCreated {
+    #[topic]
+    pub schema_version: u32,
     pub cycle_number: u32,
     pub total_pool_amount: i128,
     pub payout_recipient: Address,
@@ -887,6 +1076,8 @@ pub struct CycleRecordCreated {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct CycleRecordArchived {
+    #[topic]
+    pub schema_version: u32,
     pub cycle_number: u32,
 }
 
@@ -894,39 +1085,67 @@ pub struct CycleRecordArchived {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct RetentionWindowUpdated {
+    #[topic]
+    pub schema_version: u32,
     pub old_window: u32,
     pub new_window: u32,
 }
 
 pub fn emit_cycle_record_created(e: &Env, cycle_number: u32, total_pool_amount: i128, payout_recipient: Address) {
-    CycleRecordCreated { cycle_number, total_pool_amount, payout_recipient }.publish(e);
+    CycleRecordCreated { schema_version: EVENT_SCHEMA_VERSION, cycle_number, total_pool_amount, payout_recipient }.publish(e);
 }
 
 pub fn emit_cycle_record_archived(e: &Env, cycle_number: u32) {
-    CycleRecordArchived { cycle_number }.publish(e);
+    CycleRecordArchived { schema_version: EVENT_SCHEMA_VERSION, cycle_number }.publish(e);
 }
 
 pub fn emit_retention_window_updated(e: &Env, old_window: u32, new_window: u32) {
-    RetentionWindowUpdated { old_window, new_window }.publish(e);
+    RetentionWindowUpdated { schema_version: EVENT_SCHEMA_VERSION, old_window, new_window }.publish(e);
+}
+
+/// Event: Member joined or left the waitlist
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct WaitlistUpdated {
+    #[topic]
+    pub schema_version: u32,
+    pub member: Address,
+    pub joined: bool,
+    pub size: u32,
 }
 
 pub fn emit_waitlist_updated(e: &Env, member: Address, joined: bool, size: u32) {
-    e.events()
-        .publish((Symbol::new(e, "WaitlistUpd"),), (member, joined, size));
+    WaitlistUpdated { schema_version: EVENT_SCHEMA_VERSION, member, joined, size }.publish(e);
 }
 
-pub fn emit_member_enrolled_from_waitlist(
-    e: &Env,
-    member: Address,
-    vacated_by: Address,
-    round: u32,
-    catch_up_amount: i128,
-) {
-    e.events()
-        .publish(
-            (Symbol::new(e, "WaitEnroll"),),
-            (member, vacated_by, round, catch_up_amount),
-        );
+/// Event: Waitlist order changed after a promotion; `order_hash` is the
+/// SHA-256 of the remaining addresses in order
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct WaitlistOrderUpdated {
+    #[topic]
+    pub schema_version: u32,
+    pub order_hash: BytesN<32>,
+}
+
+pub fn emit_waitlist_order_updated(e: &Env, order_hash: BytesN<32>) {
+    WaitlistOrderUpdated { schema_version: EVENT_SCHEMA_VERSION, order_hash }.publish(e);
+}
+
+/// Event: Waitlisted address enrolled into a vacated slot
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct MemberEnrolledFromWaitlist {
+    #[topic]
+    pub schema_version: u32,
+    pub member: Address,
+    pub vacated_by: Address,
+    pub round: u32,
+    pub catch_up_amount: i128,
+}
+
+pub fn emit_member_enrolled_from_waitlist(e: &Env, member: Address, vacated_by: Address, round: u32, catch_up_amount: i128) {
+    MemberEnrolledFromWaitlist { schema_version: EVENT_SCHEMA_VERSION, member, vacated_by, round, catch_up_amount }.publish(e);
 }
 
 // --- Emergency Payout Events ---
@@ -935,6 +1154,8 @@ pub fn emit_member_enrolled_from_waitlist(
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct EmergencyPayoutRequested {
+    #[topic]
+    pub schema_version: u32,
     pub requester: Address,
     pub round: u32,
     pub reason_hash: BytesN<32>,
@@ -945,6 +1166,8 @@ pub struct EmergencyPayoutRequested {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct EmergencyPayoutVoteCast {
+    #[topic]
+    pub schema_version: u32,
     pub requester: Address,
     pub round: u32,
     pub voter: Address,
@@ -957,6 +1180,8 @@ pub struct EmergencyPayoutVoteCast {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct EmergencyPayoutApproved {
+    #[topic]
+    pub schema_version: u32,
     pub requester: Address,
     pub round: u32,
     pub payout_amount: i128,
@@ -966,6 +1191,8 @@ pub struct EmergencyPayoutApproved {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct EmergencyPayoutRejected {
+    #[topic]
+    pub schema_version: u32,
     pub requester: Address,
     pub round: u32,
     pub reason: Symbol,
@@ -975,6 +1202,8 @@ pub struct EmergencyPayoutRejected {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct EmergencyPayoutExecuted {
+    #[topic]
+    pub schema_version: u32,
     pub requester: Address,
     pub round: u32,
     pub payout_amount: i128,
@@ -984,6 +1213,8 @@ pub struct EmergencyPayoutExecuted {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct EmergencyPayoutConfigUpdated {
+    #[topic]
+    pub schema_version: u32,
     pub emergency_quorum_bps: u32,
     pub vote_window_seconds: u64,
     pub max_emergency_per_cycle: u32,
@@ -995,6 +1226,8 @@ pub struct EmergencyPayoutConfigUpdated {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct DissolutionVoteStarted {
+    #[topic]
+    pub schema_version: u32,
     pub round: u32,
     pub deadline: u64,
 }
@@ -1003,6 +1236,8 @@ pub struct DissolutionVoteStarted {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct DissolutionVoteCast {
+    #[topic]
+    pub schema_version: u32,
     pub round: u32,
     pub voter: Address,
     pub approve: bool,
@@ -1013,6 +1248,8 @@ pub struct DissolutionVoteCast {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct DissolutionQuorumReached {
+    #[topic]
+    pub schema_version: u32,
     pub round: u32,
     pub votes_for: i128,
 }
@@ -1021,6 +1258,8 @@ pub struct DissolutionQuorumReached {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct GroupDissolved {
+    #[topic]
+    pub schema_version: u32,
     pub round: u32,
     pub reason_hash: BytesN<32>,
     pub total_pool: i128,
@@ -1031,6 +1270,8 @@ pub struct GroupDissolved {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct MemberRefunded {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub amount: i128,
     pub contribution: i128,
@@ -1041,6 +1282,8 @@ pub struct MemberRefunded {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct DissolutionConfigUpdated {
+    #[topic]
+    pub schema_version: u32,
     pub dissolution_quorum_bps: u32,
     pub vote_window_seconds: u64,
 }
@@ -1048,105 +1291,267 @@ pub struct DissolutionConfigUpdated {
 // --- Helper Emission Functions ---
 
 pub fn emit_emergency_payout_requested(e: &Env, requester: Address, round: u32, reason_hash: BytesN<32>, deadline: u64) {
-    EmergencyPayoutRequested { requester, round, reason_hash, deadline }.publish(e);
+    EmergencyPayoutRequested { schema_version: EVENT_SCHEMA_VERSION, requester, round, reason_hash, deadline }.publish(e);
 }
 
 pub fn emit_emergency_payout_vote_cast(e: &Env, requester: Address, round: u32, voter: Address, approve: bool, votes_for: i128, votes_against: i128) {
-    EmergencyPayoutVoteCast { requester, round, voter, approve, votes_for, votes_against }.publish(e);
+    EmergencyPayoutVoteCast { schema_version: EVENT_SCHEMA_VERSION, requester, round, voter, approve, votes_for, votes_against }.publish(e);
 }
 
 pub fn emit_emergency_payout_approved(e: &Env, requester: Address, round: u32, payout_amount: i128) {
-    EmergencyPayoutApproved { requester, round, payout_amount }.publish(e);
+    EmergencyPayoutApproved { schema_version: EVENT_SCHEMA_VERSION, requester, round, payout_amount }.publish(e);
 }
 
 pub fn emit_emergency_payout_rejected(e: &Env, requester: Address, round: u32, reason: Symbol) {
-    EmergencyPayoutRejected { requester, round, reason }.publish(e);
+    EmergencyPayoutRejected { schema_version: EVENT_SCHEMA_VERSION, requester, round, reason }.publish(e);
 }
 
 pub fn emit_emergency_payout_executed(e: &Env, requester: Address, round: u32, payout_amount: i128) {
-    EmergencyPayoutExecuted { requester, round, payout_amount }.publish(e);
+    EmergencyPayoutExecuted { schema_version: EVENT_SCHEMA_VERSION, requester, round, payout_amount }.publish(e);
 }
 
 pub fn emit_emergency_payout_config_updated(e: &Env, emergency_quorum_bps: u32, vote_window_seconds: u64, max_emergency_per_cycle: u32) {
-    EmergencyPayoutConfigUpdated { emergency_quorum_bps, vote_window_seconds, max_emergency_per_cycle }.publish(e);
+    EmergencyPayoutConfigUpdated { schema_version: EVENT_SCHEMA_VERSION, emergency_quorum_bps, vote_window_seconds, max_emergency_per_cycle }.publish(e);
 }
 
 pub fn emit_dissolution_vote_started(e: &Env, round: u32, deadline: u64) {
-    DissolutionVoteStarted { round, deadline }.publish(e);
+    DissolutionVoteStarted { schema_version: EVENT_SCHEMA_VERSION, round, deadline }.publish(e);
 }
 
 pub fn emit_dissolution_vote_cast(e: &Env, round: u32, voter: Address, approve: bool, votes_for: i128) {
-    DissolutionVoteCast { round, voter, approve, votes_for }.publish(e);
+    DissolutionVoteCast { schema_version: EVENT_SCHEMA_VERSION, round, voter, approve, votes_for }.publish(e);
 }
 
 pub fn emit_dissolution_quorum_reached(e: &Env, round: u32, votes_for: i128) {
-    DissolutionQuorumReached { round, votes_for }.publish(e);
+    DissolutionQuorumReached { schema_version: EVENT_SCHEMA_VERSION, round, votes_for }.publish(e);
 }
 
 pub fn emit_group_dissolved(e: &Env, round: u32, reason_hash: BytesN<32>, total_pool: i128, member_count: u32) {
-    GroupDissolved { round, reason_hash, total_pool, member_count }.publish(e);
+    GroupDissolved { schema_version: EVENT_SCHEMA_VERSION, round, reason_hash, total_pool, member_count }.publish(e);
 }
 
 pub fn emit_member_refunded(e: &Env, member: Address, amount: i128, contribution: i128, total_pool: i128) {
-    MemberRefunded { member, amount, contribution, total_pool }.publish(e);
+    MemberRefunded { schema_version: EVENT_SCHEMA_VERSION, member, amount, contribution, total_pool }.publish(e);
 }
 
 pub fn emit_dissolution_config_updated(e: &Env, dissolution_quorum_bps: u32, vote_window_seconds: u64) {
-    DissolutionConfigUpdated { dissolution_quorum_bps, vote_window_seconds }.publish(e);
+    DissolutionConfigUpdated { schema_version: EVENT_SCHEMA_VERSION, dissolution_quorum_bps, vote_window_seconds }.publish(e);
 }
 
 // #213: Slot Swap Events
+/// Event: Slot swap requested
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct SlotSwapRequested {
+    #[topic]
+    pub schema_version: u32,
+    pub swap_id: u32,
+    pub initiator: Address,
+    pub counterparty: Address,
+    pub round_a: u32,
+    pub round_b: u32,
+}
+
 pub fn emit_slot_swap_requested(e: &Env, swap_id: u32, initiator: Address, counterparty: Address, round_a: u32, round_b: u32) {
-    e.events().publish((Symbol::new(e, "SlotSwapReq"),), (swap_id, initiator, counterparty, round_a, round_b));
+    SlotSwapRequested { schema_version: EVENT_SCHEMA_VERSION, swap_id, initiator, counterparty, round_a, round_b }.publish(e);
+}
+
+/// Event: Slot swap accepted by the counterparty
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct SlotSwapAccepted {
+    #[topic]
+    pub schema_version: u32,
+    pub swap_id: u32,
+    pub counterparty: Address,
 }
+
 pub fn emit_slot_swap_accepted(e: &Env, swap_id: u32, counterparty: Address) {
-    e.events().publish((Symbol::new(e, "SlotSwapAcc"),), (swap_id, counterparty));
+    SlotSwapAccepted { schema_version: EVENT_SCHEMA_VERSION, swap_id, counterparty }.publish(e);
+}
+
+/// Event: Slot swap rejected by the counterparty
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct SlotSwapRejected {
+    #[topic]
+    pub schema_version: u32,
+    pub swap_id: u32,
+    pub counterparty: Address,
 }
+
 pub fn emit_slot_swap_rejected(e: &Env, swap_id: u32, counterparty: Address) {
-    e.events().publish((Symbol::new(e, "SlotSwapRej"),), (swap_id, counterparty));
+    SlotSwapRejected { schema_version: EVENT_SCHEMA_VERSION, swap_id, counterparty }.publish(e);
 }
+
+/// Event: Slot swap executed
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct SlotSwapExecuted {
+    #[topic]
+    pub schema_version: u32,
+    pub swap_id: u32,
+    pub round_a: u32,
+    pub round_b: u32,
+}
+
 pub fn emit_slot_swap_executed(e: &Env, swap_id: u32, round_a: u32, round_b: u32) {
-    e.events().publish((Symbol::new(e, "SlotSwapExec"),), (swap_id, round_a, round_b));
+    SlotSwapExecuted { schema_version: EVENT_SCHEMA_VERSION, swap_id, round_a, round_b }.publish(e);
+}
+
+/// Event: Slot swap expired unanswered
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct SlotSwapExpired {
+    #[topic]
+    pub schema_version: u32,
+    pub swap_id: u32,
 }
+
 pub fn emit_slot_swap_expired(e: &Env, swap_id: u32) {
-    e.events().publish((Symbol::new(e, "SlotSwapExp"),), (swap_id,));
+    SlotSwapExpired { schema_version: EVENT_SCHEMA_VERSION, swap_id }.publish(e);
 }
 
 // #214: Insurance Coverage Events
+/// Event: Insurance covered a defaulter
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct InsuranceClaimExecuted {
+    #[topic]
+    pub schema_version: u32,
+    pub round: u32,
+    pub defaulter: Address,
+    pub amount_covered: i128,
+}
+
 pub fn emit_insurance_claim_executed(e: &Env, round: u32, defaulter: Address, amount_covered: i128) {
-    e.events().publish((Symbol::new(e, "InsClaim"),), (round, defaulter, amount_covered));
+    InsuranceClaimExecuted { schema_version: EVENT_SCHEMA_VERSION, round, defaulter, amount_covered }.publish(e);
+}
+
+/// Event: Insurance pool could not cover a shortfall
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct InsurancePoolExhausted {
+    #[topic]
+    pub schema_version: u32,
+    pub round: u32,
+    pub shortfall_remaining: i128,
 }
+
 pub fn emit_insurance_pool_exhausted(e: &Env, round: u32, shortfall_remaining: i128) {
-    e.events().publish((Symbol::new(e, "InsExhausted"),), (round, shortfall_remaining));
+    InsurancePoolExhausted { schema_version: EVENT_SCHEMA_VERSION, round, shortfall_remaining }.publish(e);
+}
+
+/// Event: Insurance coverage mode changed
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct InsuranceCoverageModeSet {
+    #[topic]
+    pub schema_version: u32,
+    pub mode: u32,
 }
+
 pub fn emit_insurance_coverage_mode_set(e: &Env, mode: u32) {
-    e.events().publish((Symbol::new(e, "InsModeSet"),), (mode,));
+    InsuranceCoverageModeSet { schema_version: EVENT_SCHEMA_VERSION, mode }.publish(e);
 }
 
 // #218: Reinstatement Events
+/// Event: Suspended member requested reinstatement
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ReinstatementRequested {
+    #[topic]
+    pub schema_version: u32,
+    pub member: Address,
+    pub proposal_id: u32,
+}
+
 pub fn emit_reinstatement_requested(e: &Env, member: Address, proposal_id: u32) {
-    e.events().publish((Symbol::new(e, "ReinReq"),), (member, proposal_id));
+    ReinstatementRequested { schema_version: EVENT_SCHEMA_VERSION, member, proposal_id }.publish(e);
 }
+
+/// Event: Member reinstated
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ReinstatementApproved {
+    #[topic]
+    pub schema_version: u32,
+    pub member: Address,
+}
+
 pub fn emit_reinstatement_approved(e: &Env, member: Address) {
-    e.events().publish((Symbol::new(e, "ReinApproved"),), (member,));
+    ReinstatementApproved { schema_version: EVENT_SCHEMA_VERSION, member }.publish(e);
+}
+
+/// Event: Reinstatement fee collected
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ReinstatementFeeCollected {
+    #[topic]
+    pub schema_version: u32,
+    pub member: Address,
+    pub amount: i128,
 }
+
 pub fn emit_reinstatement_fee_collected(e: &Env, member: Address, amount: i128) {
-    e.events().publish((Symbol::new(e, "ReinFee"),), (member, amount));
+    ReinstatementFeeCollected { schema_version: EVENT_SCHEMA_VERSION, member, amount }.publish(e);
 }
 
 
 // #230: Group Merge Events
+/// Event: Group merge proposed
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct MergeProposed {
+    #[topic]
+    pub schema_version: u32,
+    pub proposal_id: u32,
+    pub group_a_admin: Address,
+    pub group_b_id: u32,
+}
+
 pub fn emit_merge_proposed(e: &Env, proposal_id: u32, group_a_admin: Address, group_b_id: u32) {
-    e.events().publish((Symbol::new(e, "MergeProposed"),), (proposal_id, group_a_admin, group_b_id));
+    MergeProposed { schema_version: EVENT_SCHEMA_VERSION, proposal_id, group_a_admin, group_b_id }.publish(e);
+}
+
+/// Event: Group merge accepted
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct MergeAccepted {
+    #[topic]
+    pub schema_version: u32,
+    pub proposal_id: u32,
 }
+
 pub fn emit_merge_accepted(e: &Env, proposal_id: u32) {
-    e.events().publish((Symbol::new(e, "MergeAccepted"),), (proposal_id,));
+    MergeAccepted { schema_version: EVENT_SCHEMA_VERSION, proposal_id }.publish(e);
+}
+
+/// Event: Group merge completed
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct MergeCompleted {
+    #[topic]
+    pub schema_version: u32,
+    pub proposal_id: u32,
+    pub members_added: u32,
 }
+
 pub fn emit_merge_completed(e: &Env, proposal_id: u32, members_added: u32) {
-    e.events().publish((Symbol::new(e, "MergeCompleted"),), (proposal_id, members_added));
+    MergeCompleted { schema_version: EVENT_SCHEMA_VERSION, proposal_id, members_added }.publish(e);
+}
+
+/// Event: Group marked as merged into another
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct GroupMarkedMerged {
+    #[topic]
+    pub schema_version: u32,
+    pub group_b_id: u32,
 }
+
 pub fn emit_group_marked_merged(e: &Env, group_b_id: u32) {
-    e.events().publish((Symbol::new(e, "GroupMerged"),), (group_b_id,));
+    GroupMarkedMerged { schema_version: EVENT_SCHEMA_VERSION, group_b_id }.publish(e);
 }
 // #224: Cycle Completion Bonus Events
 
@@ -1154,6 +1559,8 @@ pub fn emit_group_marked_merged(e: &Env, group_b_id: u32) {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct CycleBonusConfigured {
+    #[topic]
+    pub schema_version: u32,
     pub amount: i128,
 }
 
@@ -1161,6 +1568,8 @@ pub struct CycleBonusConfigured {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct CycleBonusPaid {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub amount: i128,
     pub cycle: u32,
@@ -1170,20 +1579,22 @@ pub struct CycleBonusPaid {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct CycleBonusProrated {
+    #[topic]
+    pub schema_version: u32,
     pub cycle: u32,
     pub shortfall: i128,
 }
 
 pub fn emit_cycle_bonus_configured(e: &Env, amount: i128) {
-    CycleBonusConfigured { amount }.publish(e);
+    CycleBonusConfigured { schema_version: EVENT_SCHEMA_VERSION, amount }.publish(e);
 }
 
 pub fn emit_cycle_bonus_paid(e: &Env, member: Address, amount: i128, cycle: u32) {
-    CycleBonusPaid { member, amount, cycle }.publish(e);
+    CycleBonusPaid { schema_version: EVENT_SCHEMA_VERSION, member, amount, cycle }.publish(e);
 }
 
 pub fn emit_cycle_bonus_prorated(e: &Env, cycle: u32, shortfall: i128) {
-    CycleBonusProrated { cycle, shortfall }.publish(e);
+    CycleBonusProrated { schema_version: EVENT_SCHEMA_VERSION, cycle, shortfall }.publish(e);
 }
 
 // #227: Round Duration Update Events
@@ -1192,6 +1603,8 @@ pub fn emit_cycle_bonus_prorated(e: &Env, cycle: u32, shortfall: i128) {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct RoundDurationUpdateScheduled {
+    #[topic]
+    pub schema_version: u32,
     pub old_duration: u64,
     pub new_duration: u64,
     pub effective_from_round: u32,
@@ -1201,39 +1614,87 @@ pub struct RoundDurationUpdateScheduled {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct RoundDurationApplied {
+    #[topic]
+    pub schema_version: u32,
     pub round: u32,
     pub duration: u64,
 }
 
 pub fn emit_round_duration_update_scheduled(e: &Env, old_duration: u64, new_duration: u64, effective_from_round: u32) {
-    RoundDurationUpdateScheduled { old_duration, new_duration, effective_from_round }.publish(e);
+    RoundDurationUpdateScheduled { schema_version: EVENT_SCHEMA_VERSION, old_duration, new_duration, effective_from_round }.publish(e);
 }
 
 pub fn emit_round_duration_applied(e: &Env, round: u32, duration: u64) {
-    RoundDurationApplied { round, duration }.publish(e);
+    RoundDurationApplied { schema_version: EVENT_SCHEMA_VERSION, round, duration }.publish(e);
 }
 
 // #240: Co-Signer Guarantee Events
 
+/// Event: Co-signer nominated
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct CoSignerSet {
+    #[topic]
+    pub schema_version: u32,
+    pub group_id: u32,
+    pub member: Address,
+    pub co_signer: Address,
+}
+
 pub fn emit_co_signer_set(e: &Env, group_id: u32, member: Address, co_signer: Address) {
-    e.events().publish((soroban_sdk::Symbol::new(e, "CoSignerSet"),), (group_id, member, co_signer));
+    CoSignerSet { schema_version: EVENT_SCHEMA_VERSION, group_id, member, co_signer }.publish(e);
+}
+
+/// Event: Co-signer accepted the nomination
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct CoSignerAccepted {
+    #[topic]
+    pub schema_version: u32,
+    pub group_id: u32,
+    pub member: Address,
+    pub co_signer: Address,
 }
 
 pub fn emit_co_signer_accepted(e: &Env, group_id: u32, member: Address, co_signer: Address) {
-    e.events().publish((soroban_sdk::Symbol::new(e, "CoSignerAccepted"),), (group_id, member, co_signer));
+    CoSignerAccepted { schema_version: EVENT_SCHEMA_VERSION, group_id, member, co_signer }.publish(e);
+}
+
+/// Event: Co-signer covered a missed contribution
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct CoSignerContributed {
+    #[topic]
+    pub schema_version: u32,
+    pub group_id: u32,
+    pub member: Address,
+    pub co_signer: Address,
+    pub amount: i128,
 }
 
 pub fn emit_co_signer_contributed(e: &Env, group_id: u32, member: Address, co_signer: Address, amount: i128) {
-    e.events().publish((soroban_sdk::Symbol::new(e, "CoSignerContributed"),), (group_id, member, co_signer, amount));
+    CoSignerContributed { schema_version: EVENT_SCHEMA_VERSION, group_id, member, co_signer, amount }.publish(e);
+}
+
+/// Event: Co-signer window expired
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct CoSignerWindowExpired {
+    #[topic]
+    pub schema_version: u32,
+    pub group_id: u32,
+    pub member: Address,
 }
 
 pub fn emit_co_signer_window_expired(e: &Env, group_id: u32, member: Address) {
-    e.events().publish((soroban_sdk::Symbol::new(e, "CoSignerWinExpired"),), (group_id, member));
+    CoSignerWindowExpired { schema_version: EVENT_SCHEMA_VERSION, group_id, member }.publish(e);
 }
 
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ProxyAuthorized {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub member: Address,
     pub proxy: Address,
@@ -1243,6 +1704,8 @@ pub struct ProxyAuthorized {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ProxyContributed {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub member: Address,
     pub proxy: Address,
@@ -1252,6 +1715,8 @@ pub struct ProxyContributed {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ProxyRevoked {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub member: Address,
     pub proxy: Address,
@@ -1265,6 +1730,7 @@ pub fn emit_proxy_authorized(
     max_rounds: u32,
 ) {
     ProxyAuthorized {
+        schema_version: EVENT_SCHEMA_VERSION,
         group_id,
         member,
         proxy,
@@ -1281,6 +1747,7 @@ pub fn emit_proxy_contributed(
     round_index: u32,
 ) {
     ProxyContributed {
+        schema_version: EVENT_SCHEMA_VERSION,
         group_id,
         member,
         proxy,
@@ -1291,6 +1758,7 @@ pub fn emit_proxy_contributed(
 
 pub fn emit_proxy_revoked(e: &Env, group_id: u32, member: Address, proxy: Address) {
     ProxyRevoked {
+        schema_version: EVENT_SCHEMA_VERSION,
         group_id,
         member,
         proxy,
@@ -1303,6 +1771,8 @@ pub fn emit_proxy_revoked(e: &Env, group_id: u32, member: Address, proxy: Addres
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct GroupFrozen {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub reason_hash: BytesN<32>,
     pub frozen_at: u32,
@@ -1312,17 +1782,19 @@ pub struct GroupFrozen {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct GroupUnfrozen {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub resolution_hash: BytesN<32>,
     pub unfrozen_at: u32,
 }
 
 pub fn emit_group_frozen(e: &Env, group_id: u32, reason_hash: BytesN<32>, frozen_at: u32) {
-    GroupFrozen { group_id, reason_hash, frozen_at }.publish(e);
+    GroupFrozen { schema_version: EVENT_SCHEMA_VERSION, group_id, reason_hash, frozen_at }.publish(e);
 }
 
 pub fn emit_group_unfrozen(e: &Env, group_id: u32, resolution_hash: BytesN<32>, unfrozen_at: u32) {
-    GroupUnfrozen { group_id, resolution_hash, unfrozen_at }.publish(e);
+    GroupUnfrozen { schema_version: EVENT_SCHEMA_VERSION, group_id, resolution_hash, unfrozen_at }.publish(e);
 }
 // #243: Group State Snapshot Events
 
@@ -1330,13 +1802,15 @@ pub fn emit_group_unfrozen(e: &Env, group_id: u32, resolution_hash: BytesN<32>,
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct SnapshotTaken {
+    #[topic]
+    pub schema_version: u32,
     pub snapshot_id: u32,
     pub taken_by: Address,
     pub state_hash: BytesN<32>,
 }
 
 pub fn emit_snapshot_taken(e: &Env, snapshot_id: u32, taken_by: Address, state_hash: BytesN<32>) {
-    SnapshotTaken { snapshot_id, taken_by, state_hash }.publish(e);
+    SnapshotTaken { schema_version: EVENT_SCHEMA_VERSION, snapshot_id, taken_by, state_hash }.publish(e);
 }
 
 // #267: Tiered Contribution Level Events
@@ -1345,6 +1819,8 @@ pub fn emit_snapshot_taken(e: &Env, snapshot_id: u32, taken_by: Address, state_h
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct TierDefined {
+    #[topic]
+    pub schema_version: u32,
     pub tier_id: u32,
     pub name: soroban_sdk::Symbol,
     pub contribution_amount: i128,
@@ -1355,6 +1831,8 @@ pub struct TierDefined {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct MemberJoinedWithTier {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub tier_id: u32,
 }
@@ -1363,6 +1841,8 @@ pub struct MemberJoinedWithTier {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct MemberTierChanged {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub old_tier: u32,
     pub new_tier: u32,
@@ -1370,15 +1850,15 @@ pub struct MemberTierChanged {
 }
 
 pub fn emit_tier_defined(e: &Env, tier_id: u32, name: soroban_sdk::Symbol, contribution_amount: i128, payout_weight: u32) {
-    TierDefined { tier_id, name, contribution_amount, payout_weight }.publish(e);
+    TierDefined { schema_version: EVENT_SCHEMA_VERSION, tier_id, name, contribution_amount, payout_weight }.publish(e);
 }
 
 pub fn emit_member_joined_with_tier(e: &Env, member: Address, tier_id: u32) {
-    MemberJoinedWithTier { member, tier_id }.publish(e);
+    MemberJoinedWithTier { schema_version: EVENT_SCHEMA_VERSION, member, tier_id }.publish(e);
 }
 
 pub fn emit_member_tier_changed(e: &Env, member: Address, old_tier: u32, new_tier: u32, effective_cycle: u32) {
-    MemberTierChanged { member, old_tier, new_tier, effective_cycle }.publish(e);
+    MemberTierChanged { schema_version: EVENT_SCHEMA_VERSION, member, old_tier, new_tier, effective_cycle }.publish(e);
 }
 
 // #269: On-Chain Member Credit Score Events
@@ -1387,6 +1867,8 @@ pub fn emit_member_tier_changed(e: &Env, member: Address, old_tier: u32, new_tie
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct CreditScoreUpdated {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub old_score: i128,
     pub new_score: i128,
@@ -1394,7 +1876,7 @@ pub struct CreditScoreUpdated {
 }
 
 pub fn emit_credit_score_updated(e: &Env, member: Address, old_score: i128, new_score: i128, reason: soroban_sdk::Symbol) {
-    CreditScoreUpdated { member, old_score, new_score, reason }.publish(e);
+    CreditScoreUpdated { schema_version: EVENT_SCHEMA_VERSION, member, old_score, new_score, reason }.publish(e);
 }
 
 // ── Reputation-Gated Fee Discount Event ──────────────────────────────────────
@@ -1403,6 +1885,8 @@ pub fn emit_credit_score_updated(e: &Env, member: Address, old_score: i128, new_
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct RepFeeDiscountApplied {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub original_fee_bps: u32,
     pub effective_fee_bps: u32,
@@ -1417,6 +1901,7 @@ pub fn emit_rep_fee_discount_applied(
     score: i128,
 ) {
     RepFeeDiscountApplied {
+        schema_version: EVENT_SCHEMA_VERSION,
         member,
         original_fee_bps,
         effective_fee_bps,
@@ -1431,6 +1916,8 @@ pub fn emit_rep_fee_discount_applied(
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct DelegationGranted {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub member: Address,
     pub proxy: Address,
@@ -1441,17 +1928,19 @@ pub struct DelegationGranted {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ContribDelegationRevoked {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub member: Address,
     pub proxy: Address,
 }
 
 pub fn emit_delegation_granted(e: &Env, group_id: u32, member: Address, proxy: Address, expiry_ledger: u64) {
-    DelegationGranted { group_id, member, proxy, expiry_ledger }.publish(e);
+    DelegationGranted { schema_version: EVENT_SCHEMA_VERSION, group_id, member, proxy, expiry_ledger }.publish(e);
 }
 
 pub fn emit_contribution_delegation_revoked(e: &Env, group_id: u32, member: Address, proxy: Address) {
-    ContribDelegationRevoked { group_id, member, proxy }.publish(e);
+    ContribDelegationRevoked { schema_version: EVENT_SCHEMA_VERSION, group_id, member, proxy }.publish(e);
 }
 
 // ── #331: Group Split Events ──────────────────────────────────────────────────
@@ -1460,6 +1949,8 @@ pub fn emit_contribution_delegation_revoked(e: &Env, group_id: u32, member: Addr
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct GroupSplitProposed {
+    #[topic]
+    pub schema_version: u32,
     pub source_group_id: u32,
     pub proposal_id: u32,
 }
@@ -1468,17 +1959,19 @@ pub struct GroupSplitProposed {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct GroupSplitExecuted {
+    #[topic]
+    pub schema_version: u32,
     pub source_group_id: u32,
     pub group_a_id: u32,
     pub group_b_id: u32,
 }
 
 pub fn emit_group_split_proposed(e: &Env, source_group_id: u32, proposal_id: u32) {
-    GroupSplitProposed { source_group_id, proposal_id }.publish(e);
+    GroupSplitProposed { schema_version: EVENT_SCHEMA_VERSION, source_group_id, proposal_id }.publish(e);
 }
 
 pub fn emit_group_split_executed(e: &Env, source_group_id: u32, group_a_id: u32, group_b_id: u32) {
-    GroupSplitExecuted { source_group_id, group_a_id, group_b_id }.publish(e);
+    GroupSplitExecuted { schema_version: EVENT_SCHEMA_VERSION, source_group_id, group_a_id, group_b_id }.publish(e);
 }
 
 
@@ -1486,6 +1979,8 @@ pub fn emit_group_split_executed(e: &Env, source_group_id: u32, group_a_id: u32,
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct TreasuryEnabled {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub treasury_admin: Address,
 }
@@ -1494,6 +1989,8 @@ pub struct TreasuryEnabled {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct TreasuryRoundProposed {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub round_index: u32,
 }
@@ -1502,6 +1999,8 @@ pub struct TreasuryRoundProposed {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct TreasuryRoundConfirmed {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub round_index: u32,
 }
@@ -1510,6 +2009,8 @@ pub struct TreasuryRoundConfirmed {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct TreasuryPaymentExecuted {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub recipient: Address,
     pub amount: i128,
@@ -1517,19 +2018,19 @@ pub struct TreasuryPaymentExecuted {
 
 
 pub fn emit_treasury_enabled(env: &Env, treasury_admin: Address) {
-    TreasuryEnabled { group_id: 0, treasury_admin }.publish(env);
+    TreasuryEnabled { schema_version: EVENT_SCHEMA_VERSION, group_id: 0, treasury_admin }.publish(env);
 }
 
 pub fn emit_treasury_round_proposed(env: &Env, round_index: u32) {
-    TreasuryRoundProposed { group_id: 0, round_index }.publish(env);
+    TreasuryRoundProposed { schema_version: EVENT_SCHEMA_VERSION, group_id: 0, round_index }.publish(env);
 }
 
 pub fn emit_treasury_round_confirmed(env: &Env, round_index: u32) {
-    TreasuryRoundConfirmed { group_id: 0, round_index }.publish(env);
+    TreasuryRoundConfirmed { schema_version: EVENT_SCHEMA_VERSION, group_id: 0, round_index }.publish(env);
 }
 
 pub fn emit_treasury_payment_executed(env: &Env, recipient: Address, amount: i128) {
-    TreasuryPaymentExecuted { group_id: 0, recipient, amount }.publish(env);
+    TreasuryPaymentExecuted { schema_version: EVENT_SCHEMA_VERSION, group_id: 0, recipient, amount }.publish(env);
 }
 
 // --- Emergency Liquidity Reserve Events (#313) ---
@@ -1538,6 +2039,8 @@ pub fn emit_treasury_payment_executed(env: &Env, recipient: Address, amount: i12
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct EmergencyLoanGranted {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub member: Address,
     pub loan_id: u32,
@@ -1549,6 +2052,8 @@ pub struct EmergencyLoanGranted {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct EmergencyLoanRepaid {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub loan_id: u32,
     pub amount: i128,
@@ -1559,6 +2064,8 @@ pub struct EmergencyLoanRepaid {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct LoanDefaultDeducted {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub loan_id: u32,
     pub deducted_from_payout: i128,
@@ -1573,6 +2080,7 @@ pub fn emit_emergency_loan_granted(
     repayment_deadline: u32,
 ) {
     EmergencyLoanGranted {
+        schema_version: EVENT_SCHEMA_VERSION,
         group_id,
         member,
         loan_id,
@@ -1584,6 +2092,7 @@ pub fn emit_emergency_loan_granted(
 
 pub fn emit_emergency_loan_repaid(e: &Env, group_id: u32, loan_id: u32, amount: i128, remaining: i128) {
     EmergencyLoanRepaid {
+        schema_version: EVENT_SCHEMA_VERSION,
         group_id,
         loan_id,
         amount,
@@ -1594,6 +2103,7 @@ pub fn emit_emergency_loan_repaid(e: &Env, group_id: u32, loan_id: u32, amount:
 
 pub fn emit_loan_default_deducted(e: &Env, group_id: u32, loan_id: u32, deducted_from_payout: i128) {
     LoanDefaultDeducted {
+        schema_version: EVENT_SCHEMA_VERSION,
         group_id,
         loan_id,
         deducted_from_payout,
@@ -1607,6 +2117,8 @@ pub fn emit_loan_default_deducted(e: &Env, group_id: u32, loan_id: u32, deducted
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ContributionRebalanced {
+    #[topic]
+    pub schema_version: u32,
     pub old_amount: i128,
     pub new_amount: i128,
     pub reason: soroban_sdk::Symbol,
@@ -1619,6 +2131,7 @@ pub fn emit_contribution_rebalanced(
     reason: soroban_sdk::Symbol,
 ) {
     ContributionRebalanced {
+        schema_version: EVENT_SCHEMA_VERSION,
         old_amount,
         new_amount,
         reason,
@@ -1633,6 +2146,8 @@ pub fn emit_contribution_rebalanced(
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct MemberDemoted {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub new_slot_index: u32,
     pub late_count: u32,
@@ -1640,6 +2155,7 @@ pub struct MemberDemoted {
 
 pub fn emit_member_demoted(e: &Env, member: Address, new_slot_index: u32, late_count: u32) {
     MemberDemoted {
+        schema_version: EVENT_SCHEMA_VERSION,
         member,
         new_slot_index,
         late_count,
@@ -1651,11 +2167,13 @@ pub fn emit_member_demoted(e: &Env, member: Address, new_slot_index: u32, late_c
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct LateCountReset {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
 }
 
 pub fn emit_late_count_reset(e: &Env, member: Address) {
-    LateCountReset { member }.publish(e);
+    LateCountReset { schema_version: EVENT_SCHEMA_VERSION, member }.publish(e);
 }
 
 // ── #364: Cycle Snapshot Versioning ──────────────────────────────────────────
@@ -1664,13 +2182,15 @@ pub fn emit_late_count_reset(e: &Env, member: Address) {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct SnapshotCreated {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub cycle_number: u32,
     pub snapshot_hash: BytesN<32>,
 }
 
 pub fn emit_snapshot_created(e: &Env, group_id: u32, cycle_number: u32, snapshot_hash: BytesN<32>) {
-    SnapshotCreated { group_id, cycle_number, snapshot_hash }.publish(e);
+    SnapshotCreated { schema_version: EVENT_SCHEMA_VERSION, group_id, cycle_number, snapshot_hash }.publish(e);
 }
 
 // ── #359: Savings Goal Milestone Rewards ─────────────────────────────────────
@@ -1679,6 +2199,8 @@ pub fn emit_snapshot_created(e: &Env, group_id: u32, cycle_number: u32, snapshot
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct MilestoneReached {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub member: Address,
     pub milestone_pct: u32,
@@ -1692,7 +2214,7 @@ pub fn emit_milestone_reached(
     milestone_pct: u32,
     reward_amount: i128,
 ) {
-    MilestoneReached { group_id, member, milestone_pct, reward_amount }.publish(e);
+    MilestoneReached { schema_version: EVENT_SCHEMA_VERSION, group_id, member, milestone_pct, reward_amount }.publish(e);
 }
 
 // ── #375: Sealed-Bid (Commit-Reveal) Slot Auction Events ──────────────────────
@@ -1701,6 +2223,8 @@ pub fn emit_milestone_reached(
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct SealedAuctionOpened {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub round: u32,
     pub commit_until: u64,
@@ -1711,6 +2235,8 @@ pub struct SealedAuctionOpened {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct SlotBidCommitted {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub round: u32,
     pub bidder: Address,
@@ -1720,6 +2246,8 @@ pub struct SlotBidCommitted {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct SlotBidRevealed {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub round: u32,
     pub bidder: Address,
@@ -1732,6 +2260,8 @@ pub struct SlotBidRevealed {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct SealedAuctionSettled {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub round: u32,
     pub winner: Address,
@@ -1745,11 +2275,11 @@ pub fn emit_sealed_auction_opened(
     commit_until: u64,
     reveal_until: u64,
 ) {
-    SealedAuctionOpened { group_id, round, commit_until, reveal_until }.publish(e);
+    SealedAuctionOpened { schema_version: EVENT_SCHEMA_VERSION, group_id, round, commit_until, reveal_until }.publish(e);
 }
 
 pub fn emit_slot_bid_committed(e: &Env, group_id: u32, round: u32, bidder: Address) {
-    SlotBidCommitted { group_id, round, bidder }.publish(e);
+    SlotBidCommitted { schema_version: EVENT_SCHEMA_VERSION, group_id, round, bidder }.publish(e);
 }
 
 pub fn emit_slot_bid_revealed(
@@ -1760,7 +2290,7 @@ pub fn emit_slot_bid_revealed(
     desired_slot: u32,
     bid_amount: i128,
 ) {
-    SlotBidRevealed { group_id, round, bidder, desired_slot, bid_amount }.publish(e);
+    SlotBidRevealed { schema_version: EVENT_SCHEMA_VERSION, group_id, round, bidder, desired_slot, bid_amount }.publish(e);
 }
 
 pub fn emit_sealed_auction_settled(
@@ -1770,7 +2300,7 @@ pub fn emit_sealed_auction_settled(
     winner: Address,
     winning_bid: i128,
 ) {
-    SealedAuctionSettled { group_id, round, winner, winning_bid }.publish(e);
+    SealedAuctionSettled { schema_version: EVENT_SCHEMA_VERSION, group_id, round, winner, winning_bid }.publish(e);
 }
 
 
@@ -1780,6 +2310,8 @@ pub fn emit_sealed_auction_settled(
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct SlotBidPlaced {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub bidder: Address,
     pub desired_slot: u32,
@@ -1787,13 +2319,15 @@ pub struct SlotBidPlaced {
 }
 
 pub fn emit_slot_bid_placed(e: &Env, group_id: u32, bidder: Address, desired_slot: u32, bid_amount: i128) {
-    SlotBidPlaced { group_id, bidder, desired_slot, bid_amount }.publish(e);
+    SlotBidPlaced { schema_version: EVENT_SCHEMA_VERSION, group_id, bidder, desired_slot, bid_amount }.publish(e);
 }
 
 /// Event: An open slot auction was resolved
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct SlotAuctionResolved {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub winner: Address,
     pub slot: u32,
@@ -1802,7 +2336,7 @@ pub struct SlotAuctionResolved {
 }
 
 pub fn emit_slot_auction_resolved(e: &Env, group_id: u32, winner: Address, slot: u32, winning_bid: i128, bonus_per_member: i128) {
-    SlotAuctionResolved { group_id, winner, slot, winning_bid, bonus_per_member }.publish(e);
+    SlotAuctionResolved { schema_version: EVENT_SCHEMA_VERSION, group_id, winner, slot, winning_bid, bonus_per_member }.publish(e);
 }
 
 // --- Cross-Group Migration Events ---
@@ -1811,19 +2345,23 @@ pub fn emit_slot_auction_resolved(e: &Env, group_id: u32, winner: Address, slot:
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct MigrationRequested {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub src_contract: Address,
     pub to_group: Address,
 }
 
 pub fn emit_migration_requested(e: &Env, member: Address, src_contract: Address, to_group: Address) {
-    MigrationRequested { member, src_contract, to_group }.publish(e);
+    MigrationRequested { schema_version: EVENT_SCHEMA_VERSION, member, src_contract, to_group }.publish(e);
 }
 
 /// Event: A member migration was executed into this group
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct MigrationExecuted {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub from_group: Address,
     pub dest_contract: Address,
@@ -1831,7 +2369,7 @@ pub struct MigrationExecuted {
 }
 
 pub fn emit_migration_executed(e: &Env, member: Address, from_group: Address, dest_contract: Address, target_slot: u32) {
-    MigrationExecuted { member, from_group, dest_contract, target_slot }.publish(e);
+    MigrationExecuted { schema_version: EVENT_SCHEMA_VERSION, member, from_group, dest_contract, target_slot }.publish(e);
 }
 
 // --- Proxy Authorization Events ---
@@ -1840,6 +2378,8 @@ pub fn emit_migration_executed(e: &Env, member: Address, from_group: Address, de
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ProxyExpired {
+    #[topic]
+    pub schema_version: u32,
     pub group_id: u32,
     pub member: Address,
     pub proxy: Address,
@@ -1847,7 +2387,7 @@ pub struct ProxyExpired {
 }
 
 pub fn emit_proxy_expired(e: &Env, group_id: u32, member: Address, proxy: Address, expiry_ledger: u64) {
-    ProxyExpired { group_id, member, proxy, expiry_ledger }.publish(e);
+    ProxyExpired { schema_version: EVENT_SCHEMA_VERSION, group_id, member, proxy, expiry_ledger }.publish(e);
 }
 
 // ── Co-payer Contribution Splitting Events ────────────────────────────────────
@@ -1856,6 +2396,8 @@ pub fn emit_proxy_expired(e: &Env, group_id: u32, member: Address, proxy: Addres
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct CoPayerSplitRegistered {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub co_payer_count: u32,
     pub total_split_amount: i128,
@@ -1865,6 +2407,8 @@ pub struct CoPayerSplitRegistered {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct CoPayerContributed {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
     pub co_payer: Address,
     pub amount: i128,
@@ -1875,19 +2419,21 @@ pub struct CoPayerContributed {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct CoPayerSplitRevoked {
+    #[topic]
+    pub schema_version: u32,
     pub member: Address,
 }
 
 pub fn emit_co_payer_split_registered(e: &Env, member: Address, co_payer_count: u32, total_split_amount: i128) {
-    CoPayerSplitRegistered { member, co_payer_count, total_split_amount }.publish(e);
+    CoPayerSplitRegistered { schema_version: EVENT_SCHEMA_VERSION, member, co_payer_count, total_split_amount }.publish(e);
 }
 
 pub fn emit_co_payer_contributed(e: &Env, member: Address, co_payer: Address, amount: i128, round: u32) {
-    CoPayerContributed { member, co_payer, amount, round }.publish(e);
+    CoPayerContributed { schema_version: EVENT_SCHEMA_VERSION, member, co_payer, amount, round }.publish(e);
 }
 
 pub fn emit_co_payer_split_revoked(e: &Env, member: Address) {
-    CoPayerSplitRevoked { member }.publish(e);
+    CoPayerSplitRevoked { schema_version: EVENT_SCHEMA_VERSION, member }.publish(e);
 }
 
 // ── NFT-Style Contribution Receipt Events ─────────────────────────────────────
@@ -1896,6 +2442,8 @@ pub fn emit_co_payer_split_revoked(e: &Env, member: Address) {
 #[contractevent]
 #[derive(Clone, Debug)]
 pub struct ContributionReceiptMinted {
+    #[topic]
+    pub schema_version: u32,
     pub receipt_id: u32,
     pub member: Address,
     pub round: u32,
@@ -1912,6 +2460,7 @@ pub fn emit_contribution_receipt_minted(
     receipt_hash: BytesN<32>,
 ) {
     ContributionReceiptMinted {
+        schema_version: EVENT_SCHEMA_VERSION,
         receipt_id,
         member,
         round,
@@ -1920,3 +2469,1408 @@ pub fn emit_contribution_receipt_minted(
     }
     .publish(e);
 }
+
+// ── Dust Sweep ────────────────────────────────────────────────────────────────
+
+/// Event: Unaccounted surplus balance swept out of the contract
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct DustSwept {
+    #[topic]
+    pub schema_version: u32,
+    pub to: Address,
+    pub amount: i128,
+}
+
+pub fn emit_dust_swept(e: &Env, to: Address, amount: i128) {
+    DustSwept { schema_version: EVENT_SCHEMA_VERSION, to, amount }.publish(e);
+}
+
+// ── Early Payout Claim ────────────────────────────────────────────────────────
+
+/// Event: Scheduled recipient took the partial pot before all members paid
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct EarlyPayoutClaimed {
+    #[topic]
+    pub schema_version: u32,
+    pub recipient: Address,
+    pub round: u32,
+    pub claimed: i128,
+    pub forgone: i128,
+}
+
+pub fn emit_early_payout_claimed(e: &Env, recipient: Address, round: u32, claimed: i128, forgone: i128) {
+    EarlyPayoutClaimed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        recipient,
+        round,
+        claimed,
+        forgone,
+    }
+    .publish(e);
+}
+
+// ── Late Contribution Fee ─────────────────────────────────────────────────────
+
+/// Event: Member paid the late fee while contributing within the grace period
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct LateFeePaid {
+    #[topic]
+    pub schema_version: u32,
+    pub member: Address,
+    pub round: u32,
+    pub fee: i128,
+}
+
+pub fn emit_late_fee_paid(e: &Env, member: Address, round: u32, fee: i128) {
+    LateFeePaid { schema_version: EVENT_SCHEMA_VERSION, member, round, fee }.publish(e);
+}
+
+// ── Contribution Amount Update ────────────────────────────────────────────────
+
+/// Event: Admin changed the flat contribution amount, effective from `round`
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ContributionAmountUpdated {
+    #[topic]
+    pub schema_version: u32,
+    pub old_amount: i128,
+    pub new_amount: i128,
+    pub round: u32,
+}
+
+pub fn emit_contribution_amount_updated(e: &Env, old_amount: i128, new_amount: i128, round: u32) {
+    ContributionAmountUpdated {
+        schema_version: EVENT_SCHEMA_VERSION,
+        old_amount,
+        new_amount,
+        round,
+    }
+    .publish(e);
+}
+
+// ── Auto-Contribute ───────────────────────────────────────────────────────────
+
+/// Event: Member set (or cleared, with 0) their auto-contribute rounds
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct AutoContributeSet {
+    #[topic]
+    pub schema_version: u32,
+    pub member: Address,
+    pub rounds: u32,
+}
+
+/// Event: Contribution pulled from a member's auto-contribute allowance
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct AutoContributeTriggered {
+    #[topic]
+    pub schema_version: u32,
+    pub member: Address,
+    pub amount: i128,
+    pub rounds_remaining: u32,
+}
+
+pub fn emit_auto_contribute_set(e: &Env, member: Address, rounds: u32) {
+    AutoContributeSet { schema_version: EVENT_SCHEMA_VERSION, member, rounds }.publish(e);
+}
+
+pub fn emit_auto_contribute_triggered(e: &Env, member: Address, amount: i128, rounds_remaining: u32) {
+    AutoContributeTriggered {
+        schema_version: EVENT_SCHEMA_VERSION,
+        member,
+        amount,
+        rounds_remaining,
+    }
+    .publish(e);
+}
+
+// ── Batch Contribution ────────────────────────────────────────────────────────
+
+/// Event: One payer funded several members' contributions in a single call
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct BatchContributed {
+    #[topic]
+    pub schema_version: u32,
+    pub payer: Address,
+    pub count: u32,
+    pub total: i128,
+}
+
+pub fn emit_batch_contributed(e: &Env, payer: Address, count: u32, total: i128) {
+    BatchContributed { schema_version: EVENT_SCHEMA_VERSION, payer, count, total }.publish(e);
+}
+
+// ── Fee Reserve ───────────────────────────────────────────────────────────────
+
+/// Event: Payout fee retained in the reserve
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ReserveCredited {
+    #[topic]
+    pub schema_version: u32,
+    pub round: u32,
+    pub amount: i128,
+    pub reserve_balance: i128,
+}
+
+/// Event: Reserve drawn to cover a round's shortfall
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ReserveDrawn {
+    #[topic]
+    pub schema_version: u32,
+    pub round: u32,
+    pub amount: i128,
+    pub reserve_balance: i128,
+}
+
+pub fn emit_reserve_credited(e: &Env, round: u32, amount: i128, reserve_balance: i128) {
+    ReserveCredited {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round,
+        amount,
+        reserve_balance,
+    }
+    .publish(e);
+}
+
+pub fn emit_reserve_drawn(e: &Env, round: u32, amount: i128, reserve_balance: i128) {
+    ReserveDrawn {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round,
+        amount,
+        reserve_balance,
+    }
+    .publish(e);
+}
+
+// ── Member Deposits ───────────────────────────────────────────────────────────
+
+/// Event: Member posted a security deposit
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct DepositPosted {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub member: Address,
+    pub amount: i128,
+    pub balance: i128,
+}
+
+/// Event: Part of a defaulter's deposit was slashed into the reserve
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct DepositSlashed {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub member: Address,
+    pub round: u32,
+    pub amount: i128,
+    pub remaining: i128,
+}
+
+pub fn emit_deposit_posted(e: &Env, member: Address, amount: i128, balance: i128) {
+    DepositPosted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        member,
+        amount,
+        balance,
+    }
+    .publish(e);
+}
+
+pub fn emit_deposit_slashed(e: &Env, member: Address, round: u32, amount: i128, remaining: i128) {
+    DepositSlashed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        member,
+        round,
+        amount,
+        remaining,
+    }
+    .publish(e);
+}
+
+/// Event: A member's remaining deposit was returned (cycle finished or exit)
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct DepositRefunded {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub member: Address,
+    pub amount: i128,
+}
+
+pub fn emit_deposit_refunded(e: &Env, member: Address, amount: i128) {
+    DepositRefunded {
+        schema_version: EVENT_SCHEMA_VERSION,
+        member,
+        amount,
+    }
+    .publish(e);
+}
+
+// ── Finite Cycle ──────────────────────────────────────────────────────────────
+
+/// Event: The configured number of rounds has been paid out; the cycle is
+/// finished. Emitted once, with the total paid out across all rounds.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Finished {
+    #[topic]
+    pub schema_version: u32,
+    pub rounds: u32,
+    pub total_distributed: i128,
+}
+
+pub fn emit_finished(e: &Env, rounds: u32, total_distributed: i128) {
+    Finished {
+        schema_version: EVENT_SCHEMA_VERSION,
+        rounds,
+        total_distributed,
+    }
+    .publish(e);
+}
+
+// ── Keeper ────────────────────────────────────────────────────────────────────
+
+/// Event: A keeper closed an expired round via `poke`
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RoundPoked {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub keeper: Address,
+    pub round: u32,
+    pub reward: i128,
+}
+
+pub fn emit_round_poked(e: &Env, keeper: Address, round: u32, reward: i128) {
+    RoundPoked {
+        schema_version: EVENT_SCHEMA_VERSION,
+        keeper,
+        round,
+        reward,
+    }
+    .publish(e);
+}
+
+// ── Admin Member Suspension ───────────────────────────────────────────────────
+
+/// Event: Admin suspended a member
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct MemberAdminSuspended {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub member: Address,
+}
+
+/// Event: Admin lifted a member's suspension
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct MemberAdminUnsuspended {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub member: Address,
+}
+
+pub fn emit_member_admin_suspended(e: &Env, member: Address) {
+    MemberAdminSuspended { schema_version: EVENT_SCHEMA_VERSION, member }.publish(e);
+}
+
+pub fn emit_member_admin_unsuspended(e: &Env, member: Address) {
+    MemberAdminUnsuspended { schema_version: EVENT_SCHEMA_VERSION, member }.publish(e);
+}
+
+// ── Manual Default ────────────────────────────────────────────────────────────
+
+/// Event: Admin marked a member as defaulted before the round closed
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct MemberMarkedDefault {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub member: Address,
+    pub round: u32,
+}
+
+pub fn emit_member_marked_default(e: &Env, member: Address, round: u32) {
+    MemberMarkedDefault { schema_version: EVENT_SCHEMA_VERSION, member, round }.publish(e);
+}
+
+// ── Debt Settlement ───────────────────────────────────────────────────────────
+
+/// Event: Member paid off debt recorded for missed contributions
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct DebtSettled {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub member: Address,
+    pub amount: i128,
+}
+
+pub fn emit_debt_settled(e: &Env, member: Address, amount: i128) {
+    DebtSettled { schema_version: EVENT_SCHEMA_VERSION, member, amount }.publish(e);
+}
+
+// ── Defaulting Recipient ──────────────────────────────────────────────────────
+
+/// Event: A defaulting recipient's turn passed to the next member who paid
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RecipientSkipped {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub skipped: Address,
+    pub recipient: Address,
+    pub round: u32,
+}
+
+pub fn emit_recipient_skipped(e: &Env, skipped: Address, recipient: Address, round: u32) {
+    RecipientSkipped {
+        schema_version: EVENT_SCHEMA_VERSION,
+        skipped,
+        recipient,
+        round,
+    }
+    .publish(e);
+}
+
+// ── Contribution Refund ───────────────────────────────────────────────────────
+
+/// Event: Paid member took their contribution back after the deadline
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ContributionRefunded {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub member: Address,
+    pub round: u32,
+    pub amount: i128,
+}
+
+pub fn emit_contribution_refunded(e: &Env, member: Address, round: u32, amount: i128) {
+    ContributionRefunded {
+        schema_version: EVENT_SCHEMA_VERSION,
+        member,
+        round,
+        amount,
+    }
+    .publish(e);
+}
+
+// ── Minimum Participation ─────────────────────────────────────────────────────
+
+/// Event: Round fell short of the minimum participation and was re-run
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RoundRestarted {
+    #[topic]
+    pub schema_version: u32,
+    pub round: u32,
+    pub paid: u32,
+    pub deadline: u64,
+}
+
+pub fn emit_round_restarted(e: &Env, round: u32, paid: u32, deadline: u64) {
+    RoundRestarted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round,
+        paid,
+        deadline,
+    }
+    .publish(e);
+}
+
+// ── Round Skipped ─────────────────────────────────────────────────────────────
+
+/// Event: Admin skipped a round; contributions were refunded
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RoundSkipped {
+    #[topic]
+    pub schema_version: u32,
+    pub round: u32,
+    pub refunded: u32,
+    pub deadline: u64,
+}
+
+pub fn emit_round_skipped(e: &Env, round: u32, refunded: u32, deadline: u64) {
+    RoundSkipped {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round,
+        refunded,
+        deadline,
+    }
+    .publish(e);
+}
+
+// ── Payout Delay ──────────────────────────────────────────────────────────────
+
+/// Event: Round pot held back until the payout delay elapses
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct PayoutHeld {
+    #[topic]
+    pub schema_version: u32,
+    pub round: u32,
+    pub recipient: Address,
+    pub release_at: u64,
+}
+
+pub fn emit_payout_held(e: &Env, round: u32, recipient: Address, release_at: u64) {
+    PayoutHeld {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round,
+        recipient,
+        release_at,
+    }
+    .publish(e);
+}
+
+/// Event: Held pot released to its recipient
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct PayoutReleased {
+    #[topic]
+    pub schema_version: u32,
+    pub round: u32,
+    pub recipient: Address,
+}
+
+pub fn emit_payout_released(e: &Env, round: u32, recipient: Address) {
+    PayoutReleased {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round,
+        recipient,
+    }
+    .publish(e);
+}
+
+// ── Payout Disputes ───────────────────────────────────────────────────────────
+
+/// Event: Member disputed a held payout
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct DisputeRaised {
+    #[topic]
+    pub schema_version: u32,
+    pub member: Address,
+    pub round: u32,
+}
+
+pub fn emit_dispute_raised(e: &Env, member: Address, round: u32) {
+    DisputeRaised {
+        schema_version: EVENT_SCHEMA_VERSION,
+        member,
+        round,
+    }
+    .publish(e);
+}
+
+/// Event: Admin resolved a payout dispute
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct DisputeResolved {
+    #[topic]
+    pub schema_version: u32,
+    pub round: u32,
+    pub upheld: bool,
+}
+
+pub fn emit_dispute_resolved(e: &Env, round: u32, upheld: bool) {
+    DisputeResolved {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round,
+        upheld,
+    }
+    .publish(e);
+}
+
+// ── Reserve Distribution ──────────────────────────────────────────────────────
+
+/// Event: Reserve split equally among current members
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ReserveDistributed {
+    #[topic]
+    pub schema_version: u32,
+    pub total: i128,
+    pub per_member: i128,
+    pub members: u32,
+}
+
+pub fn emit_reserve_distributed(e: &Env, total: i128, per_member: i128, members: u32) {
+    ReserveDistributed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        total,
+        per_member,
+        members,
+    }
+    .publish(e);
+}
+
+// ── Cycle Rollover ────────────────────────────────────────────────────────────
+
+/// Event: A new cycle started on the same terms after a finite cycle completed
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Cycle {
+    #[topic]
+    pub schema_version: u32,
+    pub cycle_number: u32,
+}
+
+pub fn emit_cycle(e: &Env, cycle_number: u32) {
+    Cycle {
+        schema_version: EVENT_SCHEMA_VERSION,
+        cycle_number,
+    }
+    .publish(e);
+}
+
+// ── Payout Circuit Breaker ────────────────────────────────────────────────────
+
+/// Event: A payout transfer reverted; the amount is held for `release_payout`
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct PayoutFailed {
+    #[topic]
+    pub schema_version: u32,
+    pub round: u32,
+    pub recipient: Address,
+    pub amount: i128,
+    pub consecutive_failures: u32,
+}
+
+pub fn emit_payout_failed(
+    e: &Env,
+    round: u32,
+    recipient: Address,
+    amount: i128,
+    consecutive_failures: u32,
+) {
+    PayoutFailed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round,
+        recipient,
+        amount,
+        consecutive_failures,
+    }
+    .publish(e);
+}
+
+/// Event: Repeated payout failures paused the group
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct CircuitBreakerTripped {
+    #[topic]
+    pub schema_version: u32,
+    pub round: u32,
+    pub consecutive_failures: u32,
+}
+
+pub fn emit_circuit_breaker_tripped(e: &Env, round: u32, consecutive_failures: u32) {
+    CircuitBreakerTripped {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round,
+        consecutive_failures,
+    }
+    .publish(e);
+}
+
+// ── Position Transfer ─────────────────────────────────────────────────────────
+
+/// Event: A member handed their position to a new address
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct PositionTransferred {
+    #[topic]
+    pub schema_version: u32,
+    pub from: Address,
+    pub to: Address,
+}
+
+pub fn emit_position_transferred(e: &Env, from: Address, to: Address) {
+    PositionTransferred {
+        schema_version: EVENT_SCHEMA_VERSION,
+        from,
+        to,
+    }
+    .publish(e);
+}
+
+// ── Cycle Finalization ────────────────────────────────────────────────────────
+
+/// Event: Leftover funds were settled at the end of a cycle
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct CycleFinalized {
+    #[topic]
+    pub schema_version: u32,
+    pub policy: EndOfCyclePolicy,
+    pub residual: i128,
+}
+
+pub fn emit_cycle_finalized(e: &Env, policy: EndOfCyclePolicy, residual: i128) {
+    CycleFinalized {
+        schema_version: EVENT_SCHEMA_VERSION,
+        policy,
+        residual,
+    }
+    .publish(e);
+}
+
+// ── Round Opened ──────────────────────────────────────────────────────────────
+
+/// Event: The first contribution of a round was recorded
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Opened {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub round: u32,
+    pub deadline: u64,
+}
+
+pub fn emit_opened(e: &Env, round: u32, deadline: u64) {
+    Opened {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round,
+        deadline,
+    }
+    .publish(e);
+}
+
+// ── Prepaid Contributions ─────────────────────────────────────────────────────
+
+/// Event: A member paid ahead for upcoming rounds
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Prepaid {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub member: Address,
+    pub amount: i128,
+    pub balance: i128,
+}
+
+pub fn emit_prepaid(e: &Env, member: Address, amount: i128, balance: i128) {
+    Prepaid {
+        schema_version: EVENT_SCHEMA_VERSION,
+        member,
+        amount,
+        balance,
+    }
+    .publish(e);
+}
+
+// ── Contribution Memo ─────────────────────────────────────────────────────────
+
+/// Event: A contribution was made with an accounting reference
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ContributionMemo {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub contributor: Address,
+    pub round: u32,
+    pub amount: i128,
+    pub memo: Bytes,
+}
+
+pub fn emit_contribution_memo(e: &Env, contributor: Address, round: u32, amount: i128, memo: Bytes) {
+    ContributionMemo {
+        schema_version: EVENT_SCHEMA_VERSION,
+        contributor,
+        round,
+        amount,
+        memo,
+    }
+    .publish(e);
+}
+
+// ── Streak Reward ─────────────────────────────────────────────────────────────
+
+/// Event: A member claimed the reward for their on-time streak
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct StreakRewardClaimed {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub member: Address,
+    pub streak: u32,
+    pub amount: i128,
+}
+
+pub fn emit_streak_reward_claimed(e: &Env, member: Address, streak: u32, amount: i128) {
+    StreakRewardClaimed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        member,
+        streak,
+        amount,
+    }
+    .publish(e);
+}
+
+// ── Token Rescue ──────────────────────────────────────────────────────────────
+
+/// Event: A stray token was transferred out of the contract by the admin
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct TokenRescued {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+}
+
+pub fn emit_token_rescued(e: &Env, token: Address, to: Address, amount: i128) {
+    TokenRescued {
+        schema_version: EVENT_SCHEMA_VERSION,
+        token,
+        to,
+        amount,
+    }
+    .publish(e);
+}
+
+// ── Group Configuration ───────────────────────────────────────────────────────
+
+/// Event: Full initial configuration, so an indexer can bootstrap the group
+/// from events alone and replay every later event on top of it
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct GroupConfigured {
+    #[topic]
+    pub schema_version: u32,
+    pub admin: Address,
+    pub members: Vec<Address>,
+    pub token: Address,
+    pub contribution_amount: i128,
+    pub round_duration: u64,
+    pub payout_order: Vec<Address>,
+    pub start_at: u64,
+    pub config: RoscaConfig,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn emit_group_configured(
+    e: &Env,
+    admin: Address,
+    members: Vec<Address>,
+    token: Address,
+    contribution_amount: i128,
+    round_duration: u64,
+    payout_order: Vec<Address>,
+    start_at: u64,
+    config: RoscaConfig,
+) {
+    GroupConfigured {
+        schema_version: EVENT_SCHEMA_VERSION,
+        admin,
+        members,
+        token,
+        contribution_amount,
+        round_duration,
+        payout_order,
+        start_at,
+        config,
+    }
+    .publish(e);
+}
+
+// ── Auto-Ejection ─────────────────────────────────────────────────────────────
+
+/// Event: A member missed too many rounds in a row and was ejected
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct MemberEjected {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub member: Address,
+    pub consecutive_defaults: u32,
+    pub policy: EjectionPolicy,
+}
+
+pub fn emit_member_ejected(e: &Env, member: Address, consecutive_defaults: u32, policy: EjectionPolicy) {
+    MemberEjected {
+        schema_version: EVENT_SCHEMA_VERSION,
+        member,
+        consecutive_defaults,
+        policy,
+    }
+    .publish(e);
+}
+
+// ── Round Clock ───────────────────────────────────────────────────────────────
+
+/// Event: The admin froze the round clock
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ClockFrozen {
+    #[topic]
+    pub schema_version: u32,
+    pub frozen_at: u64,
+}
+
+/// Event: The round clock restarted; deadlines moved back by `frozen_for`
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct ClockUnfrozen {
+    #[topic]
+    pub schema_version: u32,
+    pub frozen_for: u64,
+}
+
+pub fn emit_clock_frozen(e: &Env, frozen_at: u64) {
+    ClockFrozen { schema_version: EVENT_SCHEMA_VERSION, frozen_at }.publish(e);
+}
+
+pub fn emit_clock_unfrozen(e: &Env, frozen_for: u64) {
+    ClockUnfrozen { schema_version: EVENT_SCHEMA_VERSION, frozen_for }.publish(e);
+}
+
+// ── Payout Address ────────────────────────────────────────────────────────────
+
+/// Event: A member chose where their payouts are sent
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct PayoutAddressSet {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub member: Address,
+    pub payout_to: Address,
+}
+
+pub fn emit_payout_address_set(e: &Env, member: Address, payout_to: Address) {
+    PayoutAddressSet {
+        schema_version: EVENT_SCHEMA_VERSION,
+        member,
+        payout_to,
+    }
+    .publish(e);
+}
+
+// ── Admin Recovery ────────────────────────────────────────────────────────────
+
+/// Event: The guardian took over the admin role after admin inactivity
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct AdminRecovered {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub guardian: Address,
+    pub old_admin: Address,
+    pub new_admin: Address,
+}
+
+pub fn emit_admin_recovered(e: &Env, guardian: Address, old_admin: Address, new_admin: Address) {
+    AdminRecovered {
+        schema_version: EVENT_SCHEMA_VERSION,
+        guardian,
+        old_admin,
+        new_admin,
+    }
+    .publish(e);
+}
+
+// ── Pot Carry-Over ────────────────────────────────────────────────────────────
+
+/// Event: An under-subscribed round's pot was carried forward and the round re-run
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct PotCarriedOver {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub round: u32,
+    pub amount: i128,
+    pub total_carried: i128,
+}
+
+pub fn emit_pot_carried_over(e: &Env, round: u32, amount: i128, total_carried: i128) {
+    PotCarriedOver {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round,
+        amount,
+        total_carried,
+    }
+    .publish(e);
+}
+
+// ── Excused Absences ──────────────────────────────────────────────────────────
+
+/// Event: A missed round was excused instead of counted as a default
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct AbsenceExcused {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub member: Address,
+    pub round: u32,
+    pub remaining: u32,
+}
+
+pub fn emit_absence_excused(e: &Env, member: Address, round: u32, remaining: u32) {
+    AbsenceExcused {
+        schema_version: EVENT_SCHEMA_VERSION,
+        member,
+        round,
+        remaining,
+    }
+    .publish(e);
+}
+
+// ── Quick Polls ───────────────────────────────────────────────────────────────
+
+/// Event: The admin opened a quick yes/no poll
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct PollOpened {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub topic: Symbol,
+    pub deadline: u64,
+}
+
+/// Event: A member answered a quick poll
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct PollVoteCast {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub topic: Symbol,
+    pub member: Address,
+    pub yes: bool,
+}
+
+pub fn emit_poll_opened(e: &Env, topic: Symbol, deadline: u64) {
+    PollOpened {
+        schema_version: EVENT_SCHEMA_VERSION,
+        topic,
+        deadline,
+    }
+    .publish(e);
+}
+
+pub fn emit_poll_vote_cast(e: &Env, topic: Symbol, member: Address, yes: bool) {
+    PollVoteCast {
+        schema_version: EVENT_SCHEMA_VERSION,
+        topic,
+        member,
+        yes,
+    }
+    .publish(e);
+}
+
+// ── Payout Hook ───────────────────────────────────────────────────────────────
+
+/// Event: The payout hook failed; the payout itself went ahead
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct PayoutHookFailed {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub hook: Address,
+    pub round: u32,
+}
+
+pub fn emit_payout_hook_failed(e: &Env, hook: Address, round: u32) {
+    PayoutHookFailed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        hook,
+        round,
+    }
+    .publish(e);
+}
+
+// ── Reconciliation ────────────────────────────────────────────────────────────
+
+/// Event: Stray `PaidMembers` entries were removed and their contributions refunded
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Reconciled {
+    #[topic]
+    pub schema_version: u32,
+    pub removed: Vec<Address>,
+    pub refunded: i128,
+}
+
+pub fn emit_reconciled(e: &Env, removed: Vec<Address>, refunded: i128) {
+    Reconciled {
+        schema_version: EVENT_SCHEMA_VERSION,
+        removed,
+        refunded,
+    }
+    .publish(e);
+}
+
+// ── Deferred Payouts ──────────────────────────────────────────────────────────
+
+/// Event: A short payout was deferred at the recipient's request and the round re-run
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct PayoutDeferred {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub recipient: Address,
+    pub round: u32,
+    pub collected: i128,
+    pub carried: i128,
+}
+
+pub fn emit_payout_deferred(
+    e: &Env,
+    recipient: Address,
+    round: u32,
+    collected: i128,
+    carried: i128,
+) {
+    PayoutDeferred {
+        schema_version: EVENT_SCHEMA_VERSION,
+        recipient,
+        round,
+        collected,
+        carried,
+    }
+    .publish(e);
+}
+
+// ── Token Change ──────────────────────────────────────────────────────────────
+
+/// Event: Base token switched between cycles
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct TokenChanged {
+    #[topic]
+    pub schema_version: u32,
+    pub old_token: Address,
+    pub new_token: Address,
+    pub decimals: u32,
+}
+
+pub fn emit_token_changed(e: &Env, old_token: Address, new_token: Address, decimals: u32) {
+    TokenChanged {
+        schema_version: EVENT_SCHEMA_VERSION,
+        old_token,
+        new_token,
+        decimals,
+    }
+    .publish(e);
+}
+
+// ── Fund Flows ────────────────────────────────────────────────────────────────
+
+/// Event: Uniform record of a token movement into or out of the contract
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct FundFlow {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub kind: Symbol,
+    pub from: Address,
+    pub to: Address,
+    pub amount: i128,
+    pub round: u32,
+}
+
+pub fn emit_fund_flow(e: &Env, kind: Symbol, from: Address, to: Address, amount: i128, round: u32) {
+    FundFlow {
+        schema_version: EVENT_SCHEMA_VERSION,
+        kind,
+        from,
+        to,
+        amount,
+        round,
+    }
+    .publish(e);
+}
+
+// ── Declined Turns ────────────────────────────────────────────────────────────
+
+/// Event: The scheduled recipient passed their turn to the next member
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct TurnDeclined {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub member: Address,
+    pub round: u32,
+    pub new_recipient: Address,
+}
+
+pub fn emit_turn_declined(e: &Env, member: Address, round: u32, new_recipient: Address) {
+    TurnDeclined {
+        schema_version: EVENT_SCHEMA_VERSION,
+        member,
+        round,
+        new_recipient,
+    }
+    .publish(e);
+}
+
+// ── Pot Boosts ────────────────────────────────────────────────────────────────
+
+/// Event: A member voluntarily topped up the current round's pot
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct PotBoosted {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub donor: Address,
+    pub round: u32,
+    pub amount: i128,
+    pub total_boosted: i128,
+}
+
+pub fn emit_pot_boosted(e: &Env, donor: Address, round: u32, amount: i128, total_boosted: i128) {
+    PotBoosted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        donor,
+        round,
+        amount,
+        total_boosted,
+    }
+    .publish(e);
+}
+
+/// Event: A boost was returned to its donor because the round it was made
+/// for will not pay it out
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct BoostRefunded {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub donor: Address,
+    pub round: u32,
+    pub amount: i128,
+}
+
+pub fn emit_boost_refunded(e: &Env, donor: Address, round: u32, amount: i128) {
+    BoostRefunded {
+        schema_version: EVENT_SCHEMA_VERSION,
+        donor,
+        round,
+        amount,
+    }
+    .publish(e);
+}
+
+// ── Over-payments ─────────────────────────────────────────────────────────────
+
+/// Event: A contribution exceeded what the member owed. `credited` is the part
+/// moved to prepaid escrow; the rest of `excess` was never taken.
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct Overpayment {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub member: Address,
+    pub round: u32,
+    pub excess: i128,
+    pub credited: i128,
+}
+
+pub fn emit_overpayment(e: &Env, member: Address, round: u32, excess: i128, credited: i128) {
+    Overpayment {
+        schema_version: EVENT_SCHEMA_VERSION,
+        member,
+        round,
+        excess,
+        credited,
+    }
+    .publish(e);
+}
+
+// ── Admin Refunds ─────────────────────────────────────────────────────────────
+
+/// Event: The admin refunded a member's contribution to the current round
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct AdminRefund {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub member: Address,
+    pub round: u32,
+    pub amount: i128,
+}
+
+pub fn emit_admin_refund(e: &Env, member: Address, round: u32, amount: i128) {
+    AdminRefund {
+        schema_version: EVENT_SCHEMA_VERSION,
+        member,
+        round,
+        amount,
+    }
+    .publish(e);
+}
+
+// ── History Pruning ───────────────────────────────────────────────────────────
+
+/// Event: Round records before `before_round` are about to be deleted; the
+/// totals summarise what they held
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct HistoryPruned {
+    #[topic]
+    pub schema_version: u32,
+    pub before_round: u32,
+    pub removed: u32,
+    pub total_pot: i128,
+    pub total_defaults: u32,
+}
+
+pub fn emit_history_pruned(
+    e: &Env,
+    before_round: u32,
+    removed: u32,
+    total_pot: i128,
+    total_defaults: u32,
+) {
+    HistoryPruned {
+        schema_version: EVENT_SCHEMA_VERSION,
+        before_round,
+        removed,
+        total_pot,
+        total_defaults,
+    }
+    .publish(e);
+}
+
+// ── Slot Beneficiaries ────────────────────────────────────────────────────────
+
+/// Event: A rotation slot's payout is now split between co-owners
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct SlotBeneficiariesSet {
+    #[topic]
+    pub schema_version: u32,
+    pub slot: u32,
+    pub beneficiaries: Vec<SlotBeneficiary>,
+}
+
+pub fn emit_slot_beneficiaries_set(e: &Env, slot: u32, beneficiaries: Vec<SlotBeneficiary>) {
+    SlotBeneficiariesSet {
+        schema_version: EVENT_SCHEMA_VERSION,
+        slot,
+        beneficiaries,
+    }
+    .publish(e);
+}
+
+// ── Round Retries ─────────────────────────────────────────────────────────────
+
+/// Event: The admin voided the current round and re-ran it for the same recipient
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct RoundRetried {
+    #[topic]
+    pub schema_version: u32,
+    pub round: u32,
+    pub retries: u32,
+    pub new_deadline: u64,
+}
+
+pub fn emit_round_retried(e: &Env, round: u32, retries: u32, new_deadline: u64) {
+    RoundRetried {
+        schema_version: EVENT_SCHEMA_VERSION,
+        round,
+        retries,
+        new_deadline,
+    }
+    .publish(e);
+}
+
+// ── Payout Swaps ──────────────────────────────────────────────────────────────
+
+/// Event: A payout was swapped into the member's preferred token
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct PayoutSwapped {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub member: Address,
+    pub round: u32,
+    pub token_out: Address,
+    pub amount_in: i128,
+    pub amount_out: i128,
+}
+
+pub fn emit_payout_swapped(
+    e: &Env,
+    member: Address,
+    round: u32,
+    token_out: Address,
+    amount_in: i128,
+    amount_out: i128,
+) {
+    PayoutSwapped {
+        schema_version: EVENT_SCHEMA_VERSION,
+        member,
+        round,
+        token_out,
+        amount_in,
+        amount_out,
+    }
+    .publish(e);
+}
+
+/// Event: A payout swap failed and the member was paid in the pool token
+#[contractevent]
+#[derive(Clone, Debug)]
+pub struct PayoutSwapFailed {
+    #[topic]
+    pub schema_version: u32,
+    #[topic]
+    pub member: Address,
+    pub round: u32,
+    pub amount: i128,
+}
+
+pub fn emit_payout_swap_failed(e: &Env, member: Address, round: u32, amount: i128) {
+    PayoutSwapFailed {
+        schema_version: EVENT_SCHEMA_VERSION,
+        member,
+        round,
+        amount,
+    }
+    .publish(e);
+}
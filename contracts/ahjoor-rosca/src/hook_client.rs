@@ -0,0 +1,9 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Interface a payout hook contract implements to be told about payouts.
+#[contractclient(name = "PayoutHookClient")]
+pub trait PayoutHookInterface {
+    /// Called after `recipient` was paid `amount` of the base token for
+    /// `round`. Failures are caught and do not affect the payout.
+    fn on_payout(env: Env, recipient: Address, round: u32, amount: i128);
+}
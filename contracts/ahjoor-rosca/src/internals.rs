@@ -1,5 +1,8 @@
-use crate::{errors::{Error, ExtError}, events, audit_trail, ContributionEntry, CycleSnapshotData, DataKey, DataKey2, DataKey3, DataKey4, PersistentKey, PayoutRecord, SlotBid, types::{InsuranceClaim, InsuranceCoverageMode}};
-use soroban_sdk::{panic_with_error, token, Address, Bytes, BytesN, Env, Map, Vec};
+use crate::{errors::{Error, ExtError, ExtError2, ExtError3}, events, audit_trail, ContributionEntry, CycleSnapshotData, DataKey, DataKey2, DataKey3, DataKey4, DataKey5, PendingPayout, PersistentKey, PayoutRecord, RoundRecord, SlotBeneficiary, SlotBid, types::{DefaultingRecipientPolicy, GroupStatus, InsuranceClaim, InsuranceCoverageMode}};
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::{
+    panic_with_error, symbol_short, token, Address, Bytes, BytesN, Env, IntoVal, Map, Symbol, Vec,
+};
 
 /// Returns the timestamp (seconds) after which the grace period for a given round deadline expires.
 ///
@@ -23,7 +26,7 @@ pub(crate) fn get_grace_deadline(env: &Env, round_deadline: u64) -> u64 {
         let grace_ledgers: u32 = env
             .storage()
             .instance()
-            .get(&DataKey4::GracePeriodLedgers)
+            .get(&DataKey2::GracePeriodLedgers)
             .unwrap_or(0);
         round_deadline.saturating_add(grace_ledgers as u64)
     }
@@ -32,6 +35,919 @@ pub(crate) fn get_grace_deadline(env: &Env, round_deadline: u64) -> u64 {
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 100_000;
 const PERSISTENT_BUMP_AMOUNT: u32 = 120_000;
 
+/// Consecutive payout failures that pause the group unless configured.
+const DEFAULT_PAYOUT_FAILURE_THRESHOLD: u32 = 3;
+
+/// Sets `key` to `value` in instance storage only if it is not already present.
+/// Returns `true` when the key was written.
+fn set_if_missing<K, V>(env: &Env, key: &K, value: &V) -> bool
+where
+    K: soroban_sdk::IntoVal<Env, soroban_sdk::Val>,
+    V: soroban_sdk::IntoVal<Env, soroban_sdk::Val>,
+{
+    if env.storage().instance().has(key) {
+        return false;
+    }
+    env.storage().instance().set(key, value);
+    true
+}
+
+/// Moves a `Vec<Address>` entry from instance to persistent storage for
+/// deployments that predate the move. Returns `true` when the key was moved.
+fn move_to_persistent(env: &Env, key: &DataKey) -> bool {
+    let value: Option<Vec<Address>> = env.storage().instance().get(key);
+    match value {
+        Some(v) if !env.storage().persistent().has(key) => {
+            env.storage().persistent().set(key, &v);
+            env.storage().instance().remove(key);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Moves the member list and payout order into persistent storage, then
+/// backfills instance keys that older deployments may be missing with the
+/// same defaults `init` would use. Returns the number of keys written.
+pub(crate) fn backfill_missing_keys(env: &Env) -> u32 {
+    let mut written: u32 = 0;
+    written += move_to_persistent(env, &DataKey::Members) as u32;
+    written += move_to_persistent(env, &DataKey::PayoutOrder) as u32;
+    extend_member_ttl(env);
+    written += set_if_missing(env, &DataKey::PenaltyAmount, &0i128) as u32;
+    written += set_if_missing(env, &DataKey::Defaulters, &Vec::<Address>::new(env)) as u32;
+    written += set_if_missing(env, &DataKey::DefaultCount, &Map::<Address, u32>::new(env)) as u32;
+    written += set_if_missing(env, &DataKey::SuspendedMembers, &Vec::<Address>::new(env)) as u32;
+    written += set_if_missing(env, &DataKey::ExitedMembers, &Vec::<Address>::new(env)) as u32;
+    written += set_if_missing(env, &DataKey::MemberContributions, &Map::<Address, i128>::new(env)) as u32;
+    written += set_if_missing(env, &DataKey::RewardPool, &0i128) as u32;
+    written += set_if_missing(env, &DataKey::FeeBps, &0u32) as u32;
+    written += set_if_missing(env, &DataKey::MaxDefaults, &3u32) as u32;
+    written += set_if_missing(env, &DataKey::MaxMembers, &50u32) as u32;
+    written += set_if_missing(env, &DataKey2::GracePeriodLedgers, &0u32) as u32;
+    written += set_if_missing(env, &DataKey3::GracePeriodSeconds, &0u64) as u32;
+    written += set_if_missing(env, &DataKey2::PendingPenalties, &Map::<Address, u32>::new(env)) as u32;
+    written += set_if_missing(env, &DataKey2::SkipFee, &0i128) as u32;
+    written += set_if_missing(env, &DataKey2::MaxSkipsPerCycle, &0u32) as u32;
+    written += set_if_missing(env, &DataKey2::InsurancePool, &0i128) as u32;
+
+    written
+}
+
+/// Extends the TTL of the persistent member list and payout order. They are
+/// large and rarely written, so they live outside the shared instance TTL.
+pub(crate) fn extend_member_ttl(env: &Env) {
+    extend_persistent_ttl(env, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+/// Extends every long-lived persistent entry (member list, payout order and
+/// round history) that exists to at least `extend_to` ledgers.
+pub(crate) fn extend_persistent_ttl(env: &Env, threshold: u32, extend_to: u32) {
+    let storage = env.storage().persistent();
+    for key in [DataKey::Members, DataKey::PayoutOrder] {
+        if storage.has(&key) {
+            storage.extend_ttl(&key, threshold, extend_to);
+        }
+    }
+    if storage.has(&PersistentKey::RoundHistory) {
+        storage.extend_ttl(&PersistentKey::RoundHistory, threshold, extend_to);
+    }
+}
+
+/// Per-round keys (paid list and deadlines) kept in temporary storage. They
+/// are rewritten every round, so they expire with it instead of lingering.
+pub(crate) const ROUND_STATE_KEYS: [DataKey; 3] = [
+    DataKey::PaidMembers,
+    DataKey::RoundDeadline,
+    DataKey::RoundDeadlineTimestamp,
+];
+
+/// Ledgers round state outlives the round itself, so a late `close_round` or
+/// `finalize_round` still finds it.
+const ROUND_STATE_GRACE_LEDGERS: u32 = 120_000;
+
+/// TTL for round state: one round duration, the group's late-contribution
+/// grace period and the grace margin, capped at the network maximum. The
+/// duration is read in seconds on timestamp schedules; a ledger closes at
+/// most once a second, so the round and its grace period are covered.
+fn round_state_ttl(env: &Env) -> u32 {
+    let use_timestamp: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey2::UseTimestampSchedule)
+        .unwrap_or(false);
+    let duration: u64 = if use_timestamp {
+        env.storage()
+            .instance()
+            .get(&DataKey2::RoundDurationSeconds)
+            .unwrap_or(0)
+    } else {
+        env.storage()
+            .instance()
+            .get(&DataKey::RoundDuration)
+            .unwrap_or(0)
+    };
+    let grace_seconds: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey3::GracePeriodSeconds)
+        .unwrap_or(0);
+    let grace_ledgers: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey2::GracePeriodLedgers)
+        .unwrap_or(0);
+    let duration = u32::try_from(duration.saturating_add(grace_seconds)).unwrap_or(u32::MAX);
+    duration
+        .saturating_add(grace_ledgers)
+        .saturating_add(ROUND_STATE_GRACE_LEDGERS)
+        .min(env.storage().max_ttl())
+}
+
+/// Reads round state from temporary storage, falling back to instance storage
+/// for groups that have not run `migrate_storage` yet. If a deadline has
+/// expired anyway (a round stalled past its TTL), it is recomputed from the
+/// round's start; an expired paid list reads as missing.
+pub(crate) fn round_state_get<V>(env: &Env, key: &DataKey) -> Option<V>
+where
+    V: soroban_sdk::TryFromVal<Env, soroban_sdk::Val>,
+{
+    if let Some(value) = env
+        .storage()
+        .temporary()
+        .get(key)
+        .or_else(|| env.storage().instance().get(key))
+    {
+        return Some(value);
+    }
+    match key {
+        DataKey::RoundDeadline | DataKey::RoundDeadlineTimestamp => {
+            let rebuilt: soroban_sdk::Val = rebuild_deadline(env, key)?.into_val(env);
+            V::try_from_val(env, &rebuilt).ok()
+        }
+        _ => None,
+    }
+}
+
+/// The current round's start plus one round duration, or `None` before
+/// `init` or for a timestamp deadline on a ledger-schedule group. Loses any
+/// shift from a pause or clock freeze, which is the best left to go on once
+/// the stored deadline has expired.
+fn rebuild_deadline(env: &Env, key: &DataKey) -> Option<u64> {
+    let started_at: u64 = env.storage().instance().get(&DataKey4::RoundStartedAt)?;
+    let use_timestamp: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey2::UseTimestampSchedule)
+        .unwrap_or(false);
+    let duration: u64 = if use_timestamp {
+        env.storage()
+            .instance()
+            .get(&DataKey2::RoundDurationSeconds)
+            .unwrap_or(0)
+    } else if matches!(key, DataKey::RoundDeadlineTimestamp) {
+        return None;
+    } else {
+        env.storage()
+            .instance()
+            .get(&DataKey::RoundDuration)
+            .unwrap_or(0)
+    };
+    Some(started_at.saturating_add(duration))
+}
+
+/// Writes round state to temporary storage with a TTL covering the round,
+/// dropping any legacy instance copy so it cannot shadow a newer value.
+pub(crate) fn round_state_set<V>(env: &Env, key: &DataKey, value: &V)
+where
+    V: soroban_sdk::IntoVal<Env, soroban_sdk::Val>,
+{
+    let ttl = round_state_ttl(env);
+    env.storage().temporary().set(key, value);
+    env.storage().temporary().extend_ttl(key, ttl, ttl);
+    env.storage().instance().remove(key);
+}
+
+/// Extends every live round-state entry to cover the current round. Called
+/// on contributions and whenever the round clock is paused or frozen.
+pub(crate) fn extend_round_state_ttl(env: &Env) {
+    let ttl = round_state_ttl(env);
+    let storage = env.storage().temporary();
+    for key in ROUND_STATE_KEYS {
+        if storage.has(&key) {
+            storage.extend_ttl(&key, ttl, ttl);
+        }
+    }
+}
+
+/// Returns the amount `member` owes each round: their explicit per-member
+/// amount if one was set at init, otherwise the flat `ContributionAmt`
+/// scaled by the member's tier (10_000 bps = 1x).
+pub(crate) fn member_required_amount(env: &Env, member: &Address) -> i128 {
+    let explicit: Map<Address, i128> = env
+        .storage()
+        .instance()
+        .get(&DataKey::MemberContributionAmt)
+        .unwrap_or(Map::new(env));
+    if let Some(amount) = explicit.get(member.clone()) {
+        return amount;
+    }
+    let base_amount: i128 = pegged_amount(env).unwrap_or_else(|| {
+        env.storage()
+            .instance()
+            .get(&DataKey::ContributionAmt)
+            .unwrap_or(0)
+    });
+    let tiers: Map<Address, u32> = env
+        .storage()
+        .instance()
+        .get(&DataKey2::MemberTiers)
+        .unwrap_or(Map::new(env));
+    let tier_bps = tiers.get(member.clone()).unwrap_or(10_000);
+    (base_amount * tier_bps as i128) / 10_000
+}
+
+/// Flat contribution in base token while the group is pegged: the pegged
+/// value at the last oracle price read, scaled to the base token's decimals.
+/// `None` when no peg is set.
+pub(crate) fn pegged_amount(env: &Env) -> Option<i128> {
+    let pegged_value: i128 = env.storage().instance().get(&DataKey5::PeggedValue)?;
+    let price: i128 = env.storage().instance().get(&DataKey5::PegPrice)?;
+    let decimals: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey4::TokenDecimals)
+        .unwrap_or(7);
+    let unit = 10i128
+        .checked_pow(decimals)
+        .unwrap_or_else(|| panic_with_error!(env, ExtError::InvalidAmount));
+    Some(
+        pegged_value
+            .checked_mul(unit)
+            .unwrap_or_else(|| panic_with_error!(env, ExtError::InvalidAmount))
+            / price,
+    )
+}
+
+/// Reads the current price from the peg oracle, if one is set, and stores it
+/// for `pegged_amount`. Panics on a non-positive or stale price.
+pub(crate) fn refresh_peg_price(env: &Env) {
+    let Some(oracle) = env.storage().instance().get::<_, Address>(&DataKey5::Oracle) else {
+        return;
+    };
+    let (price, published_at) = crate::PriceOracleClient::new(env, &oracle).price();
+    if price <= 0 {
+        panic_with_error!(env, Error::InvalidExchangeRate);
+    }
+    let max_age: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey5::OracleMaxAge)
+        .unwrap_or(0);
+    if env.ledger().timestamp() > published_at.saturating_add(max_age) {
+        panic_with_error!(env, ExtError3::StalePrice);
+    }
+    env.storage().instance().set(&DataKey5::PegPrice, &price);
+}
+
+/// Base-token balance the contract is expected to hold: the current round's
+/// contributions, late fees, boosts and carried-over pot plus every tracked
+/// reserve (rewards, insurance, fee reserve, emergency reserve, treasury) and
+/// any open slot-auction deposits.
+pub(crate) fn accounted_balance(env: &Env) -> i128 {
+    let mut accounted: i128 = member_contributions_total(env);
+
+    accounted += env
+        .storage()
+        .instance()
+        .get::<_, i128>(&DataKey::RewardPool)
+        .unwrap_or(0);
+    accounted += env
+        .storage()
+        .instance()
+        .get::<_, i128>(&DataKey2::InsurancePool)
+        .unwrap_or(0);
+    accounted += side_escrow_total(env);
+    accounted += env
+        .storage()
+        .instance()
+        .get::<_, i128>(&DataKey4::LateFeePot)
+        .unwrap_or(0);
+    accounted += env
+        .storage()
+        .instance()
+        .get::<_, i128>(&DataKey4::ReservePool)
+        .unwrap_or(0);
+    accounted += env
+        .storage()
+        .instance()
+        .get::<_, i128>(&DataKey5::CarryOver)
+        .unwrap_or(0);
+    accounted += env
+        .storage()
+        .instance()
+        .get::<_, i128>(&DataKey5::RoundBoost)
+        .unwrap_or(0);
+    accounted += member_deposits_total(env);
+    accounted += prepaid_total(env);
+    if let Some(token_addr) = env.storage().instance().get::<_, Address>(&DataKey::Token) {
+        accounted += pending_payout_total(env, &token_addr);
+    }
+
+    accounted
+}
+
+/// Base-token balances held for purposes other than a round's pot: the
+/// emergency reserve, the treasury and open slot-auction deposits. None of it
+/// is ever paid out as a round's payout.
+pub(crate) fn side_escrow_total(env: &Env) -> i128 {
+    let mut total: i128 = env
+        .storage()
+        .persistent()
+        .get::<_, i128>(&DataKey3::EmergencyReserveBalance)
+        .unwrap_or(0);
+    total += env
+        .storage()
+        .instance()
+        .get::<_, i128>(&DataKey3::TreasuryBalance)
+        .unwrap_or(0);
+    let bids: Vec<SlotBid> = env
+        .storage()
+        .instance()
+        .get(&DataKey3::AuctionBids)
+        .unwrap_or(Vec::new(env));
+    for bid in bids.iter() {
+        total += bid.amount;
+    }
+    total
+}
+
+/// Rejects an inflow of `amount` that would take `accounted_balance` above
+/// the group's `MaxTvl` cap, if one is set.
+pub(crate) fn check_tvl(env: &Env, amount: i128) {
+    let max_tvl: i128 = env
+        .storage()
+        .instance()
+        .get(&DataKey5::MaxTvl)
+        .unwrap_or(0);
+    if max_tvl > 0 && accounted_balance(env).saturating_add(amount) > max_tvl {
+        panic_with_error!(env, ExtError3::TvlCapExceeded);
+    }
+}
+
+/// Balance of `token_addr` not owed to anyone in particular: everything but
+/// security deposits, held payouts, auction escrow, unpaid-out contributions,
+/// boosts and the carried-over pot.
+pub(crate) fn residual_balance(env: &Env, token_addr: &Address) -> i128 {
+    let balance = token::Client::new(env, token_addr).balance(&env.current_contract_address());
+    let mut owed = pending_payout_total(env, token_addr);
+    let base_token: Option<Address> = env.storage().instance().get(&DataKey::Token);
+    if base_token.as_ref() == Some(token_addr) {
+        owed += member_deposits_total(env);
+        owed += prepaid_total(env);
+        owed += member_contributions_total(env);
+        owed += env
+            .storage()
+            .instance()
+            .get::<_, i128>(&DataKey5::CarryOver)
+            .unwrap_or(0);
+        owed += env
+            .storage()
+            .instance()
+            .get::<_, i128>(&DataKey5::RoundBoost)
+            .unwrap_or(0);
+        let bids: Vec<SlotBid> = env
+            .storage()
+            .instance()
+            .get(&DataKey3::AuctionBids)
+            .unwrap_or(Vec::new(env));
+        for bid in bids.iter() {
+            owed += bid.amount;
+        }
+    }
+    (balance - owed).max(0)
+}
+
+/// Removes the pending boosts and returns what each donor put in, for a
+/// caller that refunds them because the round will not pay them out.
+pub(crate) fn take_boosts(env: &Env) -> Map<Address, i128> {
+    let boosts: Map<Address, i128> = env
+        .storage()
+        .instance()
+        .get(&DataKey5::RoundBoosts)
+        .unwrap_or(Map::new(env));
+    env.storage().instance().remove(&DataKey5::RoundBoost);
+    env.storage().instance().remove(&DataKey5::RoundBoosts);
+    boosts
+}
+
+/// Removes the carried-over pot and returns what each member contributed to
+/// it, for a caller that refunds it because no round will pay it out.
+pub(crate) fn take_carry_over(env: &Env) -> Map<Address, i128> {
+    let shares: Map<Address, i128> = env
+        .storage()
+        .instance()
+        .get(&DataKey5::CarryOverShares)
+        .unwrap_or(Map::new(env));
+    env.storage().instance().remove(&DataKey5::CarryOver);
+    env.storage().instance().remove(&DataKey5::CarryOverShares);
+    shares
+}
+
+/// Sum of the shared pools `clear_pools` zeroes: rewards, insurance, fee and
+/// emergency reserves, treasury and late fees. The carried-over pot and boosts
+/// belong to individual members and are not included.
+pub(crate) fn shared_pools_total(env: &Env) -> i128 {
+    let mut total: i128 = 0;
+    total += env
+        .storage()
+        .instance()
+        .get::<_, i128>(&DataKey::RewardPool)
+        .unwrap_or(0);
+    total += env
+        .storage()
+        .instance()
+        .get::<_, i128>(&DataKey2::InsurancePool)
+        .unwrap_or(0);
+    total += env
+        .storage()
+        .persistent()
+        .get::<_, i128>(&DataKey3::EmergencyReserveBalance)
+        .unwrap_or(0);
+    total += env
+        .storage()
+        .instance()
+        .get::<_, i128>(&DataKey3::TreasuryBalance)
+        .unwrap_or(0);
+    total += env
+        .storage()
+        .instance()
+        .get::<_, i128>(&DataKey4::LateFeePot)
+        .unwrap_or(0);
+    total += env
+        .storage()
+        .instance()
+        .get::<_, i128>(&DataKey4::ReservePool)
+        .unwrap_or(0);
+    total
+}
+
+/// Zeroes every pool that `accounted_balance` tracks outside the round itself.
+pub(crate) fn clear_pools(env: &Env) {
+    env.storage().instance().set(&DataKey::RewardPool, &0i128);
+    env.storage().instance().set(&DataKey2::InsurancePool, &0i128);
+    env.storage().instance().set(&DataKey3::TreasuryBalance, &0i128);
+    env.storage().instance().set(&DataKey4::LateFeePot, &0i128);
+    env.storage().instance().set(&DataKey4::ReservePool, &0i128);
+    env.storage().instance().remove(&DataKey5::CarryOver);
+    env.storage().instance().remove(&DataKey5::CarryOverShares);
+    env.storage().instance().remove(&DataKey5::RoundBoost);
+    env.storage().instance().remove(&DataKey5::RoundBoosts);
+    if env.storage().persistent().has(&DataKey3::EmergencyReserveBalance) {
+        env.storage()
+            .persistent()
+            .set(&DataKey3::EmergencyReserveBalance, &0i128);
+    }
+}
+
+/// Emits `opened` when the first member of a round is about to be marked
+/// paid, giving schedulers a "round is live" anchor. Pass the paid list as it
+/// was before the push.
+pub(crate) fn note_round_opened(env: &Env, paid_members: &Vec<Address>) {
+    if !paid_members.is_empty() {
+        return;
+    }
+    let round: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::CurrentRound)
+        .unwrap_or(0);
+    let use_timestamp: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey2::UseTimestampSchedule)
+        .unwrap_or(false);
+    let deadline: u64 = if use_timestamp {
+        round_state_get(env, &DataKey::RoundDeadlineTimestamp)
+            .unwrap_or(0)
+    } else {
+        round_state_get(env, &DataKey::RoundDeadline)
+            .unwrap_or(0)
+    };
+    events::emit_opened(env, round, deadline);
+}
+
+/// Sum of every member's prepaid escrow held by the contract.
+pub(crate) fn prepaid_total(env: &Env) -> i128 {
+    let members: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Members)
+        .unwrap_or(Vec::new(env));
+    let mut total: i128 = 0;
+    for member in members.iter() {
+        total += env
+            .storage()
+            .persistent()
+            .get::<_, i128>(&DataKey::Prepaid(member))
+            .unwrap_or(0);
+    }
+    total
+}
+
+/// Marks every member whose prepaid escrow covers the new round as paid,
+/// drawing the contribution from the escrow. Called when a round opens; the
+/// payout still waits for the remaining members or `close_round`.
+fn apply_prepaid(env: &Env, round: u32) {
+    let group_status: Option<GroupStatus> = env.storage().instance().get(&DataKey2::GroupStatus);
+    if group_status == Some(GroupStatus::Completed) {
+        return;
+    }
+    let members: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Members)
+        .unwrap_or(Vec::new(env));
+    let exited_members: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&DataKey::ExitedMembers)
+        .unwrap_or(Vec::new(env));
+    let suspended_members: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&DataKey::SuspendedMembers)
+        .unwrap_or(Vec::new(env));
+    let admin_suspended = admin_suspended(env);
+    let token_addr: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Token)
+        .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
+
+    let mut paid_members: Vec<Address> = round_state_get(env, &DataKey::PaidMembers)
+        .unwrap_or(Vec::new(env));
+    let mut member_contributions: Map<Address, i128> = env
+        .storage()
+        .instance()
+        .get(&DataKey::MemberContributions)
+        .unwrap_or(Map::new(env));
+    for member in members.iter() {
+        let key = DataKey::Prepaid(member.clone());
+        let prepaid: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        let required = member_required_amount(env, &member);
+        if prepaid < required
+            || paid_members.contains(&member)
+            || exited_members.contains(&member)
+            || suspended_members.contains(&member)
+            || admin_suspended.contains(&member)
+        {
+            continue;
+        }
+        if prepaid == required {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &(prepaid - required));
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+        note_round_opened(env, &paid_members);
+        paid_members.push_back(member.clone());
+        member_contributions.set(member.clone(), required);
+        events::emit_contrib(env, member, round, token_addr.clone(), required);
+    }
+    round_state_set(env, &DataKey::PaidMembers, &paid_members);
+    env.storage()
+        .instance()
+        .set(&DataKey::MemberContributions, &member_contributions);
+}
+
+/// Clears the settlement flags and early-claim logs of rounds `0..rounds`, so
+/// the round counter can start again from 0.
+pub(crate) fn clear_round_settlements(env: &Env, rounds: u32) {
+    for round in 0..rounds {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::RoundSettled(round));
+        env.storage()
+            .persistent()
+            .remove(&PersistentKey::ClaimLog(round));
+    }
+}
+
+/// Wipes what a dissolved group leaves behind so `init` starts from the same
+/// state as a fresh contract: round and member state, pools, configuration
+/// set after init, co-admins, the price peg, per-member settings of the old
+/// members and per-round records. Only the storage TTL floor is kept.
+pub(crate) fn reset_group_state(env: &Env) {
+    let instance = env.storage().instance();
+    for key in [
+        DataKey::Defaulters,
+        DataKey::MemberContributions,
+        DataKey::DefaultCount,
+        DataKey::SuspendedMembers,
+        DataKey::ApprovedTokens,
+        DataKey::ExitedMembers,
+    ] {
+        instance.remove(&key);
+    }
+    for key in [DataKey2::GroupStatus, DataKey2::LastRoundDeadline] {
+        instance.remove(&key);
+    }
+    for key in [
+        DataKey4::MergeProposalCounter,
+        DataKey4::MergeProposals,
+        DataKey4::GroupMergedInto,
+        DataKey4::CycleBonusAmount,
+        DataKey4::PendingRoundDuration,
+        DataKey4::MinRoundDuration,
+        DataKey4::MaxRoundDuration,
+        DataKey4::LateFeePot,
+        DataKey4::ReservePool,
+        DataKey4::FeeToReserve,
+        DataKey4::MemberDeposits,
+        DataKey4::SlashBps,
+        DataKey4::MaxRounds,
+        DataKey4::KeeperReward,
+        DataKey4::AdminSuspended,
+        DataKey4::DissolvedAt,
+        DataKey4::MemberWhitelist,
+        DataKey4::WhitelistEnabled,
+        DataKey4::OpenEnrollment,
+        DataKey4::JoinDeposit,
+        DataKey4::InterestRateBps,
+        DataKey4::MemberDebt,
+        DataKey4::DefaultingRecipientPolicy,
+        DataKey4::ContributionsPaused,
+        DataKey4::DepositsPaused,
+        DataKey4::TotalDistributed,
+        DataKey4::MinParticipationBps,
+        DataKey4::CreatedAt,
+        DataKey4::RoundStartedAt,
+        DataKey4::TokenDecimals,
+        DataKey4::PayoutDelay,
+        DataKey4::PendingPayouts,
+        DataKey4::Disputed,
+        DataKey4::AdminProposals,
+        DataKey4::NextAdminProposalId,
+        DataKey4::CycleNumber,
+        DataKey4::FailedPayouts,
+        DataKey4::PayoutFailureThreshold,
+        DataKey4::Weights,
+        DataKey4::EndOfCyclePolicy,
+        DataKey4::FixedSchedule,
+        DataKey4::Operators,
+        DataKey4::OnTimeStreaks,
+        DataKey4::StreakReward,
+        DataKey4::StreakClaims,
+        DataKey4::FeeToRewards,
+        DataKey4::WindowOpen,
+        DataKey4::WindowClose,
+        DataKey4::MinContribution,
+    ] {
+        instance.remove(&key);
+    }
+    for key in [
+        DataKey5::MaxContribution,
+        DataKey5::MaxConsecutiveDefaults,
+        DataKey5::ConsecutiveDefaults,
+        DataKey5::EjectionPolicy,
+        DataKey5::LastClosedRound,
+        DataKey5::ClockFrozenAt,
+        DataKey5::Guardian,
+        DataKey5::RecoveryDelay,
+        DataKey5::LastAdminAction,
+        DataKey5::CarryOverEnabled,
+        DataKey5::CarryOver,
+        DataKey5::ExcuseAllowance,
+        DataKey5::Oracle,
+        DataKey5::PeggedValue,
+        DataKey5::OracleMaxAge,
+        DataKey5::PegPrice,
+        DataKey5::PayoutHook,
+        DataKey5::DeferredRound,
+        DataKey5::MaxTvl,
+        DataKey5::RoundExcused,
+        DataKey5::RoundBoost,
+        DataKey5::OverpayPolicy,
+        DataKey5::HistorySize,
+        DataKey5::MinPayoutPot,
+        DataKey5::RetryCount,
+        DataKey5::SwapRouter,
+        DataKey5::SwapSlippageBps,
+        DataKey5::RoundRefunded,
+        DataKey5::RoundForeignPaid,
+        DataKey5::RoundBoosts,
+        DataKey5::CarryOverShares,
+        DataKey5::Admins,
+        DataKey5::AdminThreshold,
+    ] {
+        instance.remove(&key);
+    }
+
+    let persistent = env.storage().persistent();
+    let members: Vec<Address> = persistent.get(&DataKey::Members).unwrap_or(Vec::new(env));
+    for member in members.iter() {
+        persistent.remove(&DataKey::AutoContribute(member.clone()));
+        persistent.remove(&DataKey::Prepaid(member.clone()));
+        for key in [
+            PersistentKey::PayoutRounds(member.clone()),
+            PersistentKey::MemberMeta(member.clone()),
+            PersistentKey::PayoutAddress(member.clone()),
+            PersistentKey::ExcusedRemaining(member.clone()),
+            PersistentKey::DeferIfShort(member.clone()),
+            PersistentKey::PullLimit(member.clone()),
+            PersistentKey::Pulled(member.clone()),
+            PersistentKey::PayoutToken(member.clone()),
+        ] {
+            persistent.remove(&key);
+        }
+    }
+    let current_round: u32 = instance.get(&DataKey::CurrentRound).unwrap_or(0);
+    for round in 0..=current_round {
+        persistent.remove(&PersistentKey::RoundRecord(round));
+        persistent.remove(&PersistentKey::ClaimLog(round));
+    }
+    let payout_order: Vec<Address> = persistent
+        .get(&DataKey::PayoutOrder)
+        .unwrap_or(Vec::new(env));
+    for slot in 0..payout_order.len() {
+        persistent.remove(&PersistentKey::SlotBeneficiaries(slot));
+    }
+    persistent.remove(&PersistentKey::RoundHistory);
+}
+
+/// Requires `caller`'s auth and that they are the admin or an operator.
+/// Operators may run upkeep such as closing expired rounds, but never touch
+/// configuration or funds directly.
+pub(crate) fn operator_only(env: &Env, caller: &Address) {
+    caller.require_auth();
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
+    let operators: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&DataKey4::Operators)
+        .unwrap_or(Vec::new(env));
+    if *caller != admin && !operators.contains(caller) {
+        panic_with_error!(env, ExtError2::NotAnOperator);
+    }
+}
+
+/// Sum of the current round's contributions held by the contract.
+pub(crate) fn member_contributions_total(env: &Env) -> i128 {
+    let contributions: Map<Address, i128> = env
+        .storage()
+        .instance()
+        .get(&DataKey::MemberContributions)
+        .unwrap_or(Map::new(env));
+    let mut total: i128 = 0;
+    for (_, amount) in contributions.iter() {
+        total += amount;
+    }
+    total
+}
+
+/// Whether `MinPayoutPot` is set and the pot accumulated so far — this
+/// round's contributions plus anything carried over or boosted — is below it.
+pub(crate) fn below_min_payout_pot(env: &Env) -> bool {
+    let min_pot: i128 = env
+        .storage()
+        .instance()
+        .get(&DataKey5::MinPayoutPot)
+        .unwrap_or(0);
+    if min_pot <= 0 {
+        return false;
+    }
+    let carried: i128 = env
+        .storage()
+        .instance()
+        .get(&DataKey5::CarryOver)
+        .unwrap_or(0);
+    let boosted: i128 = env
+        .storage()
+        .instance()
+        .get(&DataKey5::RoundBoost)
+        .unwrap_or(0);
+    member_contributions_total(env) + carried + boosted < min_pot
+}
+
+/// Sum of all member security deposits held by the contract.
+pub(crate) fn member_deposits_total(env: &Env) -> i128 {
+    let deposits: Map<Address, i128> = env
+        .storage()
+        .instance()
+        .get(&DataKey4::MemberDeposits)
+        .unwrap_or(Map::new(env));
+    let mut total: i128 = 0;
+    for (_, amount) in deposits.iter() {
+        total += amount;
+    }
+    total
+}
+
+/// Returns `member`'s remaining security deposit to them and drops their
+/// entry. Returns the amount refunded (0 if they held none).
+pub(crate) fn refund_deposit(env: &Env, member: &Address) -> i128 {
+    let mut deposits: Map<Address, i128> = env
+        .storage()
+        .instance()
+        .get(&DataKey4::MemberDeposits)
+        .unwrap_or(Map::new(env));
+    let Some(amount) = deposits.get(member.clone()) else {
+        return 0;
+    };
+    deposits.remove(member.clone());
+    env.storage()
+        .instance()
+        .set(&DataKey4::MemberDeposits, &deposits);
+    if amount > 0 {
+        let token_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
+        let contract = env.current_contract_address();
+        token::Client::new(env, &token_addr).transfer(&contract, member, &amount);
+        record_fund_flow(env, "refund", &contract, member, amount);
+        events::emit_deposit_refunded(env, member.clone(), amount);
+    }
+    amount
+}
+
+/// Returns every remaining security deposit once the cycle has finished.
+pub(crate) fn refund_all_deposits(env: &Env) {
+    let deposits: Map<Address, i128> = env
+        .storage()
+        .instance()
+        .get(&DataKey4::MemberDeposits)
+        .unwrap_or(Map::new(env));
+    for member in deposits.keys().iter() {
+        refund_deposit(env, &member);
+    }
+}
+
+/// Protocol fee on `pot` at `fee_bps`, rounded down. The remainder of the
+/// division always stays with the recipient (`pot - fee`), so fee and payout
+/// add up to the pot exactly and no units are lost.
+pub(crate) fn fee_for(pot: i128, fee_bps: u32) -> i128 {
+    (pot * fee_bps as i128) / 10_000
+}
+
+/// Configured round length: `RoundDurationSeconds` on the timestamp
+/// schedule, otherwise `RoundDuration`.
+pub(crate) fn configured_round_duration(env: &Env) -> u64 {
+    let use_timestamp: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey2::UseTimestampSchedule)
+        .unwrap_or(false);
+    if use_timestamp {
+        env.storage()
+            .instance()
+            .get(&DataKey2::RoundDurationSeconds)
+            .unwrap_or(0)
+    } else {
+        env.storage()
+            .instance()
+            .get(&DataKey::RoundDuration)
+            .unwrap_or(0)
+    }
+}
+
+/// Sum of `token` held in pending payouts awaiting release.
+pub(crate) fn pending_payout_total(env: &Env, token_addr: &Address) -> i128 {
+    let pending: Vec<PendingPayout> = env
+        .storage()
+        .instance()
+        .get(&DataKey4::PendingPayouts)
+        .unwrap_or(Vec::new(env));
+    let mut total: i128 = 0;
+    for payout in pending.iter() {
+        total += payout.amounts.get(token_addr.clone()).unwrap_or(0);
+    }
+    total
+}
+
+/// Add `amount` to the missed-contribution debt recorded for `member`.
+pub(crate) fn record_debt(env: &Env, member: &Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let mut debts: Map<Address, i128> = env
+        .storage()
+        .instance()
+        .get(&DataKey4::MemberDebt)
+        .unwrap_or(Map::new(env));
+    let owed = debts.get(member.clone()).unwrap_or(0);
+    debts.set(member.clone(), owed + amount);
+    env.storage().instance().set(&DataKey4::MemberDebt, &debts);
+}
+
 /// Panics if the contract is currently paused.
 pub(crate) fn check_not_paused(env: &Env) {
     let is_paused: bool = env
@@ -45,27 +961,301 @@ pub(crate) fn check_not_paused(env: &Env) {
     }
 }
 
+/// Panics if the admin has paused contributions.
+pub(crate) fn check_contributions_open(env: &Env) {
+    let paused: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey4::ContributionsPaused)
+        .unwrap_or(false);
+    if paused {
+        panic_with_error!(env, ExtError2::ContributionsPaused);
+    }
+}
+
+/// Where now falls relative to the round's contribution window, given as
+/// offsets from `RoundStartedAt`: `Less` before it opens, `Greater` once it
+/// has closed. Groups without a window accept payments for the whole round.
+pub(crate) fn contribution_window_position(env: &Env) -> core::cmp::Ordering {
+    let Some(close) = env.storage().instance().get::<_, u64>(&DataKey4::WindowClose) else {
+        return core::cmp::Ordering::Equal;
+    };
+    let open: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey4::WindowOpen)
+        .unwrap_or(0);
+    let started_at: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey4::RoundStartedAt)
+        .unwrap_or(0);
+    let now = clock_now(env);
+    if now < started_at.saturating_add(open) {
+        core::cmp::Ordering::Less
+    } else if now >= started_at.saturating_add(close) {
+        core::cmp::Ordering::Greater
+    } else {
+        core::cmp::Ordering::Equal
+    }
+}
+
+/// Panics unless now falls inside the round's contribution window.
+pub(crate) fn check_contribution_window(env: &Env) {
+    match contribution_window_position(env) {
+        core::cmp::Ordering::Less => panic_with_error!(env, ExtError3::ContributionWindowNotOpen),
+        core::cmp::Ordering::Greater => panic_with_error!(env, Error::ContributionWindowClosed),
+        core::cmp::Ordering::Equal => {}
+    }
+}
+
+/// Panics if `amount` falls outside the admin's contribution band. A payment
+/// equal to `remaining` is exempt from the floor so a member can always finish.
+pub(crate) fn check_contribution_bounds(env: &Env, amount: i128, remaining: i128) {
+    let min: i128 = env
+        .storage()
+        .instance()
+        .get(&DataKey4::MinContribution)
+        .unwrap_or(0);
+    let max: i128 = env
+        .storage()
+        .instance()
+        .get(&DataKey5::MaxContribution)
+        .unwrap_or(0);
+    if min > 0 && amount < min && amount != remaining {
+        panic_with_error!(env, ExtError3::ContributionBelowMinimum);
+    }
+    if max > 0 && amount > max {
+        panic_with_error!(env, ExtError3::ContributionAboveMaximum);
+    }
+}
+
+/// Panics if the admin has paused security deposits.
+pub(crate) fn check_deposits_open(env: &Env) {
+    let paused: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey4::DepositsPaused)
+        .unwrap_or(false);
+    if paused {
+        panic_with_error!(env, ExtError2::DepositsPaused);
+    }
+}
+
+/// Members the admin has temporarily excluded with `suspend_member`.
+pub(crate) fn admin_suspended(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey4::AdminSuspended)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Members who still owe this round: not exited and not suspended by the
+/// admin. A round is complete once this many members have paid.
+pub(crate) fn active_member_count(env: &Env) -> u32 {
+    let members: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Members)
+        .unwrap_or(Vec::new(env));
+    let exited_members: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&DataKey::ExitedMembers)
+        .unwrap_or(Vec::new(env));
+    let suspended = admin_suspended(env);
+    let mut count: u32 = 0;
+    for member in members.iter() {
+        if !exited_members.contains(&member) && !suspended.contains(&member) {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Panics with `NotInitialized` if `init` has not been called yet.
+pub(crate) fn require_initialized(env: &Env) {
+    if !env.storage().persistent().has(&DataKey::Members) {
+        panic_with_error!(env, ExtError2::NotInitialized);
+    }
+}
+
 /// Panics if the group is currently frozen by the contract-level admin.
 pub(crate) fn check_not_frozen(env: &Env) {
     let is_frozen: bool = env
         .storage()
         .instance()
-        .get(&DataKey3::IsFrozen)
-        .unwrap_or(false);
-    if is_frozen {
-        panic_with_error!(env, ExtError::GroupFrozen);
-    }
+        .get(&DataKey3::IsFrozen)
+        .unwrap_or(false);
+    if is_frozen {
+        panic_with_error!(env, ExtError::GroupFrozen);
+    }
+}
+
+/// Returns the member due to receive the current round's payout: the payout
+/// order entry for this round, skipping suspended, exited and skipping members.
+pub(crate) fn scheduled_recipient(env: &Env) -> Address {
+    let current_round: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::CurrentRound)
+        .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
+    scheduled_recipient_for(env, current_round)
+}
+
+/// Recipient of `round` under the current payout order, skipping members who
+/// are suspended, exited or skipping that round.
+pub(crate) fn scheduled_recipient_for(env: &Env, current_round: u32) -> Address {
+    let payout_order: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PayoutOrder)
+        .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
+    let suspended_members: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&DataKey::SuspendedMembers)
+        .unwrap_or(Vec::new(env));
+    let exited_members: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&DataKey::ExitedMembers)
+        .unwrap_or(Vec::new(env));
+    let skip_requests: Map<(Address, u32), bool> = env
+        .storage()
+        .instance()
+        .get(&DataKey2::SkipRequests)
+        .unwrap_or(Map::new(env));
+    let admin_suspended = admin_suspended(env);
+
+    let mut recipient_idx = (current_round % payout_order.len()) as u32;
+    let mut attempts = 0;
+    while attempts < payout_order.len() {
+        let potential_recipient = payout_order.get(recipient_idx).unwrap();
+        let has_skipped = skip_requests.get((potential_recipient.clone(), current_round)).unwrap_or(false);
+        if !suspended_members.contains(&potential_recipient)
+            && !exited_members.contains(&potential_recipient)
+            && !admin_suspended.contains(&potential_recipient)
+            && !has_skipped
+        {
+            break;
+        }
+        recipient_idx = (recipient_idx + 1) % payout_order.len();
+        attempts += 1;
+    }
+
+    if attempts >= payout_order.len() {
+        panic_with_error!(env, Error::AllMembersSuspended);
+    }
+
+    payout_order.get(recipient_idx).unwrap()
+}
+
+/// Swaps `defaulter` with the next member after it in the payout order who
+/// has paid this round, so that member is paid now and the defaulter takes
+/// their later slot. Returns `None` when no later member has paid.
+fn swap_in_next_payer(env: &Env, defaulter: &Address, paid_members: &Vec<Address>) -> Option<Address> {
+    let mut payout_order: Vec<Address> = env.storage().persistent().get(&DataKey::PayoutOrder)?;
+    let idx = payout_order.first_index_of(defaulter)?;
+    for j in (idx + 1)..payout_order.len() {
+        let candidate = payout_order.get(j).unwrap();
+        if paid_members.contains(&candidate) {
+            payout_order.set(idx, candidate.clone());
+            payout_order.set(j, defaulter.clone());
+            env.storage().persistent().set(&DataKey::PayoutOrder, &payout_order);
+            return Some(candidate);
+        }
+    }
+    None
+}
+
+/// Discounted fee bps and credit score when `recipient` qualifies for the
+/// reputation-gated fee discount; `None` when the full fee applies.
+pub(crate) fn rep_fee_discount(env: &Env, recipient: &Address, fee_bps: u32) -> Option<(u32, i128)> {
+    if fee_bps == 0 {
+        return None;
+    }
+    let cfg: crate::RepFeeDiscountConfig = env
+        .storage()
+        .instance()
+        .get(&DataKey3::RepFeeDiscount)?;
+    let ms_map: Map<Address, crate::MemberScore> = env
+        .storage()
+        .persistent()
+        .get(&PersistentKey::MemberCreditScores)
+        .unwrap_or(Map::new(env));
+    let score = ms_map
+        .get(recipient.clone())
+        .map(|ms| ms.score)
+        .unwrap_or(0);
+    if score >= cfg.threshold {
+        Some((fee_bps.saturating_sub(cfg.discount_bps), score))
+    } else {
+        None
+    }
+}
+
+/// Base-token balance the current round would pay out without any insurance
+/// or reserve top-up: the contract balance minus the reward, insurance and
+/// reserve pools, member deposits, prepaid escrow, held payouts and the
+/// balances in `side_escrow_total`. Late fees,
+/// boosts and a carried-over pot are included; a pot already claimed early has
+/// left the balance.
+pub(crate) fn collected_pot(env: &Env) -> i128 {
+    let base_token: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Token)
+        .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
+    let pools: i128 = [
+        env.storage().instance().get::<_, i128>(&DataKey::RewardPool),
+        env.storage().instance().get::<_, i128>(&DataKey2::InsurancePool),
+        env.storage().instance().get::<_, i128>(&DataKey4::ReservePool),
+    ]
+    .iter()
+    .map(|pool| pool.unwrap_or(0))
+    .sum();
+    token::Client::new(env, &base_token).balance(&env.current_contract_address())
+        - pools
+        - member_deposits_total(env)
+        - prepaid_total(env)
+        - pending_payout_total(env, &base_token)
+        - side_escrow_total(env)
+}
+
+/// Pays out the current round and advances it. A shortfall against the full
+/// pot is topped up from the insurance pool and then the fee reserve.
+pub(crate) fn complete_round_payout(env: &Env, paid_members: &Vec<Address>) {
+    settle_round_payout(env, paid_members, true);
 }
 
-/// Pays out the current round's pot to the next eligible recipient, records
-/// the payout in history, and resets the round state for the next round.
-pub(crate) fn complete_round_payout(env: &Env, _paid_members: &Vec<Address>) {
+/// Pays out the current round and advances it. With `cover_shortfall` unset
+/// only what was collected goes out: no insurance or reserve draw tops it up,
+/// and the insurance pool is left in place. Returns the base-token payout net
+/// of fees, or 0 when it was forfeited to the reserve.
+pub(crate) fn settle_round_payout(
+    env: &Env,
+    paid_members: &Vec<Address>,
+    cover_shortfall: bool,
+) -> i128 {
     let current_round: u32 = env
         .storage()
         .instance()
         .get(&DataKey::CurrentRound)
-        .unwrap();
-    let payout_order: Vec<Address> = env.storage().instance().get(&DataKey::PayoutOrder).unwrap();
+        .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
+
+    // Settlement is idempotent per round: a round whose pot already went out
+    // can never be paid again, even if the round counter is wound back.
+    let settled_key = DataKey::RoundSettled(current_round);
+    if env.storage().persistent().has(&settled_key) {
+        panic_with_error!(env, ExtError2::RoundAlreadySettled);
+    }
+    env.storage().persistent().set(&settled_key, &true);
+    env.storage().persistent().extend_ttl(
+        &settled_key,
+        PERSISTENT_LIFETIME_THRESHOLD,
+        PERSISTENT_BUMP_AMOUNT,
+    );
     let suspended_members: Vec<Address> = env
         .storage()
         .instance()
@@ -77,32 +1267,57 @@ pub(crate) fn complete_round_payout(env: &Env, _paid_members: &Vec<Address>) {
         .get(&DataKey::ExitedMembers)
         .unwrap_or(Vec::new(env));
 
-    let skip_requests: Map<(Address, u32), bool> = env
-        .storage()
-        .instance()
-        .get(&DataKey2::SkipRequests)
-        .unwrap_or(Map::new(env));
-
-    let mut recipient_idx = (current_round % payout_order.len()) as u32;
-    let mut attempts = 0;
-    while attempts < payout_order.len() {
-        let potential_recipient = payout_order.get(recipient_idx).unwrap();
-        let has_skipped = skip_requests.get((potential_recipient.clone(), current_round)).unwrap_or(false);
-        if !suspended_members.contains(&potential_recipient)
-            && !exited_members.contains(&potential_recipient)
-            && !has_skipped
-        {
-            break;
+    let mut payout_recipient = scheduled_recipient(env);
+    let mut forfeit_to_reserve = false;
+    if !paid_members.contains(&payout_recipient) {
+        let policy: DefaultingRecipientPolicy = env
+            .storage()
+            .instance()
+            .get(&DataKey4::DefaultingRecipientPolicy)
+            .unwrap_or(DefaultingRecipientPolicy::StillReceive);
+        match policy {
+            DefaultingRecipientPolicy::StillReceive => {}
+            DefaultingRecipientPolicy::SkipToNext => {
+                if let Some(next) = swap_in_next_payer(env, &payout_recipient, paid_members) {
+                    events::emit_recipient_skipped(env, payout_recipient.clone(), next.clone(), current_round);
+                    payout_recipient = next;
+                }
+            }
+            DefaultingRecipientPolicy::ForfeitToReserve => forfeit_to_reserve = true,
         }
-        recipient_idx = (recipient_idx + 1) % payout_order.len();
-        attempts += 1;
     }
+    let payout_order: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PayoutOrder)
+        .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
+    extend_member_ttl(env);
 
-    if attempts >= payout_order.len() {
-        panic_with_error!(env, Error::AllMembersSuspended);
+    // A finite cycle pays each member once; receiving twice means the cap
+    // outruns the payout order.
+    let payout_rounds_key = PersistentKey::PayoutRounds(payout_recipient.clone());
+    let mut payout_rounds: Vec<u32> = env
+        .storage()
+        .persistent()
+        .get(&payout_rounds_key)
+        .unwrap_or(Vec::new(env));
+    let max_rounds: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey4::MaxRounds)
+        .unwrap_or(0);
+    if max_rounds > 0 && !forfeit_to_reserve && !payout_rounds.is_empty() {
+        panic_with_error!(env, ExtError2::PayoutAlreadyReceived);
+    }
+    if !forfeit_to_reserve {
+        payout_rounds.push_back(current_round);
+        env.storage().persistent().set(&payout_rounds_key, &payout_rounds);
+        env.storage().persistent().extend_ttl(
+            &payout_rounds_key,
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
     }
-
-    let payout_recipient = payout_order.get(recipient_idx).unwrap();
     let preferences: Map<Address, bool> = env
         .storage()
         .instance()
@@ -115,7 +1330,11 @@ pub(crate) fn complete_round_payout(env: &Env, _paid_members: &Vec<Address>) {
         .instance()
         .get(&DataKey::RewardPool)
         .unwrap_or(0);
-    let base_token: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+    let base_token: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Token)
+        .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
 
     let approved_tokens: Vec<Address> = env
         .storage()
@@ -124,120 +1343,87 @@ pub(crate) fn complete_round_payout(env: &Env, _paid_members: &Vec<Address>) {
         .unwrap_or(Vec::new(env));
 
     // Get protocol fee configuration
-    let fee_bps: u32 = env
-        .storage()
-        .instance()
-        .get(&DataKey::FeeBps)
-        .unwrap_or(0);
+    let fee_bps = payout_fee_bps(env);
+    // Fees go to the configured fee recipient, or to the group admin if none is set.
     let fee_recipient_opt: Option<Address> = env
         .storage()
         .instance()
-        .get(&DataKey2::FeeRecipient);
+        .get(&DataKey2::FeeRecipient)
+        .or_else(|| env.storage().instance().get(&DataKey::Admin));
 
     // Apply reputation-gated fee discount if the payout recipient's credit score
     // meets the configured threshold.
-    let effective_fee_bps: u32 = if fee_bps > 0 {
-        let discount_cfg: Option<crate::RepFeeDiscountConfig> = env
-            .storage()
-            .instance()
-            .get(&DataKey3::RepFeeDiscount);
-        if let Some(cfg) = discount_cfg {
-            let ms_map: Map<Address, crate::MemberScore> = env
-                .storage()
-                .persistent()
-                .get(&PersistentKey::MemberCreditScores)
-                .unwrap_or(Map::new(env));
-            let score = ms_map
-                .get(payout_recipient.clone())
-                .map(|ms| ms.score)
-                .unwrap_or(0);
-            if score >= cfg.threshold {
-                let discounted = fee_bps.saturating_sub(cfg.discount_bps);
-                events::emit_rep_fee_discount_applied(
-                    env,
-                    payout_recipient.clone(),
-                    fee_bps,
-                    discounted,
-                    score,
-                );
-                discounted
-            } else {
-                fee_bps
-            }
-        } else {
-            fee_bps
+    let effective_fee_bps: u32 = match rep_fee_discount(env, &payout_recipient, fee_bps) {
+        Some((discounted, score)) => {
+            events::emit_rep_fee_discount_applied(
+                env,
+                payout_recipient.clone(),
+                fee_bps,
+                discounted,
+                score,
+            );
+            discounted
         }
-    } else {
-        0
+        None => fee_bps,
     };
 
     let mut total_payout_history_amt = 0i128;
     let mut reinvested_amount = 0i128;
     let mut total_fee_collected = 0i128;
+    let mut recipient_payout = 0i128;
 
-    // Calculate expected pot based on member tiers and check for shortfall
-    let base_amount: i128 = env
-        .storage()
-        .instance()
-        .get(&DataKey::ContributionAmt)
-        .unwrap_or(0);
-    let tiers: Map<Address, u32> = env
-        .storage()
-        .instance()
-        .get(&DataKey2::MemberTiers)
-        .unwrap_or(Map::new(env));
     let member_contributions: Map<Address, i128> = env
         .storage()
         .instance()
         .get(&DataKey::MemberContributions)
         .unwrap_or(Map::new(env));
 
-    // Read insurance pool here so we can exclude it from actual_pot.
     let mut insurance_pool: i128 = env
         .storage()
         .instance()
         .get(&DataKey2::InsurancePool)
         .unwrap_or(0);
 
-    // expected_pot = what ALL non-suspended, non-exited members were supposed to contribute.
-    // Using all active members (not just paid ones) lets defaulters create a real shortfall.
+    // The payout is what was actually collected. Only active members who have
+    // not paid leave a shortfall, of whatever they still owe; a required
+    // amount that changed after a member paid is not a shortfall.
     let all_members: Vec<Address> = env
         .storage()
-        .instance()
+        .persistent()
         .get(&DataKey::Members)
         .unwrap_or(Vec::new(env));
-    let mut expected_pot: i128 = 0;
+    let admin_suspended = admin_suspended(env);
+    let mut shortfall: i128 = 0;
     for member in all_members.iter() {
-        if suspended_members.contains(&member) || exited_members.contains(&member) {
+        if paid_members.contains(&member)
+            || suspended_members.contains(&member)
+            || exited_members.contains(&member)
+            || admin_suspended.contains(&member)
+        {
             continue;
         }
-        let tier_bps = tiers.get(member.clone()).unwrap_or(10_000);
-        let member_expected = (base_amount * tier_bps as i128) / 10_000;
-        expected_pot += member_expected;
+        let owed = member_required_amount(env, &member)
+            - member_contributions.get(member.clone()).unwrap_or(0);
+        shortfall += owed.max(0);
     }
 
-    // actual_pot = contract balance minus reward pool and insurance reserves.
-    // Insurance reserves are not round contributions; excluding them prevents
-    // the pool from masking defaulter shortfalls.
-    let mut actual_pot: i128 = 0;
-    for token_addr in approved_tokens.iter() {
-        let client = token::Client::new(env, &token_addr);
-        let mut balance = client.balance(&env.current_contract_address());
-
-        if token_addr == base_token {
-            balance -= reward_pool;
-            balance -= insurance_pool;
-            actual_pot = balance;
-        }
-    }
-    let shortfall = expected_pot - actual_pot;
+    // Fee-funded reserve held back from the pot; tops up shortfalls below.
+    let mut reserve_pool: i128 = env
+        .storage()
+        .instance()
+        .get(&DataKey4::ReservePool)
+        .unwrap_or(0);
+    let deposits_total = member_deposits_total(env);
+    let prepaid_escrow = prepaid_total(env);
+    let side_escrow = side_escrow_total(env);
+    let mut covered: i128 = 0;
     let coverage_mode: InsuranceCoverageMode = env
         .storage()
         .instance()
         .get(&DataKey2::InsuranceCoverageMode)
         .unwrap_or(InsuranceCoverageMode::Partial);
 
-    if shortfall > 0 && coverage_mode != InsuranceCoverageMode::None {
+    if cover_shortfall && shortfall > 0 && coverage_mode != InsuranceCoverageMode::None {
         let draw_amount = match coverage_mode {
             InsuranceCoverageMode::None => 0,
             InsuranceCoverageMode::Partial => {
@@ -267,44 +1453,154 @@ pub(crate) fn complete_round_payout(env: &Env, _paid_members: &Vec<Address>) {
             round_claims.push_back(InsuranceClaim { round: current_round, defaulter: payout_recipient.clone(), amount_covered: draw_amount });
             claims.set(current_round, round_claims);
             env.storage().instance().set(&DataKey2::InsuranceClaims, &claims);
-            actual_pot += draw_amount;
+            covered += draw_amount;
         }
-    } else if shortfall > 0 && insurance_pool == 0 && coverage_mode != InsuranceCoverageMode::None {
+    } else if cover_shortfall
+        && shortfall > 0
+        && insurance_pool == 0
+        && coverage_mode != InsuranceCoverageMode::None
+    {
         events::emit_insurance_pool_exhausted(env, current_round, shortfall);
     }
 
+    // Whatever shortfall insurance did not cover is drawn from the fee reserve,
+    // up to its balance. The drawn amount simply stays in the payout below.
+    let remaining_shortfall = shortfall - covered;
+    if cover_shortfall && remaining_shortfall > 0 && reserve_pool > 0 {
+        let draw_amount = remaining_shortfall.min(reserve_pool);
+        reserve_pool -= draw_amount;
+        events::emit_reserve_drawn(env, current_round, draw_amount, reserve_pool);
+    }
+    let fee_to_reserve: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey4::FeeToReserve)
+        .unwrap_or(false);
+    let fee_to_rewards: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey4::FeeToRewards)
+        .unwrap_or(false);
+    let mut rewards_funded: i128 = 0;
+
+    // Outgoing transfers are queued here and only executed once every storage
+    // update (including the round reset) is done: checks-effects-interactions.
+    // The host already rejects re-entry, so this is defence in depth.
+    let mut transfers: Vec<(Address, Address, i128, Symbol)> = Vec::new(env);
+
+    // With a payout delay each recipient's share is held instead of sent,
+    // keyed by recipient and then token.
+    let payout_delay: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey4::PayoutDelay)
+        .unwrap_or(0);
+    let mut held: Map<Address, Map<Address, i128>> = Map::new(env);
+
+    // Weighted payouts split the pot across current members who have not
+    // exited; weights left behind by removed members are ignored.
+    let mut weights: Map<Address, u32> = Map::new(env);
+    let mut total_weight: i128 = 0;
+    let configured_weights: Map<Address, u32> = env
+        .storage()
+        .instance()
+        .get(&DataKey4::Weights)
+        .unwrap_or(Map::new(env));
+    for (member, weight) in configured_weights.iter() {
+        if all_members.contains(&member) && !exited_members.contains(&member) {
+            total_weight += weight as i128;
+            weights.set(member, weight);
+        }
+    }
+
     for token_addr in approved_tokens.iter() {
         let client = token::Client::new(env, &token_addr);
         let mut balance = client.balance(&env.current_contract_address());
+        balance -= pending_payout_total(env, &token_addr);
 
         if token_addr == base_token {
             balance -= reward_pool;
+            balance -= reserve_pool;
+            balance -= deposits_total;
+            balance -= prepaid_escrow;
+            balance -= side_escrow;
+            // Whatever insurance did not draw above stays in the pool.
+            balance -= insurance_pool;
             total_payout_history_amt = balance;
         }
 
         if balance > 0 {
             // Calculate protocol fee (already adjusted for reputation discount)
             let fee_amount = if effective_fee_bps > 0 && fee_recipient_opt.is_some() {
-                (balance * (effective_fee_bps as i128)) / 10_000
+                fee_for(balance, effective_fee_bps)
             } else {
                 0
             };
 
             let payout_amount = balance - fee_amount;
+            if token_addr == base_token {
+                recipient_payout = payout_amount;
+            }
 
-            if should_reinvest && token_addr == base_token {
+            if forfeit_to_reserve && token_addr == base_token {
+                if payout_amount > 0 {
+                    reserve_pool += payout_amount;
+                    events::emit_reserve_credited(env, current_round, payout_amount, reserve_pool);
+                }
+            } else if !weights.is_empty() {
+                // Weighted groups split the pot; rounding dust goes to the
+                // scheduled recipient. Each share is then held or split across
+                // its slot's co-owners like a single-recipient payout.
+                let mut shares: Vec<(Address, i128)> = Vec::new(env);
+                let mut remaining = payout_amount;
+                for (member, weight) in weights.iter() {
+                    let share = payout_amount * weight as i128 / total_weight;
+                    if share > 0 {
+                        remaining -= share;
+                        shares.push_back((member, share));
+                    }
+                }
+                if remaining > 0 {
+                    shares.push_back((payout_recipient.clone(), remaining));
+                }
+                for (member, share) in shares.iter() {
+                    if payout_delay > 0 {
+                        hold_payout(env, &mut held, &member, &token_addr, share);
+                    } else {
+                        let slot = payout_order.first_index_of(&member).unwrap_or(0);
+                        queue_slot_payout(env, &mut transfers, &token_addr, slot, &member, share);
+                    }
+                }
+            } else if should_reinvest && token_addr == base_token {
                 reinvested_amount = payout_amount;
                 events::emit_payout_reinvested(env, payout_recipient.clone(), current_round, payout_amount);
+            } else if payout_amount > 0 && payout_delay > 0 {
+                hold_payout(env, &mut held, &payout_recipient, &token_addr, payout_amount);
             } else if payout_amount > 0 {
-                // Transfer payout to recipient
-                client.transfer(&env.current_contract_address(), &payout_recipient, &payout_amount);
+                // Transfer payout to recipient, or to the slot's co-owners
+                let slot = payout_order.first_index_of(&payout_recipient).unwrap_or(0);
+                queue_slot_payout(
+                    env,
+                    &mut transfers,
+                    &token_addr,
+                    slot,
+                    &payout_recipient,
+                    payout_amount,
+                );
             }
 
-            // Transfer fee to fee recipient
-            if fee_amount > 0 {
+            // Transfer fee to fee recipient, or keep it in the reserve
+            if fee_amount > 0 && fee_to_reserve && token_addr == base_token {
+                reserve_pool += fee_amount;
+                total_fee_collected = fee_amount;
+                events::emit_reserve_credited(env, current_round, fee_amount, reserve_pool);
+            } else if fee_amount > 0 && fee_to_rewards && token_addr == base_token {
+                rewards_funded = fee_amount;
+                total_fee_collected = fee_amount;
+            } else if fee_amount > 0 {
                 if let Some(fee_recipient) = fee_recipient_opt.clone() {
-                    client.transfer(&env.current_contract_address(), &fee_recipient, &fee_amount);
-                    
+                    transfers.push_back((token_addr.clone(), fee_recipient.clone(), fee_amount, symbol_short!("fee")));
+
                     // Emit fee collected event (only for base token to avoid duplicates)
                     if token_addr == base_token {
                         total_fee_collected = fee_amount;
@@ -315,6 +1611,50 @@ pub(crate) fn complete_round_payout(env: &Env, _paid_members: &Vec<Address>) {
         }
     }
 
+    env.storage().instance().set(&DataKey4::ReservePool, &reserve_pool);
+    env.storage().instance().remove(&DataKey5::CarryOver);
+    env.storage().instance().remove(&DataKey5::CarryOverShares);
+    env.storage().instance().remove(&DataKey5::DeferredRound);
+    if rewards_funded > 0 {
+        env.storage()
+            .instance()
+            .set(&DataKey::RewardPool, &(reward_pool + rewards_funded));
+        events::emit_rew_dep(env, env.current_contract_address(), rewards_funded);
+    }
+
+    if !held.is_empty() {
+        let release_at = env.ledger().timestamp() + payout_delay;
+        let mut pending: Vec<PendingPayout> = env
+            .storage()
+            .instance()
+            .get(&DataKey4::PendingPayouts)
+            .unwrap_or(Vec::new(env));
+        for (recipient, amounts) in held.iter() {
+            pending.push_back(PendingPayout {
+                round: current_round,
+                recipient: recipient.clone(),
+                amounts,
+                release_at,
+            });
+            events::emit_payout_held(env, current_round, recipient, release_at);
+        }
+        env.storage().instance().set(&DataKey4::PendingPayouts, &pending);
+    }
+
+    if !forfeit_to_reserve {
+        let total_distributed: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::TotalDistributed)
+            .unwrap_or(0);
+        let total_distributed = total_distributed
+            .checked_add(total_payout_history_amt)
+            .unwrap_or_else(|| panic_with_error!(env, ExtError::InvalidAmount));
+        env.storage()
+            .instance()
+            .set(&DataKey4::TotalDistributed, &total_distributed);
+    }
+
     // Persistent: RoundHistory — append new record and extend its individual TTL
     let mut history: Vec<PayoutRecord> = env
         .storage()
@@ -334,6 +1674,27 @@ pub(crate) fn complete_round_payout(env: &Env, _paid_members: &Vec<Address>) {
         PERSISTENT_BUMP_AMOUNT,
     );
 
+    // `close_round` leaves its defaulters listed until the next close, so
+    // only those who have not paid this round belong to it.
+    let listed_defaulters: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&DataKey::Defaulters)
+        .unwrap_or(Vec::new(env));
+    let mut round_defaulters: Vec<Address> = Vec::new(env);
+    for member in listed_defaulters.iter() {
+        if !paid_members.contains(&member) {
+            round_defaulters.push_back(member);
+        }
+    }
+    write_round_record(
+        env,
+        current_round,
+        Some(payout_recipient.clone()),
+        total_payout_history_amt,
+        round_defaulters.clone(),
+    );
+
     events::emit_rd_done(
         env,
         current_round,
@@ -345,7 +1706,7 @@ pub(crate) fn complete_round_payout(env: &Env, _paid_members: &Vec<Address>) {
     {
         let snap_members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
             .unwrap_or(Vec::new(env));
         let mut preimage = Bytes::new(env);
@@ -384,12 +1745,7 @@ pub(crate) fn complete_round_payout(env: &Env, _paid_members: &Vec<Address>) {
         });
     }
 
-    // Get defaulters from storage (set during finalize_round)
-    let defaulters: Vec<Address> = env
-        .storage()
-        .instance()
-        .get(&DataKey::Defaulters)
-        .unwrap_or(Vec::new(env));
+    let defaulters = round_defaulters;
 
     // Get skippers for this round
     let skip_requests: Map<(Address, u32), bool> = env
@@ -462,7 +1818,12 @@ pub(crate) fn complete_round_payout(env: &Env, _paid_members: &Vec<Address>) {
         cycle_end_timestamp,
     );
 
-    reset_round_state(env, current_round);
+    // Late fees and boosts went out with this payout; a round closed without
+    // one keeps them in the pot for the next payout.
+    env.storage().instance().remove(&DataKey4::LateFeePot);
+    env.storage().instance().remove(&DataKey5::RoundBoost);
+    env.storage().instance().remove(&DataKey5::RoundBoosts);
+    advance_round(env, current_round, None, true);
 
     // Apply reinvestment to the next round's contributions
     if should_reinvest && reinvested_amount > 0 {
@@ -478,30 +1839,15 @@ pub(crate) fn complete_round_payout(env: &Env, _paid_members: &Vec<Address>) {
             .set(&DataKey::MemberContributions, &next_contributions);
         
         // Check if this reinvestment fulfills the next round's requirement
-        let base_amount: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::ContributionAmt)
-            .unwrap_or(0);
-        let tiers: Map<Address, u32> = env
-            .storage()
-            .instance()
-            .get(&DataKey2::MemberTiers)
-            .unwrap_or(Map::new(env));
-        let tier_bps = tiers.get(payout_recipient.clone()).unwrap_or(10_000);
-        let member_required = (base_amount * tier_bps as i128) / 10_000;
+        let member_required = member_required_amount(env, &payout_recipient);
 
         if reinvested_amount >= member_required {
-            let mut next_paid_members: Vec<Address> = env
-                .storage()
-                .instance()
-                .get(&DataKey::PaidMembers)
+            let mut next_paid_members: Vec<Address> = round_state_get(env, &DataKey::PaidMembers)
                 .unwrap_or(Vec::new(env));
             if !next_paid_members.contains(&payout_recipient) {
+                note_round_opened(env, &next_paid_members);
                 next_paid_members.push_back(payout_recipient.clone());
-                env.storage()
-                    .instance()
-                    .set(&DataKey::PaidMembers, &next_paid_members);
+                round_state_set(env, &DataKey::PaidMembers, &next_paid_members);
             }
 
             // Track reward participation for the next round
@@ -520,24 +1866,359 @@ pub(crate) fn complete_round_payout(env: &Env, _paid_members: &Vec<Address>) {
             member_participation.set(payout_recipient.clone(), current_participation + 1);
             total_participations += 1;
 
-            env.storage()
-                .instance()
-                .set(&DataKey::TotalParticipations, &total_participations);
-            env.storage()
-                .instance()
-                .set(&DataKey::MemberParticipation, &member_participation);
-        }
+            env.storage()
+                .instance()
+                .set(&DataKey::TotalParticipations, &total_participations);
+            env.storage()
+                .instance()
+                .set(&DataKey::MemberParticipation, &member_participation);
+        }
+    }
+
+    for (token_addr, to, amount, kind) in transfers.iter() {
+        if kind == symbol_short!("swap") {
+            pay_swapped(env, current_round, &token_addr, &to, amount);
+        } else {
+            pay_or_hold(env, current_round, &token_addr, &to, amount, kind);
+        }
+    }
+    if forfeit_to_reserve {
+        return 0;
+    }
+    notify_payout_hook(env, &payout_recipient, current_round, recipient_payout);
+    recipient_payout
+}
+
+/// Adds `amount` of `token_addr` to the payout held back for `recipient`.
+fn hold_payout(
+    env: &Env,
+    held: &mut Map<Address, Map<Address, i128>>,
+    recipient: &Address,
+    token_addr: &Address,
+    amount: i128,
+) {
+    let mut amounts = held.get(recipient.clone()).unwrap_or(Map::new(env));
+    let total = amounts.get(token_addr.clone()).unwrap_or(0) + amount;
+    amounts.set(token_addr.clone(), total);
+    held.set(recipient.clone(), amounts);
+}
+
+/// Queues `amount` of `token_addr` for rotation `slot`, held by `recipient`.
+/// A slot with `SlotBeneficiaries` is split by their shares, rounding dust
+/// going to the last beneficiary; otherwise it all goes to the recipient.
+fn queue_slot_payout(
+    env: &Env,
+    transfers: &mut Vec<(Address, Address, i128, Symbol)>,
+    token_addr: &Address,
+    slot: u32,
+    recipient: &Address,
+    amount: i128,
+) {
+    let beneficiaries: Vec<SlotBeneficiary> = env
+        .storage()
+        .persistent()
+        .get(&PersistentKey::SlotBeneficiaries(slot))
+        .unwrap_or(Vec::new(env));
+    if beneficiaries.is_empty() {
+        // A recipient with a preferred payout token is queued as a swap,
+        // keyed by the member rather than their payout destination.
+        let wants_swap = env.storage().instance().has(&DataKey5::SwapRouter)
+            && env
+                .storage()
+                .persistent()
+                .get::<_, Address>(&PersistentKey::PayoutToken(recipient.clone()))
+                .is_some_and(|token| token != *token_addr);
+        if wants_swap {
+            transfers.push_back((
+                token_addr.clone(),
+                recipient.clone(),
+                amount,
+                symbol_short!("swap"),
+            ));
+        } else {
+            transfers.push_back((
+                token_addr.clone(),
+                payout_destination(env, recipient),
+                amount,
+                symbol_short!("payout"),
+            ));
+        }
+        return;
+    }
+    let mut remaining = amount;
+    for (i, entry) in beneficiaries.iter().enumerate() {
+        let share = if i as u32 + 1 == beneficiaries.len() {
+            remaining
+        } else {
+            amount * entry.share_bps as i128 / 10_000
+        };
+        remaining -= share;
+        if share > 0 {
+            transfers.push_back((
+                token_addr.clone(),
+                entry.beneficiary,
+                share,
+                symbol_short!("payout"),
+            ));
+        }
+    }
+}
+
+/// Pays `member` `amount` of `token_in` converted into their preferred payout
+/// token through the swap router. The minimum accepted is the amount implied
+/// by the token's exchange rate less `SwapSlippageBps`. A failed swap leaves
+/// no trace and the payout falls back to `token_in`.
+fn pay_swapped(env: &Env, round: u32, token_in: &Address, member: &Address, amount: i128) {
+    let destination = payout_destination(env, member);
+    let router: Option<Address> = env.storage().instance().get(&DataKey5::SwapRouter);
+    let token_out: Option<Address> = env
+        .storage()
+        .persistent()
+        .get(&PersistentKey::PayoutToken(member.clone()));
+    let rate: i128 = token_out
+        .clone()
+        .and_then(|token| {
+            env.storage()
+                .instance()
+                .get::<_, Map<Address, i128>>(&DataKey::ExchangeRates)
+                .and_then(|rates| rates.get(token))
+        })
+        .unwrap_or(0);
+    if let (Some(router), Some(token_out)) = (router, token_out) {
+        if rate > 0 {
+            let slippage_bps: u32 = env
+                .storage()
+                .instance()
+                .get(&DataKey5::SwapSlippageBps)
+                .unwrap_or(0);
+            // Same valuation as `contribute`: rates are pool-token value per
+            // 10^7 units of the other token.
+            let expected = amount * 10_000_000 / rate;
+            let min_out = expected * (10_000 - slippage_bps as i128) / 10_000;
+            let contract = env.current_contract_address();
+            env.authorize_as_current_contract(Vec::from_array(
+                env,
+                [InvokerContractAuthEntry::Contract(SubContractInvocation {
+                    context: ContractContext {
+                        contract: token_in.clone(),
+                        fn_name: Symbol::new(env, "transfer"),
+                        args: (contract.clone(), router.clone(), amount).into_val(env),
+                    },
+                    sub_invocations: Vec::new(env),
+                })],
+            ));
+            let result = crate::SwapRouterClient::new(env, &router).try_swap(
+                &contract,
+                token_in,
+                &token_out,
+                &amount,
+                &min_out,
+                &destination,
+            );
+            if let Ok(Ok(amount_out)) = result {
+                events::emit_fund_flow(
+                    env,
+                    symbol_short!("payout"),
+                    contract,
+                    router,
+                    amount,
+                    round,
+                );
+                events::emit_payout_swapped(
+                    env,
+                    member.clone(),
+                    round,
+                    token_out,
+                    amount,
+                    amount_out,
+                );
+                return;
+            }
+        }
+    }
+    events::emit_payout_swap_failed(env, member.clone(), round, amount);
+    pay_or_hold(env, round, token_in, &destination, amount, symbol_short!("payout"));
+}
+
+/// Tells the configured payout hook about a payout. The call is isolated: a
+/// hook that panics or rejects is reported with an event and otherwise ignored.
+fn notify_payout_hook(env: &Env, recipient: &Address, round: u32, amount: i128) {
+    let Some(hook) = env.storage().instance().get::<_, Address>(&DataKey5::PayoutHook) else {
+        return;
+    };
+    let result = crate::PayoutHookClient::new(env, &hook).try_on_payout(recipient, &round, &amount);
+    if !matches!(result, Ok(Ok(()))) {
+        events::emit_payout_hook_failed(env, hook, round);
+    }
+}
+
+/// Records a token movement of `kind` as a `FundFlow` event in the current
+/// round, so an indexer can keep a full ledger of the contract's funds.
+pub(crate) fn record_fund_flow(env: &Env, kind: &str, from: &Address, to: &Address, amount: i128) {
+    let round: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::CurrentRound)
+        .unwrap_or(0);
+    events::emit_fund_flow(
+        env,
+        Symbol::new(env, kind),
+        from.clone(),
+        to.clone(),
+        amount,
+        round,
+    );
+}
+
+/// Sends a payout, or holds it in the pending-payout queue if the token
+/// reverts so the round still completes. Consecutive failures trip a circuit
+/// breaker that pauses the group; `release_payout` retries held amounts.
+fn pay_or_hold(
+    env: &Env,
+    round: u32,
+    token_addr: &Address,
+    to: &Address,
+    amount: i128,
+    kind: Symbol,
+) {
+    let client = token::Client::new(env, token_addr);
+    let sent = matches!(
+        client.try_transfer(&env.current_contract_address(), to, &amount),
+        Ok(Ok(_))
+    );
+    if sent {
+        env.storage().instance().remove(&DataKey4::FailedPayouts);
+        let from = env.current_contract_address();
+        events::emit_fund_flow(env, kind, from, to.clone(), amount, round);
+        return;
+    }
+
+    let mut amounts: Map<Address, i128> = Map::new(env);
+    amounts.set(token_addr.clone(), amount);
+    let mut pending: Vec<PendingPayout> = env
+        .storage()
+        .instance()
+        .get(&DataKey4::PendingPayouts)
+        .unwrap_or(Vec::new(env));
+    pending.push_back(PendingPayout {
+        round,
+        recipient: to.clone(),
+        amounts,
+        release_at: env.ledger().timestamp(),
+    });
+    env.storage().instance().set(&DataKey4::PendingPayouts, &pending);
+
+    let failures: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey4::FailedPayouts)
+        .unwrap_or(0)
+        + 1;
+    env.storage().instance().set(&DataKey4::FailedPayouts, &failures);
+    events::emit_payout_failed(env, round, to.clone(), amount, failures);
+
+    let threshold: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey4::PayoutFailureThreshold)
+        .unwrap_or(DEFAULT_PAYOUT_FAILURE_THRESHOLD);
+    let is_paused: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey::Paused)
+        .unwrap_or(false);
+    if failures >= threshold && !is_paused {
+        let reason = soroban_sdk::String::from_str(env, "payout transfers failing");
+        env.storage().instance().set(&DataKey::Paused, &true);
+        env.storage().instance().set(&DataKey::IsPaused, &true);
+        env.storage().instance().set(&DataKey::PauseReason, &reason);
+        env.storage()
+            .instance()
+            .set(&DataKey::PauseTimestamp, &env.ledger().timestamp());
+        events::emit_paused(env, reason);
+        events::emit_circuit_breaker_tripped(env, round, failures);
+    }
+}
+
+/// Persists the outcome of `round` under `PersistentKey::RoundRecord`, along
+/// with who paid, who was excused and what was boosted. Call before the round
+/// state is reset.
+pub(crate) fn write_round_record(
+    env: &Env,
+    round: u32,
+    recipient: Option<Address>,
+    pot: i128,
+    defaulters: Vec<Address>,
+) {
+    let paid: Vec<Address> = round_state_get(env, &DataKey::PaidMembers).unwrap_or(Vec::new(env));
+    let excused: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&DataKey5::RoundExcused)
+        .unwrap_or(Vec::new(env));
+    env.storage().instance().remove(&DataKey5::RoundExcused);
+    // Boosts are only spent by a payout; a round closed without one carries
+    // them into the next pot.
+    let boosted: i128 = if recipient.is_some() {
+        env.storage()
+            .instance()
+            .get(&DataKey5::RoundBoost)
+            .unwrap_or(0)
+    } else {
+        0
+    };
+
+    let key = PersistentKey::RoundRecord(round);
+    if !env.storage().persistent().has(&key) {
+        let size: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey5::HistorySize)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey5::HistorySize, &(size + 1));
     }
+    env.storage().persistent().set(
+        &key,
+        &RoundRecord {
+            recipient,
+            pot,
+            defaulters,
+            paid,
+            excused,
+            boosted,
+        },
+    );
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+/// Rounds this cycle that ended in a payout. A finite cycle completes once
+/// this reaches `MaxRounds`; rounds closed without a payout do not count.
+pub(crate) fn rounds_paid_out(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey5::RoundsPaidOut)
+        .unwrap_or(0)
 }
 
 /// Advances the round counter, clears paid-members and per-round contributions,
-/// and sets a new deadline.
-pub(crate) fn reset_round_state(env: &Env, current_round: u32) {
+/// and sets a new deadline. The single place a finished round moves on, used
+/// by both the payout path (`paid_out`) and `close_round`. `next_duration`
+/// overrides the next round's length, counted from now, whatever the schedule.
+pub(crate) fn advance_round(
+    env: &Env,
+    current_round: u32,
+    next_duration: Option<u64>,
+    paid_out: bool,
+) {
     // #227: Apply pending round duration if one was scheduled
-    let pending_duration: Option<u64> = env.storage().instance().get(&DataKey2::PendingRoundDuration);
+    let pending_duration: Option<u64> = env.storage().instance().get(&DataKey4::PendingRoundDuration);
     let duration: u64 = if let Some(pending) = pending_duration {
         env.storage().instance().set(&DataKey::RoundDuration, &pending);
-        env.storage().instance().remove(&DataKey2::PendingRoundDuration);
+        env.storage().instance().remove(&DataKey4::PendingRoundDuration);
         // Also update RoundDurationSeconds for timestamp-based scheduling
         env.storage().instance().set(&DataKey2::RoundDurationSeconds, &pending);
         events::emit_round_duration_applied(env, current_round + 1, pending);
@@ -549,9 +2230,40 @@ pub(crate) fn reset_round_state(env: &Env, current_round: u32) {
     env.storage()
         .instance()
         .set(&DataKey::CurrentRound, &new_round);
-    env.storage()
+    let mut paid_rounds = rounds_paid_out(env);
+    if paid_out {
+        paid_rounds += 1;
+        env.storage()
+            .instance()
+            .set(&DataKey5::RoundsPaidOut, &paid_rounds);
+    }
+    let max_rounds: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey4::MaxRounds)
+        .unwrap_or(0);
+    let already_completed = env
+        .storage()
         .instance()
-        .set(&DataKey::PaidMembers, &Vec::<Address>::new(env));
+        .get::<_, GroupStatus>(&DataKey2::GroupStatus)
+        == Some(GroupStatus::Completed);
+    if max_rounds > 0 && paid_rounds >= max_rounds && !already_completed {
+        env.storage()
+            .instance()
+            .set(&DataKey2::GroupStatus, &GroupStatus::Completed);
+        let history: Vec<PayoutRecord> = env
+            .storage()
+            .persistent()
+            .get(&PersistentKey::RoundHistory)
+            .unwrap_or(Vec::new(env));
+        let mut total_distributed: i128 = 0;
+        for record in history.iter() {
+            total_distributed += record.amount;
+        }
+        events::emit_finished(env, paid_rounds, total_distributed);
+        refund_all_deposits(env);
+    }
+    round_state_set(env, &DataKey::PaidMembers, &Vec::<Address>::new(env));
     env.storage().instance().set(
         &DataKey::MemberContributions,
         &Map::<Address, i128>::new(env),
@@ -559,10 +2271,37 @@ pub(crate) fn reset_round_state(env: &Env, current_round: u32) {
     env.storage()
         .instance()
         .set(&DataKey::Defaulters, &Vec::<Address>::new(env));
-    env.storage().instance().set(
-        &DataKey::RoundDeadline,
-        &(env.ledger().timestamp() + duration),
-    );
+    // Excuses are spent per round; none carry into the next one.
+    env.storage().instance().remove(&DataKey5::RoundExcused);
+    env.storage().instance().remove(&DataKey5::RoundRefunded);
+    env.storage().instance().remove(&DataKey5::RoundForeignPaid);
+    // A fixed schedule anchors every deadline to the group's start so a late
+    // close does not push the calendar back; a rolling one counts from now.
+    let fixed_schedule: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey4::FixedSchedule)
+        .unwrap_or(false);
+    let schedule_start: u64 = if fixed_schedule {
+        env.storage()
+            .instance()
+            .get(&DataKey2::StartAt)
+            .unwrap_or(0)
+    } else {
+        0
+    };
+    let next_deadline = |round_duration: u64| -> u64 {
+        match next_duration {
+            Some(duration) => env.ledger().timestamp() + duration,
+            None if fixed_schedule => schedule_start
+                .saturating_add((new_round as u64 + 1).saturating_mul(round_duration)),
+            None => env.ledger().timestamp() + round_duration,
+        }
+    };
+    round_state_set(env, &DataKey::RoundDeadline, &next_deadline(duration));
+    env.storage()
+        .instance()
+        .set(&DataKey4::RoundStartedAt, &env.ledger().timestamp());
 
     // Update timestamp-based deadline if enabled
     let use_timestamp: bool = env
@@ -577,17 +2316,15 @@ pub(crate) fn reset_round_state(env: &Env, current_round: u32) {
             .instance()
             .get(&DataKey2::RoundDurationSeconds)
             .unwrap_or(0);
-        let next_timestamp_deadline = env.ledger().timestamp() + duration_seconds;
-        env.storage()
-            .instance()
-            .set(&DataKey::RoundDeadlineTimestamp, &next_timestamp_deadline);
+        let next_timestamp_deadline = next_deadline(duration_seconds);
+        round_state_set(env, &DataKey::RoundDeadlineTimestamp, &next_timestamp_deadline);
         events::emit_round_deadline_timestamp_set(env, new_round, next_timestamp_deadline);
     }
 
     // ── Issue #402: Record cycle start timestamp when a new cycle begins ─────────
     let payout_order: Vec<Address> = env
         .storage()
-        .instance()
+        .persistent()
         .get(&DataKey::PayoutOrder)
         .unwrap_or(Vec::new(env));
     let cycle_len = payout_order.len() as u32;
@@ -613,7 +2350,7 @@ pub(crate) fn reset_round_state(env: &Env, current_round: u32) {
         let payout_order_len: u32 = {
             let order: Vec<Address> = env
                 .storage()
-                .instance()
+                .persistent()
                 .get(&DataKey::PayoutOrder)
                 .unwrap_or(Vec::new(env));
             order.len() as u32
@@ -639,6 +2376,8 @@ pub(crate) fn reset_round_state(env: &Env, current_round: u32) {
         }
     }
 
+    apply_prepaid(env, new_round);
+
     events::emit_reset(env, current_round);
 }
 
@@ -692,7 +2431,7 @@ pub(crate) fn execute_max_members_update(env: &Env, new_max_val: Option<i128>) {
         if new_max >= 1 && new_max <= 100 {
             let current_members: Vec<Address> = env
                 .storage()
-                .instance()
+                .persistent()
                 .get(&DataKey::Members)
                 .unwrap_or(Vec::new(env));
 
@@ -717,7 +2456,7 @@ pub(crate) fn execute_max_members_update(env: &Env, new_max_val: Option<i128>) {
 pub(crate) fn execute_member_removal(env: &Env, member: &Address) {
     let old_members: Vec<Address> = env
         .storage()
-        .instance()
+        .persistent()
         .get(&DataKey::Members)
         .unwrap_or(Vec::new(env));
     let mut new_members: Vec<Address> = Vec::new(env);
@@ -727,12 +2466,12 @@ pub(crate) fn execute_member_removal(env: &Env, member: &Address) {
         }
     }
     env.storage()
-        .instance()
+        .persistent()
         .set(&DataKey::Members, &new_members);
 
     let old_order: Vec<Address> = env
         .storage()
-        .instance()
+        .persistent()
         .get(&DataKey::PayoutOrder)
         .unwrap_or(Vec::new(env));
     let mut new_order: Vec<Address> = Vec::new(env);
@@ -742,9 +2481,452 @@ pub(crate) fn execute_member_removal(env: &Env, member: &Address) {
         }
     }
     env.storage()
-        .instance()
+        .persistent()
         .set(&DataKey::PayoutOrder, &new_order);
 
     events::emit_mem_del(env, member.clone());
 }
 
+
+/// Replaces `from` with `to` in the `Vec<Address>` stored under `key`.
+fn replace_in_list<K>(env: &Env, persistent: bool, key: &K, from: &Address, to: &Address)
+where
+    K: soroban_sdk::IntoVal<Env, soroban_sdk::Val>,
+{
+    let list: Option<Vec<Address>> = if persistent {
+        env.storage().persistent().get(key)
+    } else {
+        env.storage().instance().get(key)
+    };
+    let Some(mut list) = list else { return };
+    let Some(index) = list.first_index_of(from) else { return };
+    list.set(index, to.clone());
+    if persistent {
+        env.storage().persistent().set(key, &list);
+    } else {
+        env.storage().instance().set(key, &list);
+    }
+}
+
+/// Moves the entry for `from` in the `Map<Address, V>` stored under `key` to `to`.
+fn rekey_map<K, V>(env: &Env, persistent: bool, key: &K, from: &Address, to: &Address)
+where
+    K: soroban_sdk::IntoVal<Env, soroban_sdk::Val>,
+    V: soroban_sdk::IntoVal<Env, soroban_sdk::Val> + soroban_sdk::TryFromVal<Env, soroban_sdk::Val>,
+{
+    let map: Option<Map<Address, V>> = if persistent {
+        env.storage().persistent().get(key)
+    } else {
+        env.storage().instance().get(key)
+    };
+    let Some(mut map) = map else { return };
+    let Some(value) = map.get(from.clone()) else { return };
+    map.remove(from.clone());
+    map.set(to.clone(), value);
+    if persistent {
+        env.storage().persistent().set(key, &map);
+    } else {
+        env.storage().instance().set(key, &map);
+    }
+}
+
+/// Hands every piece of per-member state held by `from` over to `to`, keeping
+/// its turn, payment status, deposit, debt and history.
+pub(crate) fn execute_position_transfer(env: &Env, from: &Address, to: &Address) {
+    replace_in_list(env, true, &DataKey::Members, from, to);
+    replace_in_list(env, true, &DataKey::PayoutOrder, from, to);
+    if let Some(mut paid) = round_state_get::<Vec<Address>>(env, &DataKey::PaidMembers) {
+        if let Some(index) = paid.first_index_of(from) {
+            paid.set(index, to.clone());
+            round_state_set(env, &DataKey::PaidMembers, &paid);
+        }
+    }
+    replace_in_list(env, false, &DataKey::Defaulters, from, to);
+    replace_in_list(env, false, &DataKey::SuspendedMembers, from, to);
+    replace_in_list(env, false, &DataKey4::AdminSuspended, from, to);
+
+    rekey_map::<_, i128>(env, false, &DataKey::MemberContributions, from, to);
+    rekey_map::<_, i128>(env, false, &DataKey::MemberContributionAmt, from, to);
+    rekey_map::<_, u32>(env, false, &DataKey::DefaultCount, from, to);
+    rekey_map::<_, u32>(env, false, &DataKey::MemberParticipation, from, to);
+    rekey_map::<_, i128>(env, false, &DataKey4::MemberDeposits, from, to);
+    rekey_map::<_, i128>(env, false, &DataKey4::MemberDebt, from, to);
+    rekey_map::<_, u32>(env, false, &DataKey4::Weights, from, to);
+    rekey_map::<_, u32>(env, false, &DataKey4::OnTimeStreaks, from, to);
+    rekey_map::<_, u32>(env, false, &DataKey4::StreakClaims, from, to);
+    rekey_map::<_, u32>(env, false, &DataKey5::ConsecutiveDefaults, from, to);
+    rekey_map::<_, i128>(env, true, &PersistentKey::ReputationScores, from, to);
+
+    let rounds_key = PersistentKey::PayoutRounds(from.clone());
+    if let Some(rounds) = env.storage().persistent().get::<_, Vec<u32>>(&rounds_key) {
+        env.storage()
+            .persistent()
+            .set(&PersistentKey::PayoutRounds(to.clone()), &rounds);
+        env.storage().persistent().remove(&rounds_key);
+    }
+    let prepaid_key = DataKey::Prepaid(from.clone());
+    if let Some(prepaid) = env.storage().persistent().get::<_, i128>(&prepaid_key) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Prepaid(to.clone()), &prepaid);
+        env.storage().persistent().remove(&prepaid_key);
+    }
+    // The payout address belongs to the old key holder, not the position.
+    env.storage()
+        .persistent()
+        .remove(&PersistentKey::PayoutAddress(from.clone()));
+    let meta_key = PersistentKey::MemberMeta(from.clone());
+    if let Some(meta) = env.storage().persistent().get::<_, Bytes>(&meta_key) {
+        env.storage()
+            .persistent()
+            .set(&PersistentKey::MemberMeta(to.clone()), &meta);
+        env.storage().persistent().remove(&meta_key);
+    }
+    let excuse_key = PersistentKey::ExcusedRemaining(from.clone());
+    if let Some(remaining) = env.storage().persistent().get::<_, u32>(&excuse_key) {
+        env.storage()
+            .persistent()
+            .set(&PersistentKey::ExcusedRemaining(to.clone()), &remaining);
+        env.storage().persistent().remove(&excuse_key);
+    }
+    let defer_key = PersistentKey::DeferIfShort(from.clone());
+    if env.storage().persistent().get::<_, bool>(&defer_key).unwrap_or(false) {
+        env.storage()
+            .persistent()
+            .set(&PersistentKey::DeferIfShort(to.clone()), &true);
+        env.storage().persistent().remove(&defer_key);
+    }
+
+    let mut history: Vec<PayoutRecord> = env
+        .storage()
+        .persistent()
+        .get(&PersistentKey::RoundHistory)
+        .unwrap_or(Vec::new(env));
+    let mut history_changed = false;
+    for i in 0..history.len() {
+        let mut record = history.get(i).unwrap();
+        if record.recipient == *from {
+            record.recipient = to.clone();
+            history.set(i, record);
+            history_changed = true;
+        }
+    }
+    if history_changed {
+        env.storage()
+            .persistent()
+            .set(&PersistentKey::RoundHistory, &history);
+    }
+
+    let mut pending: Vec<PendingPayout> = env
+        .storage()
+        .instance()
+        .get(&DataKey4::PendingPayouts)
+        .unwrap_or(Vec::new(env));
+    let mut pending_changed = false;
+    for i in 0..pending.len() {
+        let mut held = pending.get(i).unwrap();
+        if held.recipient == *from {
+            held.recipient = to.clone();
+            pending.set(i, held);
+            pending_changed = true;
+        }
+    }
+    if pending_changed {
+        env.storage()
+            .instance()
+            .set(&DataKey4::PendingPayouts, &pending);
+    }
+
+    let disputed: Option<Address> = env.storage().instance().get(&DataKey4::Disputed);
+    if disputed.as_ref() == Some(from) {
+        env.storage().instance().set(&DataKey4::Disputed, to);
+    }
+}
+
+/// Returns `paid` reordered into the canonical member order.
+///
+/// Contributions landing in the same ledger have no meaningful arrival order,
+/// so anything that consumes the paid list sorts it by each member's index in
+/// `DataKey::Members`. Addresses no longer in `Members` keep their relative
+/// order at the end.
+pub(crate) fn canonical_paid_order(env: &Env, paid: &Vec<Address>) -> Vec<Address> {
+    let members: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Members)
+        .unwrap_or(Vec::new(env));
+    let mut ordered: Vec<Address> = Vec::new(env);
+    for member in members.iter() {
+        if paid.contains(&member) {
+            ordered.push_back(member);
+        }
+    }
+    for member in paid.iter() {
+        if !members.contains(&member) {
+            ordered.push_back(member);
+        }
+    }
+    ordered
+}
+
+/// Extends `member`'s on-time streak after a full contribution made before
+/// the deadline.
+pub(crate) fn extend_on_time_streak(env: &Env, member: &Address) {
+    let mut streaks: Map<Address, u32> = env
+        .storage()
+        .instance()
+        .get(&DataKey4::OnTimeStreaks)
+        .unwrap_or(Map::new(env));
+    let streak = streaks.get(member.clone()).unwrap_or(0);
+    streaks.set(member.clone(), streak + 1);
+    env.storage().instance().set(&DataKey4::OnTimeStreaks, &streaks);
+}
+
+/// Breaks the on-time streak of every listed member (late payers and defaulters).
+pub(crate) fn reset_on_time_streaks(env: &Env, members: &Vec<Address>) {
+    let mut streaks: Map<Address, u32> = env
+        .storage()
+        .instance()
+        .get(&DataKey4::OnTimeStreaks)
+        .unwrap_or(Map::new(env));
+    let mut changed = false;
+    for member in members.iter() {
+        if streaks.get(member.clone()).unwrap_or(0) > 0 {
+            streaks.set(member, 0);
+            changed = true;
+        }
+    }
+    if changed {
+        env.storage().instance().set(&DataKey4::OnTimeStreaks, &streaks);
+    }
+}
+
+/// Returns `order` with `member` removed, re-based so that the rounds from
+/// `current_round` onward still pay the remaining members in the same
+/// sequence. Plain filtering would shift every later turn, because the
+/// recipient is `order[round % order.len()]`.
+pub(crate) fn order_without(env: &Env, order: &Vec<Address>, member: &Address, current_round: u32) -> Vec<Address> {
+    let len = order.len();
+    let mut upcoming: Vec<Address> = Vec::new(env);
+    for k in 0..len {
+        let m = order.get((current_round + k) % len).unwrap();
+        if m != *member {
+            upcoming.push_back(m);
+        }
+    }
+    let new_len = upcoming.len();
+    let mut rebased: Vec<Address> = Vec::new(env);
+    if new_len == 0 {
+        return rebased;
+    }
+    let offset = current_round % new_len;
+    for i in 0..new_len {
+        rebased.push_back(upcoming.get((i + new_len - offset) % new_len).unwrap());
+    }
+    rebased
+}
+
+/// Records that instance and persistent storage live for at least the given
+/// number of ledgers from now. `extend_ttl(threshold, _)` only guarantees
+/// `threshold`, so callers pass the threshold rather than the extension.
+/// Floors only ever move forward.
+pub(crate) fn record_ttl_floor(env: &Env, instance: u32, persistent: u32) {
+    let now = env.ledger().sequence();
+    let (instance_until, persistent_until): (u32, u32) = env
+        .storage()
+        .instance()
+        .get(&DataKey4::TtlFloor)
+        .unwrap_or((0, 0));
+    env.storage().instance().set(
+        &DataKey4::TtlFloor,
+        &(
+            instance_until.max(now.saturating_add(instance)),
+            persistent_until.max(now.saturating_add(persistent)),
+        ),
+    );
+}
+
+/// Removes `member` from the member list and payout order. The order is
+/// re-based with `order_without` so everyone else keeps their upcoming turn.
+pub(crate) fn drop_member(env: &Env, member: &Address) {
+    let members: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Members)
+        .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
+    let mut new_members: Vec<Address> = Vec::new(env);
+    for m in members.iter() {
+        if m != *member {
+            new_members.push_back(m);
+        }
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::Members, &new_members);
+
+    let old_order: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PayoutOrder)
+        .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
+    let current_round: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::CurrentRound)
+        .unwrap_or(0);
+    let new_order = order_without(env, &old_order, member, current_round);
+    env.storage()
+        .persistent()
+        .set(&DataKey::PayoutOrder, &new_order);
+
+    events::emit_mem_rmv(env, member.clone(), new_members.len());
+}
+
+/// Extends the consecutive-default streak of every defaulter and clears it
+/// for every other member, since anyone not in `defaulters` paid in full or
+/// was excused. Returns the defaulters whose streak has reached
+/// `MaxConsecutiveDefaults`.
+pub(crate) fn track_consecutive_defaults(env: &Env, defaulters: &Vec<Address>) -> Vec<Address> {
+    let max_consecutive: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey5::MaxConsecutiveDefaults)
+        .unwrap_or(0);
+    let mut streaks: Map<Address, u32> = env
+        .storage()
+        .instance()
+        .get(&DataKey5::ConsecutiveDefaults)
+        .unwrap_or(Map::new(env));
+    let mut ejected: Vec<Address> = Vec::new(env);
+    for (member, _) in streaks.clone().iter() {
+        if !defaulters.contains(&member) {
+            streaks.remove(member);
+        }
+    }
+    for member in defaulters.iter() {
+        let streak = streaks.get(member.clone()).unwrap_or(0) + 1;
+        streaks.set(member.clone(), streak);
+        if max_consecutive > 0 && streak >= max_consecutive {
+            ejected.push_back(member);
+        }
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey5::ConsecutiveDefaults, &streaks);
+    ejected
+}
+
+/// The time deadlines are judged against: the ledger time, or the moment the
+/// admin froze the clock while it stays frozen.
+pub(crate) fn clock_now(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey5::ClockFrozenAt)
+        .unwrap_or_else(|| env.ledger().timestamp())
+}
+
+/// Records that the admin acted just now, restarting the guardian's recovery
+/// delay. Skipped when no guardian is configured.
+pub(crate) fn note_admin_action(env: &Env) {
+    if env.storage().instance().has(&DataKey5::Guardian) {
+        env.storage()
+            .instance()
+            .set(&DataKey5::LastAdminAction, &env.ledger().timestamp());
+    }
+}
+
+/// Excused absences `member` has left: the admin's allowance until the
+/// member first uses one.
+pub(crate) fn excuses_remaining(env: &Env, member: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&PersistentKey::ExcusedRemaining(member.clone()))
+        .unwrap_or_else(|| {
+            env.storage()
+                .instance()
+                .get(&DataKey5::ExcuseAllowance)
+                .unwrap_or(0)
+        })
+}
+
+/// Spends one of `member`'s excused absences on missing `round`, if any are
+/// left. Returns whether the absence was excused rather than a default.
+pub(crate) fn consume_excuse(env: &Env, member: &Address, round: u32) -> bool {
+    let remaining = excuses_remaining(env, member);
+    if remaining == 0 {
+        return false;
+    }
+    let key = PersistentKey::ExcusedRemaining(member.clone());
+    env.storage().persistent().set(&key, &(remaining - 1));
+    let mut excused: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&DataKey5::RoundExcused)
+        .unwrap_or(Vec::new(env));
+    excused.push_back(member.clone());
+    env.storage().instance().set(&DataKey5::RoundExcused, &excused);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    events::emit_absence_excused(env, member.clone(), round, remaining - 1);
+    true
+}
+
+/// Adds a contract-initiated pull of `amount` to `member`'s running total,
+/// rejecting it if that would pass the limit they set.
+pub(crate) fn record_pull(env: &Env, member: &Address, amount: i128) {
+    let key = PersistentKey::Pulled(member.clone());
+    let pulled: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    let pulled = pulled
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(env, ExtError::InvalidAmount));
+    let limit: Option<i128> = env
+        .storage()
+        .persistent()
+        .get(&PersistentKey::PullLimit(member.clone()));
+    if limit.is_some_and(|limit| pulled > limit) {
+        panic_with_error!(env, ExtError3::PullLimitExceeded);
+    }
+    env.storage().persistent().set(&key, &pulled);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+/// Where a payout owed to `member` is sent: their configured payout address
+/// if they set one, otherwise the member address.
+pub(crate) fn payout_destination(env: &Env, member: &Address) -> Address {
+    env.storage()
+        .persistent()
+        .get(&PersistentKey::PayoutAddress(member.clone()))
+        .unwrap_or_else(|| member.clone())
+}
+
+/// Fee rate charged on a payout. A group with a single remaining member only
+/// ever pays that member their own contribution back, so it is charged no fee
+/// and the payout equals the contribution exactly.
+pub(crate) fn payout_fee_bps(env: &Env) -> u32 {
+    let members: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Members)
+        .unwrap_or(Vec::new(env));
+    let exited_members: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&DataKey::ExitedMembers)
+        .unwrap_or(Vec::new(env));
+    let mut remaining = 0u32;
+    for member in members.iter() {
+        if !exited_members.contains(&member) {
+            remaining += 1;
+        }
+    }
+    if remaining <= 1 {
+        return 0;
+    }
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeBps)
+        .unwrap_or(0)
+}
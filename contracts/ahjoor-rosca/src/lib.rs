@@ -1,26 +1,52 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, panic_with_error, token, Address, Bytes, BytesN, Env, Map, String, Symbol, Vec,
+    contract, contractimpl, panic_with_error, symbol_short, token, Address, Bytes, BytesN, Env, Map, String, Symbol, Val, Vec,
 };
 use soroban_sdk::xdr::ToXdr;
 use ahjoor_token_whitelist::TokenWhitelistClient;
 
+// Semantic version of this contract's logic, baked into the Wasm. Bump it with
+// the crate version on every release so callers can confirm an `upgrade` took
+// effect.
+pub const VERSION: (u32, u32, u32) = (0, 1, 0);
+
 // Instance storage: config, counters, and active round state (bounded, shared TTL)
 pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = 100_000;
 pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 120_000;
 
-// Persistent storage: RoundHistory (grows by one record per round — unbounded)
+// Persistent storage: RoundHistory (grows by one record per round — unbounded),
+// Members and PayoutOrder (large, rarely written).
 // Each write extends its own TTL independently of the instance.
 pub(crate) const PERSISTENT_LIFETIME_THRESHOLD: u32 = 100_000;
 pub(crate) const PERSISTENT_BUMP_AMOUNT: u32 = 120_000;
 
 // Temporary storage: ExitRequests (in-progress, pending admin approval — short-lived)
-// Auto-expires if not acted upon; no long-term retention needed.
+// Auto-expires if not acted upon; no long-term retention needed. Per-round
+// state uses its own round-aligned TTL (see `internals::round_state_set`).
 const TEMP_LIFETIME_THRESHOLD: u32 = 10_000;
 const TEMP_BUMP_AMOUNT: u32 = 15_000;
 
+// Cap on the member metadata blob; it only needs to hold a short handle
+// or topic, not arbitrary documents.
+pub(crate) const MAX_MEMBER_METADATA_LEN: u32 = 256;
+
+// Cap on a contribution memo; enough for an invoice number or short reference.
+pub(crate) const MAX_MEMO_LEN: u32 = 64;
+
+// Cap on rounds `close_expired` closes in one call, keeping its cost bounded
+// however long a group has been dormant.
+pub(crate) const MAX_CLOSE_BATCH: u32 = 20;
+
 pub(crate) const MIGRATION_TIMEOUT_SECONDS: u64 = 604800; // 7 days in seconds
 
+// After `dissolve`, `init` is rejected for this long so an admin cannot
+// immediately relaunch the group on different terms.
+pub(crate) const REINIT_COOLDOWN_SECONDS: u64 = 604800; // 7 days in seconds
+
+// Shortest guardian recovery delay `set_guardian` accepts, so a guardian
+// cannot take over from an admin who merely missed a few hours.
+pub(crate) const MIN_RECOVERY_DELAY_SECONDS: u64 = 86_400; // 1 day in seconds
+
 pub mod types;
 pub use types::*;
 
@@ -41,8 +67,16 @@ mod test_migration;
 // mod test_contribution_receipts; // source file not yet committed
 mod migration_client;
 pub use migration_client::RoscaMigrationClient;
+mod oracle_client;
+pub use oracle_client::{PriceOracleClient, PriceOracleInterface};
+mod hook_client;
+pub use hook_client::{PayoutHookClient, PayoutHookInterface};
+mod swap_client;
+pub use swap_client::{SwapRouterClient, SwapRouterInterface};
+#[cfg(any(test, feature = "testutils"))]
+pub mod testutils;
 
-use crate::errors::{Error, ExtError, ExtError2};
+use crate::errors::{Error, ExtError, ExtError2, ExtError3};
 
 #[contract]
 pub struct AhjoorContract;
@@ -59,8 +93,17 @@ impl AhjoorContract {
         config: RoscaConfig,
         start_at: Option<u64>,
     ) {
-        if env.storage().instance().has(&DataKey::Members) {
-            panic_with_error!(&env, Error::AlreadyInitialized);
+        if env.storage().persistent().has(&DataKey::Members) {
+            // A dissolved group may be set up again, but only once the
+            // re-initialization cooldown has elapsed.
+            let dissolved_at: Option<u64> = env.storage().instance().get(&DataKey4::DissolvedAt);
+            match dissolved_at {
+                Some(at) if env.ledger().timestamp() < at.saturating_add(REINIT_COOLDOWN_SECONDS) => {
+                    panic_with_error!(&env, ExtError2::ReinitCooldownActive);
+                }
+                Some(_) => internals::reset_group_state(&env),
+                None => panic_with_error!(&env, Error::AlreadyInitialized),
+            }
         }
 
         // Validate fee_bps: max 500 bps (5%)
@@ -82,6 +125,27 @@ impl AhjoorContract {
             panic_with_error!(&env, Error::GroupFull);
         }
 
+        // Validate recovery_delay: a guardian needs at least MIN_RECOVERY_DELAY_SECONDS
+        if config.guardian.is_some() && config.recovery_delay < MIN_RECOVERY_DELAY_SECONDS {
+            panic_with_error!(&env, ExtError::InvalidAmount);
+        }
+
+        // The contract itself can never be a participant: paying it the pot or
+        // pulling contributions from it would just move funds to itself.
+        let self_address = env.current_contract_address();
+        if admin == self_address || members.contains(&self_address) {
+            panic_with_error!(&env, ExtError2::ContractAddressNotAllowed);
+        }
+
+        // Each member holds exactly one slot; `validate_config` reports the
+        // same case as `dup_member`.
+        for i in 0..members.len() {
+            let member = members.get(i).unwrap();
+            if members.last_index_of(&member) != Some(i) {
+                panic_with_error!(&env, Error::AlreadyAMember);
+            }
+        }
+
         let approved_tokens: Vec<Address> = env
             .storage()
             .instance()
@@ -98,9 +162,11 @@ impl AhjoorContract {
         let resolved_order = match config.strategy {
             PayoutStrategy::RoundRobin => members.clone(),
             PayoutStrategy::AdminAssigned => {
+                // A missing order cannot match the member count.
                 let order = config
                     .custom_order
-                    .expect("AdminAssigned strategy requires a custom order");
+                    .clone()
+                    .unwrap_or_else(|| panic_with_error!(&env, Error::CustomOrderLengthMismatch));
                 if order.len() != members.len() {
                     panic_with_error!(&env, Error::CustomOrderLengthMismatch);
                 }
@@ -113,26 +179,67 @@ impl AhjoorContract {
             }
         };
 
+        // Per-member amounts must line up one-to-one with `members`.
+        let mut member_amounts: Map<Address, i128> = Map::new(&env);
+        if let Some(amounts) = config.member_amounts.clone() {
+            if amounts.len() != members.len() {
+                panic_with_error!(&env, ExtError2::MemberAmountsLengthMismatch);
+            }
+            for (member, amount) in members.iter().zip(amounts.iter()) {
+                if amount <= 0 {
+                    panic_with_error!(&env, Error::AmountMustBePositive);
+                }
+                member_amounts.set(member, amount);
+            }
+        }
+
+        // Once the config has validated, confirm the token is a functioning
+        // token contract before any state is written, rather than failing later
+        // inside the first transfer, and keep its decimals for clients
+        // formatting amounts.
+        let token_decimals = match token::Client::new(&env, &token).try_decimals() {
+            Ok(Ok(decimals)) => decimals,
+            _ => panic_with_error!(&env, ExtError2::InvalidToken),
+        };
+
         let now = env.ledger().timestamp();
         let resolved_start_at = start_at.unwrap_or(now);
         let deadline = resolved_start_at + round_duration;
         let member_count = members.len();
 
         env.storage().instance().set(&DataKey::Admin, &admin);
+        if let Some(guardian) = &config.guardian {
+            env.storage().instance().set(&DataKey5::Guardian, guardian);
+            env.storage()
+                .instance()
+                .set(&DataKey5::RecoveryDelay, &config.recovery_delay);
+            internals::note_admin_action(&env);
+        }
         env.storage()
             .instance()
             .set(&DataKey2::ContractVersion, &1u32);
-        env.storage().instance().set(&DataKey::Members, &members);
+        env.storage().persistent().set(&DataKey::Members, &members);
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::PayoutOrder, &resolved_order);
+        internals::extend_member_ttl(&env);
         env.storage()
             .instance()
             .set(&DataKey::Strategy, &config.strategy);
         env.storage()
             .instance()
             .set(&DataKey::ContributionAmt, &contribution_amount);
+        env.storage()
+            .instance()
+            .set(&DataKey::MemberContributionAmt, &member_amounts);
         env.storage().instance().set(&DataKey::Token, &token);
+        env.storage()
+            .instance()
+            .set(&DataKey4::TokenDecimals, &token_decimals);
+        env.storage().instance().set(
+            &DataKey4::DefaultingRecipientPolicy,
+            &config.defaulting_recipient_policy,
+        );
 
         // Auto-approve the base token
         let mut approved_tokens: Vec<Address> = env
@@ -148,24 +255,22 @@ impl AhjoorContract {
         }
 
         env.storage().instance().set(&DataKey::CurrentRound, &0u32);
-        env.storage()
-            .instance()
-            .set(&DataKey::PaidMembers, &Vec::<Address>::new(&env));
         env.storage()
             .instance()
             .set(&DataKey::RoundDuration, &round_duration);
         env.storage()
             .instance()
-            .set(&DataKey::RoundDeadline, &deadline);
+            .set(&DataKey2::LastRoundDeadline, &deadline);
         env.storage()
             .instance()
-            .set(&DataKey4::LastRoundDeadline, &deadline);
+            .set(&DataKey2::StartAt, &resolved_start_at);
+        env.storage().instance().set(&DataKey4::CreatedAt, &now);
         env.storage()
             .instance()
-            .set(&DataKey4::StartAt, &resolved_start_at);
+            .set(&DataKey4::RoundStartedAt, &resolved_start_at);
         env.storage()
             .instance()
-            .set(&DataKey4::GroupActivationEmitted, &false);
+            .set(&DataKey2::GroupActivationEmitted, &false);
         env.storage()
             .instance()
             .set(&DataKey::Defaulters, &Vec::<Address>::new(&env));
@@ -229,6 +334,11 @@ impl AhjoorContract {
         env.storage()
             .instance()
             .set(&DataKey::ExitedMembers, &Vec::<Address>::new(&env));
+        // A classic ROSCA ends once every member has had the pot.
+        env.storage()
+            .instance()
+            .set(&DataKey4::MaxRounds, &members.len());
+        env.storage().instance().remove(&DataKey5::RoundsPaidOut);
         env.storage().instance().set(&DataKey::Paused, &false);
         env.storage().instance().set(&DataKey::IsPaused, &false);
         env.storage().instance().set(
@@ -240,10 +350,10 @@ impl AhjoorContract {
         env.storage()
             .instance()
             .set(&DataKey::FeeBps, &config.fee_bps);
-        if let Some(recipient) = config.fee_recipient {
+        if let Some(recipient) = &config.fee_recipient {
             env.storage()
                 .instance()
-                .set(&DataKey2::FeeRecipient, &recipient);
+                .set(&DataKey2::FeeRecipient, recipient);
         }
 
         // Suspension Threshold Configuration
@@ -253,13 +363,13 @@ impl AhjoorContract {
         events::emit_suspension_threshold_set(&env, config.max_defaults);
         env.storage()
             .instance()
-            .set(&DataKey4::GracePeriodLedgers, &config.grace_period_ledgers);
+            .set(&DataKey2::GracePeriodLedgers, &config.grace_period_ledgers);
         env.storage()
             .instance()
             .set(&DataKey3::GracePeriodSeconds, &config.grace_period_seconds);
         env.storage()
             .instance()
-            .set(&DataKey4::PendingPenalties, &Map::<Address, u32>::new(&env));
+            .set(&DataKey2::PendingPenalties, &Map::<Address, u32>::new(&env));
 
         env.storage()
             .instance()
@@ -272,12 +382,14 @@ impl AhjoorContract {
         env.storage()
             .instance()
             .set(&DataKey2::RoundDurationSeconds, &config.round_duration_seconds);
+        // Round state is written once the schedule and grace periods it is
+        // kept alive for are stored.
+        internals::round_state_set(&env, &DataKey::PaidMembers, &Vec::<Address>::new(&env));
+        internals::round_state_set(&env, &DataKey::RoundDeadline, &deadline);
 
         if config.use_timestamp_schedule {
             let timestamp_deadline = resolved_start_at + config.round_duration_seconds;
-            env.storage()
-                .instance()
-                .set(&DataKey::RoundDeadlineTimestamp, &timestamp_deadline);
+            internals::round_state_set(&env, &DataKey::RoundDeadlineTimestamp, &timestamp_deadline);
             events::emit_round_deadline_timestamp_set(&env, 0, timestamp_deadline);
         }
 
@@ -287,8 +399,8 @@ impl AhjoorContract {
                 .instance()
                 .set(&DataKey::CollectiveGoal, &goal);
         }
-        if let Some(goals) = config.member_goals {
-            env.storage().instance().set(&DataKey::MemberGoals, &goals);
+        if let Some(goals) = &config.member_goals {
+            env.storage().instance().set(&DataKey::MemberGoals, goals);
         }
         env.storage()
             .instance()
@@ -353,11 +465,32 @@ impl AhjoorContract {
         }
 
         events::emit_rosc_init(&env, member_count as u32, contribution_amount);
+        events::emit_group_configured(
+            &env,
+            admin.clone(),
+            members.clone(),
+            token.clone(),
+            contribution_amount,
+            round_duration,
+            resolved_order.clone(),
+            resolved_start_at,
+            config.clone(),
+        );
 
-        // #352: Store immutable base pool target (initial_members × contribution_amount)
-        let base_pool_target = contribution_amount
-            .checked_mul(member_count as i128)
-            .unwrap_or_else(|| panic_with_error!(&env, ExtError::InvalidAmount));
+        // #352: Store base pool target (sum of every member's required amount)
+        let base_pool_target = if member_amounts.is_empty() {
+            contribution_amount
+                .checked_mul(member_count as i128)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError::InvalidAmount))
+        } else {
+            let mut total: i128 = 0;
+            for (_, amount) in member_amounts.iter() {
+                total = total
+                    .checked_add(amount)
+                    .unwrap_or_else(|| panic_with_error!(&env, ExtError::InvalidAmount));
+            }
+            total
+        };
         env.storage()
             .instance()
             .set(&DataKey3::BasePoolTarget, &base_pool_target);
@@ -383,6 +516,67 @@ impl AhjoorContract {
         env.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        internals::record_ttl_floor(&env, INSTANCE_LIFETIME_THRESHOLD, PERSISTENT_LIFETIME_THRESHOLD);
+    }
+
+    /// `init` with native XLM as the base token and the default round-robin
+    /// config. `Token` is set to the native asset's Stellar Asset Contract
+    /// address, derived by `native_asset_address`; the SAC must already be
+    /// deployed on the network (it is on every public network).
+    pub fn init_native(
+        env: Env,
+        admin: Address,
+        members: Vec<Address>,
+        contribution_amount: i128,
+        round_duration: u64,
+    ) {
+        let token = Self::native_asset_address(env.clone());
+        let config = RoscaConfig::default();
+        Self::init(env, admin, members, contribution_amount, token, round_duration, config, None);
+    }
+
+    /// Address of the native XLM Stellar Asset Contract on this network.
+    pub fn native_asset_address(env: Env) -> Address {
+        // XDR of `Asset::Native`: the 4-byte ASSET_TYPE_NATIVE discriminant.
+        let native_asset = Bytes::from_array(&env, &[0u8; 4]);
+        env.deployer().with_stellar_asset(native_asset).deployed_address()
+    }
+
+    /// Checks a proposed `init` configuration without writing anything and
+    /// returns every problem found, so a UI can show them all at once.
+    /// Codes: `empty_members`, `dup_member`, `too_many`, `contract_member`,
+    /// `bad_amount`, `zero_duration`. An empty result means the basic
+    /// parameters are acceptable.
+    pub fn validate_config(
+        env: Env,
+        members: Vec<Address>,
+        contribution_amount: i128,
+        round_duration: u64,
+    ) -> Vec<Symbol> {
+        let mut problems: Vec<Symbol> = Vec::new(&env);
+        if members.is_empty() {
+            problems.push_back(Symbol::new(&env, "empty_members"));
+        }
+        for i in 0..members.len() {
+            let member = members.get(i).unwrap();
+            if members.last_index_of(&member) != Some(i) {
+                problems.push_back(Symbol::new(&env, "dup_member"));
+                break;
+            }
+        }
+        if members.len() > 100 {
+            problems.push_back(Symbol::new(&env, "too_many"));
+        }
+        if members.contains(env.current_contract_address()) {
+            problems.push_back(Symbol::new(&env, "contract_member"));
+        }
+        if contribution_amount <= 0 {
+            problems.push_back(Symbol::new(&env, "bad_amount"));
+        }
+        if round_duration == 0 {
+            problems.push_back(Symbol::new(&env, "zero_duration"));
+        }
+        problems
     }
 
     /// Propose a new admin address. Only the current admin can propose.
@@ -391,8 +585,9 @@ impl AhjoorContract {
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
+        internals::note_admin_action(&env);
 
         env.storage()
             .instance()
@@ -411,19 +606,20 @@ impl AhjoorContract {
             .storage()
             .instance()
             .get(&DataKey2::ProposedAdmin)
-            .expect("No admin transfer proposed");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError3::NoAdminTransferProposed));
         proposed_admin.require_auth();
 
         let old_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
 
         env.storage()
             .instance()
             .set(&DataKey::Admin, &proposed_admin);
         env.storage().instance().remove(&DataKey2::ProposedAdmin);
+        internals::note_admin_action(&env);
 
         events::emit_admin_transferred(&env, old_admin, proposed_admin);
 
@@ -432,22 +628,298 @@ impl AhjoorContract {
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
     }
 
+    /// Admin replaces the guardian named in `RoscaConfig` at `init`, or clears
+    /// it with `None`. The guardian can take over the admin role once the
+    /// admin has not acted for `recovery_delay` seconds, which must be at
+    /// least `MIN_RECOVERY_DELAY_SECONDS`.
+    pub fn set_guardian(env: Env, guardian: Option<Address>, recovery_delay: u64) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+
+        match guardian {
+            Some(guardian) => {
+                if recovery_delay < MIN_RECOVERY_DELAY_SECONDS {
+                    panic_with_error!(&env, ExtError::InvalidAmount);
+                }
+                env.storage().instance().set(&DataKey5::Guardian, &guardian);
+                env.storage()
+                    .instance()
+                    .set(&DataKey5::RecoveryDelay, &recovery_delay);
+                internals::note_admin_action(&env);
+            }
+            None => {
+                env.storage().instance().remove(&DataKey5::Guardian);
+                env.storage().instance().remove(&DataKey5::RecoveryDelay);
+                env.storage().instance().remove(&DataKey5::LastAdminAction);
+            }
+        }
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// The guardian and recovery delay, if a guardian is configured.
+    pub fn get_guardian(env: Env) -> Option<(Address, u64)> {
+        let guardian: Address = env.storage().instance().get(&DataKey5::Guardian)?;
+        let delay: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey5::RecoveryDelay)
+            .unwrap_or(0);
+        Some((guardian, delay))
+    }
+
+    /// Guardian reassigns the admin role after the admin has been inactive for
+    /// the recovery delay, e.g. because the admin key was lost.
+    pub fn recover_admin(env: Env, new_admin: Address) {
+        let guardian: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey5::Guardian)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError3::NoGuardianConfigured));
+        guardian.require_auth();
+
+        let delay: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey5::RecoveryDelay)
+            .unwrap_or(0);
+        let last_action: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey5::LastAdminAction)
+            .unwrap_or(0);
+        if env.ledger().timestamp() < last_action.saturating_add(delay) {
+            panic_with_error!(&env, ExtError3::RecoveryDelayNotElapsed);
+        }
+
+        let old_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        env.storage().instance().remove(&DataKey2::ProposedAdmin);
+        internals::note_admin_action(&env);
+
+        events::emit_admin_recovered(&env, guardian, old_admin, new_admin);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
     /// Get the current admin address.
     pub fn get_admin(env: Env) -> Address {
         env.storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized")
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
+    }
+
+    /// Returns true when `addr` is the admin or one of the `init_multisig`
+    /// co-admins. Needs no auth, so frontends can use it to show admin controls.
+    pub fn is_admin(env: Env, addr: Address) -> bool {
+        let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+        if admin == Some(addr.clone()) {
+            return true;
+        }
+        let co_admins: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey5::Admins)
+            .unwrap_or(Vec::new(&env));
+        co_admins.contains(&addr)
     }
 
     /// Returns the configured group start timestamp.
     pub fn get_start_time(env: Env) -> u64 {
         env.storage()
             .instance()
-            .get(&DataKey4::StartAt)
+            .get(&DataKey2::StartAt)
             .unwrap_or(env.ledger().timestamp())
     }
 
+    /// Decimals of the base token, read once at init, so clients can format
+    /// `contribution_amount` and balances.
+    pub fn get_token_decimals(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey4::TokenDecimals)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
+    }
+
+    /// Admin switches the base token between cycles. Only allowed while no
+    /// one has paid into the current round and the contract holds nothing it
+    /// tracks in the old token. The new token must implement the token
+    /// interface and pass the whitelist; it is approved and its decimals
+    /// replace the stored ones.
+    pub fn set_token(env: Env, new_token: Address) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        if !paid_members.is_empty() {
+            panic_with_error!(&env, Error::CannotChangeMidRound);
+        }
+        if internals::accounted_balance(&env) != 0 {
+            panic_with_error!(&env, ExtError3::FundsHeld);
+        }
+
+        let decimals = match token::Client::new(&env, &new_token).try_decimals() {
+            Ok(Ok(decimals)) => decimals,
+            _ => panic_with_error!(&env, ExtError2::InvalidToken),
+        };
+        Self::require_token_allowed(&env, &new_token);
+
+        let old_token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        // Auto-approve the new base token, as init does
+        let mut approved_tokens: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ApprovedTokens)
+            .unwrap_or(Vec::new(&env));
+        if !approved_tokens.contains(&new_token) {
+            approved_tokens.push_back(new_token.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::ApprovedTokens, &approved_tokens);
+        }
+        env.storage().instance().set(&DataKey::Token, &new_token);
+        env.storage()
+            .instance()
+            .set(&DataKey4::TokenDecimals, &decimals);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        events::emit_token_changed(&env, old_token, new_token, decimals);
+    }
+
+    /// Returns `(created_at, round_started_at, deadline)`: when the group was
+    /// initialized, when the current round began, and the current deadline.
+    pub fn get_timing(env: Env) -> (u64, u64, u64) {
+        let created_at: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::CreatedAt)
+            .unwrap_or(0);
+        let round_started_at: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::RoundStartedAt)
+            .unwrap_or(created_at);
+        let use_timestamp: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey2::UseTimestampSchedule)
+            .unwrap_or(false);
+        let deadline: u64 = if use_timestamp {
+            internals::round_state_get(&env, &DataKey::RoundDeadlineTimestamp)
+                .unwrap_or(0)
+        } else {
+            internals::round_state_get(&env, &DataKey::RoundDeadline)
+                .unwrap_or(0)
+        };
+        (created_at, round_started_at, deadline)
+    }
+
+    /// Admin switches between a rolling schedule (default), where each new
+    /// deadline is one duration after the previous round closed, and a fixed
+    /// one, where round `n` always ends at `start + (n + 1) * duration` no
+    /// matter how late earlier rounds closed.
+    pub fn set_fixed_schedule(env: Env, enabled: bool) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        env.storage()
+            .instance()
+            .set(&DataKey4::FixedSchedule, &enabled);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    pub fn is_fixed_schedule(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey4::FixedSchedule)
+            .unwrap_or(false)
+    }
+
+    /// Estimated round number that timestamp `ts` falls in, assuming every
+    /// round runs exactly the configured duration from `init`. Deadline
+    /// extensions, restarts and early payouts shift real rounds, so treat the
+    /// result as a schedule estimate. Clamped to the last round of a finite
+    /// cycle; timestamps before `init` map to round 0.
+    pub fn round_at_timestamp(env: Env, ts: u64) -> u32 {
+        let created_at: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::CreatedAt)
+            .unwrap_or(0);
+        let duration = internals::configured_round_duration(&env);
+        if duration == 0 || ts <= created_at {
+            return 0;
+        }
+
+        let round = ((ts - created_at) / duration).min(u32::MAX as u64) as u32;
+        let max_rounds: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::MaxRounds)
+            .unwrap_or(0);
+        if max_rounds > 0 {
+            round.min(max_rounds - 1)
+        } else {
+            round
+        }
+    }
+
+    /// Projected deadlines of the current and following rounds, up to `count`
+    /// (and no further than the last round of a finite cycle), assuming each
+    /// round runs the configured duration. An estimate only: admin deadline
+    /// extensions, restarts and early payouts move the real deadlines.
+    pub fn upcoming_deadlines(env: Env, count: u32) -> Vec<u64> {
+        let (_, _, deadline) = Self::get_timing(env.clone());
+        let duration = internals::configured_round_duration(&env);
+        let max_rounds: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::MaxRounds)
+            .unwrap_or(0);
+        let count = if max_rounds > 0 {
+            count.min(max_rounds.saturating_sub(internals::rounds_paid_out(&env)))
+        } else {
+            count
+        };
+
+        let mut deadlines: Vec<u64> = Vec::new(&env);
+        for i in 0..count {
+            deadlines.push_back(deadline.saturating_add(i as u64 * duration));
+        }
+        deadlines
+    }
+
     /// Returns true when group contributions can begin.
     pub fn is_active(env: Env) -> bool {
         let start_at = Self::get_start_time(env.clone());
@@ -462,13 +934,14 @@ impl AhjoorContract {
     /// Cancel a pending (not-yet-active) group and refund deposited rewards to admin.
     pub fn cancel_pending_group(env: Env, admin: Address) {
         admin.require_auth();
+        internals::note_admin_action(&env);
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
-            panic!("Only admin can cancel pending group");
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
 
         let start_at = Self::get_start_time(env.clone());
@@ -482,9 +955,16 @@ impl AhjoorContract {
             .get(&DataKey::RewardPool)
             .unwrap_or(0);
         if reward_pool > 0 {
-            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
             let client = token::Client::new(&env, &token_addr);
             client.transfer(&env.current_contract_address(), &admin, &reward_pool);
+            internals::record_fund_flow(
+                &env,
+                "refund",
+                &env.current_contract_address(),
+                &admin,
+                reward_pool,
+            );
             env.storage().instance().set(&DataKey::RewardPool, &0i128);
         }
 
@@ -493,28 +973,37 @@ impl AhjoorContract {
             .set(&DataKey2::GroupStatus, &GroupStatus::Dissolved);
     }
 
-    /// Upgrade this contract's WASM code. Admin only.
+    /// Upgrade this contract's WASM code. Admin only; with a co-admin
+    /// threshold above 1 the upgrade must go through `propose_upgrade`.
     pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) {
         admin.require_auth();
+        internals::note_admin_action(&env);
 
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
-            panic!("Only admin can upgrade contract");
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
+        Self::require_no_quorum(&env);
+        Self::upgrade_internal(&env, new_wasm_hash, admin);
+    }
 
-        let old_version = Self::get_or_init_version(&env);
+    /// Body of `upgrade`; callers have already authorized the upgrade.
+    fn upgrade_internal(env: &Env, new_wasm_hash: BytesN<32>, admin: Address) {
+        let old_version = Self::get_or_init_version(env);
         env.deployer().update_current_contract_wasm(new_wasm_hash);
 
-        let new_version = old_version.checked_add(1).expect("Version overflow");
+        let new_version = old_version
+            .checked_add(1)
+            .unwrap_or_else(|| panic_with_error!(env, ExtError::InvalidAmount));
         env.storage()
             .instance()
             .set(&DataKey2::ContractVersion, &new_version);
 
-        events::emit_contract_upgraded(&env, old_version, new_version, admin);
+        events::emit_contract_upgraded(env, old_version, new_version, admin);
 
         env.storage()
             .instance()
@@ -524,17 +1013,24 @@ impl AhjoorContract {
     /// Run one-time migration logic for the current version. Admin only.
     pub fn migrate(env: Env, admin: Address) {
         admin.require_auth();
+        internals::note_admin_action(&env);
 
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
-            panic!("Only admin can migrate contract");
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
 
-        // Migration logic would go here
+        // Move the member list and payout order out of instance storage on
+        // deployments that predate persistent storage, then backfill keys
+        // introduced after this instance was deployed. Keys that already
+        // exist are left untouched, so repeated calls are no-ops.
+        let backfilled = internals::backfill_missing_keys(&env);
+        events::emit_state_migrated(&env, Self::get_or_init_version(&env), backfilled);
+
         env.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
@@ -545,20 +1041,33 @@ impl AhjoorContract {
         Self::get_or_init_version(&env)
     }
 
+    /// Returns the `(major, minor, patch)` version of the live Wasm. Unlike
+    /// `get_version`, which counts upgrades in storage, this comes from the
+    /// code itself.
+    pub fn version(_env: Env) -> (u32, u32, u32) {
+        VERSION
+    }
+
+    /// Returns the schema version carried in every event's topics.
+    pub fn event_schema_version(_env: Env) -> u32 {
+        EVENT_SCHEMA_VERSION
+    }
+
     // --- Token Whitelist Integration ---
 
     /// Set the token whitelist contract address (admin only)
     pub fn set_token_whitelist_contract(env: Env, admin: Address, whitelist_contract: Address) {
         internals::check_not_paused(&env);
         admin.require_auth();
+        internals::note_admin_action(&env);
 
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
-            panic!("Only admin can set token whitelist contract");
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
 
         env.storage()
@@ -596,14 +1105,15 @@ impl AhjoorContract {
     /// Set the contribution tier for a member. Tier changes take effect in the next round.
     pub fn set_member_tier(env: Env, admin: Address, member: Address, tier_bps: u32) {
         admin.require_auth();
+        internals::note_admin_action(&env);
 
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
-            panic!("Only admin can set member tier");
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
 
         if tier_bps == 0 {
@@ -612,9 +1122,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
             panic_with_error!(&env, Error::NotAMember);
         }
@@ -645,9 +1155,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&contributor) {
             panic_with_error!(&env, Error::NotAMember);
         }
@@ -679,6 +1189,13 @@ impl AhjoorContract {
             &env.current_contract_address(),
             &amount,
         );
+        internals::record_fund_flow(
+            &env,
+            "insurance",
+            &contributor,
+            &env.current_contract_address(),
+            amount,
+        );
 
         let mut insurance_pool: i128 = env
             .storage()
@@ -741,23 +1258,77 @@ impl AhjoorContract {
         // If no whitelist contract is set, allow all tokens (backward compatibility)
     }
 
-    pub fn contribute(env: Env, contributor: Address, token: Address, amount: i128) {
-        internals::check_not_paused(&env);
-        internals::check_not_frozen(&env);
-        contributor.require_auth();
+    /// Rejects crediting `member` on their behalf once they have paid the
+    /// current round, before the owed amount drops to zero.
+    fn require_unpaid(env: &Env, member: &Address) {
+        let paid_members: Vec<Address> = internals::round_state_get(env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(env));
+        if paid_members.contains(member) {
+            panic_with_error!(env, Error::AlreadyContributed);
+        }
+    }
 
-        let start_at = Self::get_start_time(env.clone());
-        if env.ledger().timestamp() < start_at {
-            panic_with_error!(&env, ExtError::GroupNotYetActive);
+    /// Moves `amount` of `token` from `payer` into the contract, through the
+    /// allowance `payer` granted the contract when `via_allowance` is set.
+    fn take_payment(env: &Env, token: &Address, payer: &Address, via_allowance: bool, amount: i128) {
+        let client = token::Client::new(env, token);
+        let contract = env.current_contract_address();
+        if via_allowance {
+            client.transfer_from(&contract, payer, &contract, &amount);
+        } else {
+            client.transfer(payer, &contract, &amount);
         }
-        let group_status: GroupStatus = env
-            .storage()
-            .instance()
-            .get(&DataKey2::GroupStatus)
+    }
+
+    /// Contribute `amount` of `token` towards the current round. Every rejection
+    /// is raised as a typed contract error (`Error`, `ExtError`, `ExtError2`,
+    /// `ExtError3`), so `try_contribute` callers get a stable numeric code.
+    /// Returns the round's progress after this contribution (see
+    /// `ContributeResult`). This changed the return type from `()`, so
+    /// `try_contribute` and cross-contract callers must expect the struct.
+    pub fn contribute(env: Env, contributor: Address, token: Address, amount: i128) -> ContributeResult {
+        contributor.require_auth();
+        Self::apply_contribution(env, contributor.clone(), contributor, false, token, amount, false).0
+    }
+
+    /// Body of `contribute` once the caller has authorized the payment, shared
+    /// with `contribute_late`, `trigger_auto` and `contribute_batch`. `payer`
+    /// funds the contribution credited to `contributor`; with `via_allowance`
+    /// the funds are drawn through the allowance `payer` granted the contract.
+    /// With `defer_payout` a contribution that fills the round leaves the
+    /// payout to the caller. Also returns the amount accepted towards the
+    /// round, which the overpay policy may have clipped below `amount`.
+    fn apply_contribution(
+        env: Env,
+        contributor: Address,
+        payer: Address,
+        via_allowance: bool,
+        token: Address,
+        amount: i128,
+        defer_payout: bool,
+    ) -> (ContributeResult, i128) {
+        internals::check_not_paused(&env);
+        internals::check_contributions_open(&env);
+        internals::check_contribution_window(&env);
+        internals::check_not_frozen(&env);
+        internals::require_initialized(&env);
+        internals::refresh_peg_price(&env);
+
+        let start_at = Self::get_start_time(env.clone());
+        if env.ledger().timestamp() < start_at {
+            panic_with_error!(&env, ExtError::GroupNotYetActive);
+        }
+        let group_status: GroupStatus = env
+            .storage()
+            .instance()
+            .get(&DataKey2::GroupStatus)
             .unwrap_or(GroupStatus::Active);
         if group_status == GroupStatus::Dissolved {
             panic_with_error!(&env, ExtError::GroupAlreadyDissolved);
         }
+        if group_status == GroupStatus::Completed {
+            panic_with_error!(&env, ExtError2::CycleComplete);
+        }
 
         if amount <= 0 {
             panic_with_error!(&env, Error::AmountMustBePositive);
@@ -770,18 +1341,14 @@ impl AhjoorContract {
             .unwrap_or(false);
 
         let deadline: u64 = if use_timestamp {
-            env.storage()
-                .instance()
-                .get(&DataKey::RoundDeadlineTimestamp)
-                .expect("Timestamp deadline not set")
+            internals::round_state_get(&env, &DataKey::RoundDeadlineTimestamp)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
         } else {
-            env.storage()
-                .instance()
-                .get(&DataKey::RoundDeadline)
-                .expect("Deadline not set")
+            internals::round_state_get(&env, &DataKey::RoundDeadline)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
         };
 
-        let now_ts = env.ledger().timestamp();
+        let now_ts = internals::clock_now(&env);
 
         // #356: Allow late contributions during the grace period.
         let grace_period_seconds: u64 = env
@@ -807,41 +1374,39 @@ impl AhjoorContract {
             panic_with_error!(&env, Error::MemberHasExited);
         }
 
+        if !Self::is_member(env.clone(), contributor.clone()) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+        if internals::admin_suspended(&env).contains(&contributor) {
+            panic_with_error!(&env, ExtError2::MemberSuspended);
+        }
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
-        if !members.contains(&contributor) {
-            panic_with_error!(&env, Error::NotAMember);
-        }
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        internals::extend_member_ttl(&env);
 
         let activation_emitted: bool = env
             .storage()
             .instance()
-            .get(&DataKey4::GroupActivationEmitted)
+            .get(&DataKey2::GroupActivationEmitted)
             .unwrap_or(false);
 
-        let mut paid_members: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::PaidMembers)
-            .expect("Not initialized");
+        let mut paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if paid_members.contains(&contributor) {
             panic_with_error!(&env, Error::AlreadyContributed);
         }
+        let mut triggered_payout = false;
 
-        // #218: collect reinstatement fee before first contribution after reinstatement
+        // #218: a reinstatement fee is due with the first contribution after
+        // reinstatement; it is collected with the contribution below.
+        let mut reinstatement_fee: i128 = 0;
         {
-            let mut pending: Vec<Address> = env.storage().instance().get(&DataKey2::PendingReinstatementFee).unwrap_or(Vec::new(&env));
+            let pending: Vec<Address> = env.storage().instance().get(&DataKey2::PendingReinstatementFee).unwrap_or(Vec::new(&env));
             if pending.contains(&contributor) {
-                let fee: i128 = env.storage().instance().get(&DataKey2::ReinstatementFee).unwrap_or(0);
-                if fee > 0 {
-                    let fee_token: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-                    let fee_client = token::Client::new(&env, &fee_token);
-                    fee_client.transfer(&contributor, &env.current_contract_address(), &fee);
-                    events::emit_reinstatement_fee_collected(&env, contributor.clone(), fee);
-                }
+                reinstatement_fee = env.storage().instance().get(&DataKey2::ReinstatementFee).unwrap_or(0);
                 let mut new_pending: Vec<Address> = Vec::new(&env);
                 for m in pending.iter() { if m != contributor { new_pending.push_back(m); } }
                 env.storage().instance().set(&DataKey2::PendingReinstatementFee, &new_pending);
@@ -861,21 +1426,37 @@ impl AhjoorContract {
         // Token whitelist validation
         Self::require_token_allowed(&env, &token);
 
-        let base_token: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let base_amount: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::ContributionAmt)
-            .unwrap();
+        let base_token: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
 
-        // Calculate member-specific required amount based on tier
-        let tiers: Map<Address, u32> = env
-            .storage()
-            .instance()
-            .get(&DataKey2::MemberTiers)
-            .unwrap_or(Map::new(&env));
-        let tier_bps = tiers.get(contributor.clone()).unwrap_or(10_000); // Default to 1x (10000 bps)
-        let member_required_amount = (base_amount * tier_bps as i128) / 10_000;
+        // Member-specific required amount (explicit per-member amount or tier-scaled)
+        let member_required_amount = internals::member_required_amount(&env, &contributor);
+
+        // Anything above what is still owed is handled per `OverpayPolicy`.
+        let owed = member_required_amount
+            - env
+                .storage()
+                .instance()
+                .get::<_, Map<Address, i128>>(&DataKey::MemberContributions)
+                .unwrap_or(Map::new(&env))
+                .get(contributor.clone())
+                .unwrap_or(0);
+        let excess = (amount - owed).max(0);
+        let mut credited: i128 = 0;
+        if excess > 0 {
+            let policy: OverpayPolicy = env
+                .storage()
+                .instance()
+                .get(&DataKey5::OverpayPolicy)
+                .unwrap_or(OverpayPolicy::Reject);
+            match policy {
+                OverpayPolicy::Reject => {
+                    panic_with_error!(&env, Error::ExceedsRemainingContribution)
+                }
+                OverpayPolicy::Credit if token == base_token => credited = excess,
+                _ => {}
+            }
+        }
+        let amount = amount - excess;
 
         let amount_to_transfer = if token == base_token {
             amount  // For base token, transfer the exact amount specified
@@ -885,7 +1466,9 @@ impl AhjoorContract {
                 .instance()
                 .get(&DataKey::ExchangeRates)
                 .unwrap_or(Map::new(&env));
-            let rate = rates.get(token.clone()).expect("Exchange rate not set");
+            let rate = rates
+                .get(token.clone())
+                .unwrap_or_else(|| panic_with_error!(&env, Error::InvalidExchangeRate));
             if rate <= 0 {
                 panic_with_error!(&env, Error::InvalidExchangeRate);
             }
@@ -917,13 +1500,38 @@ impl AhjoorContract {
         } else {
             0
         };
-        let total_transfer_amount = amount_to_transfer + insurance_deduction;
+        let total_transfer_amount = amount_to_transfer + insurance_deduction + credited;
+
+        // Everything drawn through the allowance counts toward the payer's
+        // pull limit, checked before any of it moves.
+        if via_allowance {
+            internals::record_pull(&env, &payer, reinstatement_fee + total_transfer_amount);
+        }
+        if reinstatement_fee > 0 {
+            Self::take_payment(&env, &base_token, &payer, via_allowance, reinstatement_fee);
+            internals::record_fund_flow(
+                &env,
+                "fee",
+                &payer,
+                &env.current_contract_address(),
+                reinstatement_fee,
+            );
+            events::emit_reinstatement_fee_collected(&env, contributor.clone(), reinstatement_fee);
+        }
 
+        internals::check_tvl(&env, total_transfer_amount);
         let client = token::Client::new(&env, &token);
-        client.transfer(
-            &contributor,
+        // Fail with a clear error rather than the token contract's own panic.
+        if client.balance(&payer) < total_transfer_amount {
+            panic_with_error!(&env, ExtError2::InsufficientBalance);
+        }
+        Self::take_payment(&env, &token, &payer, via_allowance, total_transfer_amount);
+        internals::record_fund_flow(
+            &env,
+            "contribution",
+            &payer,
             &env.current_contract_address(),
-            &total_transfer_amount,
+            total_transfer_amount,
         );
 
         // Update insurance pool if auto-deduction was applied
@@ -958,12 +1566,42 @@ impl AhjoorContract {
         if amount > remaining {
             panic_with_error!(&env, Error::ExceedsRemainingContribution);
         }
+        internals::check_contribution_bounds(&env, amount, remaining);
+
+        // The excess is only credited or reported once the owed part has
+        // passed the checks above.
+        if credited > 0 {
+            let key = DataKey::Prepaid(contributor.clone());
+            let balance = env.storage().persistent().get::<_, i128>(&key).unwrap_or(0) + credited;
+            env.storage().persistent().set(&key, &balance);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+            events::emit_prepaid(&env, contributor.clone(), credited, balance);
+        }
+        if excess > 0 {
+            events::emit_overpayment(&env, contributor.clone(), current_round, excess, credited);
+        }
 
         let new_total = already_paid + amount;
         member_contributions.set(contributor.clone(), new_total);
         env.storage()
             .instance()
             .set(&DataKey::MemberContributions, &member_contributions);
+        // Only base-token payments can be handed back by `request_refund`.
+        if token != base_token {
+            let mut foreign_paid: Vec<Address> = env
+                .storage()
+                .instance()
+                .get(&DataKey5::RoundForeignPaid)
+                .unwrap_or(Vec::new(&env));
+            if !foreign_paid.contains(&contributor) {
+                foreign_paid.push_back(contributor.clone());
+                env.storage()
+                    .instance()
+                    .set(&DataKey5::RoundForeignPaid, &foreign_paid);
+            }
+        }
 
         events::emit_contrib(
             &env,
@@ -1001,6 +1639,7 @@ impl AhjoorContract {
                     .set(&DataKey3::LateContributionCount, &late_counts);
                 Self::apply_reputation_delta(&env, contributor.clone(), -5, "late_full");
                 Self::update_credit_score_internal(&env, &contributor, Symbol::new(&env, "late"));
+                internals::reset_on_time_streaks(&env, &Vec::from_array(&env, [contributor.clone()]));
             } else {
                 // On-time: reset consecutive late count and reward reputation
                 let mut late_counts: Map<Address, u32> = env
@@ -1017,11 +1656,11 @@ impl AhjoorContract {
                 }
                 Self::apply_reputation_delta(&env, contributor.clone(), 10, "on_time_full");
                 Self::update_credit_score_internal(&env, &contributor, Symbol::new(&env, "on_time"));
+                internals::extend_on_time_streak(&env, &contributor);
             }
+            internals::note_round_opened(&env, &paid_members);
             paid_members.push_back(contributor.clone());
-            env.storage()
-                .instance()
-                .set(&DataKey::PaidMembers, &paid_members);
+            internals::round_state_set(&env, &DataKey::PaidMembers, &paid_members);
 
             // Track reward participation
             let mut total_participations: u32 = env
@@ -1046,24 +1685,9 @@ impl AhjoorContract {
                 .instance()
                 .set(&DataKey::MemberParticipation, &member_participation);
 
-            // Only trigger payout when all members have fully contributed
-            if new_total == member_required_amount && paid_members.len() == members.len() {
-                internals::complete_round_payout(&env, &paid_members);
-
-                // Emit auto-close event if enabled
-                let auto_close_enabled: bool = env
-                    .storage()
-                    .temporary()
-                    .get(&Symbol::new(&env, "auto_close_enabled"))
-                    .unwrap_or(false);
-                if auto_close_enabled {
-                    let current_round: u32 = env
-                        .storage()
-                        .instance()
-                        .get(&DataKey::CurrentRound)
-                        .unwrap_or(0);
-                    events::emit_round_auto_closed_early(&env, current_round, env.ledger().timestamp());
-                }
+            // Only trigger payout when all active members have fully contributed
+            if !defer_payout {
+                triggered_payout = Self::pay_out_if_complete(&env, &paid_members);
             }
 
             // Savings Goal Progress Tracking
@@ -1123,12 +1747,82 @@ impl AhjoorContract {
             events::emit_group_activated(&env, start_at);
             env.storage()
                 .instance()
-                .set(&DataKey4::GroupActivationEmitted, &true);
+                .set(&DataKey2::GroupActivationEmitted, &true);
         }
 
         env.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        internals::extend_round_state_ttl(&env);
+
+        (
+            ContributeResult {
+                paid_count: paid_members.len(),
+                total_members: members.len(),
+                triggered_payout,
+            },
+            amount,
+        )
+    }
+
+    /// Pays out the round once every active member has paid, unless the pot
+    /// is carried forward for being below the minimum. Returns whether the
+    /// payout ran.
+    fn pay_out_if_complete(env: &Env, paid_members: &Vec<Address>) -> bool {
+        if paid_members.len() < internals::active_member_count(env)
+            || Self::carry_below_min_pot(env)
+        {
+            return false;
+        }
+        internals::complete_round_payout(env, paid_members);
+
+        // Emit auto-close event if enabled
+        let auto_close_enabled: bool = env
+            .storage()
+            .temporary()
+            .get(&Symbol::new(env, "auto_close_enabled"))
+            .unwrap_or(false);
+        if auto_close_enabled {
+            let current_round: u32 = env
+                .storage()
+                .instance()
+                .get(&DataKey::CurrentRound)
+                .unwrap_or(0);
+            events::emit_round_auto_closed_early(env, current_round, env.ledger().timestamp());
+        }
+        true
+    }
+
+    /// Same as `contribute`, with a reference attached for reconciliation.
+    /// The memo is published in a `contribution_memo` event next to the usual
+    /// contribution event.
+    pub fn contribute_with_memo(
+        env: Env,
+        contributor: Address,
+        token: Address,
+        amount: i128,
+        memo: Bytes,
+    ) -> ContributeResult {
+        if memo.len() > MAX_MEMO_LEN {
+            panic_with_error!(&env, ExtError2::MemoTooLong);
+        }
+        let round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        contributor.require_auth();
+        let (result, accepted) = Self::apply_contribution(
+            env.clone(),
+            contributor.clone(),
+            contributor.clone(),
+            false,
+            token,
+            amount,
+            false,
+        );
+        events::emit_contribution_memo(&env, contributor, round, accepted, memo);
+        result
     }
 
     pub fn request_skip(env: Env, member: Address, round: u32) {
@@ -1152,15 +1846,11 @@ impl AhjoorContract {
             .unwrap_or(false);
 
         let deadline: u64 = if use_timestamp {
-            env.storage()
-                .instance()
-                .get(&DataKey::RoundDeadlineTimestamp)
-                .expect("Timestamp deadline not set")
+            internals::round_state_get(&env, &DataKey::RoundDeadlineTimestamp)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
         } else {
-            env.storage()
-                .instance()
-                .get(&DataKey::RoundDeadline)
-                .expect("Deadline not set")
+            internals::round_state_get(&env, &DataKey::RoundDeadline)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
         };
 
         // Only allow skip for current round if before deadline
@@ -1170,9 +1860,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
             panic_with_error!(&env, Error::NotAMember);
         }
@@ -1189,11 +1879,8 @@ impl AhjoorContract {
 
         // Check if already contributed this round
         if round == current_round {
-            let paid_members: Vec<Address> = env
-                .storage()
-                .instance()
-                .get(&DataKey::PaidMembers)
-                .expect("Not initialized");
+            let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
             if paid_members.contains(&member) {
                 panic_with_error!(&env, Error::AlreadyContributed);
             }
@@ -1201,9 +1888,9 @@ impl AhjoorContract {
 
         let payout_order: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::PayoutOrder)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         let cycle_index = round / (payout_order.len() as u32);
         let max_skips: u32 = env
             .storage()
@@ -1229,9 +1916,16 @@ impl AhjoorContract {
             .unwrap_or(0);
 
         if skip_fee > 0 {
-            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
             let client = token::Client::new(&env, &token_addr);
             client.transfer(&member, &env.current_contract_address(), &skip_fee);
+            internals::record_fund_flow(
+                &env,
+                "fee",
+                &member,
+                &env.current_contract_address(),
+                skip_fee,
+            );
         }
 
         skip_requests.set((member.clone(), round), true);
@@ -1251,2582 +1945,5832 @@ impl AhjoorContract {
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
     }
 
-    pub fn close_round(env: Env) {
+    /// Admin makes payouts weighted: every round's pot is split across members
+    /// in proportion to `weights` instead of going to one recipient. Every
+    /// member needs a positive weight. An empty map restores winner-take-all.
+    /// Weighted shares are sent immediately; reinvest preferences and the
+    /// payout delay do not apply to them.
+    pub fn set_payout_weights(env: Env, weights: Map<Address, u32>) {
         internals::check_not_paused(&env);
-        internals::check_not_frozen(&env);
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
+        internals::note_admin_action(&env);
 
-        let use_timestamp: bool = env
-            .storage()
-            .instance()
-            .get(&DataKey2::UseTimestampSchedule)
-            .unwrap_or(false);
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        if !paid_members.is_empty() {
+            panic_with_error!(&env, Error::CannotChangeMidRound);
+        }
 
-        let deadline: u64 = if use_timestamp {
-            env.storage()
-                .instance()
-                .get(&DataKey::RoundDeadlineTimestamp)
-                .expect("Timestamp deadline not set")
+        if weights.is_empty() {
+            env.storage().instance().remove(&DataKey4::Weights);
         } else {
-            env.storage()
-                .instance()
-                .get(&DataKey::RoundDeadline)
-                .unwrap()
-        };
-        if env.ledger().timestamp() <= deadline {
-            panic_with_error!(&env, Error::DeadlineNotPassed);
+            let members: Vec<Address> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Members)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+            if weights.len() != members.len() {
+                panic_with_error!(&env, ExtError2::InvalidPayoutWeights);
+            }
+            for member in members.iter() {
+                if weights.get(member).unwrap_or(0) == 0 {
+                    panic_with_error!(&env, ExtError2::InvalidPayoutWeights);
+                }
+            }
+            env.storage().instance().set(&DataKey4::Weights, &weights);
         }
-
-        let members: Vec<Address> = env.storage().instance().get(&DataKey::Members).unwrap();
-        let paid_members: Vec<Address> =
-            env.storage().instance().get(&DataKey::PaidMembers).unwrap();
-        let exited_members: Vec<Address> = env
-            .storage()
+        env.storage()
             .instance()
-            .get(&DataKey::ExitedMembers)
-            .unwrap_or(Vec::new(&env));
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
 
-        let skip_requests: Map<(Address, u32), bool> = env
-            .storage()
+    /// Payout weights per member; empty when payouts are winner-take-all.
+    pub fn get_payout_weights(env: Env) -> Map<Address, u32> {
+        env.storage()
             .instance()
-            .get(&DataKey2::SkipRequests)
-            .unwrap_or(Map::new(&env));
+            .get(&DataKey4::Weights)
+            .unwrap_or(Map::new(&env))
+    }
 
-        let current_round: u32 = env
+    /// Admin sets how long (seconds) a completed round's pot is held before
+    /// `release_payout` can send it. 0 pays out immediately.
+    pub fn set_payout_delay(env: Env, delay: u64) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey::CurrentRound)
-            .unwrap();
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
 
-        let mut defaulters = Vec::new(&env);
-        for member in members.iter() {
-            let has_skipped = skip_requests.get((member.clone(), current_round)).unwrap_or(false);
-            if !paid_members.contains(&member) && !exited_members.contains(&member) && !has_skipped {
-                defaulters.push_back(member);
-            }
-        }
+        env.storage().instance().set(&DataKey4::PayoutDelay, &delay);
         env.storage()
             .instance()
-            .set(&DataKey::Defaulters, &defaulters);
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
 
-        let current_round: u32 = env
-            .storage()
-            .instance()
-            .get(&DataKey::CurrentRound)
-            .unwrap();
-        events::emit_closed(&env, current_round, defaulters);
+    /// Seconds a completed round's pot is held before release.
+    pub fn get_payout_delay(env: Env) -> u64 {
         env.storage()
             .instance()
-            .set(&DataKey4::LastRoundDeadline, &deadline);
-
-        internals::reset_round_state(&env, current_round);
+            .get(&DataKey4::PayoutDelay)
+            .unwrap_or(0)
     }
 
-    /// Finalize a round once its deadline has passed.
-    ///
-    /// Unlike `close_round` (which only resets state), this function also:
-    /// - Identifies non-contributors as delinquent and increments their default count
-    /// - Suspends members after 3 consecutive missed rounds
-    /// - Executes the payout with whatever funds have been collected
-    ///
-    /// Admin only. Panics with `DeadlineNotPassed` if called before the deadline.
-    // ── Audit Trail Public Methods ────────────────────────────────────────────
-    pub fn get_cycle_record(env: Env, cycle_number: u32) -> Option<CycleRecord> {
-        audit_trail::get_cycle_record(&env, cycle_number)
+    /// Pots held by the payout delay, oldest first.
+    pub fn get_pending_payouts(env: Env) -> Vec<PendingPayout> {
+        env.storage()
+            .instance()
+            .get(&DataKey4::PendingPayouts)
+            .unwrap_or(Vec::new(&env))
     }
 
-    pub fn set_cycle_retention_window(env: Env, new_window: u32) {
+    /// Admin sets how many consecutive reverted payout transfers pause the
+    /// group (default 3). Must be at least 1.
+    pub fn set_payout_failure_threshold(env: Env, threshold: u32) {
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
-        audit_trail::set_retention_window(&env, new_window);
-    }
+        internals::note_admin_action(&env);
+        if threshold == 0 {
+            panic_with_error!(&env, ExtError::InvalidAmount);
+        }
 
-    pub fn get_cycle_retention_window(env: Env) -> u32 {
-        audit_trail::get_retention_window(&env)
+        env.storage()
+            .instance()
+            .set(&DataKey4::PayoutFailureThreshold, &threshold);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
     }
 
-    pub fn get_member_contribution_history(env: Env, member: Address) -> Vec<ContributionEntry> {
-        audit_trail::get_member_contribution_history(&env, member)
+    /// Consecutive payout transfers that have reverted since the last success.
+    pub fn get_failed_payouts(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey4::FailedPayouts)
+            .unwrap_or(0)
     }
 
-    pub fn finalize_round(env: Env) {
+    /// Send every held pot whose release time has passed to its recipient.
+    /// Callable by anyone.
+    pub fn release_payout(env: Env) {
         internals::check_not_paused(&env);
-        internals::check_not_frozen(&env);
-        let admin: Address = env
+        let pending: Vec<PendingPayout> = env
             .storage()
             .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-        Self::process_pending_penalties(&env);
+            .get(&DataKey4::PendingPayouts)
+            .unwrap_or(Vec::new(&env));
+        if pending.is_empty() {
+            panic_with_error!(&env, ExtError2::NoPendingPayout);
+        }
+        if env.storage().instance().has(&DataKey4::Disputed) {
+            panic_with_error!(&env, ExtError2::PayoutDisputed);
+        }
 
-        let use_timestamp: bool = env
-            .storage()
+        let now = env.ledger().timestamp();
+        let mut released: Vec<PendingPayout> = Vec::new(&env);
+        let mut still_held: Vec<PendingPayout> = Vec::new(&env);
+        for payout in pending.iter() {
+            if payout.release_at <= now {
+                released.push_back(payout);
+            } else {
+                still_held.push_back(payout);
+            }
+        }
+        if released.is_empty() {
+            panic_with_error!(&env, ExtError2::PayoutStillHeld);
+        }
+        env.storage()
             .instance()
-            .get(&DataKey2::UseTimestampSchedule)
-            .unwrap_or(false);
+            .set(&DataKey4::PendingPayouts, &still_held);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 
-        let deadline: u64 = if use_timestamp {
-            env.storage()
-                .instance()
-                .get(&DataKey::RoundDeadlineTimestamp)
-                .expect("Timestamp deadline not set")
-        } else {
-            env.storage()
-                .instance()
-                .get(&DataKey::RoundDeadline)
-                .unwrap()
-        };
-        if env.ledger().timestamp() <= deadline {
-            panic_with_error!(&env, Error::DeadlineNotPassed);
+        for payout in released.iter() {
+            let to = internals::payout_destination(&env, &payout.recipient);
+            for (token_addr, amount) in payout.amounts.iter() {
+                token::Client::new(&env, &token_addr).transfer(
+                    &env.current_contract_address(),
+                    &to,
+                    &amount,
+                );
+                internals::record_fund_flow(
+                    &env,
+                    "payout",
+                    &env.current_contract_address(),
+                    &to,
+                    amount,
+                );
+            }
+            events::emit_payout_released(&env, payout.round, payout.recipient);
         }
+    }
 
-        let members: Vec<Address> = env.storage().instance().get(&DataKey::Members).unwrap();
-        let paid_members: Vec<Address> =
-            env.storage().instance().get(&DataKey::PaidMembers).unwrap();
-        let exited_members: Vec<Address> = env
+    /// Member disputes the oldest held payout, blocking `release_payout`
+    /// until the admin calls `resolve_dispute`.
+    pub fn raise_dispute(env: Env, member: Address) {
+        member.require_auth();
+        internals::check_not_paused(&env);
+        if !Self::is_member(env.clone(), member.clone()) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+        let pending: Vec<PendingPayout> = env
             .storage()
             .instance()
-            .get(&DataKey::ExitedMembers)
+            .get(&DataKey4::PendingPayouts)
             .unwrap_or(Vec::new(&env));
+        let disputed = pending
+            .first()
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NoPendingPayout));
+        if env.storage().instance().has(&DataKey4::Disputed) {
+            panic_with_error!(&env, ExtError2::PayoutDisputed);
+        }
 
-        let current_round: u32 = env
+        env.storage().instance().set(&DataKey4::Disputed, &member);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        events::emit_dispute_raised(&env, member, disputed.round);
+    }
+
+    /// Member who raised the open dispute, if any.
+    pub fn get_dispute(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey4::Disputed)
+    }
+
+    /// Admin settles the open dispute. Upheld: the disputed pot is withheld
+    /// from its recipient and its base-token amount moves to the reserve.
+    /// Rejected: the pot stays queued and `release_payout` is unblocked.
+    pub fn resolve_dispute(env: Env, uphold: bool) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey::CurrentRound)
-            .unwrap_or(0);
-        let penalty_amount: i128 = env
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        if !env.storage().instance().has(&DataKey4::Disputed) {
+            panic_with_error!(&env, ExtError2::NoOpenDispute);
+        }
+        env.storage().instance().remove(&DataKey4::Disputed);
+
+        let mut pending: Vec<PendingPayout> = env
             .storage()
             .instance()
-            .get(&DataKey::PenaltyAmount)
-            .unwrap_or(0);
+            .get(&DataKey4::PendingPayouts)
+            .unwrap_or(Vec::new(&env));
+        let disputed = pending
+            .first()
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NoPendingPayout));
+        if uphold {
+            pending.pop_front();
+            let token_addr: Address = env
+                .storage()
+                .instance()
+                .get(&DataKey::Token)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+            let amount = disputed.amounts.get(token_addr).unwrap_or(0);
+            let reserve_pool: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey4::ReservePool)
+                .unwrap_or(0);
+            let reserve_pool = reserve_pool + amount;
+            env.storage()
+                .instance()
+                .set(&DataKey4::ReservePool, &reserve_pool);
+            env.storage()
+                .instance()
+                .set(&DataKey4::PendingPayouts, &pending);
+            events::emit_reserve_credited(&env, disputed.round, amount, reserve_pool);
+        }
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        events::emit_dispute_resolved(&env, disputed.round, uphold);
+    }
 
-        let mut default_count: Map<Address, u32> = env
+    /// Pay out the current round once every active member has paid. Payouts
+    /// normally run inside the final contribution; this settles a fully paid
+    /// round that did not trigger one (e.g. a member was suspended mid-round).
+    /// Callable by anyone.
+    pub fn settle_round(env: Env) {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
+        internals::require_initialized(&env);
+
+        let group_status: GroupStatus = env
             .storage()
             .instance()
-            .get(&DataKey::DefaultCount)
-            .unwrap_or(Map::new(&env));
-        let mut suspended_members: Vec<Address> = env
+            .get(&DataKey2::GroupStatus)
+            .unwrap_or(GroupStatus::Active);
+        if group_status == GroupStatus::Dissolved {
+            panic_with_error!(&env, ExtError::GroupAlreadyDissolved);
+        }
+        if group_status == GroupStatus::Completed {
+            panic_with_error!(&env, ExtError2::CycleComplete);
+        }
+
+        let members: Vec<Address> = env.storage().persistent().get(&DataKey::Members).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        let exited_members: Vec<Address> = env
             .storage()
             .instance()
-            .get(&DataKey::SuspendedMembers)
+            .get(&DataKey::ExitedMembers)
             .unwrap_or(Vec::new(&env));
-
-        let skip_requests: Map<(Address, u32), bool> = env
+        let suspended_members: Vec<Address> = env
             .storage()
             .instance()
-            .get(&DataKey2::SkipRequests)
-            .unwrap_or(Map::new(&env));
+            .get(&DataKey::SuspendedMembers)
+            .unwrap_or(Vec::new(&env));
+        let admin_suspended = internals::admin_suspended(&env);
 
-        // Identify defaulters (non-contributors, non-exited, non-skippers)
-        let mut defaulters: Vec<Address> = Vec::new(&env);
+        let mut active_count: u32 = 0;
         for member in members.iter() {
-            let has_skipped = skip_requests.get((member.clone(), current_round)).unwrap_or(false);
-            if !paid_members.contains(&member) && !exited_members.contains(&member) && !has_skipped {
-                defaulters.push_back(member.clone());
+            if !exited_members.contains(&member)
+                && !suspended_members.contains(&member)
+                && !admin_suspended.contains(&member)
+            {
+                active_count += 1;
             }
         }
+        if active_count == 0 || paid_members.len() < active_count {
+            panic_with_error!(&env, ExtError2::RoundNotFullyPaid);
+        }
 
+        if !Self::carry_below_min_pot(&env) {
+            internals::complete_round_payout(&env, &paid_members);
+        }
         env.storage()
             .instance()
-            .set(&DataKey::Defaulters, &defaulters);
-
-        events::emit_round_finalized(&env, current_round, defaulters.clone());
-        env.storage()
-            .instance()
-            .set(&DataKey4::LastRoundDeadline, &deadline);
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
 
-        // Execute payout BEFORE applying new suspensions so the recipient selection
-        // uses the pre-round suspension state (newly delinquent members don't affect
-        // this round's payout).
-        internals::complete_round_payout(&env, &paid_members);
+    /// A member who paid takes their contribution back once the deadline has
+    /// passed, instead of waiting for a round that is likely to close short.
+    /// Only possible until the round is closed or paid out, and only for
+    /// contributions paid entirely in the base token. The refund is the
+    /// recorded contribution; any insurance deduction stays in the pool.
+    pub fn request_refund(env: Env, member: Address) {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
+        internals::require_initialized(&env);
+        member.require_auth();
 
-        // Apply default tracking and suspensions after the payout
-        let max_defaults: u32 = env
+        let use_timestamp: bool = env
             .storage()
             .instance()
-            .get(&DataKey::MaxDefaults)
-            .unwrap_or(3);
+            .get(&DataKey2::UseTimestampSchedule)
+            .unwrap_or(false);
+        let deadline: u64 = if use_timestamp {
+            internals::round_state_get(&env, &DataKey::RoundDeadlineTimestamp)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
+        } else {
+            internals::round_state_get(&env, &DataKey::RoundDeadline)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
+        };
+        if internals::clock_now(&env) <= deadline {
+            panic_with_error!(&env, Error::DeadlineNotPassed);
+        }
 
-        // #240: co-signer window config
-        let co_signer_window: u32 = env
+        let mut paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        let idx = paid_members
+            .first_index_of(&member)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::ContributionNotRecorded));
+        let foreign_paid: Vec<Address> = env
             .storage()
             .instance()
-            .get(&DataKey4::CoSignerWindowLedgers)
-            .unwrap_or(0);
-        let co_signers: Map<Address, CoSignerRecord> = env
+            .get(&DataKey5::RoundForeignPaid)
+            .unwrap_or(Vec::new(&env));
+        if foreign_paid.contains(&member) {
+            panic_with_error!(&env, ExtError3::RefundNotInBaseToken);
+        }
+        paid_members.remove(idx);
+        internals::round_state_set(&env, &DataKey::PaidMembers, &paid_members);
+
+        // A refunded member paid on time, so closing the round must not treat
+        // them as a defaulter.
+        let mut refunded: Vec<Address> = env
             .storage()
             .instance()
-            .get(&DataKey4::CoSigners)
-            .unwrap_or(Map::new(&env));
-        let mut window_starts: Map<Address, u32> = env
+            .get(&DataKey5::RoundRefunded)
+            .unwrap_or(Vec::new(&env));
+        refunded.push_back(member.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey5::RoundRefunded, &refunded);
+
+        let mut member_contributions: Map<Address, i128> = env
             .storage()
             .instance()
-            .get(&DataKey3::CoSignerWindowStart)
+            .get(&DataKey::MemberContributions)
             .unwrap_or(Map::new(&env));
+        let amount = member_contributions.get(member.clone()).unwrap_or(0);
+        member_contributions.remove(member.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::MemberContributions, &member_contributions);
 
-        for member in defaulters.iter() {
-            // #240: if member has an active co-signer and window > 0, open grace period
-            // instead of immediately applying the penalty
-            // #399: if member has a pending co-signer, skip window and apply penalty immediately
-            if co_signer_window > 0 {
-                if let Some(record) = co_signers.get(member.clone()) {
-                    if record.status == CoSignerStatus::Active {
-                        // Open window if not already open
-                        if window_starts.get(member.clone()).is_none() {
-                            window_starts.set(member.clone(), env.ledger().sequence());
-                            env.storage()
-                                .instance()
-                                .set(&DataKey3::CoSignerWindowStart, &window_starts);
-                            // Skip penalty this round — co-signer has a window to act
-                            continue;
-                        }
-                        // Window already open — check if expired
-                        let start = window_starts.get(member.clone()).unwrap();
-                        if env.ledger().sequence() < start + co_signer_window {
-                            // Still within window — skip penalty
-                            continue;
-                        }
-                        // Window expired — clear it and fall through to penalty
-                        window_starts.remove(member.clone());
-                        env.storage()
-                            .instance()
-                            .set(&DataKey3::CoSignerWindowStart, &window_starts);
-                        events::emit_co_signer_window_expired(&env, 0, member.clone());
-                    }
-                    // #399: If status is Pending, fall through to apply penalty immediately
-                }
-            }
-
-            let count = default_count.get(member.clone()).unwrap_or(0) + 1;
-            default_count.set(member.clone(), count);
-
-            events::emit_defaulted(&env, member.clone(), current_round, penalty_amount, count);
-            Self::update_credit_score_internal(&env, &member, Symbol::new(&env, "default"));
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 
-            // Suspend after reaching max_defaults consecutive missed rounds
-            if count >= max_defaults && !suspended_members.contains(&member) {
-                suspended_members.push_back(member.clone());
-                events::emit_suspended(&env, member.clone(), count);
-                Self::try_promote_from_waitlist(&env, &member);
-            }
+        if amount > 0 {
+            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+            token::Client::new(&env, &token_addr).transfer(
+                &env.current_contract_address(),
+                &member,
+                &amount,
+            );
+            internals::record_fund_flow(
+                &env,
+                "refund",
+                &env.current_contract_address(),
+                &member,
+                amount,
+            );
         }
+        events::emit_contribution_refunded(&env, member, current_round, amount);
+    }
 
-        env.storage()
+    /// Admin closes the current round once its deadline has passed. With a
+    /// co-admin threshold above 1 it only runs as a `propose_action` proposal
+    /// (action 3).
+    pub fn close_round(env: Env) {
+        let admin: Address = env
+            .storage()
             .instance()
-            .set(&DataKey::DefaultCount, &default_count);
-        env.storage()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        Self::require_no_quorum(&env);
+        Self::close_round_internal(env, None);
+    }
+
+    /// Admin closes up to `max_rounds` (at most `MAX_CLOSE_BATCH`) rounds whose
+    /// deadlines have passed, one after another, exactly as repeated
+    /// `close_round` calls would, recording each round's defaulters. Stops at
+    /// the first round still open. Returns the number of rounds closed.
+    ///
+    /// On a rolling schedule each close restarts the clock, so only a fixed
+    /// schedule can leave more than one round expired.
+    pub fn close_expired(env: Env, max_rounds: u32) -> u32 {
+        let admin: Address = env
+            .storage()
             .instance()
-            .set(&DataKey::SuspendedMembers, &suspended_members);
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        Self::require_no_quorum(&env);
 
-        // ── #356: Penalty-Based Slot Demotion ─────────────────────────────────
-        // After tracking defaults, check if any late contributors have hit the threshold
-        // and demote them to the back of the unfulfilled payout queue.
-        {
-            let late_threshold: u32 = env
-                .storage()
-                .instance()
-                .get(&DataKey3::LateContribThreshold)
-                .unwrap_or(3);
-            let late_counts: Map<Address, u32> = env
+        let limit = max_rounds.min(MAX_CLOSE_BATCH);
+        let mut closed: u32 = 0;
+        while closed < limit {
+            let group_status: GroupStatus = env
                 .storage()
                 .instance()
-                .get(&DataKey3::LateContributionCount)
-                .unwrap_or(Map::new(&env));
-            let mut payout_order: Vec<Address> = env
+                .get(&DataKey2::GroupStatus)
+                .unwrap_or(GroupStatus::Active);
+            if group_status == GroupStatus::Completed || group_status == GroupStatus::Dissolved {
+                break;
+            }
+            let use_timestamp: bool = env
                 .storage()
                 .instance()
-                .get(&DataKey::PayoutOrder)
-                .unwrap_or(Vec::new(&env));
-            let mut order_changed = false;
-
-            for member in members.iter() {
-                let late_count = late_counts.get(member.clone()).unwrap_or(0);
-                if late_count >= late_threshold {
-                    // Find and remove the member from their current position
-                    let mut new_order: Vec<Address> = Vec::new(&env);
-                    let mut found = false;
-                    for addr in payout_order.iter() {
-                        if addr == member && !found {
-                            found = true; // skip (remove from current position)
-                        } else {
-                            new_order.push_back(addr);
-                        }
-                    }
-                    if found {
-                        // Append to end (demoted to last unfulfilled slot)
-                        new_order.push_back(member.clone());
-                        let new_slot = new_order.len() - 1;
-                        payout_order = new_order;
-                        order_changed = true;
-                        events::emit_member_demoted(&env, member.clone(), new_slot, late_count);
-                    }
-                }
+                .get(&DataKey2::UseTimestampSchedule)
+                .unwrap_or(false);
+            let deadline: u64 = if use_timestamp {
+                internals::round_state_get(&env, &DataKey::RoundDeadlineTimestamp)
+                    .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
+            } else {
+                internals::round_state_get(&env, &DataKey::RoundDeadline)
+                    .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
+            };
+            if internals::clock_now(&env) <= deadline {
+                break;
             }
+            Self::close_round_internal(env.clone(), None);
+            closed += 1;
+        }
+        closed
+    }
 
-            if order_changed {
-                env.storage()
+    /// `close_round` for an operator: closes the expired round on schedule
+    /// without giving the operator any other admin power. Like `close_round`,
+    /// it is rejected once a co-admin threshold above 1 is set.
+    pub fn operator_close_round(env: Env, operator: Address) {
+        internals::operator_only(&env, &operator);
+        Self::require_no_quorum(&env);
+        Self::close_round_internal(env, None);
+    }
+
+    /// `close_round`, but the next round's deadline is `next_duration` seconds
+    /// from now instead of the configured round duration (e.g. a short make-up
+    /// round). Later rounds go back to the configured duration.
+    pub fn close_round_with_duration(env: Env, next_duration: u64) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        Self::require_no_quorum(&env);
+        if next_duration == 0 {
+            panic_with_error!(&env, ExtError::InvalidAmount);
+        }
+        Self::close_round_internal(env, Some(next_duration));
+    }
+
+    /// `close_round` that names the round it means to close, so a duplicate
+    /// or delayed submission cannot close the following round by accident.
+    /// Rejects a round that was already closed and any round but the current
+    /// one, and runs the same checks as `close_round`, including the co-admin
+    /// quorum.
+    pub fn close_round_at(env: Env, round: u32) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        Self::require_no_quorum(&env);
+
+        let last_closed: Option<u32> = env.storage().instance().get(&DataKey5::LastClosedRound);
+        if last_closed.is_some_and(|closed| round <= closed) {
+            panic_with_error!(&env, ExtError3::RoundAlreadyClosed);
+        }
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        if round != current_round {
+            panic_with_error!(&env, ExtError2::RoundNotFound);
+        }
+        Self::close_round_internal(env, None);
+    }
+
+    /// Admin: skip the current round without a payout. Every contribution is
+    /// refunded and the round restarts with a fresh deadline; unlike
+    /// `close_round`, the round number and recipient stay the same so nobody
+    /// loses their turn.
+    pub fn skip_round(env: Env) {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
+        internals::require_initialized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        let group_status: GroupStatus = env
+            .storage()
+            .instance()
+            .get(&DataKey2::GroupStatus)
+            .unwrap_or(GroupStatus::Active);
+        if group_status == GroupStatus::Dissolved {
+            panic_with_error!(&env, ExtError::GroupAlreadyDissolved);
+        }
+        if group_status == GroupStatus::Completed {
+            panic_with_error!(&env, ExtError2::CycleComplete);
+        }
+
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        let deadline = Self::refund_round(&env, None);
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        events::emit_round_skipped(&env, current_round, paid_members.len(), deadline);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Body of `close_round`; callers have already authorized the admin.
+    fn close_round_internal(env: Env, next_duration: Option<u64>) {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
+        internals::require_initialized(&env);
+
+        let group_status: GroupStatus = env
+            .storage()
+            .instance()
+            .get(&DataKey2::GroupStatus)
+            .unwrap_or(GroupStatus::Active);
+        if group_status == GroupStatus::Dissolved {
+            panic_with_error!(&env, ExtError::GroupAlreadyDissolved);
+        }
+        if group_status == GroupStatus::Completed {
+            panic_with_error!(&env, ExtError2::CycleComplete);
+        }
+
+        let use_timestamp: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey2::UseTimestampSchedule)
+            .unwrap_or(false);
+
+        let deadline: u64 = if use_timestamp {
+            internals::round_state_get(&env, &DataKey::RoundDeadlineTimestamp)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
+        } else {
+            internals::round_state_get(&env, &DataKey::RoundDeadline)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
+        };
+        if internals::clock_now(&env) <= deadline {
+            panic_with_error!(&env, Error::DeadlineNotPassed);
+        }
+
+        let members: Vec<Address> = env.storage().persistent().get(&DataKey::Members).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let paid_members: Vec<Address> =
+            internals::round_state_get(&env, &DataKey::PaidMembers).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let exited_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ExitedMembers)
+            .unwrap_or(Vec::new(&env));
+
+        // A round in which every active member has already paid is complete;
+        // closing it would discard those contributions instead of paying out.
+        let admin_suspended = internals::admin_suspended(&env);
+        let mut active_count: u32 = 0;
+        for member in members.iter() {
+            if !exited_members.contains(&member) && !admin_suspended.contains(&member) {
+                active_count += 1;
+            }
+        }
+        if active_count > 0 && paid_members.len() >= active_count {
+            panic_with_error!(&env, ExtError2::RoundAlreadyComplete);
+        }
+        if Self::restart_underfilled_round(&env, next_duration) {
+            return;
+        }
+
+        let skip_requests: Map<(Address, u32), bool> = env
+            .storage()
+            .instance()
+            .get(&DataKey2::SkipRequests)
+            .unwrap_or(Map::new(&env));
+
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+
+        let refunded: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey5::RoundRefunded)
+            .unwrap_or(Vec::new(&env));
+
+        let mut defaulters = Vec::new(&env);
+        let mut excused = Vec::new(&env);
+        for member in members.iter() {
+            let has_skipped = skip_requests.get((member.clone(), current_round)).unwrap_or(false);
+            if !paid_members.contains(&member)
+                && !refunded.contains(&member)
+                && !exited_members.contains(&member)
+                && !admin_suspended.contains(&member)
+                && !has_skipped
+            {
+                if internals::consume_excuse(&env, &member, current_round) {
+                    excused.push_back(member);
+                } else {
+                    defaulters.push_back(member);
+                }
+            }
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::Defaulters, &defaulters);
+        internals::reset_on_time_streaks(&env, &defaulters);
+        let ejected = internals::track_consecutive_defaults(&env, &defaulters);
+
+        let member_contributions: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MemberContributions)
+            .unwrap_or(Map::new(&env));
+        let mut pot: i128 = 0;
+        for (_, amount) in member_contributions.iter() {
+            pot += amount;
+        }
+        let summary = RoundSummary {
+            round: current_round,
+            paid: paid_members.len(),
+            defaulted: defaulters.len(),
+            pot,
+        };
+        internals::write_round_record(&env, current_round, None, pot, defaulters.clone());
+
+        // Defaulters and excused members who paid only part of their
+        // contribution get it back; transfers run after the round reset.
+        let mut refunds: Vec<(Address, i128)> = Vec::new(&env);
+        for member in defaulters.iter() {
+            internals::record_debt(&env, &member, internals::member_required_amount(&env, &member));
+        }
+        for member in defaulters.iter().chain(excused.iter()) {
+            let partial = member_contributions.get(member.clone()).unwrap_or(0);
+            if partial > 0 {
+                refunds.push_back((member, partial));
+            }
+        }
+
+        // Slash defaulters' deposits into the reserve. The slash is capped at
+        // the deposit so it never goes negative.
+        let slash_bps: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::SlashBps)
+            .unwrap_or(0);
+        if slash_bps > 0 {
+            let mut deposits: Map<Address, i128> = env
+                .storage()
+                .instance()
+                .get(&DataKey4::MemberDeposits)
+                .unwrap_or(Map::new(&env));
+            let mut reserve_pool: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey4::ReservePool)
+                .unwrap_or(0);
+            for member in defaulters.iter() {
+                let deposit = deposits.get(member.clone()).unwrap_or(0);
+                let slash = ((deposit * slash_bps as i128) / 10_000).min(deposit);
+                if slash > 0 {
+                    deposits.set(member.clone(), deposit - slash);
+                    reserve_pool += slash;
+                    events::emit_deposit_slashed(&env, member, current_round, slash, deposit - slash);
+                }
+            }
+            env.storage()
+                .instance()
+                .set(&DataKey4::MemberDeposits, &deposits);
+            env.storage()
+                .instance()
+                .set(&DataKey4::ReservePool, &reserve_pool);
+        }
+
+        events::emit_closed(&env, current_round, defaulters.clone(), summary);
+        env.storage()
+            .instance()
+            .set(&DataKey2::LastRoundDeadline, &deadline);
+        env.storage()
+            .instance()
+            .set(&DataKey5::LastClosedRound, &current_round);
+
+        internals::advance_round(&env, current_round, next_duration, false);
+        // Advancing clears the list; keep the closed round's defaulters so
+        // they can still be penalised or settled.
+        env.storage()
+            .instance()
+            .set(&DataKey::Defaulters, &defaulters);
+        for member in ejected.iter() {
+            Self::eject_member(&env, &member);
+        }
+
+        if !refunds.is_empty() {
+            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+            let client = token::Client::new(&env, &token_addr);
+            for (member, amount) in refunds.iter() {
+                client.transfer(&env.current_contract_address(), &member, &amount);
+                internals::record_fund_flow(
+                    &env,
+                    "refund",
+                    &env.current_contract_address(),
+                    &member,
+                    amount,
+                );
+                events::emit_partial_refunded(&env, member, current_round, amount);
+            }
+        }
+    }
+
+    /// Finalize a round once its deadline has passed.
+    ///
+    /// Unlike `close_round` (which only resets state), this function also:
+    /// - Identifies non-contributors as delinquent and increments their default count
+    /// - Suspends members after 3 consecutive missed rounds
+    /// - Executes the payout with whatever funds have been collected
+    ///
+    /// Admin only. Panics with `DeadlineNotPassed` if called before the deadline.
+    // ── Audit Trail Public Methods ────────────────────────────────────────────
+    pub fn get_cycle_record(env: Env, cycle_number: u32) -> Option<CycleRecord> {
+        audit_trail::get_cycle_record(&env, cycle_number)
+    }
+
+    pub fn set_cycle_retention_window(env: Env, new_window: u32) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        audit_trail::set_retention_window(&env, new_window);
+    }
+
+    pub fn get_cycle_retention_window(env: Env) -> u32 {
+        audit_trail::get_retention_window(&env)
+    }
+
+    pub fn get_member_contribution_history(env: Env, member: Address) -> Vec<ContributionEntry> {
+        audit_trail::get_member_contribution_history(&env, member)
+    }
+
+    pub fn finalize_round(env: Env) {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        Self::finalize_expired_round(env, 0);
+    }
+
+    /// Permissionless keeper entry point: once the round deadline plus the
+    /// grace period has passed, runs the same defaulter accounting and payout
+    /// as `finalize_round`, then pays `keeper` the configured keeper reward
+    /// out of the fee reserve (capped at the reserve balance). Returns the
+    /// reward paid. Panics with `DeadlineNotPassed` if the round is not expired.
+    pub fn poke(env: Env, keeper: Address) -> i128 {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
+        internals::require_initialized(&env);
+        keeper.require_auth();
+
+        let grace_period_seconds: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey3::GracePeriodSeconds)
+            .unwrap_or(0);
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        Self::finalize_expired_round(env.clone(), grace_period_seconds);
+
+        let keeper_reward: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::KeeperReward)
+            .unwrap_or(0);
+        let reserve_pool: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::ReservePool)
+            .unwrap_or(0);
+        let reward = keeper_reward.min(reserve_pool);
+        if reward > 0 {
+            env.storage()
+                .instance()
+                .set(&DataKey4::ReservePool, &(reserve_pool - reward));
+            let token_addr: Address = env
+                .storage()
+                .instance()
+                .get(&DataKey::Token)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+            token::Client::new(&env, &token_addr).transfer(
+                &env.current_contract_address(),
+                &keeper,
+                &reward,
+            );
+            internals::record_fund_flow(
+                &env,
+                "reward",
+                &env.current_contract_address(),
+                &keeper,
+                reward,
+            );
+        }
+        events::emit_round_poked(&env, keeper, current_round, reward);
+        reward
+    }
+
+    /// Admin grants `operator` the upkeep role: closing rounds and taking
+    /// snapshots, with no control over configuration or funds.
+    pub fn add_operator(env: Env, operator: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        let mut operators = Self::get_operators(env.clone());
+        if !operators.contains(&operator) {
+            operators.push_back(operator);
+            env.storage().instance().set(&DataKey4::Operators, &operators);
+        }
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Admin revokes the upkeep role from `operator`.
+    pub fn remove_operator(env: Env, operator: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        let mut operators = Self::get_operators(env.clone());
+        if let Some(index) = operators.first_index_of(&operator) {
+            operators.remove(index);
+            env.storage().instance().set(&DataKey4::Operators, &operators);
+        }
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    pub fn get_operators(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey4::Operators)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Admin sets the reward paid from the fee reserve to whoever `poke`s an
+    /// expired round.
+    pub fn set_keeper_reward(env: Env, reward: i128) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        if reward < 0 {
+            panic_with_error!(&env, ExtError::InvalidAmount);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey4::KeeperReward, &reward);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Get the keeper reward paid by `poke`.
+    pub fn get_keeper_reward(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey4::KeeperReward)
+            .unwrap_or(0)
+    }
+
+    /// Rounds in which `member` received the pot, oldest first.
+    pub fn get_payout_rounds(env: Env, member: Address) -> Vec<u32> {
+        env.storage()
+            .persistent()
+            .get(&PersistentKey::PayoutRounds(member))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Total of all pots paid out to recipients so far. Pots forfeited to the
+    /// reserve are not counted.
+    pub fn total_distributed(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey4::TotalDistributed)
+            .unwrap_or(0)
+    }
+
+    /// Admin sets a contract to notify via `on_payout` after each payout, or
+    /// clears it with `None`. A failing hook never blocks the payout.
+    pub fn set_payout_hook(env: Env, hook: Option<Address>) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        match hook {
+            Some(hook) => env.storage().instance().set(&DataKey5::PayoutHook, &hook),
+            None => env.storage().instance().remove(&DataKey5::PayoutHook),
+        }
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Admin sets the DEX router used to pay recipients in their preferred
+    /// payout token, or clears it with `None`. `max_slippage_bps` bounds how
+    /// far below the configured exchange rate a swap may fill.
+    pub fn set_swap_router(env: Env, router: Option<Address>, max_slippage_bps: u32) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        if max_slippage_bps > 10_000 {
+            panic_with_error!(&env, ExtError::InvalidAmount);
+        }
+
+        match router {
+            Some(router) => env.storage().instance().set(&DataKey5::SwapRouter, &router),
+            None => env.storage().instance().remove(&DataKey5::SwapRouter),
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey5::SwapSlippageBps, &max_slippage_bps);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Member asks for their payouts in `token` instead of the pool token,
+    /// swapped through the router at payout; `None` goes back to the pool
+    /// token. `token` needs an exchange rate, which bounds the swap. If the
+    /// swap fails the payout is made in the pool token.
+    pub fn set_payout_token(env: Env, member: Address, token: Option<Address>) {
+        member.require_auth();
+        if !Self::is_member(env.clone(), member.clone()) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+
+        let key = PersistentKey::PayoutToken(member.clone());
+        match token {
+            Some(token) => {
+                let rates: Map<Address, i128> = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::ExchangeRates)
+                    .unwrap_or(Map::new(&env));
+                if rates.get(token.clone()).unwrap_or(0) <= 0 {
+                    panic_with_error!(&env, Error::InvalidExchangeRate);
+                }
+                env.storage().persistent().set(&key, &token);
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+    }
+
+    pub fn get_payout_token(env: Env, member: Address) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&PersistentKey::PayoutToken(member))
+    }
+
+    /// Admin pegs the contribution to `pegged_value` (7 decimals) of an external
+    /// unit priced by `oracle`, or clears the peg with `None`. While pegged,
+    /// the flat contribution amount is `pegged_value / price` in base token,
+    /// re-read from the oracle on every contribution. Prices older than
+    /// `max_age` seconds are rejected; `max_age` must be non-zero. Like the
+    /// contribution amount, the peg only changes before anyone pays into the
+    /// round.
+    pub fn set_price_peg(env: Env, oracle: Option<Address>, pegged_value: i128, max_age: u64) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        if !paid_members.is_empty() || internals::member_contributions_total(&env) > 0 {
+            panic_with_error!(&env, Error::CannotChangeMidRound);
+        }
+
+        match oracle {
+            Some(oracle) => {
+                if pegged_value <= 0 {
+                    panic_with_error!(&env, Error::AmountMustBePositive);
+                }
+                if max_age == 0 {
+                    panic_with_error!(&env, ExtError::InvalidAmount);
+                }
+                env.storage().instance().set(&DataKey5::Oracle, &oracle);
+                env.storage()
+                    .instance()
+                    .set(&DataKey5::PeggedValue, &pegged_value);
+                env.storage()
+                    .instance()
+                    .set(&DataKey5::OracleMaxAge, &max_age);
+                internals::refresh_peg_price(&env);
+            }
+            None => {
+                env.storage().instance().remove(&DataKey5::Oracle);
+                env.storage().instance().remove(&DataKey5::PeggedValue);
+                env.storage().instance().remove(&DataKey5::OracleMaxAge);
+                env.storage().instance().remove(&DataKey5::PegPrice);
+            }
+        }
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Admin sets how many missed rounds each member may have excused before
+    /// a miss counts as a default. Members who already used an excuse keep
+    /// their remaining count.
+    pub fn set_excuse_allowance(env: Env, allowance: u32) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        env.storage()
+            .instance()
+            .set(&DataKey5::ExcuseAllowance, &allowance);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Excused absences `member` has left.
+    pub fn excuses_remaining(env: Env, member: Address) -> u32 {
+        internals::excuses_remaining(&env, &member)
+    }
+
+    /// Member opts in to waiting when their round expires under-collected:
+    /// instead of a short pot, the round is re-run once for them with what was
+    /// collected carried into it.
+    pub fn defer_if_short(env: Env, member: Address, enabled: bool) {
+        internals::check_not_paused(&env);
+        member.require_auth();
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if !members.contains(&member) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+
+        let key = PersistentKey::DeferIfShort(member);
+        if enabled {
+            env.storage().persistent().set(&key, &true);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    /// Whether `member` has opted to defer a short payout.
+    pub fn get_defer_if_short(env: Env, member: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&PersistentKey::DeferIfShort(member))
+            .unwrap_or(false)
+    }
+
+    /// Admin chooses whether a round that expires under-subscribed is re-run
+    /// for the same recipient with its pot carried forward, instead of paying
+    /// out what was collected.
+    pub fn set_carry_over(env: Env, enabled: bool) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        env.storage()
+            .instance()
+            .set(&DataKey5::CarryOverEnabled, &enabled);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Admin sets the smallest accumulated pot that may be paid out. A round
+    /// that completes below it is carried over and re-run for the same
+    /// recipient, whether or not `set_carry_over` is on. 0 disables it.
+    pub fn set_min_payout_pot(env: Env, min_pot: i128) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        if min_pot < 0 {
+            panic_with_error!(&env, ExtError::InvalidAmount);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey5::MinPayoutPot, &min_pot);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    pub fn get_min_payout_pot(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey5::MinPayoutPot)
+            .unwrap_or(0)
+    }
+
+    /// Pot carried forward from under-subscribed rounds, paid out with the
+    /// next completed round.
+    pub fn get_carry_over(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey5::CarryOver)
+            .unwrap_or(0)
+    }
+
+    /// Admin sets the share of active members, in basis points, who must have
+    /// paid for `close_round` or `finalize_round` to go ahead. Below it the
+    /// round is refunded and re-run. 0 disables the check.
+    pub fn set_min_participation_bps(env: Env, min_bps: u32) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        if min_bps > 10_000 {
+            panic_with_error!(&env, ExtError::InvalidAmount);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey4::MinParticipationBps, &min_bps);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Get the minimum participation in basis points (default: 0, disabled).
+    pub fn get_min_participation_bps(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey4::MinParticipationBps)
+            .unwrap_or(0)
+    }
+
+    /// Admin voids the current round and re-runs it for the same recipient,
+    /// e.g. after a payout failed on a frozen token. Every contribution is
+    /// refunded and the round gets a fresh deadline; the round number and
+    /// payout pointer do not move. Returns the new deadline.
+    pub fn retry_round(env: Env) -> u64 {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        let group_status: GroupStatus = env
+            .storage()
+            .instance()
+            .get(&DataKey2::GroupStatus)
+            .unwrap_or(GroupStatus::Active);
+        if group_status == GroupStatus::Dissolved {
+            panic_with_error!(&env, ExtError::GroupAlreadyDissolved);
+        }
+        if group_status == GroupStatus::Completed {
+            panic_with_error!(&env, ExtError2::CycleComplete);
+        }
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::RoundSettled(current_round))
+        {
+            panic_with_error!(&env, ExtError2::RoundAlreadySettled);
+        }
+
+        let deadline = Self::refund_round(&env, None);
+        let retries: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey5::RetryCount)
+            .unwrap_or(0)
+            + 1;
+        env.storage().instance().set(&DataKey5::RetryCount, &retries);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        events::emit_round_retried(&env, current_round, retries, deadline);
+        deadline
+    }
+
+    /// Number of times `retry_round` has been used.
+    pub fn get_retry_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey5::RetryCount)
+            .unwrap_or(0)
+    }
+
+    /// When fewer active members than `MinParticipationBps` have paid, refunds
+    /// every contribution and restarts the same round with a fresh deadline.
+    /// Returns whether the round was restarted.
+    fn restart_underfilled_round(env: &Env, next_duration: Option<u64>) -> bool {
+        let min_bps: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::MinParticipationBps)
+            .unwrap_or(0);
+        if min_bps == 0 {
+            return false;
+        }
+
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or(Vec::new(env));
+        let paid_members: Vec<Address> = internals::round_state_get(env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(env));
+        let exited_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ExitedMembers)
+            .unwrap_or(Vec::new(env));
+        let admin_suspended = internals::admin_suspended(env);
+        let mut active_count: u32 = 0;
+        for member in members.iter() {
+            if !exited_members.contains(&member) && !admin_suspended.contains(&member) {
+                active_count += 1;
+            }
+        }
+        if active_count == 0
+            || (paid_members.len() as u64) * 10_000 / (active_count as u64) >= min_bps as u64
+        {
+            return false;
+        }
+
+        let deadline = Self::refund_round(env, next_duration);
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        events::emit_round_restarted(env, current_round, paid_members.len(), deadline);
+        true
+    }
+
+    /// Refunds every contribution of the current round and gives it a fresh
+    /// deadline, keeping the round number and recipient. Returns the new
+    /// deadline.
+    fn refund_round(env: &Env, next_duration: Option<u64>) -> u64 {
+        let member_contributions: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MemberContributions)
+            .unwrap_or(Map::new(env));
+        let deadline = Self::rerun_round(env, next_duration);
+
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        let token_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
+        let client = token::Client::new(env, &token_addr);
+        for (member, amount) in member_contributions.iter() {
+            if amount > 0 {
+                client.transfer(&env.current_contract_address(), &member, &amount);
+                internals::record_fund_flow(
+                    env,
+                    "refund",
+                    &env.current_contract_address(),
+                    &member,
+                    amount,
+                );
+                events::emit_contribution_refunded(env, member, current_round, amount);
+            }
+        }
+        Self::refund_boosts(env, &internals::take_boosts(env));
+        deadline
+    }
+
+    /// Returns each donor's boost; `boosts` comes from `internals::take_boosts`.
+    fn refund_boosts(env: &Env, boosts: &Map<Address, i128>) {
+        if boosts.is_empty() {
+            return;
+        }
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        let token_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
+        let client = token::Client::new(env, &token_addr);
+        for (donor, amount) in boosts.iter() {
+            if amount > 0 {
+                client.transfer(&env.current_contract_address(), &donor, &amount);
+                internals::record_fund_flow(
+                    env,
+                    "refund",
+                    &env.current_contract_address(),
+                    &donor,
+                    amount,
+                );
+                events::emit_boost_refunded(env, donor, current_round, amount);
+            }
+        }
+    }
+
+    /// Clears the current round's payments and gives it a fresh deadline,
+    /// keeping the round number and recipient. Returns the new deadline.
+    fn rerun_round(env: &Env, next_duration: Option<u64>) -> u64 {
+        internals::round_state_set(env, &DataKey::PaidMembers, &Vec::<Address>::new(env));
+        env.storage().instance().remove(&DataKey5::RoundRefunded);
+        env.storage().instance().remove(&DataKey5::RoundForeignPaid);
+        env.storage()
+            .instance()
+            .set(&DataKey::MemberContributions, &Map::<Address, i128>::new(env));
+
+        let use_timestamp: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey2::UseTimestampSchedule)
+            .unwrap_or(false);
+        let duration: u64 = next_duration.unwrap_or_else(|| {
+            if use_timestamp {
+                env.storage()
+                    .instance()
+                    .get(&DataKey2::RoundDurationSeconds)
+                    .unwrap_or(0)
+            } else {
+                env.storage()
                     .instance()
+                    .get(&DataKey::RoundDuration)
+                    .unwrap_or(0)
+            }
+        });
+        let deadline = env.ledger().timestamp() + duration;
+        env.storage()
+            .instance()
+            .set(&DataKey4::RoundStartedAt, &env.ledger().timestamp());
+        internals::round_state_set(env, &DataKey::RoundDeadline, &deadline);
+        if use_timestamp {
+            internals::round_state_set(env, &DataKey::RoundDeadlineTimestamp, &deadline);
+        }
+        deadline
+    }
+
+    /// With carry-over enabled, a round that not every active member paid is
+    /// re-run for the same recipient instead of paying out. What was collected
+    /// moves to `CarryOver` and joins the pot of the next completed round.
+    /// Returns whether the round was carried over.
+    fn carry_over_underfilled_round(env: &Env) -> bool {
+        let enabled: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey5::CarryOverEnabled)
+            .unwrap_or(false);
+        if !enabled {
+            return false;
+        }
+
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or(Vec::new(env));
+        let paid_members: Vec<Address> = internals::round_state_get(env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(env));
+        let exited_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ExitedMembers)
+            .unwrap_or(Vec::new(env));
+        let admin_suspended = internals::admin_suspended(env);
+        let mut active_count: u32 = 0;
+        for member in members.iter() {
+            if !exited_members.contains(&member) && !admin_suspended.contains(&member) {
+                active_count += 1;
+            }
+        }
+        if paid_members.len() >= active_count {
+            return false;
+        }
+
+        let (collected, carried) = Self::carry_pot_forward(env);
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        events::emit_pot_carried_over(env, current_round, collected, carried);
+        true
+    }
+
+    /// Moves the current round's contributions into `CarryOver` and re-runs
+    /// the round. Returns what was collected and the new carried total.
+    fn carry_pot_forward(env: &Env) -> (i128, i128) {
+        let collected = internals::member_contributions_total(env);
+        let carried: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey5::CarryOver)
+            .unwrap_or(0);
+        let carried = carried
+            .checked_add(collected)
+            .unwrap_or_else(|| panic_with_error!(env, ExtError::InvalidAmount));
+        env.storage().instance().set(&DataKey5::CarryOver, &carried);
+
+        // Keep who paid what, so a dissolve can hand the carried pot back.
+        let member_contributions: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MemberContributions)
+            .unwrap_or(Map::new(env));
+        let mut shares: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey5::CarryOverShares)
+            .unwrap_or(Map::new(env));
+        for (member, amount) in member_contributions.iter() {
+            if amount > 0 {
+                shares.set(member.clone(), shares.get(member).unwrap_or(0) + amount);
+            }
+        }
+        env.storage().instance().set(&DataKey5::CarryOverShares, &shares);
+        Self::rerun_round(env, None);
+        (collected, carried)
+    }
+
+    /// Below `MinPayoutPot`, a round that would otherwise pay out is re-run
+    /// for the same recipient with its pot carried forward, so the pot keeps
+    /// accumulating until it reaches the threshold. Returns whether it carried.
+    fn carry_below_min_pot(env: &Env) -> bool {
+        if !internals::below_min_payout_pot(env) {
+            return false;
+        }
+        let (collected, carried) = Self::carry_pot_forward(env);
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        events::emit_pot_carried_over(env, current_round, collected, carried);
+        true
+    }
+
+    /// When the scheduled recipient opted into `defer_if_short` and not every
+    /// active member paid, re-runs the round once for them with the collected
+    /// pot carried into it. A round is deferred at most once, so a second
+    /// shortfall pays out what was collected. Returns whether it deferred.
+    fn defer_short_payout(env: &Env) -> bool {
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        let recipient = internals::scheduled_recipient(env);
+        let opted: bool = env
+            .storage()
+            .persistent()
+            .get(&PersistentKey::DeferIfShort(recipient.clone()))
+            .unwrap_or(false);
+        let already_deferred = env
+            .storage()
+            .instance()
+            .get::<_, u32>(&DataKey5::DeferredRound)
+            == Some(current_round);
+        if !opted || already_deferred {
+            return false;
+        }
+
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or(Vec::new(env));
+        let paid_members: Vec<Address> = internals::round_state_get(env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(env));
+        let exited_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ExitedMembers)
+            .unwrap_or(Vec::new(env));
+        let admin_suspended = internals::admin_suspended(env);
+        let mut active_count: u32 = 0;
+        for member in members.iter() {
+            if !exited_members.contains(&member) && !admin_suspended.contains(&member) {
+                active_count += 1;
+            }
+        }
+        if paid_members.len() >= active_count {
+            return false;
+        }
+
+        let (collected, carried) = Self::carry_pot_forward(env);
+        env.storage()
+            .instance()
+            .set(&DataKey5::DeferredRound, &current_round);
+        events::emit_payout_deferred(env, recipient, current_round, collected, carried);
+        true
+    }
+
+    /// Defaulter accounting and payout for a round whose deadline plus `grace`
+    /// seconds has passed. Shared by `finalize_round` and `poke`.
+    fn finalize_expired_round(env: Env, grace: u64) {
+        let group_status: GroupStatus = env
+            .storage()
+            .instance()
+            .get(&DataKey2::GroupStatus)
+            .unwrap_or(GroupStatus::Active);
+        if group_status == GroupStatus::Completed {
+            panic_with_error!(&env, ExtError2::CycleComplete);
+        }
+        Self::process_pending_penalties(&env);
+
+        let use_timestamp: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey2::UseTimestampSchedule)
+            .unwrap_or(false);
+
+        let deadline: u64 = if use_timestamp {
+            internals::round_state_get(&env, &DataKey::RoundDeadlineTimestamp)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
+        } else {
+            internals::round_state_get(&env, &DataKey::RoundDeadline)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
+        };
+        if internals::clock_now(&env) <= deadline.saturating_add(grace) {
+            panic_with_error!(&env, Error::DeadlineNotPassed);
+        }
+        if Self::restart_underfilled_round(&env, None) {
+            return;
+        }
+        if Self::carry_over_underfilled_round(&env) {
+            return;
+        }
+        if Self::defer_short_payout(&env) {
+            return;
+        }
+        if Self::carry_below_min_pot(&env) {
+            return;
+        }
+
+        let members: Vec<Address> = env.storage().persistent().get(&DataKey::Members).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let paid_members: Vec<Address> =
+            internals::round_state_get(&env, &DataKey::PaidMembers).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let exited_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ExitedMembers)
+            .unwrap_or(Vec::new(&env));
+
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        let penalty_amount: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::PenaltyAmount)
+            .unwrap_or(0);
+
+        let mut default_count: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::DefaultCount)
+            .unwrap_or(Map::new(&env));
+        let mut suspended_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::SuspendedMembers)
+            .unwrap_or(Vec::new(&env));
+
+        let skip_requests: Map<(Address, u32), bool> = env
+            .storage()
+            .instance()
+            .get(&DataKey2::SkipRequests)
+            .unwrap_or(Map::new(&env));
+
+        // Identify defaulters (non-contributors, non-exited, non-skippers,
+        // not excused)
+        let admin_suspended = internals::admin_suspended(&env);
+        let refunded: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey5::RoundRefunded)
+            .unwrap_or(Vec::new(&env));
+        let mut defaulters: Vec<Address> = Vec::new(&env);
+        for member in members.iter() {
+            let has_skipped = skip_requests.get((member.clone(), current_round)).unwrap_or(false);
+            if !paid_members.contains(&member)
+                && !refunded.contains(&member)
+                && !exited_members.contains(&member)
+                && !admin_suspended.contains(&member)
+                && !has_skipped
+                && !internals::consume_excuse(&env, &member, current_round)
+            {
+                defaulters.push_back(member.clone());
+            }
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Defaulters, &defaulters);
+        internals::reset_on_time_streaks(&env, &defaulters);
+
+        // Partial payments stay in the pot, so only the shortfall is owed.
+        let member_contributions: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MemberContributions)
+            .unwrap_or(Map::new(&env));
+        for member in defaulters.iter() {
+            let shortfall = internals::member_required_amount(&env, &member)
+                - member_contributions.get(member.clone()).unwrap_or(0);
+            internals::record_debt(&env, &member, shortfall);
+        }
+
+        events::emit_round_finalized(&env, current_round, defaulters.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey2::LastRoundDeadline, &deadline);
+
+        // Execute payout BEFORE applying new suspensions so the recipient selection
+        // uses the pre-round suspension state (newly delinquent members don't affect
+        // this round's payout).
+        internals::complete_round_payout(&env, &paid_members);
+        // The payout advances the round and clears the list; keep the
+        // finalized round's defaulters so they can settle or be penalised.
+        env.storage()
+            .instance()
+            .set(&DataKey::Defaulters, &defaulters);
+
+        // Apply default tracking and suspensions after the payout
+        let max_defaults: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MaxDefaults)
+            .unwrap_or(3);
+
+        // #240: co-signer window config
+        let co_signer_window: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey2::CoSignerWindowLedgers)
+            .unwrap_or(0);
+        let co_signers: Map<Address, CoSignerRecord> = env
+            .storage()
+            .instance()
+            .get(&DataKey2::CoSigners)
+            .unwrap_or(Map::new(&env));
+        let mut window_starts: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey3::CoSignerWindowStart)
+            .unwrap_or(Map::new(&env));
+
+        for member in defaulters.iter() {
+            // #240: if member has an active co-signer and window > 0, open grace period
+            // instead of immediately applying the penalty
+            // #399: if member has a pending co-signer, skip window and apply penalty immediately
+            if co_signer_window > 0 {
+                if let Some(record) = co_signers.get(member.clone()) {
+                    if record.status == CoSignerStatus::Active {
+                        // Open window if not already open
+                        if window_starts.get(member.clone()).is_none() {
+                            window_starts.set(member.clone(), env.ledger().sequence());
+                            env.storage()
+                                .instance()
+                                .set(&DataKey3::CoSignerWindowStart, &window_starts);
+                            // Skip penalty this round — co-signer has a window to act
+                            continue;
+                        }
+                        // Window already open — check if expired
+                        let start = window_starts.get(member.clone()).unwrap();
+                        if env.ledger().sequence() < start + co_signer_window {
+                            // Still within window — skip penalty
+                            continue;
+                        }
+                        // Window expired — clear it and fall through to penalty
+                        window_starts.remove(member.clone());
+                        env.storage()
+                            .instance()
+                            .set(&DataKey3::CoSignerWindowStart, &window_starts);
+                        events::emit_co_signer_window_expired(&env, 0, member.clone());
+                    }
+                    // #399: If status is Pending, fall through to apply penalty immediately
+                }
+            }
+
+            let count = default_count.get(member.clone()).unwrap_or(0) + 1;
+            default_count.set(member.clone(), count);
+
+            events::emit_defaulted(&env, member.clone(), current_round, penalty_amount, count);
+            Self::update_credit_score_internal(&env, &member, Symbol::new(&env, "default"));
+
+            // Suspend after reaching max_defaults consecutive missed rounds
+            if count >= max_defaults && !suspended_members.contains(&member) {
+                suspended_members.push_back(member.clone());
+                events::emit_suspended(&env, member.clone(), count);
+                Self::try_promote_from_waitlist(&env, &member);
+            }
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::DefaultCount, &default_count);
+        env.storage()
+            .instance()
+            .set(&DataKey::SuspendedMembers, &suspended_members);
+
+        // ── #356: Penalty-Based Slot Demotion ─────────────────────────────────
+        // After tracking defaults, check if any late contributors have hit the threshold
+        // and demote them to the back of the unfulfilled payout queue.
+        {
+            let late_threshold: u32 = env
+                .storage()
+                .instance()
+                .get(&DataKey3::LateContribThreshold)
+                .unwrap_or(3);
+            let late_counts: Map<Address, u32> = env
+                .storage()
+                .instance()
+                .get(&DataKey3::LateContributionCount)
+                .unwrap_or(Map::new(&env));
+            let mut payout_order: Vec<Address> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::PayoutOrder)
+                .unwrap_or(Vec::new(&env));
+            let mut order_changed = false;
+
+            for member in members.iter() {
+                let late_count = late_counts.get(member.clone()).unwrap_or(0);
+                if late_count >= late_threshold {
+                    // Find and remove the member from their current position
+                    let mut new_order: Vec<Address> = Vec::new(&env);
+                    let mut found = false;
+                    for addr in payout_order.iter() {
+                        if addr == member && !found {
+                            found = true; // skip (remove from current position)
+                        } else {
+                            new_order.push_back(addr);
+                        }
+                    }
+                    if found {
+                        // Append to end (demoted to last unfulfilled slot)
+                        new_order.push_back(member.clone());
+                        let new_slot = new_order.len() - 1;
+                        payout_order = new_order;
+                        order_changed = true;
+                        events::emit_member_demoted(&env, member.clone(), new_slot, late_count);
+                    }
+                }
+            }
+
+            if order_changed {
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::PayoutOrder, &payout_order);
+            }
+        }
+
+        // ── #224: Cycle completion bonus ──────────────────────────────────────
+        // A cycle ends when (current_round + 1) is a multiple of payout_order.len().
+        let payout_order: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PayoutOrder)
+            .unwrap_or(Vec::new(&env));
+        let cycle_len = payout_order.len() as u32;
+        let cycle_completed = cycle_len > 0 && (current_round + 1) % cycle_len == 0;
+        if cycle_completed {
+            // Credit score: every non-exited member who completed the cycle
+            for member in members.iter() {
+                if !exited_members.contains(&member) {
+                    Self::update_credit_score_internal(&env, &member, Symbol::new(&env, "completed"));
+                }
+            }
+        }
+        if cycle_completed {
+            let bonus_amount: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey4::CycleBonusAmount)
+                .unwrap_or(0);
+            if bonus_amount > 0 {
+                let cycle_number = (current_round + 1) / cycle_len;
+                let cycle_start = (cycle_number - 1) * cycle_len;
+                let mut qualifying: Vec<Address> = Vec::new(&env);
+                for member in members.iter() {
+                    if exited_members.contains(&member) { continue; }
+                    let defaults = default_count.get(member.clone()).unwrap_or(0);
+                    let mut had_skip = false;
+                    for r in cycle_start..=(current_round) {
+                        if skip_requests.get((member.clone(), r)).unwrap_or(false) {
+                            had_skip = true;
+                            break;
+                        }
+                    }
+                    if defaults == 0 && !had_skip {
+                        qualifying.push_back(member);
+                    }
+                }
+                let q_count = qualifying.len() as i128;
+                if q_count > 0 {
+                    let total_needed = bonus_amount * q_count;
+                    let mut reward_pool: i128 = env
+                        .storage()
+                        .instance()
+                        .get(&DataKey::RewardPool)
+                        .unwrap_or(0);
+                    let actual_bonus = if reward_pool >= total_needed {
+                        bonus_amount
+                    } else if reward_pool > 0 {
+                        let prorated = reward_pool / q_count;
+                        let shortfall = total_needed - reward_pool;
+                        events::emit_cycle_bonus_prorated(&env, cycle_number, shortfall);
+                        prorated
+                    } else {
+                        0
+                    };
+                    if actual_bonus > 0 {
+                        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+                        let token_client = token::Client::new(&env, &token_addr);
+                        for member in qualifying.iter() {
+                            token_client.transfer(
+                                &env.current_contract_address(),
+                                &member,
+                                &actual_bonus,
+                            );
+                            internals::record_fund_flow(
+                                &env,
+                                "reward",
+                                &env.current_contract_address(),
+                                &member,
+                                actual_bonus,
+                            );
+                            reward_pool -= actual_bonus;
+                            events::emit_cycle_bonus_paid(&env, member, actual_bonus, cycle_number);
+                        }
+                        env.storage().instance().set(&DataKey::RewardPool, &reward_pool);
+                    }
+                }
+            }
+        }
+
+        // Mint NFT-style contribution receipts for every member who paid this round.
+        {
+            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+            let mut counter: u32 = env
+                .storage()
+                .instance()
+                .get(&DataKey3::ContributionReceiptCounter)
+                .unwrap_or(0);
+            let member_contributions: Map<Address, i128> = env
+                .storage()
+                .instance()
+                .get(&DataKey::MemberContributions)
+                .unwrap_or(Map::new(&env));
+            let now_ts = env.ledger().timestamp();
+
+            for member in internals::canonical_paid_order(&env, &paid_members).iter() {
+                let amount_contributed = member_contributions.get(member.clone()).unwrap_or(0);
+
+                // Compute a deterministic receipt hash from (counter, member, round, amount).
+                let mut preimage = Bytes::new(&env);
+                preimage.extend_from_array(&counter.to_be_bytes());
+                preimage.extend_from_array(&current_round.to_be_bytes());
+                let member_xdr = member.clone().to_xdr(&env);
+                preimage.append(&member_xdr);
+                let hash: BytesN<32> = env.crypto().sha256(&preimage).into();
+
+                let receipt = ContributionReceipt {
+                    receipt_id: counter,
+                    member: member.clone(),
+                    round: current_round,
+                    amount_contributed,
+                    token: token_addr.clone(),
+                    minted_at: now_ts,
+                    receipt_hash: hash.clone(),
+                };
+
+                env.storage()
+                    .persistent()
+                    .set(&DataKey3::ContributionReceipt(counter), &receipt);
+                env.storage().persistent().extend_ttl(
+                    &DataKey3::ContributionReceipt(counter),
+                    PERSISTENT_LIFETIME_THRESHOLD,
+                    PERSISTENT_BUMP_AMOUNT,
+                );
+
+                // Append to member's receipt index.
+                let mut ids: Vec<u32> = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey3::MemberReceiptIds(member.clone()))
+                    .unwrap_or(Vec::new(&env));
+                ids.push_back(counter);
+                env.storage()
+                    .persistent()
+                    .set(&DataKey3::MemberReceiptIds(member.clone()), &ids);
+                env.storage().persistent().extend_ttl(
+                    &DataKey3::MemberReceiptIds(member.clone()),
+                    PERSISTENT_LIFETIME_THRESHOLD,
+                    PERSISTENT_BUMP_AMOUNT,
+                );
+
+                events::emit_contribution_receipt_minted(
+                    &env,
+                    counter,
+                    member.clone(),
+                    current_round,
+                    amount_contributed,
+                    hash,
+                );
+
+                counter += 1;
+            }
+            env.storage()
+                .instance()
+                .set(&DataKey3::ContributionReceiptCounter, &counter);
+        }
+
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    // ─── Early Payout Claim ──────────────────────────────────────────────────
+
+    /// Lets the current round's scheduled recipient take whatever has been
+    /// contributed so far instead of waiting for every member to pay. The
+    /// forgone shortfall is recorded under `PersistentKey::ClaimLog` and the
+    /// round advances immediately. Only the collected pot is paid: insurance
+    /// and the reserve do not top it up. Members who have not paid by then are
+    /// recorded as the round's defaulters and owe their shortfall. Rejects a
+    /// claim while nothing has been contributed. Returns the net amount paid
+    /// out to the recipient.
+    pub fn claim_early(env: Env, recipient: Address) -> i128 {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
+        recipient.require_auth();
+
+        if recipient != internals::scheduled_recipient(&env) {
+            panic_with_error!(&env, ExtError2::NotScheduledRecipient);
+        }
+
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        let claim_key = PersistentKey::ClaimLog(current_round);
+        if env.storage().persistent().has(&claim_key) {
+            panic_with_error!(&env, ExtError2::EarlyClaimAlreadyMade);
+        }
+
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let suspended_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::SuspendedMembers)
+            .unwrap_or(Vec::new(&env));
+        let exited_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ExitedMembers)
+            .unwrap_or(Vec::new(&env));
+        // Members the payout would not wait on owe nothing to the pot.
+        let admin_suspended = internals::admin_suspended(&env);
+        let mut expected_pot: i128 = 0;
+        for member in members.iter() {
+            if suspended_members.contains(&member)
+                || exited_members.contains(&member)
+                || admin_suspended.contains(&member)
+            {
+                continue;
+            }
+            expected_pot += internals::member_required_amount(&env, &member);
+        }
+
+        let member_contributions: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MemberContributions)
+            .unwrap_or(Map::new(&env));
+        let mut contributed: i128 = 0;
+        for (_, amount) in member_contributions.iter() {
+            contributed += amount;
+        }
+        let forgone = (expected_pot - contributed).max(0);
+        if contributed == 0 {
+            panic_with_error!(&env, Error::AmountMustBePositive);
+        }
+        if internals::below_min_payout_pot(&env) {
+            panic_with_error!(&env, ExtError3::PotBelowMinimum);
+        }
+
+        // The round ends now, so whoever has not paid yet defaulted on it,
+        // exactly as if it had been finalized at its deadline.
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        let skip_requests: Map<(Address, u32), bool> = env
+            .storage()
+            .instance()
+            .get(&DataKey2::SkipRequests)
+            .unwrap_or(Map::new(&env));
+        let refunded: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey5::RoundRefunded)
+            .unwrap_or(Vec::new(&env));
+        let mut defaulters: Vec<Address> = Vec::new(&env);
+        for member in members.iter() {
+            let has_skipped = skip_requests.get((member.clone(), current_round)).unwrap_or(false);
+            if !paid_members.contains(&member)
+                && !refunded.contains(&member)
+                && !exited_members.contains(&member)
+                && !admin_suspended.contains(&member)
+                && !has_skipped
+                && !internals::consume_excuse(&env, &member, current_round)
+            {
+                defaulters.push_back(member);
+            }
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::Defaulters, &defaulters);
+        internals::reset_on_time_streaks(&env, &defaulters);
+        for member in defaulters.iter() {
+            let shortfall = internals::member_required_amount(&env, &member)
+                - member_contributions.get(member.clone()).unwrap_or(0);
+            internals::record_debt(&env, &member, shortfall);
+        }
+
+        // What is claimed is the net payout, after fees, boosts, late fees
+        // and any carried-over pot, not the raw contributions.
+        let claimed = internals::settle_round_payout(&env, &paid_members, false);
+        // Settling advances the round and clears the list; keep this round's
+        // defaulters so they can settle or be penalised.
+        env.storage()
+            .instance()
+            .set(&DataKey::Defaulters, &defaulters);
+
+        env.storage().persistent().set(
+            &claim_key,
+            &EarlyClaim {
+                recipient: recipient.clone(),
+                claimed,
+                forgone,
+            },
+        );
+        env.storage()
+            .persistent()
+            .extend_ttl(&claim_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        events::emit_early_payout_claimed(&env, recipient, current_round, claimed, forgone);
+
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        claimed
+    }
+
+    /// Returns the early claim recorded for `round`, if any.
+    pub fn get_early_claim(env: Env, round: u32) -> Option<EarlyClaim> {
+        env.storage()
+            .persistent()
+            .get(&PersistentKey::ClaimLog(round))
+    }
+
+    // ─── Auto-Contribute ─────────────────────────────────────────────────────
+
+    /// Member opts in to having their contribution pulled automatically for the
+    /// next `rounds` rounds. Approves the contract to spend enough base token to
+    /// cover them at the amount due now, insurance cut included, replacing any
+    /// allowance `member` already granted; `trigger_auto` then draws each
+    /// round's contribution. If the required amount changes later, call this
+    /// again to re-approve, or the pull fails once the allowance runs out.
+    pub fn set_auto_contribute(env: Env, member: Address, rounds: u32) {
+        internals::check_not_paused(&env);
+        member.require_auth();
+        if !Self::is_member(env.clone(), member.clone()) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let required = internals::member_required_amount(&env, &member);
+        let insurance_bps: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey2::InsuranceContributionBps)
+            .unwrap_or(0);
+        let per_round = required + (required * insurance_bps as i128) / 10_000;
+        let allowance = per_round * rounds as i128;
+        let expiration_ledger = env.ledger().sequence() + env.storage().max_ttl();
+        token::Client::new(&env, &token_addr).approve(
+            &member,
+            &env.current_contract_address(),
+            &allowance,
+            &expiration_ledger,
+        );
+
+        let key = DataKey::AutoContribute(member.clone());
+        if rounds == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &rounds);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+        events::emit_auto_contribute_set(&env, member, rounds);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Member caps the total the contract may ever pull from them through
+    /// their allowance (`trigger_auto`), counting the insurance cut, any
+    /// reinstatement fee and any excess credited to escrow along with the
+    /// contribution. Pulls that would take the running total above `limit`
+    /// are rejected. 0 removes the cap.
+    pub fn set_pull_limit(env: Env, member: Address, limit: i128) {
+        internals::check_not_paused(&env);
+        member.require_auth();
+        if !Self::is_member(env.clone(), member.clone()) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+        if limit < 0 {
+            panic_with_error!(&env, ExtError::InvalidAmount);
+        }
+
+        let key = PersistentKey::PullLimit(member);
+        if limit == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &limit);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+    }
+
+    /// Returns `(limit, pulled)`: `member`'s pull cap (0 if none) and what the
+    /// contract has pulled from them so far.
+    pub fn get_pull_limit(env: Env, member: Address) -> (i128, i128) {
+        let limit: i128 = env
+            .storage()
+            .persistent()
+            .get(&PersistentKey::PullLimit(member.clone()))
+            .unwrap_or(0);
+        let pulled: i128 = env
+            .storage()
+            .persistent()
+            .get(&PersistentKey::Pulled(member))
+            .unwrap_or(0);
+        (limit, pulled)
+    }
+
+    /// Returns how many auto-contribute rounds `member` has left.
+    pub fn get_auto_contribute(env: Env, member: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AutoContribute(member))
+            .unwrap_or(0)
+    }
+
+    /// Member pays ahead for the next `rounds` rounds. The amount is held in
+    /// escrow and, as each new round opens, one contribution is drawn from it
+    /// and the member is marked paid without calling `contribute`.
+    pub fn prepay(env: Env, member: Address, rounds: u32) {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
+        member.require_auth();
+        if !Self::is_member(env.clone(), member.clone()) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+        let exited_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ExitedMembers)
+            .unwrap_or(Vec::new(&env));
+        if exited_members.contains(&member) {
+            panic_with_error!(&env, Error::MemberHasExited);
+        }
+        if rounds == 0 {
+            panic_with_error!(&env, Error::AmountMustBePositive);
+        }
+
+        let amount = internals::member_required_amount(&env, &member)
+            .checked_mul(rounds as i128)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError::InvalidAmount));
+        internals::check_tvl(&env, amount);
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        token::Client::new(&env, &token_addr).transfer(
+            &member,
+            env.current_contract_address(),
+            &amount,
+        );
+        internals::record_fund_flow(
+            &env,
+            "contribution",
+            &member,
+            &env.current_contract_address(),
+            amount,
+        );
+
+        let key = DataKey::Prepaid(member.clone());
+        let balance = env.storage().persistent().get::<_, i128>(&key).unwrap_or(0) + amount;
+        env.storage().persistent().set(&key, &balance);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        events::emit_prepaid(&env, member, amount, balance);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Member voluntarily tops up the current round's pot. The boost is not a
+    /// contribution and counts toward no one's obligation; it is paid to the
+    /// round's recipient along with the contributions and noted in the round
+    /// record. A round closed without a payout carries it into the next pot;
+    /// a voided round or a dissolved group refunds it to the donor.
+    pub fn boost(env: Env, donor: Address, amount: i128) {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
+        donor.require_auth();
+        internals::check_contributions_open(&env);
+        let group_status: GroupStatus = env
+            .storage()
+            .instance()
+            .get(&DataKey2::GroupStatus)
+            .unwrap_or(GroupStatus::Active);
+        if group_status == GroupStatus::Dissolved {
+            panic_with_error!(&env, ExtError::GroupAlreadyDissolved);
+        }
+        if group_status == GroupStatus::Completed {
+            panic_with_error!(&env, ExtError2::CycleComplete);
+        }
+        if !Self::is_member(env.clone(), donor.clone()) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+        if amount <= 0 {
+            panic_with_error!(&env, Error::AmountMustBePositive);
+        }
+        internals::check_tvl(&env, amount);
+
+        let token_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        token::Client::new(&env, &token_addr).transfer(
+            &donor,
+            env.current_contract_address(),
+            &amount,
+        );
+        internals::record_fund_flow(
+            &env,
+            "boost",
+            &donor,
+            &env.current_contract_address(),
+            amount,
+        );
+
+        let boosted: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey5::RoundBoost)
+            .unwrap_or(0);
+        let boosted = boosted
+            .checked_add(amount)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError::InvalidAmount));
+        env.storage().instance().set(&DataKey5::RoundBoost, &boosted);
+        let mut boosts: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey5::RoundBoosts)
+            .unwrap_or(Map::new(&env));
+        let donated = boosts.get(donor.clone()).unwrap_or(0) + amount;
+        boosts.set(donor.clone(), donated);
+        env.storage().instance().set(&DataKey5::RoundBoosts, &boosts);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        events::emit_pot_boosted(&env, donor, current_round, amount, boosted);
+    }
+
+    /// Admin sets how `contribute` handles payments above what a member still
+    /// owes for the round. Defaults to `OverpayPolicy::Reject`.
+    pub fn set_overpay_policy(env: Env, policy: OverpayPolicy) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        env.storage()
+            .instance()
+            .set(&DataKey5::OverpayPolicy, &policy);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    pub fn get_overpay_policy(env: Env) -> OverpayPolicy {
+        env.storage()
+            .instance()
+            .get(&DataKey5::OverpayPolicy)
+            .unwrap_or(OverpayPolicy::Reject)
+    }
+
+    /// Escrow `member` has prepaid for upcoming rounds.
+    pub fn get_prepaid(env: Env, member: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Prepaid(member))
+            .unwrap_or(0)
+    }
+
+    /// `member`'s current exposure: what they paid into the current round,
+    /// their security deposit and their prepaid escrow. This is what a
+    /// `dissolve` would refund them today.
+    pub fn at_risk(env: Env, member: Address) -> i128 {
+        let member_contributions: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MemberContributions)
+            .unwrap_or(Map::new(&env));
+        member_contributions.get(member.clone()).unwrap_or(0)
+            + Self::get_deposit(env.clone(), member.clone())
+            + Self::get_prepaid(env, member)
+    }
+
+    /// Pull `member`'s contribution for the current round using their
+    /// auto-contribute allowance. Callable by anyone (admin or keeper); the
+    /// member authorised the pull in `set_auto_contribute`.
+    pub fn trigger_auto(env: Env, member: Address) {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
+
+        let key = DataKey::AutoContribute(member.clone());
+        let remaining_rounds: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+        if remaining_rounds == 0 {
+            panic_with_error!(&env, ExtError2::AutoContributeNotEnabled);
+        }
+        Self::require_unpaid(&env, &member);
+
+        let member_contributions: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MemberContributions)
+            .unwrap_or(Map::new(&env));
+        let amount = internals::member_required_amount(&env, &member)
+            - member_contributions.get(member.clone()).unwrap_or(0);
+
+        if remaining_rounds == 1 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &(remaining_rounds - 1));
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        Self::apply_contribution(env.clone(), member.clone(), member.clone(), true, token_addr, amount, false);
+        events::emit_auto_contribute_triggered(&env, member, amount, remaining_rounds - 1);
+
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    // ─── Batch Contribution ──────────────────────────────────────────────────
+
+    /// `payer` funds the full contribution of every listed beneficiary (e.g. a
+    /// coordinator who collected cash offline). Each beneficiary goes through
+    /// the same path as `contribute`, so insurance, reserve and fee rules apply.
+    /// The whole list is checked first: duplicates and members who already
+    /// paid are rejected. The payout then fires once, after the last
+    /// beneficiary, if the batch filled the round.
+    pub fn contribute_batch(env: Env, payer: Address, beneficiaries: Vec<Address>) {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
+        payer.require_auth();
+
+        if beneficiaries.is_empty() {
+            panic_with_error!(&env, ExtError::InvalidAmount);
+        }
+
+        let mut seen: Vec<Address> = Vec::new(&env);
+        for beneficiary in beneficiaries.iter() {
+            if seen.contains(&beneficiary) {
+                panic_with_error!(&env, Error::AlreadyContributed);
+            }
+            Self::require_unpaid(&env, &beneficiary);
+            seen.push_back(beneficiary);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let mut total: i128 = 0;
+        for beneficiary in beneficiaries.iter() {
+            let member_contributions: Map<Address, i128> = env
+                .storage()
+                .instance()
+                .get(&DataKey::MemberContributions)
+                .unwrap_or(Map::new(&env));
+            let amount = internals::member_required_amount(&env, &beneficiary)
+                - member_contributions.get(beneficiary.clone()).unwrap_or(0);
+            Self::apply_contribution(
+                env.clone(),
+                beneficiary,
+                payer.clone(),
+                false,
+                token_addr.clone(),
+                amount,
+                true,
+            );
+            total += amount;
+        }
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        Self::pay_out_if_complete(&env, &paid_members);
+        events::emit_batch_contributed(&env, payer, beneficiaries.len(), total);
+
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    // ─── #224: Cycle Completion Bonus ────────────────────────────────────────
+
+    /// Admin sets the per-member cycle completion bonus drawn from the reward pool.
+    pub fn set_cycle_bonus(env: Env, admin: Address, amount: i128) {
+        internals::check_not_paused(&env);
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        let a: Address = env.storage().instance().get(&DataKey::Admin).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if admin != a { panic_with_error!(&env, ExtError::OnlyAdminAllowed); }
+        if amount < 0 { panic_with_error!(&env, Error::AmountMustBePositive); }
+        env.storage().instance().set(&DataKey4::CycleBonusAmount, &amount);
+        events::emit_cycle_bonus_configured(&env, amount);
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Returns the configured cycle bonus amount (0 if not set).
+    pub fn get_cycle_bonus(env: Env) -> i128 {
+        env.storage().instance().get(&DataKey4::CycleBonusAmount).unwrap_or(0)
+    }
+
+    // ─── Slot Auction ─────────────────────────────────────────────────────────
+
+    /// Place a bid in the current slot auction.
+    ///
+    /// The caller deposits `bid_amount` of the base token into the contract.
+    /// If the caller already has an active bid it is replaced atomically
+    /// (previous deposit refunded, new deposit taken).
+    ///
+    /// Panics:
+    /// - `AuctionNotEnabled`  — auction feature is off for this group
+    /// - `AuctionNotOpen`     — no auction is currently running
+    /// - `AuctionWindowClosed`— the bidding window has expired
+    /// - `InvalidSlotIndex`   — desired_slot is out of range
+    /// - `NotAMember`         — caller is not a group member
+    /// - `AmountMustBePositive`
+    pub fn place_slot_bid(env: Env, bidder: Address, desired_slot: u32, bid_amount: i128) {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
+        bidder.require_auth();
+
+        // Feature guard
+        let auction_enabled: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey3::AuctionEnabled)
+            .unwrap_or(false);
+        if !auction_enabled {
+            panic_with_error!(&env, ExtError2::AuctionNotEnabled);
+        }
+
+        // Window guard
+        let open_until: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey3::AuctionOpenUntil)
+            .unwrap_or(0);
+        if open_until == 0 {
+            panic_with_error!(&env, ExtError2::AuctionNotOpen);
+        }
+        if env.ledger().timestamp() > open_until {
+            panic_with_error!(&env, ExtError2::AuctionWindowClosed);
+        }
+
+        if bid_amount <= 0 {
+            panic_with_error!(&env, Error::AmountMustBePositive);
+        }
+
+        // Member guard
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if !members.contains(&bidder) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+
+        // Slot range guard
+        let payout_order: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PayoutOrder)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if desired_slot >= payout_order.len() as u32 {
+            panic_with_error!(&env, ExtError2::InvalidSlotIndex);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let token_client = token::Client::new(&env, &token_addr);
+
+        // Load existing bids
+        let mut bids: Vec<SlotBid> = env
+            .storage()
+            .instance()
+            .get(&DataKey3::AuctionBids)
+            .unwrap_or(Vec::new(&env));
+
+        // Refund any existing bid from this bidder
+        let mut new_bids: Vec<SlotBid> = Vec::new(&env);
+        for bid in bids.iter() {
+            if bid.bidder == bidder {
+                token_client.transfer(
+                    &env.current_contract_address(),
+                    &bidder,
+                    &bid.amount,
+                );
+                internals::record_fund_flow(
+                    &env,
+                    "refund",
+                    &env.current_contract_address(),
+                    &bidder,
+                    bid.amount,
+                );
+            } else {
+                new_bids.push_back(bid);
+            }
+        }
+
+        // Deposit new bid amount
+        token_client.transfer(&bidder, &env.current_contract_address(), &bid_amount);
+        internals::record_fund_flow(
+            &env,
+            "bid",
+            &bidder,
+            &env.current_contract_address(),
+            bid_amount,
+        );
+
+        // Record the new bid
+        new_bids.push_back(SlotBid {
+            bidder: bidder.clone(),
+            desired_slot,
+            amount: bid_amount,
+            placed_at: env.ledger().timestamp(),
+        });
+
+        env.storage()
+            .instance()
+            .set(&DataKey3::AuctionBids, &new_bids);
+
+        events::emit_slot_bid_placed(&env, 0, bidder, desired_slot, bid_amount);
+
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// #390: Switch between ledger-based and timestamp-based scheduling.
+    /// Forbidden once the first round has started (CurrentRound > 0) to prevent
+    /// grace-window aliasing between the two scheduling modes.
+    pub fn set_use_timestamp_schedule(env: Env, admin: Address, value: bool) {
+        internals::check_not_paused(&env);
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if admin != stored_admin {
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
+        }
+        let current_round: u32 = env.storage().instance().get(&DataKey::CurrentRound).unwrap_or(0);
+        if current_round > 0 {
+            panic_with_error!(&env, Error::CannotChangeMidRound);
+        }
+        env.storage().instance().set(&DataKey2::UseTimestampSchedule, &value);
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+
+
+    /// Update an existing bid during the auction window.
+    ///
+    /// Atomically refunds the previous deposit and takes the new one.
+    /// Panics with `NoBidFound` if the caller has no active bid.
+    pub fn update_slot_bid(env: Env, bidder: Address, desired_slot: u32, new_bid_amount: i128) {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
+        bidder.require_auth();
+
+        // Feature guard
+        let auction_enabled: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey3::AuctionEnabled)
+            .unwrap_or(false);
+        if !auction_enabled {
+            panic_with_error!(&env, ExtError2::AuctionNotEnabled);
+        }
+
+        // Window guard
+        let open_until: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey3::AuctionOpenUntil)
+            .unwrap_or(0);
+        if open_until == 0 {
+            panic_with_error!(&env, ExtError2::AuctionNotOpen);
+        }
+        if env.ledger().timestamp() > open_until {
+            panic_with_error!(&env, ExtError2::AuctionWindowClosed);
+        }
+
+        if new_bid_amount <= 0 {
+            panic_with_error!(&env, Error::AmountMustBePositive);
+        }
+
+        // Slot range guard
+        let payout_order: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PayoutOrder)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if desired_slot >= payout_order.len() as u32 {
+            panic_with_error!(&env, ExtError2::InvalidSlotIndex);
+        }
+
+        let bids: Vec<SlotBid> = env
+            .storage()
+            .instance()
+            .get(&DataKey3::AuctionBids)
+            .unwrap_or(Vec::new(&env));
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let token_client = token::Client::new(&env, &token_addr);
+
+        // Refund existing bid and build new bids list
+        let mut found = false;
+        let mut new_bids: Vec<SlotBid> = Vec::new(&env);
+        for bid in bids.iter() {
+            if bid.bidder == bidder {
+                found = true;
+                token_client.transfer(&env.current_contract_address(), &bidder, &bid.amount);
+                internals::record_fund_flow(
+                    &env,
+                    "refund",
+                    &env.current_contract_address(),
+                    &bidder,
+                    bid.amount,
+                );
+            } else {
+                new_bids.push_back(bid);
+            }
+        }
+        if !found {
+            panic_with_error!(&env, ExtError2::NoBidFound);
+        }
+
+        // Deposit new bid amount
+        token_client.transfer(&bidder, &env.current_contract_address(), &new_bid_amount);
+        internals::record_fund_flow(
+            &env,
+            "bid",
+            &bidder,
+            &env.current_contract_address(),
+            new_bid_amount,
+        );
+
+        // Record the new bid
+        new_bids.push_back(SlotBid {
+            bidder: bidder.clone(),
+            desired_slot,
+            amount: new_bid_amount,
+            placed_at: env.ledger().timestamp(),
+        });
+
+        env.storage().instance().set(&DataKey3::AuctionBids, &new_bids);
+        events::emit_slot_bid_placed(&env, 0, bidder, desired_slot, new_bid_amount);
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Resolve the current slot auction.
+    ///
+    /// Selects the highest bidder (earliest submission wins ties), swaps them
+    /// into their desired slot in `PayoutOrder`, refunds all losing bids, and
+    /// distributes the winning bid proportionally among non-winning active members.
+    ///
+    /// If no bids were placed this is a no-op (existing order preserved).
+    /// Panics with `AuctionNotEnabled` or `AuctionWindowClosed` (window still open).
+    pub fn resolve_slot_auction(env: Env) {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
+
+        // Only admin can resolve
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        // Feature guard
+        let auction_enabled: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey3::AuctionEnabled)
+            .unwrap_or(false);
+        if !auction_enabled {
+            panic_with_error!(&env, ExtError2::AuctionNotEnabled);
+        }
+
+        let open_until: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey3::AuctionOpenUntil)
+            .unwrap_or(0);
+
+        // Must wait for the window to close
+        if open_until > 0 && env.ledger().timestamp() <= open_until {
+            panic_with_error!(&env, ExtError2::AuctionWindowClosed);
+        }
+
+        let bids: Vec<SlotBid> = env
+            .storage()
+            .instance()
+            .get(&DataKey3::AuctionBids)
+            .unwrap_or(Vec::new(&env));
+
+        // No bids → no-op, just clear auction state
+        if bids.is_empty() {
+            env.storage()
+                .instance()
+                .set(&DataKey3::AuctionOpenUntil, &0u64);
+            env.storage()
+                .instance()
+                .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+            return;
+        }
+
+        // Select winner: highest bid; earliest placed_at breaks ties
+        let mut winner_idx: u32 = 0;
+        let mut winner_amount: i128 = 0;
+        let mut winner_placed_at: u64 = u64::MAX;
+        for (i, bid) in bids.iter().enumerate() {
+            let is_better = bid.amount > winner_amount
+                || (bid.amount == winner_amount && bid.placed_at < winner_placed_at);
+            if is_better {
+                winner_idx = i as u32;
+                winner_amount = bid.amount;
+                winner_placed_at = bid.placed_at;
+            }
+        }
+
+        let winner_bid = bids.get(winner_idx).unwrap();
+        let winner_addr = winner_bid.bidder.clone();
+        let desired_slot = winner_bid.desired_slot;
+        let winning_bid = winner_bid.amount;
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let token_client = token::Client::new(&env, &token_addr);
+
+        // Refund all losing bids
+        for (i, bid) in bids.iter().enumerate() {
+            if i as u32 != winner_idx {
+                token_client.transfer(
+                    &env.current_contract_address(),
+                    &bid.bidder,
+                    &bid.amount,
+                );
+                internals::record_fund_flow(
+                    &env,
+                    "refund",
+                    &env.current_contract_address(),
+                    &bid.bidder,
+                    bid.amount,
+                );
+            }
+        }
+
+        // Swap winner into desired_slot in PayoutOrder
+        let mut payout_order: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PayoutOrder)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+
+        // Find winner's current position
+        let mut winner_current_pos: Option<u32> = None;
+        for (i, addr) in payout_order.iter().enumerate() {
+            if addr == winner_addr {
+                winner_current_pos = Some(i as u32);
+                break;
+            }
+        }
+
+        if let Some(current_pos) = winner_current_pos {
+            if current_pos != desired_slot {
+                // Swap the two positions
+                let addr_at_desired = payout_order.get(desired_slot).unwrap();
+                let mut new_order: Vec<Address> = Vec::new(&env);
+                for (i, addr) in payout_order.iter().enumerate() {
+                    let idx = i as u32;
+                    if idx == desired_slot {
+                        new_order.push_back(winner_addr.clone());
+                    } else if idx == current_pos {
+                        new_order.push_back(addr_at_desired.clone());
+                    } else {
+                        new_order.push_back(addr);
+                    }
+                }
+                payout_order = new_order;
+                env.storage()
+                    .persistent()
                     .set(&DataKey::PayoutOrder, &payout_order);
             }
         }
 
-        // ── #224: Cycle completion bonus ──────────────────────────────────────
-        // A cycle ends when (current_round + 1) is a multiple of payout_order.len().
-        let payout_order: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::PayoutOrder)
-            .unwrap_or(Vec::new(&env));
-        let cycle_len = payout_order.len() as u32;
-        let cycle_completed = cycle_len > 0 && (current_round + 1) % cycle_len == 0;
-        if cycle_completed {
-            // Credit score: every non-exited member who completed the cycle
-            for member in members.iter() {
-                if !exited_members.contains(&member) {
-                    Self::update_credit_score_internal(&env, &member, Symbol::new(&env, "completed"));
-                }
-            }
+        // Distribute winning bid proportionally among non-winning active members
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let exited_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ExitedMembers)
+            .unwrap_or(Vec::new(&env));
+        let suspended_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::SuspendedMembers)
+            .unwrap_or(Vec::new(&env));
+
+        let mut eligible_count: i128 = 0;
+        for member in members.iter() {
+            if member != winner_addr
+                && !exited_members.contains(&member)
+                && !suspended_members.contains(&member)
+            {
+                eligible_count += 1;
+            }
+        }
+
+        let bonus_per_member: i128 = if eligible_count > 0 {
+            winning_bid / eligible_count
+        } else {
+            0
+        };
+
+        if bonus_per_member > 0 {
+            for member in members.iter() {
+                if member != winner_addr
+                    && !exited_members.contains(&member)
+                    && !suspended_members.contains(&member)
+                {
+                    token_client.transfer(
+                        &env.current_contract_address(),
+                        &member,
+                        &bonus_per_member,
+                    );
+                    internals::record_fund_flow(
+                        &env,
+                        "reward",
+                        &env.current_contract_address(),
+                        &member,
+                        bonus_per_member,
+                    );
+                }
+            }
+        }
+
+        // Clear auction state
+        env.storage()
+            .instance()
+            .set(&DataKey3::AuctionOpenUntil, &0u64);
+        env.storage()
+            .instance()
+            .set(&DataKey3::AuctionBids, &Vec::<SlotBid>::new(&env));
+
+        events::emit_slot_auction_resolved(
+            &env,
+            0,
+            winner_addr,
+            desired_slot,
+            winning_bid,
+            bonus_per_member,
+        );
+
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    // ── #375: Sealed-Bid (Commit-Reveal) Slot Auction ────────────────────────
+    //
+    // A fairer alternative to the open-bid auction above: bids are hidden
+    // during a commit phase (only a hash of `amount || salt` is stored) and
+    // disclosed during a later reveal phase, preventing last-moment sniping.
+    // The winner is the highest valid revealed bid that strictly exceeds the
+    // configured minimum reserve. group_id is 0 (single-group contract).
+
+    /// #375 helper: lightweight pause guard kept self-contained for the sealed
+    /// auction entry points.
+    fn sealed_require_not_paused(env: &Env) {
+        let paused: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::IsPaused)
+            .unwrap_or(false);
+        if paused {
+            panic_with_error!(env, Error::ContractPaused);
+        }
+    }
+
+    /// #375: Configure the commit-reveal sealed-bid slot auction — the commit
+    /// and reveal phase durations (seconds) and the minimum reserve price.
+    /// Admin only.
+    pub fn configure_sealed_slot_auction(
+        env: Env,
+        admin: Address,
+        commit_duration: u64,
+        reveal_duration: u64,
+        min_reserve: i128,
+    ) {
+        Self::sealed_require_not_paused(&env);
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if admin != stored_admin {
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
+        }
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        if commit_duration == 0 || reveal_duration == 0 {
+            panic_with_error!(&env, ExtError::InvalidAmount);
+        }
+        if min_reserve < 0 {
+            panic_with_error!(&env, ExtError::InvalidAmount);
+        }
+
+        let state = SealedAuctionState {
+            enabled: true,
+            commit_duration,
+            reveal_duration,
+            min_reserve,
+            round: 0,
+            commit_until: 0,
+            reveal_until: 0,
+            open: false,
+        };
+        env.storage().instance().set(&DataKey3::SealedAuction, &state);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// #375: Open a sealed-bid auction for `round`. Sets the commit-phase and
+    /// reveal-phase deadlines from the configured durations and resets the
+    /// per-round bookkeeping. Admin only.
+    pub fn open_sealed_slot_auction(env: Env, admin: Address, round: u32) {
+        Self::sealed_require_not_paused(&env);
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if admin != stored_admin {
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
+        }
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        let mut state: SealedAuctionState = env
+            .storage()
+            .instance()
+            .get(&DataKey3::SealedAuction)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::AuctionNotEnabled));
+        if !state.enabled {
+            panic_with_error!(&env, ExtError2::AuctionNotEnabled);
+        }
+        if state.open {
+            panic_with_error!(&env, ExtError3::AuctionAlreadyOpen);
+        }
+
+        let now = env.ledger().timestamp();
+        state.round = round;
+        state.commit_until = now + state.commit_duration;
+        state.reveal_until = state.commit_until + state.reveal_duration;
+        state.open = true;
+        env.storage().instance().set(&DataKey3::SealedAuction, &state);
+
+        // Fresh per-round bookkeeping.
+        env.storage()
+            .instance()
+            .set(&DataKey3::SealedCommitters(round), &Vec::<Address>::new(&env));
+        env.storage()
+            .instance()
+            .set(&DataKey3::SealedRevealedBids(round), &Vec::<SlotBid>::new(&env));
+
+        events::emit_sealed_auction_opened(&env, 0, round, state.commit_until, state.reveal_until);
+
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// #375: Commit a sealed bid. `commit_hash` must equal
+    /// `sha256(bidder.to_xdr() || bid_amount.to_be_bytes() || salt)` — binding
+    /// the bidder's identity prevents anyone else from copying the published
+    /// hash and revealing it as their own. `deposit` is collateral taken
+    /// up front and is also the maximum amount the bidder may later reveal; it
+    /// keeps the bid amount hidden during the commit phase. One commit per bidder
+    /// per round. Only valid during the commit phase.
+    pub fn commit_slot_bid(
+        env: Env,
+        bidder: Address,
+        commit_hash: BytesN<32>,
+        deposit: i128,
+    ) {
+        Self::sealed_require_not_paused(&env);
+        bidder.require_auth();
+
+        let state: SealedAuctionState = env
+            .storage()
+            .instance()
+            .get(&DataKey3::SealedAuction)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::AuctionNotEnabled));
+        if !state.enabled || !state.open {
+            panic_with_error!(&env, ExtError2::AuctionNotOpen);
+        }
+        if env.ledger().timestamp() > state.commit_until {
+            panic_with_error!(&env, ExtError2::AuctionWindowClosed);
+        }
+        if deposit <= 0 {
+            panic_with_error!(&env, Error::AmountMustBePositive);
+        }
+
+        // Member guard.
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if !members.contains(&bidder) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+
+        let round = state.round;
+        if env
+            .storage()
+            .instance()
+            .has(&DataKey3::SlotBidCommit(round, bidder.clone()))
+        {
+            panic_with_error!(&env, ExtError3::BidAlreadyCommitted);
+        }
+
+        // Take the collateral deposit.
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let token_client = token::Client::new(&env, &token_addr);
+        token_client.transfer(&bidder, &env.current_contract_address(), &deposit);
+        internals::record_fund_flow(&env, "bid", &bidder, &env.current_contract_address(), deposit);
+
+        env.storage().instance().set(
+            &DataKey3::SlotBidCommit(round, bidder.clone()),
+            &SealedCommit {
+                commit_hash,
+                deposit,
+                revealed: false,
+            },
+        );
+
+        let mut committers: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey3::SealedCommitters(round))
+            .unwrap_or(Vec::new(&env));
+        committers.push_back(bidder.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey3::SealedCommitters(round), &committers);
+
+        events::emit_slot_bid_committed(&env, 0, round, bidder);
+
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// #375: Reveal a previously committed sealed bid. Valid only during the
+    /// reveal phase (after the commit phase closes). The `(bid_amount, salt)`
+    /// pair must hash to the stored commitment, and `bid_amount` may not exceed
+    /// the committed deposit.
+    pub fn reveal_slot_bid(
+        env: Env,
+        bidder: Address,
+        desired_slot: u32,
+        bid_amount: i128,
+        salt: BytesN<32>,
+    ) {
+        Self::sealed_require_not_paused(&env);
+        bidder.require_auth();
+
+        let state: SealedAuctionState = env
+            .storage()
+            .instance()
+            .get(&DataKey3::SealedAuction)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::AuctionNotEnabled));
+        if !state.open {
+            panic_with_error!(&env, ExtError2::AuctionNotOpen);
+        }
+        let now = env.ledger().timestamp();
+        // The reveal phase opens only once the commit phase has closed.
+        if now <= state.commit_until {
+            panic_with_error!(&env, ExtError3::RevealNotOpen);
+        }
+        if now > state.reveal_until {
+            panic_with_error!(&env, ExtError2::AuctionWindowClosed);
+        }
+
+        let round = state.round;
+        let mut commit: SealedCommit = env
+            .storage()
+            .instance()
+            .get(&DataKey3::SlotBidCommit(round, bidder.clone()))
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NoBidFound));
+        if commit.revealed {
+            panic_with_error!(&env, ExtError3::BidAlreadyRevealed);
+        }
+
+        if bid_amount <= 0 {
+            panic_with_error!(&env, Error::AmountMustBePositive);
+        }
+        if bid_amount > commit.deposit {
+            panic_with_error!(&env, ExtError3::BidExceedsDeposit);
+        }
+
+        // Verify the revealed (amount, salt) hashes to the stored commitment.
+        // The bidder's identity is bound into the preimage so a copied
+        // commit_hash cannot be revealed by anyone but its original author —
+        // this defeats commit-copying / front-running of revealed values.
+        let mut preimage = Bytes::new(&env);
+        preimage.append(&bidder.clone().to_xdr(&env));
+        preimage.extend_from_array(&bid_amount.to_be_bytes());
+        preimage.extend_from_array(&salt.to_array());
+        let computed: BytesN<32> = env.crypto().sha256(&preimage).into();
+        if computed != commit.commit_hash {
+            panic_with_error!(&env, ExtError3::CommitmentMismatch);
+        }
+
+        // Slot range guard.
+        let payout_order: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PayoutOrder)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if desired_slot >= payout_order.len() {
+            panic_with_error!(&env, ExtError2::InvalidSlotIndex);
+        }
+
+        commit.revealed = true;
+        env.storage()
+            .instance()
+            .set(&DataKey3::SlotBidCommit(round, bidder.clone()), &commit);
+
+        let mut revealed: Vec<SlotBid> = env
+            .storage()
+            .instance()
+            .get(&DataKey3::SealedRevealedBids(round))
+            .unwrap_or(Vec::new(&env));
+        revealed.push_back(SlotBid {
+            bidder: bidder.clone(),
+            desired_slot,
+            amount: bid_amount,
+            placed_at: now,
+        });
+        env.storage()
+            .instance()
+            .set(&DataKey3::SealedRevealedBids(round), &revealed);
+
+        events::emit_slot_bid_revealed(&env, 0, round, bidder, desired_slot, bid_amount);
+
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// #375: Settle the sealed-bid auction after the reveal phase closes. The
+    /// winner is the highest revealed bid that strictly exceeds the minimum
+    /// reserve; if none qualifies the slot is left unallocated. Revealed bidders
+    /// are refunded (the winner's deposit minus the winning amount; losers in
+    /// full), while committers who never revealed forfeit their deposit. Admin
+    /// only.
+    pub fn settle_sealed_slot_auction(env: Env) {
+        Self::sealed_require_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        let mut state: SealedAuctionState = env
+            .storage()
+            .instance()
+            .get(&DataKey3::SealedAuction)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::AuctionNotEnabled));
+        if !state.open {
+            panic_with_error!(&env, ExtError2::AuctionNotOpen);
+        }
+        if env.ledger().timestamp() <= state.reveal_until {
+            panic_with_error!(&env, ExtError2::AuctionWindowClosed);
+        }
+
+        let round = state.round;
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let token_client = token::Client::new(&env, &token_addr);
+
+        let revealed: Vec<SlotBid> = env
+            .storage()
+            .instance()
+            .get(&DataKey3::SealedRevealedBids(round))
+            .unwrap_or(Vec::new(&env));
+
+        // Winner = highest revealed bid strictly above the reserve; earliest
+        // reveal breaks ties.
+        let mut winner_idx: Option<u32> = None;
+        let mut winner_amount: i128 = state.min_reserve;
+        let mut winner_placed_at: u64 = u64::MAX;
+        for (i, bid) in revealed.iter().enumerate() {
+            let better = bid.amount > winner_amount
+                || (winner_idx.is_some()
+                    && bid.amount == winner_amount
+                    && bid.placed_at < winner_placed_at);
+            if better {
+                winner_idx = Some(i as u32);
+                winner_amount = bid.amount;
+                winner_placed_at = bid.placed_at;
+            }
+        }
+
+        let mut winner_addr_opt: Option<Address> = None;
+        let mut desired_slot: u32 = 0;
+        let mut winning_amount: i128 = 0;
+        if let Some(widx) = winner_idx {
+            let wb = revealed.get(widx).unwrap();
+            winner_addr_opt = Some(wb.bidder.clone());
+            desired_slot = wb.desired_slot;
+            winning_amount = wb.amount;
         }
-        if cycle_completed {
-            let bonus_amount: i128 = env
+
+        // Refund revealed bidders. The winner gets back deposit minus the
+        // winning amount; everyone else who revealed is refunded in full.
+        // Committers who never revealed are not iterated here, so their deposit
+        // is forfeited (kept by the contract).
+        for bid in revealed.iter() {
+            let commit: SealedCommit = env
                 .storage()
                 .instance()
-                .get(&DataKey4::CycleBonusAmount)
-                .unwrap_or(0);
-            if bonus_amount > 0 {
-                let cycle_number = (current_round + 1) / cycle_len;
-                let cycle_start = (cycle_number - 1) * cycle_len;
-                let mut qualifying: Vec<Address> = Vec::new(&env);
-                for member in members.iter() {
-                    if exited_members.contains(&member) { continue; }
-                    let defaults = default_count.get(member.clone()).unwrap_or(0);
-                    let mut had_skip = false;
-                    for r in cycle_start..=(current_round) {
-                        if skip_requests.get((member.clone(), r)).unwrap_or(false) {
-                            had_skip = true;
-                            break;
-                        }
-                    }
-                    if defaults == 0 && !had_skip {
-                        qualifying.push_back(member);
-                    }
+                .get(&DataKey3::SlotBidCommit(round, bid.bidder.clone()))
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NoBidFound));
+            let refund = match &winner_addr_opt {
+                Some(w) if *w == bid.bidder => commit.deposit - winning_amount,
+                _ => commit.deposit,
+            };
+            if refund > 0 {
+                token_client.transfer(&env.current_contract_address(), &bid.bidder, &refund);
+                internals::record_fund_flow(
+                    &env,
+                    "refund",
+                    &env.current_contract_address(),
+                    &bid.bidder,
+                    refund,
+                );
+            }
+        }
+
+        if let Some(ref winner_addr) = winner_addr_opt {
+            // Move the winner into their desired slot (swap with the occupant).
+            let mut payout_order: Vec<Address> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::PayoutOrder)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+            let mut winner_current_pos: Option<u32> = None;
+            for (i, addr) in payout_order.iter().enumerate() {
+                if addr == *winner_addr {
+                    winner_current_pos = Some(i as u32);
+                    break;
                 }
-                let q_count = qualifying.len() as i128;
-                if q_count > 0 {
-                    let total_needed = bonus_amount * q_count;
-                    let mut reward_pool: i128 = env
-                        .storage()
-                        .instance()
-                        .get(&DataKey::RewardPool)
-                        .unwrap_or(0);
-                    let actual_bonus = if reward_pool >= total_needed {
-                        bonus_amount
-                    } else if reward_pool > 0 {
-                        let prorated = reward_pool / q_count;
-                        let shortfall = total_needed - reward_pool;
-                        events::emit_cycle_bonus_prorated(&env, cycle_number, shortfall);
-                        prorated
-                    } else {
-                        0
-                    };
-                    if actual_bonus > 0 {
-                        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-                        let token_client = token::Client::new(&env, &token_addr);
-                        for member in qualifying.iter() {
-                            token_client.transfer(
-                                &env.current_contract_address(),
-                                &member,
-                                &actual_bonus,
-                            );
-                            reward_pool -= actual_bonus;
-                            events::emit_cycle_bonus_paid(&env, member, actual_bonus, cycle_number);
+            }
+            if let Some(current_pos) = winner_current_pos {
+                if current_pos != desired_slot && desired_slot < payout_order.len() {
+                    let addr_at_desired = payout_order.get(desired_slot).unwrap();
+                    let mut new_order: Vec<Address> = Vec::new(&env);
+                    for (i, addr) in payout_order.iter().enumerate() {
+                        let idx = i as u32;
+                        if idx == desired_slot {
+                            new_order.push_back(winner_addr.clone());
+                        } else if idx == current_pos {
+                            new_order.push_back(addr_at_desired.clone());
+                        } else {
+                            new_order.push_back(addr);
                         }
-                        env.storage().instance().set(&DataKey::RewardPool, &reward_pool);
                     }
+                    payout_order = new_order;
+                    env.storage()
+                        .persistent()
+                        .set(&DataKey::PayoutOrder, &payout_order);
                 }
             }
-        }
 
-        // Mint NFT-style contribution receipts for every member who paid this round.
-        {
-            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-            let mut counter: u32 = env
+            // Distribute the winning bid among the other active members.
+            let members: Vec<Address> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Members)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+            let exited: Vec<Address> = env
                 .storage()
                 .instance()
-                .get(&DataKey3::ContributionReceiptCounter)
-                .unwrap_or(0);
-            let member_contributions: Map<Address, i128> = env
+                .get(&DataKey::ExitedMembers)
+                .unwrap_or(Vec::new(&env));
+            let suspended: Vec<Address> = env
                 .storage()
                 .instance()
-                .get(&DataKey::MemberContributions)
-                .unwrap_or(Map::new(&env));
-            let now_ts = env.ledger().timestamp();
+                .get(&DataKey::SuspendedMembers)
+                .unwrap_or(Vec::new(&env));
+            let mut eligible: i128 = 0;
+            for m in members.iter() {
+                if m != *winner_addr && !exited.contains(&m) && !suspended.contains(&m) {
+                    eligible += 1;
+                }
+            }
+            let bonus: i128 = if eligible > 0 { winning_amount / eligible } else { 0 };
+            if bonus > 0 {
+                for m in members.iter() {
+                    if m != *winner_addr && !exited.contains(&m) && !suspended.contains(&m) {
+                        token_client.transfer(&env.current_contract_address(), &m, &bonus);
+                        internals::record_fund_flow(
+                            &env,
+                            "reward",
+                            &env.current_contract_address(),
+                            &m,
+                            bonus,
+                        );
+                    }
+                }
+            }
+        }
 
-            for member in paid_members.iter() {
-                let amount_contributed = member_contributions.get(member.clone()).unwrap_or(0);
+        // Close the auction.
+        state.open = false;
+        env.storage().instance().set(&DataKey3::SealedAuction, &state);
 
-                // Compute a deterministic receipt hash from (counter, member, round, amount).
-                let mut preimage = Bytes::new(&env);
-                preimage.extend_from_array(&counter.to_be_bytes());
-                preimage.extend_from_array(&current_round.to_be_bytes());
-                let member_xdr = member.clone().to_xdr(&env);
-                preimage.append(&member_xdr);
-                let hash: BytesN<32> = env.crypto().sha256(&preimage).into();
+        let (winner_for_event, settled_bid) = match winner_addr_opt {
+            Some(w) => (w, winning_amount),
+            None => (env.current_contract_address(), 0i128),
+        };
+        events::emit_sealed_auction_settled(&env, 0, round, winner_for_event, settled_bid);
 
-                let receipt = ContributionReceipt {
-                    receipt_id: counter,
-                    member: member.clone(),
-                    round: current_round,
-                    amount_contributed,
-                    token: token_addr.clone(),
-                    minted_at: now_ts,
-                    receipt_hash: hash.clone(),
-                };
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
 
-                env.storage()
-                    .persistent()
-                    .set(&DataKey3::ContributionReceipt(counter), &receipt);
-                env.storage().persistent().extend_ttl(
-                    &DataKey3::ContributionReceipt(counter),
-                    PERSISTENT_LIFETIME_THRESHOLD,
-                    PERSISTENT_BUMP_AMOUNT,
-                );
+    /// #375: Read the current sealed-auction configuration and phase state.
+    pub fn get_sealed_auction(env: Env) -> Option<SealedAuctionState> {
+        env.storage().instance().get(&DataKey3::SealedAuction)
+    }
 
-                // Append to member's receipt index.
-                let mut ids: Vec<u32> = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey3::MemberReceiptIds(member.clone()))
-                    .unwrap_or(Vec::new(&env));
-                ids.push_back(counter);
-                env.storage()
-                    .persistent()
-                    .set(&DataKey3::MemberReceiptIds(member.clone()), &ids);
-                env.storage().persistent().extend_ttl(
-                    &DataKey3::MemberReceiptIds(member.clone()),
-                    PERSISTENT_LIFETIME_THRESHOLD,
-                    PERSISTENT_BUMP_AMOUNT,
-                );
+    /// #375: Read the valid revealed bids recorded for a given auction round.
+    pub fn get_sealed_revealed_bids(env: Env, round: u32) -> Vec<SlotBid> {
+        env.storage()
+            .instance()
+            .get(&DataKey3::SealedRevealedBids(round))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // ─── Cross-Group Member Migration ────────────────────────────────────────
+
+    /// Returns the base token address of this group (used by cross-contract migration checks).
+    pub fn get_token(env: Env) -> Address {
+        env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
+    }
+
+    /// Member initiates a cross-group migration request.
+    ///
+    /// Stores a `MigrationRequest` on this (source) contract.
+    /// Both admins must subsequently approve before `execute_migration` can proceed.
+    ///
+    /// Panics:
+    /// - `NotAMember`            — caller is not a member of this group
+    /// - `MigrationAlreadyPending` — a migration request already exists for this member
+    /// - `TokenMismatch`         — destination group uses a different base token
+    /// - `InvalidSlotIndex`      — target_slot is out of range in the destination group
+    pub fn request_group_migration(
+        env: Env,
+        member: Address,
+        to_group: Address,
+        target_slot: u32,
+    ) {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
+        member.require_auth();
+
+        // Member must belong to this group
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if !members.contains(&member) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
 
-                events::emit_contribution_receipt_minted(
-                    &env,
-                    counter,
-                    member.clone(),
-                    current_round,
-                    amount_contributed,
-                    hash,
-                );
+        // No duplicate pending request
+        let mut requests: Map<Address, MigrationRequest> = env
+            .storage()
+            .instance()
+            .get(&DataKey3::MigrationRequests)
+            .unwrap_or(Map::new(&env));
+        if requests.contains_key(member.clone()) {
+            panic_with_error!(&env, ExtError2::MigrationAlreadyPending);
+        }
 
-                counter += 1;
-            }
-            env.storage()
-                .instance()
-                .set(&DataKey3::ContributionReceiptCounter, &counter);
+        // Token compatibility check via cross-contract call
+        let dest_client = RoscaMigrationClient::new(&env, &to_group);
+        let dest_token = dest_client.get_token();
+        let src_token: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if dest_token != src_token {
+            panic_with_error!(&env, ExtError2::TokenMismatch);
         }
 
+        // Validate target_slot exists in destination group
+        // We do this by checking the destination's payout order length via the client
+        // (We can't directly read dest storage, but we can validate via the request itself;
+        //  the destination admin approval step will enforce slot validity.)
+
+        let request = MigrationRequest {
+            member: member.clone(),
+            to_group: to_group.clone(),
+            target_slot,
+            state: MigrationApprovalState::Pending,
+            created_at: env.ledger().timestamp(),
+        };
+        requests.set(member.clone(), request);
+        env.storage()
+            .instance()
+            .set(&DataKey3::MigrationRequests, &requests);
+
+        let src_contract = env.current_contract_address();
+        events::emit_migration_requested(&env, member, src_contract, to_group);
+
         env.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
     }
 
-    // ─── #224: Cycle Completion Bonus ────────────────────────────────────────
-
-    /// Admin sets the per-member cycle completion bonus drawn from the reward pool.
-    pub fn set_cycle_bonus(env: Env, admin: Address, amount: i128) {
+    /// Source group admin approves the member's exit for migration.
+    ///
+    /// Advances the request state from `Pending` → `SourceApproved`
+    /// or from `DestApproved` → `BothApproved`.
+    pub fn approve_migration_exit(env: Env, member: Address) {
         internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
-        let a: Address = env.storage().instance().get(&DataKey::Admin).expect("No admin");
-        if admin != a { panic_with_error!(&env, ExtError::OnlyAdminAllowed); }
-        if amount < 0 { panic_with_error!(&env, Error::AmountMustBePositive); }
-        env.storage().instance().set(&DataKey4::CycleBonusAmount, &amount);
-        events::emit_cycle_bonus_configured(&env, amount);
-        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-    }
+        internals::note_admin_action(&env);
 
-    /// Returns the configured cycle bonus amount (0 if not set).
-    pub fn get_cycle_bonus(env: Env) -> i128 {
-        env.storage().instance().get(&DataKey4::CycleBonusAmount).unwrap_or(0)
-    }
+        let mut requests: Map<Address, MigrationRequest> = env
+            .storage()
+            .instance()
+            .get(&DataKey3::MigrationRequests)
+            .unwrap_or(Map::new(&env));
 
-    // ─── Slot Auction ─────────────────────────────────────────────────────────
+        let mut req = requests
+            .get(member.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::MigrationNotFound));
 
-    /// Place a bid in the current slot auction.
+        if env.ledger().timestamp() > req.created_at + crate::MIGRATION_TIMEOUT_SECONDS {
+            panic_with_error!(&env, ExtError2::MigrationNotApproved);
+        }
+
+        match req.state {
+            MigrationApprovalState::Pending => {
+                req.state = MigrationApprovalState::SourceApproved;
+            }
+            MigrationApprovalState::DestApproved => {
+                req.state = MigrationApprovalState::BothApproved;
+            }
+            MigrationApprovalState::BothApproved | MigrationApprovalState::Executed => {
+                panic_with_error!(&env, ExtError2::MigrationAlreadyExecuted);
+            }
+            MigrationApprovalState::SourceApproved => {
+                // Already approved by source — idempotent, no-op
+            }
+        }
+
+        requests.set(member, req);
+        env.storage()
+            .instance()
+            .set(&DataKey3::MigrationRequests, &requests);
+
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Destination group admin approves the member's entry for migration.
     ///
-    /// The caller deposits `bid_amount` of the base token into the contract.
-    /// If the caller already has an active bid it is replaced atomically
-    /// (previous deposit refunded, new deposit taken).
+    /// Stores an `IncomingMigration` record on this (destination) contract.
+    /// Also validates that the target slot is not already occupied.
     ///
     /// Panics:
-    /// - `AuctionNotEnabled`  — auction feature is off for this group
-    /// - `AuctionNotOpen`     — no auction is currently running
-    /// - `AuctionWindowClosed`— the bidding window has expired
-    /// - `InvalidSlotIndex`   — desired_slot is out of range
-    /// - `NotAMember`         — caller is not a group member
-    /// - `AmountMustBePositive`
-    pub fn place_slot_bid(env: Env, bidder: Address, desired_slot: u32, bid_amount: i128) {
+    /// - `SlotOccupied`    — target_slot is already taken in this group
+    /// - `GroupFull`       — destination group has no room
+    pub fn approve_migration_entry(
+        env: Env,
+        member: Address,
+        from_group: Address,
+        target_slot: u32,
+    ) {
         internals::check_not_paused(&env);
         internals::check_not_frozen(&env);
-        bidder.require_auth();
-
-        // Feature guard
-        let auction_enabled: bool = env
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey3::AuctionEnabled)
-            .unwrap_or(false);
-        if !auction_enabled {
-            panic_with_error!(&env, ExtError2::AuctionNotEnabled);
-        }
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
 
-        // Window guard
-        let open_until: u64 = env
+        // Validate slot range
+        let payout_order: Vec<Address> = env
             .storage()
-            .instance()
-            .get(&DataKey3::AuctionOpenUntil)
-            .unwrap_or(0);
-        if open_until == 0 {
-            panic_with_error!(&env, ExtError2::AuctionNotOpen);
-        }
-        if env.ledger().timestamp() > open_until {
-            panic_with_error!(&env, ExtError2::AuctionWindowClosed);
-        }
-
-        if bid_amount <= 0 {
-            panic_with_error!(&env, Error::AmountMustBePositive);
+            .persistent()
+            .get(&DataKey::PayoutOrder)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if target_slot > payout_order.len() as u32 {
+            // Allow target_slot == len (append at end)
+            panic_with_error!(&env, ExtError2::InvalidSlotIndex);
         }
 
-        // Member guard
+        // Check member is not already in this group
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
-        if !members.contains(&bidder) {
-            panic_with_error!(&env, Error::NotAMember);
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if members.contains(&member) {
+            panic_with_error!(&env, Error::AlreadyAMember);
         }
 
-        // Slot range guard
-        let payout_order: Vec<Address> = env
+        // Check group capacity
+        let max_members: u32 = env
             .storage()
             .instance()
-            .get(&DataKey::PayoutOrder)
-            .expect("Not initialized");
-        if desired_slot >= payout_order.len() as u32 {
-            panic_with_error!(&env, ExtError2::InvalidSlotIndex);
+            .get(&DataKey::MaxMembers)
+            .unwrap_or(50);
+        if members.len() as u32 >= max_members {
+            panic_with_error!(&env, Error::GroupFull);
         }
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let token_client = token::Client::new(&env, &token_addr);
-
-        // Load existing bids
-        let mut bids: Vec<SlotBid> = env
+        // Check slot is vacant (not occupied by an active member)
+        // A slot is occupied if target_slot < payout_order.len() and the address
+        // at that position is an active (non-exited, non-migrated-out) member.
+        let exited_members: Vec<Address> = env
             .storage()
             .instance()
-            .get(&DataKey3::AuctionBids)
+            .get(&DataKey::ExitedMembers)
+            .unwrap_or(Vec::new(&env));
+        let vacant_slots: Vec<u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey3::VacantSlots)
             .unwrap_or(Vec::new(&env));
 
-        // Refund any existing bid from this bidder
-        let mut new_bids: Vec<SlotBid> = Vec::new(&env);
-        for bid in bids.iter() {
-            if bid.bidder == bidder {
-                token_client.transfer(
-                    &env.current_contract_address(),
-                    &bidder,
-                    &bid.amount,
-                );
-            } else {
-                new_bids.push_back(bid);
+        if target_slot < payout_order.len() {
+            let occupant = payout_order.get(target_slot).unwrap();
+            let is_vacant = vacant_slots.contains(&target_slot)
+                || exited_members.contains(&occupant);
+            if !is_vacant {
+                panic_with_error!(&env, ExtError2::SlotOccupied);
             }
         }
 
-        // Deposit new bid amount
-        token_client.transfer(&bidder, &env.current_contract_address(), &bid_amount);
-
-        // Record the new bid
-        new_bids.push_back(SlotBid {
-            bidder: bidder.clone(),
-            desired_slot,
-            amount: bid_amount,
-            placed_at: env.ledger().timestamp(),
-        });
-
-        env.storage()
-            .instance()
-            .set(&DataKey3::AuctionBids, &new_bids);
-
-        events::emit_slot_bid_placed(&env, 0, bidder, desired_slot, bid_amount);
-
-        env.storage()
+        let mut incoming: Map<Address, IncomingMigration> = env
+            .storage()
             .instance()
-            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-    }
-
-    /// #390: Switch between ledger-based and timestamp-based scheduling.
-    /// Forbidden once the first round has started (CurrentRound > 0) to prevent
-    /// grace-window aliasing between the two scheduling modes.
-    pub fn set_use_timestamp_schedule(env: Env, admin: Address, value: bool) {
-        internals::check_not_paused(&env);
-        admin.require_auth();
-        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).expect("No admin");
-        if admin != stored_admin {
-            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
-        }
-        let current_round: u32 = env.storage().instance().get(&DataKey::CurrentRound).unwrap_or(0);
-        if current_round > 0 {
-            panic_with_error!(&env, Error::CannotChangeMidRound);
-        }
-        env.storage().instance().set(&DataKey2::UseTimestampSchedule, &value);
-        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-    }
+            .get(&DataKey3::IncomingMigrations)
+            .unwrap_or(Map::new(&env));
 
+        incoming.set(
+            member.clone(),
+            IncomingMigration {
+                member,
+                from_group,
+                target_slot,
+                dest_approved: true,
+            },
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey3::IncomingMigrations, &incoming);
 
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
 
-    /// Update an existing bid during the auction window.
+    /// Execute the migration atomically.
     ///
-    /// Atomically refunds the previous deposit and takes the new one.
-    /// Panics with `NoBidFound` if the caller has no active bid.
-    pub fn update_slot_bid(env: Env, bidder: Address, desired_slot: u32, new_bid_amount: i128) {
+    /// Called on the **destination** contract. Requires:
+    /// 1. Source contract has the request in `BothApproved` state.
+    /// 2. Destination contract has an `IncomingMigration` record with `dest_approved = true`.
+    ///
+    /// Atomically:
+    /// - Calls `finalize_migration_exit` on the source contract (removes member, marks slot Vacant).
+    /// - Adds the member to this group at `target_slot`.
+    /// - Stores the `MigratedMemberRecord` annotation.
+    ///
+    /// Panics:
+    /// - `MigrationNotFound`    — no incoming migration record for this member
+    /// - `MigrationNotApproved` — source has not reached `BothApproved` state
+    pub fn execute_migration(env: Env, member: Address, from_group: Address) {
         internals::check_not_paused(&env);
         internals::check_not_frozen(&env);
-        bidder.require_auth();
 
-        // Feature guard
-        let auction_enabled: bool = env
+        // Load incoming migration record from this (destination) contract
+        let mut incoming: Map<Address, IncomingMigration> = env
             .storage()
             .instance()
-            .get(&DataKey3::AuctionEnabled)
-            .unwrap_or(false);
-        if !auction_enabled {
-            panic_with_error!(&env, ExtError2::AuctionNotEnabled);
-        }
+            .get(&DataKey3::IncomingMigrations)
+            .unwrap_or(Map::new(&env));
 
-        // Window guard
-        let open_until: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey3::AuctionOpenUntil)
-            .unwrap_or(0);
-        if open_until == 0 {
-            panic_with_error!(&env, ExtError2::AuctionNotOpen);
+        let inc = incoming
+            .get(member.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::MigrationNotFound));
+
+        if !inc.dest_approved {
+            panic_with_error!(&env, ExtError2::MigrationNotApproved);
         }
-        if env.ledger().timestamp() > open_until {
-            panic_with_error!(&env, ExtError2::AuctionWindowClosed);
+        if inc.from_group != from_group {
+            panic_with_error!(&env, ExtError2::MigrationNotFound);
         }
 
-        if new_bid_amount <= 0 {
-            panic_with_error!(&env, Error::AmountMustBePositive);
-        }
+        let target_slot = inc.target_slot;
+        let dest_contract = env.current_contract_address();
 
-        // Slot range guard
-        let payout_order: Vec<Address> = env
+        // Cross-contract call: remove member from source and get history
+        let src_client = RoscaMigrationClient::new(&env, &from_group);
+        let history = src_client.finalize_migration_exit(&member, &dest_contract);
+
+        // Add member to this group
+        let mut members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        members.push_back(member.clone());
+        env.storage().persistent().set(&DataKey::Members, &members);
+
+        // Insert into payout order at target_slot
+        let mut payout_order: Vec<Address> = env
+            .storage()
+            .persistent()
             .get(&DataKey::PayoutOrder)
-            .expect("Not initialized");
-        if desired_slot >= payout_order.len() as u32 {
-            panic_with_error!(&env, ExtError2::InvalidSlotIndex);
-        }
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
 
-        let bids: Vec<SlotBid> = env
+        let mut vacant_slots: Vec<u32> = env
             .storage()
             .instance()
-            .get(&DataKey3::AuctionBids)
+            .get(&DataKey3::VacantSlots)
             .unwrap_or(Vec::new(&env));
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let token_client = token::Client::new(&env, &token_addr);
-
-        // Refund existing bid and build new bids list
-        let mut found = false;
-        let mut new_bids: Vec<SlotBid> = Vec::new(&env);
-        for bid in bids.iter() {
-            if bid.bidder == bidder {
-                found = true;
-                token_client.transfer(&env.current_contract_address(), &bidder, &bid.amount);
-            } else {
-                new_bids.push_back(bid);
+        if target_slot < payout_order.len() {
+            // Replace the vacant slot in-place
+            let mut new_order: Vec<Address> = Vec::new(&env);
+            for (i, addr) in payout_order.iter().enumerate() {
+                if i as u32 == target_slot {
+                    new_order.push_back(member.clone());
+                } else {
+                    new_order.push_back(addr);
+                }
             }
-        }
-        if !found {
-            panic_with_error!(&env, ExtError2::NoBidFound);
+            payout_order = new_order;
+            // Remove from vacant slots list
+            let mut new_vacant: Vec<u32> = Vec::new(&env);
+            for s in vacant_slots.iter() {
+                if s != target_slot {
+                    new_vacant.push_back(s);
+                }
+            }
+            vacant_slots = new_vacant;
+        } else {
+            // Append at end
+            payout_order.push_back(member.clone());
         }
 
-        // Deposit new bid amount
-        token_client.transfer(&bidder, &env.current_contract_address(), &new_bid_amount);
+        env.storage()
+            .persistent()
+            .set(&DataKey::PayoutOrder, &payout_order);
+        env.storage()
+            .instance()
+            .set(&DataKey3::VacantSlots, &vacant_slots);
 
-        // Record the new bid
-        new_bids.push_back(SlotBid {
-            bidder: bidder.clone(),
-            desired_slot,
-            amount: new_bid_amount,
-            placed_at: env.ledger().timestamp(),
-        });
+        // Store migration annotation
+        let mut migrated: Map<Address, MigratedMemberRecord> = env
+            .storage()
+            .instance()
+            .get(&DataKey3::MigratedMembers)
+            .unwrap_or(Map::new(&env));
+        migrated.set(member.clone(), history.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey3::MigratedMembers, &migrated);
 
-        env.storage().instance().set(&DataKey3::AuctionBids, &new_bids);
-        events::emit_slot_bid_placed(&env, 0, bidder, desired_slot, new_bid_amount);
-        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        // Remove incoming migration record
+        incoming.remove(member.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey3::IncomingMigrations, &incoming);
+
+        events::emit_migration_executed(
+            &env,
+            member,
+            from_group,
+            dest_contract,
+            target_slot,
+        );
+
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
     }
 
-    /// Resolve the current slot auction.
+    /// Called by the destination contract during `execute_migration`.
     ///
-    /// Selects the highest bidder (earliest submission wins ties), swaps them
-    /// into their desired slot in `PayoutOrder`, refunds all losing bids, and
-    /// distributes the winning bid proportionally among non-winning active members.
+    /// Removes `member` from this (source) group, marks their slot as Vacant,
+    /// and returns their contribution history summary.
     ///
-    /// If no bids were placed this is a no-op (existing order preserved).
-    /// Panics with `AuctionNotEnabled` or `AuctionWindowClosed` (window still open).
-    pub fn resolve_slot_auction(env: Env) {
+    /// Only callable by the destination contract address that was recorded in the
+    /// migration request.
+    ///
+    /// Panics:
+    /// - `MigrationNotFound`       — no pending request for this member
+    /// - `MigrationNotApproved`    — request is not in `BothApproved` state
+    pub fn finalize_migration_exit(
+        env: Env,
+        member: Address,
+        dest_contract: Address,
+    ) -> MigratedMemberRecord {
         internals::check_not_paused(&env);
-        internals::check_not_frozen(&env);
-
-        // Only admin can resolve
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Not initialized");
-        admin.require_auth();
 
-        // Feature guard
-        let auction_enabled: bool = env
+        let mut requests: Map<Address, MigrationRequest> = env
             .storage()
             .instance()
-            .get(&DataKey3::AuctionEnabled)
-            .unwrap_or(false);
-        if !auction_enabled {
-            panic_with_error!(&env, ExtError2::AuctionNotEnabled);
-        }
+            .get(&DataKey3::MigrationRequests)
+            .unwrap_or(Map::new(&env));
 
-        let open_until: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey3::AuctionOpenUntil)
-            .unwrap_or(0);
+        let mut req = requests
+            .get(member.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::MigrationNotFound));
 
-        // Must wait for the window to close
-        if open_until > 0 && env.ledger().timestamp() <= open_until {
-            panic_with_error!(&env, ExtError2::AuctionWindowClosed);
+        // Verify destination matches
+        if req.to_group != dest_contract {
+            panic_with_error!(&env, ExtError2::MigrationNotFound);
         }
 
-        let bids: Vec<SlotBid> = env
-            .storage()
-            .instance()
-            .get(&DataKey3::AuctionBids)
-            .unwrap_or(Vec::new(&env));
-
-        // No bids → no-op, just clear auction state
-        if bids.is_empty() {
-            env.storage()
-                .instance()
-                .set(&DataKey3::AuctionOpenUntil, &0u64);
-            env.storage()
-                .instance()
-                .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-            return;
+        if req.state != MigrationApprovalState::BothApproved {
+            panic_with_error!(&env, ExtError2::MigrationNotApproved);
         }
 
-        // Select winner: highest bid; earliest placed_at breaks ties
-        let mut winner_idx: u32 = 0;
-        let mut winner_amount: i128 = 0;
-        let mut winner_placed_at: u64 = u64::MAX;
-        for (i, bid) in bids.iter().enumerate() {
-            let is_better = bid.amount > winner_amount
-                || (bid.amount == winner_amount && bid.placed_at < winner_placed_at);
-            if is_better {
-                winner_idx = i as u32;
-                winner_amount = bid.amount;
-                winner_placed_at = bid.placed_at;
+        // Collect contribution history
+        let payout_history: Vec<PayoutRecord> = env
+            .storage()
+            .persistent()
+            .get(&PersistentKey::RoundHistory)
+            .unwrap_or(Vec::new(&env));
+
+        let mut rounds_completed: u32 = 0;
+        for record in payout_history.iter() {
+            if record.recipient == member {
+                rounds_completed += 1;
             }
         }
 
-        let winner_bid = bids.get(winner_idx).unwrap();
-        let winner_addr = winner_bid.bidder.clone();
-        let desired_slot = winner_bid.desired_slot;
-        let winning_bid = winner_bid.amount;
+        // on_time_count: use reputation score as a proxy (each on-time contribution adds 10)
+        // More precisely, read MemberParticipation which tracks full-payment rounds
+        let participation: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MemberParticipation)
+            .unwrap_or(Map::new(&env));
+        let on_time_count = participation.get(member.clone()).unwrap_or(0);
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let token_client = token::Client::new(&env, &token_addr);
+        let src_contract = env.current_contract_address();
 
-        // Refund all losing bids
-        for (i, bid) in bids.iter().enumerate() {
-            if i as u32 != winner_idx {
-                token_client.transfer(
-                    &env.current_contract_address(),
-                    &bid.bidder,
-                    &bid.amount,
-                );
+        // Remove member from Members list
+        let old_members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let mut new_members: Vec<Address> = Vec::new(&env);
+        for m in old_members.iter() {
+            if m != member {
+                new_members.push_back(m);
             }
         }
+        env.storage().persistent().set(&DataKey::Members, &new_members);
 
-        // Swap winner into desired_slot in PayoutOrder
-        let mut payout_order: Vec<Address> = env
+        // Find and mark the member's slot as Vacant in PayoutOrder
+        let payout_order: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::PayoutOrder)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
 
-        // Find winner's current position
-        let mut winner_current_pos: Option<u32> = None;
+        let mut vacant_slots: Vec<u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey3::VacantSlots)
+            .unwrap_or(Vec::new(&env));
+
+        let mut member_slot: u32 = u32::MAX;
         for (i, addr) in payout_order.iter().enumerate() {
-            if addr == winner_addr {
-                winner_current_pos = Some(i as u32);
+            if addr == member {
+                member_slot = i as u32;
                 break;
             }
         }
-
-        if let Some(current_pos) = winner_current_pos {
-            if current_pos != desired_slot {
-                // Swap the two positions
-                let addr_at_desired = payout_order.get(desired_slot).unwrap();
-                let mut new_order: Vec<Address> = Vec::new(&env);
-                for (i, addr) in payout_order.iter().enumerate() {
-                    let idx = i as u32;
-                    if idx == desired_slot {
-                        new_order.push_back(winner_addr.clone());
-                    } else if idx == current_pos {
-                        new_order.push_back(addr_at_desired.clone());
-                    } else {
-                        new_order.push_back(addr);
-                    }
-                }
-                payout_order = new_order;
-                env.storage()
-                    .instance()
-                    .set(&DataKey::PayoutOrder, &payout_order);
-            }
+        if member_slot != u32::MAX && !vacant_slots.contains(&member_slot) {
+            vacant_slots.push_back(member_slot);
+            env.storage()
+                .instance()
+                .set(&DataKey3::VacantSlots, &vacant_slots);
         }
 
-        // Distribute winning bid proportionally among non-winning active members
-        let members: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::Members)
-            .expect("Not initialized");
-        let exited_members: Vec<Address> = env
+        // Also add to ExitedMembers so they are skipped in payout selection
+        let mut exited: Vec<Address> = env
             .storage()
             .instance()
             .get(&DataKey::ExitedMembers)
             .unwrap_or(Vec::new(&env));
-        let suspended_members: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::SuspendedMembers)
-            .unwrap_or(Vec::new(&env));
-
-        let mut eligible_count: i128 = 0;
-        for member in members.iter() {
-            if member != winner_addr
-                && !exited_members.contains(&member)
-                && !suspended_members.contains(&member)
-            {
-                eligible_count += 1;
-            }
-        }
-
-        let bonus_per_member: i128 = if eligible_count > 0 {
-            winning_bid / eligible_count
-        } else {
-            0
-        };
-
-        if bonus_per_member > 0 {
-            for member in members.iter() {
-                if member != winner_addr
-                    && !exited_members.contains(&member)
-                    && !suspended_members.contains(&member)
-                {
-                    token_client.transfer(
-                        &env.current_contract_address(),
-                        &member,
-                        &bonus_per_member,
-                    );
-                }
-            }
+        if !exited.contains(&member) {
+            exited.push_back(member.clone());
+            env.storage().instance().set(&DataKey::ExitedMembers, &exited);
         }
+        internals::refund_deposit(&env, &member);
 
-        // Clear auction state
-        env.storage()
-            .instance()
-            .set(&DataKey3::AuctionOpenUntil, &0u64);
+        // Mark request as executed
+        req.state = MigrationApprovalState::Executed;
+        requests.set(member.clone(), req.clone());
         env.storage()
             .instance()
-            .set(&DataKey3::AuctionBids, &Vec::<SlotBid>::new(&env));
-
-        events::emit_slot_auction_resolved(
-            &env,
-            0,
-            winner_addr,
-            desired_slot,
-            winning_bid,
-            bonus_per_member,
-        );
+            .set(&DataKey3::MigrationRequests, &requests);
 
         env.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-    }
-
-    // ── #375: Sealed-Bid (Commit-Reveal) Slot Auction ────────────────────────
-    //
-    // A fairer alternative to the open-bid auction above: bids are hidden
-    // during a commit phase (only a hash of `amount || salt` is stored) and
-    // disclosed during a later reveal phase, preventing last-moment sniping.
-    // The winner is the highest valid revealed bid that strictly exceeds the
-    // configured minimum reserve. group_id is 0 (single-group contract).
 
-    /// #375 helper: lightweight pause guard kept self-contained for the sealed
-    /// auction entry points.
-    fn sealed_require_not_paused(env: &Env) {
-        let paused: bool = env
-            .storage()
-            .instance()
-            .get(&DataKey::IsPaused)
-            .unwrap_or(false);
-        if paused {
-            panic!("Contract is paused");
+        MigratedMemberRecord {
+            from_group: src_contract,
+            rounds_completed,
+            on_time_count,
+            slot_index: req.target_slot,
+            migrated_at: env.ledger().timestamp(),
         }
     }
 
-    /// #375: Configure the commit-reveal sealed-bid slot auction — the commit
-    /// and reveal phase durations (seconds) and the minimum reserve price.
-    /// Admin only.
-    pub fn configure_sealed_slot_auction(
-        env: Env,
-        admin: Address,
-        commit_duration: u64,
-        reveal_duration: u64,
-        min_reserve: i128,
-    ) {
-        Self::sealed_require_not_paused(&env);
-        let stored_admin: Address = env
+    /// Cancels a pending cross-group migration request that has timed out.
+    /// Callable by the migrating member or this group's admin.
+    pub fn cancel_migration(env: Env, caller: Address, member: Address) {
+        internals::check_not_paused(&env);
+        caller.require_auth();
+
+        let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized");
-        if admin != stored_admin {
-            panic!("Only admin can configure the sealed auction");
-        }
-        admin.require_auth();
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
 
-        if commit_duration == 0 || reveal_duration == 0 {
-            panic!("Commit and reveal durations must be positive");
-        }
-        if min_reserve < 0 {
-            panic!("Minimum reserve cannot be negative");
+
+        if caller != member && caller != admin {
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
 
-        let state = SealedAuctionState {
-            enabled: true,
-            commit_duration,
-            reveal_duration,
-            min_reserve,
-            round: 0,
-            commit_until: 0,
-            reveal_until: 0,
-            open: false,
-        };
-        env.storage().instance().set(&DataKey3::SealedAuction, &state);
-        env.storage()
+        // Try to clear outbound migration requests
+        let mut requests: Map<Address, MigrationRequest> = env
+            .storage()
             .instance()
-            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-    }
+            .get(&DataKey3::MigrationRequests)
+            .unwrap_or(Map::new(&env));
 
-    /// #375: Open a sealed-bid auction for `round`. Sets the commit-phase and
-    /// reveal-phase deadlines from the configured durations and resets the
-    /// per-round bookkeeping. Admin only.
-    pub fn open_sealed_slot_auction(env: Env, admin: Address, round: u32) {
-        Self::sealed_require_not_paused(&env);
-        let stored_admin: Address = env
+        if let Some(req) = requests.get(member.clone()) {
+            if env.ledger().timestamp() > req.created_at + crate::MIGRATION_TIMEOUT_SECONDS {
+                requests.remove(member.clone());
+                env.storage()
+                    .instance()
+                    .set(&DataKey3::MigrationRequests, &requests);
+            }
+        }
+
+        // Try to clear incoming migration requests (only on destination contract)
+        let mut incoming: Map<Address, IncomingMigration> = env
             .storage()
             .instance()
-            .get(&DataKey::Admin)
-            .expect("Not initialized");
-        if admin != stored_admin {
-            panic!("Only admin can open the sealed auction");
+            .get(&DataKey3::IncomingMigrations)
+            .unwrap_or(Map::new(&env));
+            
+        if incoming.contains_key(member.clone()) {
+            let inc = incoming.get(member.clone()).unwrap();
+            let src_client = RoscaMigrationClient::new(&env, &inc.from_group);
+            let req_opt = src_client.get_migration_request(&member);
+            let mut is_timed_out = false;
+            
+            if let Some(req) = req_opt {
+                if env.ledger().timestamp() > req.created_at + crate::MIGRATION_TIMEOUT_SECONDS {
+                    is_timed_out = true;
+                }
+            } else {
+                is_timed_out = true;
+            }
+
+            if is_timed_out {
+                incoming.remove(member.clone());
+                env.storage()
+                    .instance()
+                    .set(&DataKey3::IncomingMigrations, &incoming);
+            }
         }
-        admin.require_auth();
+    }
 
-        let mut state: SealedAuctionState = env
+    /// Returns the pending outbound migration request for `member`, if any.
+    pub fn get_migration_request(env: Env, member: Address) -> Option<MigrationRequest> {
+        let requests: Map<Address, MigrationRequest> = env
             .storage()
             .instance()
-            .get(&DataKey3::SealedAuction)
-            .expect("Sealed auction not configured");
-        if !state.enabled {
-            panic!("Sealed auction is not enabled");
-        }
-        if state.open {
-            panic!("A sealed auction is already open");
-        }
-
-        let now = env.ledger().timestamp();
-        state.round = round;
-        state.commit_until = now + state.commit_duration;
-        state.reveal_until = state.commit_until + state.reveal_duration;
-        state.open = true;
-        env.storage().instance().set(&DataKey3::SealedAuction, &state);
+            .get(&DataKey3::MigrationRequests)
+            .unwrap_or(Map::new(&env));
+        requests.get(member)
+    }
 
-        // Fresh per-round bookkeeping.
-        env.storage()
-            .instance()
-            .set(&DataKey3::SealedCommitters(round), &Vec::<Address>::new(&env));
-        env.storage()
+    /// Returns the migration annotation for a member who joined via migration, if any.
+    pub fn get_migrated_member_record(env: Env, member: Address) -> Option<MigratedMemberRecord> {
+        let migrated: Map<Address, MigratedMemberRecord> = env
+            .storage()
             .instance()
-            .set(&DataKey3::SealedRevealedBids(round), &Vec::<SlotBid>::new(&env));
-
-        events::emit_sealed_auction_opened(&env, 0, round, state.commit_until, state.reveal_until);
+            .get(&DataKey3::MigratedMembers)
+            .unwrap_or(Map::new(&env));
+        migrated.get(member)
+    }
 
+    /// Returns the list of vacant slot indices in this group's payout order.
+    pub fn get_vacant_slots(env: Env) -> Vec<u32> {
         env.storage()
             .instance()
-            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+            .get(&DataKey3::VacantSlots)
+            .unwrap_or(Vec::new(&env))
     }
 
-    /// #375: Commit a sealed bid. `commit_hash` must equal
-    /// `sha256(bidder.to_xdr() || bid_amount.to_be_bytes() || salt)` — binding
-    /// the bidder's identity prevents anyone else from copying the published
-    /// hash and revealing it as their own. `deposit` is collateral taken
-    /// up front and is also the maximum amount the bidder may later reveal; it
-    /// keeps the bid amount hidden during the commit phase. One commit per bidder
-    /// per round. Only valid during the commit phase.
-    pub fn commit_slot_bid(
-        env: Env,
-        bidder: Address,
-        commit_hash: BytesN<32>,
-        deposit: i128,
-    ) {
-        Self::sealed_require_not_paused(&env);
-        bidder.require_auth();
+    // ─── #227: Round Duration Update ─────────────────────────────────────────
 
-        let state: SealedAuctionState = env
-            .storage()
-            .instance()
-            .get(&DataKey3::SealedAuction)
-            .expect("Sealed auction not configured");
-        if !state.enabled || !state.open {
-            panic!("Sealed auction is not open");
-        }
-        if env.ledger().timestamp() > state.commit_until {
-            panic!("Commit phase has closed");
-        }
-        if deposit <= 0 {
-            panic!("Commit deposit must be positive");
-        }
+    /// Admin schedules a round duration change that takes effect from the next round.    /// `new_duration_seconds` must be within [min_round_duration, max_round_duration].
+    pub fn update_round_duration(env: Env, admin: Address, new_duration_seconds: u64) {
+        internals::check_not_paused(&env);
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        let a: Address = env.storage().instance().get(&DataKey::Admin).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if admin != a { panic_with_error!(&env, ExtError::OnlyAdminAllowed); }
 
-        // Member guard.
-        let members: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::Members)
-            .expect("Not initialized");
-        if !members.contains(&bidder) {
-            panic!("Bidder is not a group member");
+        let min_dur: u64 = env.storage().instance().get(&DataKey4::MinRoundDuration).unwrap_or(60);
+        let max_dur: u64 = env.storage().instance().get(&DataKey4::MaxRoundDuration).unwrap_or(u64::MAX);
+        if new_duration_seconds < min_dur || new_duration_seconds > max_dur {
+            panic_with_error!(&env, ExtError::RoundDurationOutOfBounds);
         }
 
-        let round = state.round;
-        if env
+        let old_duration: u64 = env.storage().instance().get(&DataKey::RoundDuration).unwrap_or(0);
+        let current_round: u32 = env.storage().instance().get(&DataKey::CurrentRound).unwrap_or(0);
+
+        env.storage().instance().set(&DataKey4::PendingRoundDuration, &new_duration_seconds);
+        events::emit_round_duration_update_scheduled(&env, old_duration, new_duration_seconds, current_round + 1);
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Admin changes the round duration for future rounds, e.g. monthly to
+    /// biweekly. The current round keeps its deadline; the new duration is used
+    /// when the next round's deadline is set.
+    pub fn set_round_duration(env: Env, new_duration: u64) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
             .storage()
             .instance()
-            .has(&DataKey3::SlotBidCommit(round, bidder.clone()))
-        {
-            panic!("Bidder has already committed this round");
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        if new_duration == 0 {
+            panic_with_error!(&env, ExtError::InvalidAmount);
         }
 
-        // Take the collateral deposit.
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let token_client = token::Client::new(&env, &token_addr);
-        token_client.transfer(&bidder, &env.current_contract_address(), &deposit);
+        let old_duration: u64 = env.storage().instance().get(&DataKey::RoundDuration).unwrap_or(0);
+        let current_round: u32 = env.storage().instance().get(&DataKey::CurrentRound).unwrap_or(0);
 
-        env.storage().instance().set(
-            &DataKey3::SlotBidCommit(round, bidder.clone()),
-            &SealedCommit {
-                commit_hash,
-                deposit,
-                revealed: false,
-            },
-        );
+        env.storage().instance().set(&DataKey4::PendingRoundDuration, &new_duration);
+        events::emit_round_duration_update_scheduled(&env, old_duration, new_duration, current_round + 1);
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
 
-        let mut committers: Vec<Address> = env
+    /// Admin configures the min/max bounds for round duration.
+    pub fn set_round_duration_bounds(env: Env, admin: Address, min_seconds: u64, max_seconds: u64) {
+        internals::check_not_paused(&env);
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        let a: Address = env.storage().instance().get(&DataKey::Admin).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if admin != a { panic_with_error!(&env, ExtError::OnlyAdminAllowed); }
+        if min_seconds == 0 || min_seconds > max_seconds { panic_with_error!(&env, ExtError::InvalidAmount); }
+        env.storage().instance().set(&DataKey4::MinRoundDuration, &min_seconds);
+        env.storage().instance().set(&DataKey4::MaxRoundDuration, &max_seconds);
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    // ─── Contribution Amount Update ──────────────────────────────────────────
+
+    /// Admin changes the flat per-round contribution amount. Only allowed before
+    /// anyone has paid into the current round, so the new amount applies from
+    /// this round onward and no member is charged two different amounts.
+    pub fn set_contribution_amount(env: Env, new_amount: i128) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey3::SealedCommitters(round))
-            .unwrap_or(Vec::new(&env));
-        committers.push_back(bidder.clone());
-        env.storage()
-            .instance()
-            .set(&DataKey3::SealedCommitters(round), &committers);
-
-        events::emit_slot_bid_committed(&env, 0, round, bidder);
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
 
+        Self::apply_contribution_amount(&env, new_amount);
         env.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
     }
 
-    /// #375: Reveal a previously committed sealed bid. Valid only during the
-    /// reveal phase (after the commit phase closes). The `(bid_amount, salt)`
-    /// pair must hash to the stored commitment, and `bid_amount` may not exceed
-    /// the committed deposit.
-    pub fn reveal_slot_bid(
-        env: Env,
-        bidder: Address,
-        desired_slot: u32,
-        bid_amount: i128,
-        salt: BytesN<32>,
-    ) {
-        Self::sealed_require_not_paused(&env);
-        bidder.require_auth();
-
-        let state: SealedAuctionState = env
+    /// Body of `set_contribution_amount`, shared with member proposals.
+    fn apply_contribution_amount(env: &Env, new_amount: i128) {
+        if new_amount <= 0 {
+            panic_with_error!(env, Error::AmountMustBePositive);
+        }
+        let paid_members: Vec<Address> = internals::round_state_get(env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(env));
+        let member_contributions: Map<Address, i128> = env
             .storage()
             .instance()
-            .get(&DataKey3::SealedAuction)
-            .expect("Sealed auction not configured");
-        if !state.open {
-            panic!("Sealed auction is not open");
-        }
-        let now = env.ledger().timestamp();
-        // The reveal phase opens only once the commit phase has closed.
-        if now <= state.commit_until {
-            panic!("Reveal phase has not opened yet");
-        }
-        if now > state.reveal_until {
-            panic_with_error!(&env, ExtError2::AuctionWindowClosed);
+            .get(&DataKey::MemberContributions)
+            .unwrap_or(Map::new(env));
+        if !paid_members.is_empty() || !member_contributions.is_empty() {
+            panic_with_error!(env, Error::CannotChangeMidRound);
         }
 
-        let round = state.round;
-        let mut commit: SealedCommit = env
+        let old_amount: i128 = env
             .storage()
             .instance()
-            .get(&DataKey3::SlotBidCommit(round, bidder.clone()))
-            .expect("No commitment found for this bidder");
-        if commit.revealed {
-            panic!("Commitment already revealed");
-        }
-
-        if bid_amount <= 0 {
-            panic!("Revealed bid must be positive");
-        }
-        if bid_amount > commit.deposit {
-            panic!("Revealed bid exceeds committed deposit");
-        }
-
-        // Verify the revealed (amount, salt) hashes to the stored commitment.
-        // The bidder's identity is bound into the preimage so a copied
-        // commit_hash cannot be revealed by anyone but its original author —
-        // this defeats commit-copying / front-running of revealed values.
-        let mut preimage = Bytes::new(&env);
-        preimage.append(&bidder.clone().to_xdr(&env));
-        preimage.extend_from_array(&bid_amount.to_be_bytes());
-        preimage.extend_from_array(&salt.to_array());
-        let computed: BytesN<32> = env.crypto().sha256(&preimage).into();
-        if computed != commit.commit_hash {
-            panic!("Revealed values do not match commitment");
-        }
+            .get(&DataKey::ContributionAmt)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::ContributionAmt, &new_amount);
 
-        // Slot range guard.
-        let payout_order: Vec<Address> = env
+        // #352: Keep the rebalance target in step with the flat amount so a
+        // later rebalance does not revert to the old stake. Groups with explicit
+        // per-member amounts keep their target.
+        let member_amounts: Map<Address, i128> = env
             .storage()
             .instance()
-            .get(&DataKey::PayoutOrder)
-            .expect("Not initialized");
-        if desired_slot >= payout_order.len() {
-            panic!("Desired slot index is out of range");
+            .get(&DataKey::MemberContributionAmt)
+            .unwrap_or(Map::new(env));
+        if member_amounts.is_empty() {
+            let members: Vec<Address> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Members)
+                .unwrap_or(Vec::new(env));
+            let base_pool_target = new_amount
+                .checked_mul(members.len() as i128)
+                .unwrap_or_else(|| panic_with_error!(env, ExtError::InvalidAmount));
+            env.storage()
+                .instance()
+                .set(&DataKey3::BasePoolTarget, &base_pool_target);
         }
 
-        commit.revealed = true;
-        env.storage()
+        let current_round: u32 = env
+            .storage()
             .instance()
-            .set(&DataKey3::SlotBidCommit(round, bidder.clone()), &commit);
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        events::emit_contribution_amount_updated(env, old_amount, new_amount, current_round);
+    }
 
-        let mut revealed: Vec<SlotBid> = env
+    // ─── Finite Cycle ────────────────────────────────────────────────────────
+
+    /// Admin caps the group at `max_rounds` payouts; once that many rounds have
+    /// been paid out the group moves to `GroupStatus::Completed` and further
+    /// contributions and closes are rejected. Rounds closed without a payout
+    /// do not count. `init` sets the member count, as in a classic ROSCA; pass
+    /// 0 to make the group open-ended.
+    pub fn set_max_rounds(env: Env, max_rounds: u32) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey3::SealedRevealedBids(round))
-            .unwrap_or(Vec::new(&env));
-        revealed.push_back(SlotBid {
-            bidder: bidder.clone(),
-            desired_slot,
-            amount: bid_amount,
-            placed_at: now,
-        });
-        env.storage()
-            .instance()
-            .set(&DataKey3::SealedRevealedBids(round), &revealed);
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
 
-        events::emit_slot_bid_revealed(&env, 0, round, bidder, desired_slot, bid_amount);
+        if max_rounds > 0 && max_rounds <= internals::rounds_paid_out(&env) {
+            panic_with_error!(&env, ExtError::InvalidAmount);
+        }
 
+        if max_rounds == 0 {
+            env.storage().instance().remove(&DataKey4::MaxRounds);
+        } else {
+            env.storage()
+                .instance()
+                .set(&DataKey4::MaxRounds, &max_rounds);
+        }
         env.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
     }
 
-    /// #375: Settle the sealed-bid auction after the reveal phase closes. The
-    /// winner is the highest revealed bid that strictly exceeds the minimum
-    /// reserve; if none qualifies the slot is left unallocated. Revealed bidders
-    /// are refunded (the winner's deposit minus the winning amount; losers in
-    /// full), while committers who never revealed forfeit their deposit. Admin
-    /// only.
-    pub fn settle_sealed_slot_auction(env: Env) {
-        Self::sealed_require_not_paused(&env);
+    /// Get the configured round cap; 0 means the group is open-ended.
+    pub fn get_max_rounds(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey4::MaxRounds)
+            .unwrap_or(0)
+    }
+
+    /// Admin flags `member` as defaulted in the current round without closing
+    /// it (e.g. they explicitly withdrew during a dispute). The member is
+    /// added to `Defaulters` and dropped from `PaidMembers` if listed there.
+    pub fn mark_default(env: Env, member: Address) {
+        internals::check_not_paused(&env);
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
+        internals::note_admin_action(&env);
 
-        let mut state: SealedAuctionState = env
-            .storage()
-            .instance()
-            .get(&DataKey3::SealedAuction)
-            .expect("Sealed auction not configured");
-        if !state.open {
-            panic!("No open sealed auction to settle");
-        }
-        if env.ledger().timestamp() <= state.reveal_until {
-            panic_with_error!(&env, ExtError2::AuctionWindowClosed);
+        if !Self::is_member(env.clone(), member.clone()) {
+            panic_with_error!(&env, Error::NotAMember);
         }
 
-        let round = state.round;
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let token_client = token::Client::new(&env, &token_addr);
-
-        let revealed: Vec<SlotBid> = env
+        let mut defaulters: Vec<Address> = env
             .storage()
             .instance()
-            .get(&DataKey3::SealedRevealedBids(round))
+            .get(&DataKey::Defaulters)
             .unwrap_or(Vec::new(&env));
-
-        // Winner = highest revealed bid strictly above the reserve; earliest
-        // reveal breaks ties.
-        let mut winner_idx: Option<u32> = None;
-        let mut winner_amount: i128 = state.min_reserve;
-        let mut winner_placed_at: u64 = u64::MAX;
-        for (i, bid) in revealed.iter().enumerate() {
-            let better = bid.amount > winner_amount
-                || (winner_idx.is_some()
-                    && bid.amount == winner_amount
-                    && bid.placed_at < winner_placed_at);
-            if better {
-                winner_idx = Some(i as u32);
-                winner_amount = bid.amount;
-                winner_placed_at = bid.placed_at;
-            }
-        }
-
-        let mut winner_addr_opt: Option<Address> = None;
-        let mut desired_slot: u32 = 0;
-        let mut winning_amount: i128 = 0;
-        if let Some(widx) = winner_idx {
-            let wb = revealed.get(widx).unwrap();
-            winner_addr_opt = Some(wb.bidder.clone());
-            desired_slot = wb.desired_slot;
-            winning_amount = wb.amount;
-        }
-
-        // Refund revealed bidders. The winner gets back deposit minus the
-        // winning amount; everyone else who revealed is refunded in full.
-        // Committers who never revealed are not iterated here, so their deposit
-        // is forfeited (kept by the contract).
-        for bid in revealed.iter() {
-            let commit: SealedCommit = env
-                .storage()
+        if !defaulters.contains(&member) {
+            defaulters.push_back(member.clone());
+            env.storage()
                 .instance()
-                .get(&DataKey3::SlotBidCommit(round, bid.bidder.clone()))
-                .expect("Commitment missing for revealed bid");
-            let refund = match &winner_addr_opt {
-                Some(w) if *w == bid.bidder => commit.deposit - winning_amount,
-                _ => commit.deposit,
-            };
-            if refund > 0 {
-                token_client.transfer(&env.current_contract_address(), &bid.bidder, &refund);
-            }
+                .set(&DataKey::Defaulters, &defaulters);
         }
+        internals::reset_on_time_streaks(&env, &Vec::from_array(&env, [member.clone()]));
 
-        if let Some(ref winner_addr) = winner_addr_opt {
-            // Move the winner into their desired slot (swap with the occupant).
-            let mut payout_order: Vec<Address> = env
-                .storage()
-                .instance()
-                .get(&DataKey::PayoutOrder)
-                .expect("Not initialized");
-            let mut winner_current_pos: Option<u32> = None;
-            for (i, addr) in payout_order.iter().enumerate() {
-                if addr == *winner_addr {
-                    winner_current_pos = Some(i as u32);
-                    break;
-                }
-            }
-            if let Some(current_pos) = winner_current_pos {
-                if current_pos != desired_slot && desired_slot < payout_order.len() {
-                    let addr_at_desired = payout_order.get(desired_slot).unwrap();
-                    let mut new_order: Vec<Address> = Vec::new(&env);
-                    for (i, addr) in payout_order.iter().enumerate() {
-                        let idx = i as u32;
-                        if idx == desired_slot {
-                            new_order.push_back(winner_addr.clone());
-                        } else if idx == current_pos {
-                            new_order.push_back(addr_at_desired.clone());
-                        } else {
-                            new_order.push_back(addr);
-                        }
-                    }
-                    payout_order = new_order;
-                    env.storage()
-                        .instance()
-                        .set(&DataKey::PayoutOrder, &payout_order);
-                }
-            }
-
-            // Distribute the winning bid among the other active members.
-            let members: Vec<Address> = env
-                .storage()
-                .instance()
-                .get(&DataKey::Members)
-                .expect("Not initialized");
-            let exited: Vec<Address> = env
-                .storage()
-                .instance()
-                .get(&DataKey::ExitedMembers)
-                .unwrap_or(Vec::new(&env));
-            let suspended: Vec<Address> = env
-                .storage()
-                .instance()
-                .get(&DataKey::SuspendedMembers)
-                .unwrap_or(Vec::new(&env));
-            let mut eligible: i128 = 0;
-            for m in members.iter() {
-                if m != *winner_addr && !exited.contains(&m) && !suspended.contains(&m) {
-                    eligible += 1;
-                }
-            }
-            let bonus: i128 = if eligible > 0 { winning_amount / eligible } else { 0 };
-            if bonus > 0 {
-                for m in members.iter() {
-                    if m != *winner_addr && !exited.contains(&m) && !suspended.contains(&m) {
-                        token_client.transfer(&env.current_contract_address(), &m, &bonus);
-                    }
-                }
-            }
+        let mut paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        if let Some(idx) = paid_members.first_index_of(&member) {
+            paid_members.remove(idx);
+            internals::round_state_set(&env, &DataKey::PaidMembers, &paid_members);
         }
 
-        // Close the auction.
-        state.open = false;
-        env.storage().instance().set(&DataKey3::SealedAuction, &state);
-
-        let (winner_for_event, settled_bid) = match winner_addr_opt {
-            Some(w) => (w, winning_amount),
-            None => (env.current_contract_address(), 0i128),
-        };
-        events::emit_sealed_auction_settled(&env, 0, round, winner_for_event, settled_bid);
-
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        events::emit_member_marked_default(&env, member, current_round);
         env.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
     }
 
-    /// #375: Read the current sealed-auction configuration and phase state.
-    pub fn get_sealed_auction(env: Env) -> Option<SealedAuctionState> {
-        env.storage().instance().get(&DataKey3::SealedAuction)
-    }
-
-    /// #375: Read the valid revealed bids recorded for a given auction round.
-    pub fn get_sealed_revealed_bids(env: Env, round: u32) -> Vec<SlotBid> {
+    /// Get the current round's defaulters.
+    pub fn get_defaulters(env: Env) -> Vec<Address> {
         env.storage()
             .instance()
-            .get(&DataKey3::SealedRevealedBids(round))
+            .get(&DataKey::Defaulters)
             .unwrap_or(Vec::new(&env))
     }
 
-    // ─── Cross-Group Member Migration ────────────────────────────────────────
-
-    /// Returns the base token address of this group (used by cross-contract migration checks).
-    pub fn get_token(env: Env) -> Address {
-        env.storage().instance().get(&DataKey::Token).expect("Not initialized")
-    }
-
-    /// Member initiates a cross-group migration request.
-    ///
-    /// Stores a `MigrationRequest` on this (source) contract.
-    /// Both admins must subsequently approve before `execute_migration` can proceed.
-    ///
-    /// Panics:
-    /// - `NotAMember`            — caller is not a member of this group
-    /// - `MigrationAlreadyPending` — a migration request already exists for this member
-    /// - `TokenMismatch`         — destination group uses a different base token
-    /// - `InvalidSlotIndex`      — target_slot is out of range in the destination group
-    pub fn request_group_migration(
-        env: Env,
-        member: Address,
-        to_group: Address,
-        target_slot: u32,
-    ) {
-        internals::check_not_paused(&env);
-        internals::check_not_frozen(&env);
-        member.require_auth();
-
-        // Member must belong to this group
+    /// Get the members who still owe a contribution for the current round.
+    /// Exited and admin-suspended members are not expected to pay and are left out.
+    pub fn get_unpaid(env: Env) -> Vec<Address> {
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
-        if !members.contains(&member) {
-            panic_with_error!(&env, Error::NotAMember);
-        }
-
-        // No duplicate pending request
-        let mut requests: Map<Address, MigrationRequest> = env
+            .unwrap_or(Vec::new(&env));
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        let exited_members: Vec<Address> = env
             .storage()
             .instance()
-            .get(&DataKey3::MigrationRequests)
-            .unwrap_or(Map::new(&env));
-        if requests.contains_key(member.clone()) {
-            panic_with_error!(&env, ExtError2::MigrationAlreadyPending);
+            .get(&DataKey::ExitedMembers)
+            .unwrap_or(Vec::new(&env));
+        let admin_suspended = internals::admin_suspended(&env);
+
+        let mut unpaid = Vec::new(&env);
+        for member in members.iter() {
+            if !paid_members.contains(&member)
+                && !exited_members.contains(&member)
+                && !admin_suspended.contains(&member)
+            {
+                unpaid.push_back(member);
+            }
         }
+        unpaid
+    }
 
-        // Token compatibility check via cross-contract call
-        let dest_client = RoscaMigrationClient::new(&env, &to_group);
-        let dest_token = dest_client.get_token();
-        let src_token: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        if dest_token != src_token {
-            panic_with_error!(&env, ExtError2::TokenMismatch);
+    /// Whether each of `members` has paid in full for the current round, in
+    /// input order. Addresses outside the group report `false`.
+    pub fn contribution_status(env: Env, members: Vec<Address>) -> Vec<bool> {
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        let mut status = Vec::new(&env);
+        for member in members.iter() {
+            status.push_back(paid_members.contains(&member));
         }
+        status
+    }
 
-        // Validate target_slot exists in destination group
-        // We do this by checking the destination's payout order length via the client
-        // (We can't directly read dest storage, but we can validate via the request itself;
-        //  the destination admin approval step will enforce slot validity.)
+    /// Whether `member` could contribute right now, and if not, why. Mirrors
+    /// the checks in `contribute` without auth or state changes. The reason is
+    /// `ok` on success, otherwise one of `paused`, `window_closed`, `inactive`
+    /// (not started, dissolved or complete), `not_member`, `suspended`,
+    /// `deadline_passed` or `already_paid`.
+    pub fn can_contribute(env: Env, member: Address) -> (bool, Symbol) {
+        let reject = |reason: &str| (false, Symbol::new(&env, reason));
 
-        let request = MigrationRequest {
-            member: member.clone(),
-            to_group: to_group.clone(),
-            target_slot,
-            state: MigrationApprovalState::Pending,
-            created_at: env.ledger().timestamp(),
-        };
-        requests.set(member.clone(), request);
-        env.storage()
+        let is_paused: bool = env
+            .storage()
             .instance()
-            .set(&DataKey3::MigrationRequests, &requests);
-
-        let src_contract = env.current_contract_address();
-        events::emit_migration_requested(&env, member, src_contract, to_group);
-
-        env.storage()
+            .get(&DataKey::Paused)
+            .or(env.storage().instance().get(&DataKey::IsPaused))
+            .unwrap_or(false);
+        let contributions_paused: bool = env
+            .storage()
             .instance()
-            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-    }
-
-    /// Source group admin approves the member's exit for migration.
-    ///
-    /// Advances the request state from `Pending` → `SourceApproved`
-    /// or from `DestApproved` → `BothApproved`.
-    pub fn approve_migration_exit(env: Env, member: Address) {
-        internals::check_not_paused(&env);
-        let admin: Address = env
+            .get(&DataKey4::ContributionsPaused)
+            .unwrap_or(false);
+        let is_frozen: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey3::IsFrozen)
+            .unwrap_or(false);
+        if is_paused || contributions_paused || is_frozen {
+            return reject("paused");
+        }
+        if internals::contribution_window_position(&env) != core::cmp::Ordering::Equal {
+            return reject("window_closed");
+        }
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return reject("inactive");
+        }
+        let group_status: GroupStatus = env
             .storage()
             .instance()
-            .get(&DataKey::Admin)
-            .expect("Not initialized");
-        admin.require_auth();
+            .get(&DataKey2::GroupStatus)
+            .unwrap_or(GroupStatus::Active);
+        if env.ledger().timestamp() < Self::get_start_time(env.clone())
+            || group_status == GroupStatus::Dissolved
+            || group_status == GroupStatus::Completed
+        {
+            return reject("inactive");
+        }
 
-        let mut requests: Map<Address, MigrationRequest> = env
+        let exited_members: Vec<Address> = env
             .storage()
             .instance()
-            .get(&DataKey3::MigrationRequests)
-            .unwrap_or(Map::new(&env));
+            .get(&DataKey::ExitedMembers)
+            .unwrap_or(Vec::new(&env));
+        if exited_members.contains(&member) || !Self::is_member(env.clone(), member.clone()) {
+            return reject("not_member");
+        }
+        if internals::admin_suspended(&env).contains(&member) {
+            return reject("suspended");
+        }
 
-        let mut req = requests
-            .get(member.clone())
-            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::MigrationNotFound));
+        let use_timestamp: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey2::UseTimestampSchedule)
+            .unwrap_or(false);
+        let deadline: u64 = if use_timestamp {
+            internals::round_state_get(&env, &DataKey::RoundDeadlineTimestamp).unwrap_or(0)
+        } else {
+            internals::round_state_get(&env, &DataKey::RoundDeadline).unwrap_or(0)
+        };
+        let grace_period_seconds: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey3::GracePeriodSeconds)
+            .unwrap_or(0);
+        if internals::clock_now(&env) > deadline.saturating_add(grace_period_seconds) {
+            return reject("deadline_passed");
+        }
 
-        if env.ledger().timestamp() > req.created_at + crate::MIGRATION_TIMEOUT_SECONDS {
-            panic_with_error!(&env, ExtError2::MigrationNotApproved);
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        if paid_members.contains(&member) {
+            return reject("already_paid");
         }
+        (true, Symbol::new(&env, "ok"))
+    }
 
-        match req.state {
-            MigrationApprovalState::Pending => {
-                req.state = MigrationApprovalState::SourceApproved;
-            }
-            MigrationApprovalState::DestApproved => {
-                req.state = MigrationApprovalState::BothApproved;
-            }
-            MigrationApprovalState::BothApproved | MigrationApprovalState::Executed => {
-                panic_with_error!(&env, ExtError2::MigrationAlreadyExecuted);
-            }
-            MigrationApprovalState::SourceApproved => {
-                // Already approved by source — idempotent, no-op
-            }
+    /// `can_contribute` behind `member`'s auth, so a relayer can check that a
+    /// signed contribution would be accepted before submitting it. Moves no
+    /// tokens and changes no state; an invalid signature fails the call.
+    pub fn verify_member_auth(env: Env, member: Address) -> (bool, Symbol) {
+        member.require_auth();
+        Self::can_contribute(env, member)
+    }
+
+    /// Tokens `member` must hold to pay the current round: what is left of
+    /// their contribution plus the insurance deduction, and during the grace
+    /// period the late fee and interest `contribute_late` charges. 0 once
+    /// they have paid in full.
+    pub fn required_balance(env: Env, member: Address) -> i128 {
+        if !Self::is_member(env.clone(), member.clone()) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        if paid_members.contains(&member) {
+            return 0;
         }
 
-        requests.set(member, req);
-        env.storage()
+        let member_contributions: Map<Address, i128> = env
+            .storage()
             .instance()
-            .set(&DataKey3::MigrationRequests, &requests);
+            .get(&DataKey::MemberContributions)
+            .unwrap_or(Map::new(&env));
+        let remaining = (internals::member_required_amount(&env, &member)
+            - member_contributions.get(member.clone()).unwrap_or(0))
+        .max(0);
+        let insurance_bps: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey2::InsuranceContributionBps)
+            .unwrap_or(0);
+        let mut required = remaining + remaining * insurance_bps as i128 / 10_000;
 
-        env.storage()
+        let use_timestamp: bool = env
+            .storage()
             .instance()
-            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+            .get(&DataKey2::UseTimestampSchedule)
+            .unwrap_or(false);
+        let deadline: u64 = if use_timestamp {
+            internals::round_state_get(&env, &DataKey::RoundDeadlineTimestamp).unwrap_or(0)
+        } else {
+            internals::round_state_get(&env, &DataKey::RoundDeadline).unwrap_or(0)
+        };
+        let now_ts = env.ledger().timestamp();
+        if now_ts > deadline {
+            let late_fee: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::LateFee)
+                .unwrap_or(0);
+            let rate_bps: u32 = env
+                .storage()
+                .instance()
+                .get(&DataKey4::InterestRateBps)
+                .unwrap_or(0);
+            let seconds_late = (now_ts - deadline) as i128;
+            let interest = remaining
+                .checked_mul(rate_bps as i128)
+                .and_then(|v| v.checked_mul(seconds_late))
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError::InvalidAmount))
+                / (10_000 * 86_400);
+            required += late_fee + interest;
+        }
+        required
     }
 
-    /// Destination group admin approves the member's entry for migration.
-    ///
-    /// Stores an `IncomingMigration` record on this (destination) contract.
-    /// Also validates that the target slot is not already occupied.
-    ///
-    /// Panics:
-    /// - `SlotOccupied`    — target_slot is already taken in this group
-    /// - `GroupFull`       — destination group has no room
-    pub fn approve_migration_entry(
-        env: Env,
-        member: Address,
-        from_group: Address,
-        target_slot: u32,
-    ) {
+    /// Member pays off the debt recorded for their missed contributions. The
+    /// payment goes to the reserve, and the member's default count, defaulter
+    /// entries (the live list and the last round's record) and default
+    /// suspension are cleared. Admin suspensions are untouched.
+    pub fn settle_debt(env: Env, member: Address) {
         internals::check_not_paused(&env);
         internals::check_not_frozen(&env);
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Not initialized");
-        admin.require_auth();
+        member.require_auth();
 
-        // Validate slot range
-        let payout_order: Vec<Address> = env
+        let mut debts: Map<Address, i128> = env
             .storage()
             .instance()
-            .get(&DataKey::PayoutOrder)
-            .expect("Not initialized");
-        if target_slot > payout_order.len() as u32 {
-            // Allow target_slot == len (append at end)
-            panic_with_error!(&env, ExtError2::InvalidSlotIndex);
+            .get(&DataKey4::MemberDebt)
+            .unwrap_or(Map::new(&env));
+        let amount = debts.get(member.clone()).unwrap_or(0);
+        if amount <= 0 {
+            panic_with_error!(&env, ExtError2::NoDebtRecorded);
         }
 
-        // Check member is not already in this group
-        let members: Vec<Address> = env
+        let token_addr: Address = env
             .storage()
             .instance()
-            .get(&DataKey::Members)
-            .expect("Not initialized");
-        if members.contains(&member) {
-            panic_with_error!(&env, Error::AlreadyAMember);
-        }
+            .get(&DataKey::Token)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        token::Client::new(&env, &token_addr).transfer(
+            &member,
+            env.current_contract_address(),
+            &amount,
+        );
+        internals::record_fund_flow(
+            &env,
+            "repayment",
+            &member,
+            &env.current_contract_address(),
+            amount,
+        );
 
-        // Check group capacity
-        let max_members: u32 = env
+        debts.remove(member.clone());
+        env.storage().instance().set(&DataKey4::MemberDebt, &debts);
+
+        let reserve_pool: i128 = env
             .storage()
             .instance()
-            .get(&DataKey::MaxMembers)
-            .unwrap_or(50);
-        if members.len() as u32 >= max_members {
-            panic_with_error!(&env, Error::GroupFull);
-        }
+            .get(&DataKey4::ReservePool)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey4::ReservePool, &(reserve_pool + amount));
 
-        // Check slot is vacant (not occupied by an active member)
-        // A slot is occupied if target_slot < payout_order.len() and the address
-        // at that position is an active (non-exited, non-migrated-out) member.
-        let exited_members: Vec<Address> = env
+        let mut default_count: Map<Address, u32> = env
             .storage()
             .instance()
-            .get(&DataKey::ExitedMembers)
-            .unwrap_or(Vec::new(&env));
-        let vacant_slots: Vec<u32> = env
+            .get(&DataKey::DefaultCount)
+            .unwrap_or(Map::new(&env));
+        default_count.set(member.clone(), 0);
+        env.storage()
+            .instance()
+            .set(&DataKey::DefaultCount, &default_count);
+
+        let mut defaulters: Vec<Address> = env
             .storage()
             .instance()
-            .get(&DataKey3::VacantSlots)
+            .get(&DataKey::Defaulters)
             .unwrap_or(Vec::new(&env));
-
-        if target_slot < payout_order.len() {
-            let occupant = payout_order.get(target_slot).unwrap();
-            let is_vacant = vacant_slots.contains(&target_slot)
-                || exited_members.contains(&occupant);
-            if !is_vacant {
-                panic_with_error!(&env, ExtError2::SlotOccupied);
-            }
+        if let Some(idx) = defaulters.first_index_of(&member) {
+            defaulters.remove(idx);
+            env.storage()
+                .instance()
+                .set(&DataKey::Defaulters, &defaulters);
         }
 
-        let mut incoming: Map<Address, IncomingMigration> = env
+        // The debt was run up in the last settled round; drop the member from
+        // that round's recorded defaulters too.
+        let current_round: u32 = env
             .storage()
             .instance()
-            .get(&DataKey3::IncomingMigrations)
-            .unwrap_or(Map::new(&env));
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        if current_round > 0 {
+            let key = PersistentKey::RoundRecord(current_round - 1);
+            if let Some(mut record) = env.storage().persistent().get::<_, RoundRecord>(&key) {
+                if let Some(idx) = record.defaulters.first_index_of(&member) {
+                    record.defaulters.remove(idx);
+                    env.storage().persistent().set(&key, &record);
+                }
+            }
+        }
 
-        incoming.set(
-            member.clone(),
-            IncomingMigration {
-                member,
-                from_group,
-                target_slot,
-                dest_approved: true,
-            },
-        );
-        env.storage()
+        let mut suspended_members: Vec<Address> = env
+            .storage()
             .instance()
-            .set(&DataKey3::IncomingMigrations, &incoming);
+            .get(&DataKey::SuspendedMembers)
+            .unwrap_or(Vec::new(&env));
+        if let Some(idx) = suspended_members.first_index_of(&member) {
+            suspended_members.remove(idx);
+            env.storage()
+                .instance()
+                .set(&DataKey::SuspendedMembers, &suspended_members);
+        }
 
+        events::emit_debt_settled(&env, member, amount);
         env.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
     }
 
-    /// Execute the migration atomically.
-    ///
-    /// Called on the **destination** contract. Requires:
-    /// 1. Source contract has the request in `BothApproved` state.
-    /// 2. Destination contract has an `IncomingMigration` record with `dest_approved = true`.
-    ///
-    /// Atomically:
-    /// - Calls `finalize_migration_exit` on the source contract (removes member, marks slot Vacant).
-    /// - Adds the member to this group at `target_slot`.
-    /// - Stores the `MigratedMemberRecord` annotation.
-    ///
-    /// Panics:
-    /// - `MigrationNotFound`    — no incoming migration record for this member
-    /// - `MigrationNotApproved` — source has not reached `BothApproved` state
-    pub fn execute_migration(env: Env, member: Address, from_group: Address) {
-        internals::check_not_paused(&env);
-        internals::check_not_frozen(&env);
+    /// Get the missed-contribution debt recorded for a member.
+    pub fn get_debt(env: Env, member: Address) -> i128 {
+        let debts: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey4::MemberDebt)
+            .unwrap_or(Map::new(&env));
+        debts.get(member).unwrap_or(0)
+    }
 
-        // Load incoming migration record from this (destination) contract
-        let mut incoming: Map<Address, IncomingMigration> = env
+    /// Sum of every member's recorded missed-contribution debt.
+    pub fn total_outstanding_debt(env: Env) -> i128 {
+        let debts: Map<Address, i128> = env
             .storage()
             .instance()
-            .get(&DataKey3::IncomingMigrations)
+            .get(&DataKey4::MemberDebt)
             .unwrap_or(Map::new(&env));
+        let mut total: i128 = 0;
+        for (_, owed) in debts.iter() {
+            total = total
+                .checked_add(owed)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError::InvalidAmount));
+        }
+        total
+    }
 
-        let inc = incoming
-            .get(member.clone())
-            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::MigrationNotFound));
+    /// Admin manually penalises a specific defaulter from the current round's
+    /// defaulters list. Transfers the penalty amount from the member to the
+    /// contract and updates their default count and suspension status.
+    pub fn penalise_defaulter(env: Env, member: Address) {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
 
-        if !inc.dest_approved {
-            panic_with_error!(&env, ExtError2::MigrationNotApproved);
-        }
-        if inc.from_group != from_group {
-            panic_with_error!(&env, ExtError2::MigrationNotFound);
+        let defaulters: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Defaulters)
+            .unwrap_or(Vec::new(&env));
+        if !defaulters.contains(&member) {
+            panic_with_error!(&env, Error::NotADefaulter);
         }
 
-        let target_slot = inc.target_slot;
-        let dest_contract = env.current_contract_address();
+        let penalty_amount: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::PenaltyAmount)
+            .unwrap_or(0);
+
+        if penalty_amount == 0 {
+            panic_with_error!(&env, Error::PenaltyDisabled);
+        }
 
-        // Cross-contract call: remove member from source and get history
-        let src_client = RoscaMigrationClient::new(&env, &from_group);
-        let history = src_client.finalize_migration_exit(&member, &dest_contract);
+        Self::process_pending_penalties(&env);
 
-        // Add member to this group
-        let mut members: Vec<Address> = env
+        let current_round: u32 = env
             .storage()
             .instance()
-            .get(&DataKey::Members)
-            .expect("Not initialized");
-        members.push_back(member.clone());
-        env.storage().instance().set(&DataKey::Members, &members);
-
-        // Insert into payout order at target_slot
-        let mut payout_order: Vec<Address> = env
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        let round_deadline: u64 = env
             .storage()
             .instance()
-            .get(&DataKey::PayoutOrder)
-            .expect("Not initialized");
-
-        let mut vacant_slots: Vec<u32> = env
+            .get(&DataKey2::LastRoundDeadline)
+            .or(internals::round_state_get(&env, &DataKey::RoundDeadline))
+            .unwrap_or(0);
+        let grace_period_ledgers: u32 = env
             .storage()
             .instance()
-            .get(&DataKey3::VacantSlots)
-            .unwrap_or(Vec::new(&env));
+            .get(&DataKey2::GracePeriodLedgers)
+            .unwrap_or(0);
+        let grace_expires_at = round_deadline.saturating_add(grace_period_ledgers as u64);
+        let current_ledger = env.ledger().timestamp();
 
-        if target_slot < payout_order.len() {
-            // Replace the vacant slot in-place
-            let mut new_order: Vec<Address> = Vec::new(&env);
-            for (i, addr) in payout_order.iter().enumerate() {
-                if i as u32 == target_slot {
-                    new_order.push_back(member.clone());
-                } else {
-                    new_order.push_back(addr);
-                }
-            }
-            payout_order = new_order;
-            // Remove from vacant slots list
-            let mut new_vacant: Vec<u32> = Vec::new(&env);
-            for s in vacant_slots.iter() {
-                if s != target_slot {
-                    new_vacant.push_back(s);
-                }
-            }
-            vacant_slots = new_vacant;
-        } else {
-            // Append at end
-            payout_order.push_back(member.clone());
+        if current_ledger <= grace_expires_at {
+            let mut pending_penalties: Map<Address, u32> = env
+                .storage()
+                .instance()
+                .get(&DataKey2::PendingPenalties)
+                .unwrap_or(Map::new(&env));
+            pending_penalties.set(member.clone(), current_round);
+            env.storage()
+                .instance()
+                .set(&DataKey2::PendingPenalties, &pending_penalties);
+            events::emit_grace_period_warning(
+                &env,
+                member,
+                current_round,
+                grace_expires_at,
+            );
+            return;
         }
 
-        env.storage()
-            .instance()
-            .set(&DataKey::PayoutOrder, &payout_order);
-        env.storage()
-            .instance()
-            .set(&DataKey3::VacantSlots, &vacant_slots);
+        Self::apply_penalty(&env, member, penalty_amount, current_round);
+    }
 
-        // Store migration annotation
-        let mut migrated: Map<Address, MigratedMemberRecord> = env
+    /// Admin sets the number of ledgers a co-signer has to fulfil a missed
+    /// contribution on behalf of a member before the penalty is applied.
+    pub fn set_co_signer_window(env: Env, admin: Address, window_ledgers: u32) {
+        internals::check_not_paused(&env);
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        let stored_admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey3::MigratedMembers)
-            .unwrap_or(Map::new(&env));
-        migrated.set(member.clone(), history.clone());
-        env.storage()
-            .instance()
-            .set(&DataKey3::MigratedMembers, &migrated);
-
-        // Remove incoming migration record
-        incoming.remove(member.clone());
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if admin != stored_admin {
+            panic_with_error!(&env, Error::NotAMember);
+        }
         env.storage()
             .instance()
-            .set(&DataKey3::IncomingMigrations, &incoming);
-
-        events::emit_migration_executed(
-            &env,
-            member,
-            from_group,
-            dest_contract,
-            target_slot,
-        );
-
+            .set(&DataKey2::CoSignerWindowLedgers, &window_ledgers);
         env.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
     }
 
-    /// Called by the destination contract during `execute_migration`.
-    ///
-    /// Removes `member` from this (source) group, marks their slot as Vacant,
-    /// and returns their contribution history summary.
-    ///
-    /// Only callable by the destination contract address that was recorded in the
-    /// migration request.
-    ///
-    /// Panics:
-    /// - `MigrationNotFound`       — no pending request for this member
-    /// - `MigrationNotApproved`    — request is not in `BothApproved` state
-    pub fn finalize_migration_exit(
-        env: Env,
-        member: Address,
-        dest_contract: Address,
-    ) -> MigratedMemberRecord {
+    /// Member requests a grace-period deferral of their pending penalty.
+    /// Admin must approve; if within the grace window, the penalty is queued;
+    /// otherwise it is applied immediately.
+    pub fn request_penalty_grace(env: Env, member: Address) {
         internals::check_not_paused(&env);
-
-        let mut requests: Map<Address, MigrationRequest> = env
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey3::MigrationRequests)
-            .unwrap_or(Map::new(&env));
-
-        let mut req = requests
-            .get(member.clone())
-            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::MigrationNotFound));
-
-        // Verify destination matches
-        if req.to_group != dest_contract {
-            panic_with_error!(&env, ExtError2::MigrationNotFound);
-        }
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        Self::process_pending_penalties(&env);
 
-        if req.state != MigrationApprovalState::BothApproved {
-            panic_with_error!(&env, ExtError2::MigrationNotApproved);
+        let penalty_amount: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::PenaltyAmount)
+            .unwrap_or(0);
+        if penalty_amount == 0 {
+            panic_with_error!(&env, Error::PenaltyDisabled);
         }
 
-        // Collect contribution history
-        let payout_history: Vec<PayoutRecord> = env
+        let defaulters: Vec<Address> = env
             .storage()
-            .persistent()
-            .get(&PersistentKey::RoundHistory)
+            .instance()
+            .get(&DataKey::Defaulters)
             .unwrap_or(Vec::new(&env));
-
-        let mut rounds_completed: u32 = 0;
-        for record in payout_history.iter() {
-            if record.recipient == member {
-                rounds_completed += 1;
-            }
+        if !defaulters.contains(&member) {
+            panic_with_error!(&env, Error::NotADefaulter);
         }
 
-        // on_time_count: use reputation score as a proxy (each on-time contribution adds 10)
-        // More precisely, read MemberParticipation which tracks full-payment rounds
-        let participation: Map<Address, u32> = env
+        let current_round: u32 = env
             .storage()
             .instance()
-            .get(&DataKey::MemberParticipation)
-            .unwrap_or(Map::new(&env));
-        let on_time_count = participation.get(member.clone()).unwrap_or(0);
-
-        let src_contract = env.current_contract_address();
-
-        // Remove member from Members list
-        let old_members: Vec<Address> = env
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        let round_deadline: u64 = env
             .storage()
             .instance()
-            .get(&DataKey::Members)
-            .expect("Not initialized");
-        let mut new_members: Vec<Address> = Vec::new(&env);
-        for m in old_members.iter() {
-            if m != member {
-                new_members.push_back(m);
-            }
+            .get(&DataKey2::LastRoundDeadline)
+            .or(internals::round_state_get(&env, &DataKey::RoundDeadline))
+            .unwrap_or(0);
+        let grace_period_ledgers: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey2::GracePeriodLedgers)
+            .unwrap_or(0);
+        let grace_expires_at = round_deadline.saturating_add(grace_period_ledgers as u64);
+        let current_ledger = env.ledger().timestamp();
+        if current_ledger <= grace_expires_at {
+            let mut pending_penalties: Map<Address, u32> = env
+                .storage()
+                .instance()
+                .get(&DataKey2::PendingPenalties)
+                .unwrap_or(Map::new(&env));
+            pending_penalties.set(member.clone(), current_round);
+            env.storage()
+                .instance()
+                .set(&DataKey2::PendingPenalties, &pending_penalties);
+            events::emit_grace_period_warning(
+                &env,
+                member,
+                current_round,
+                grace_expires_at,
+            );
+            return;
         }
-        env.storage().instance().set(&DataKey::Members, &new_members);
 
-        // Find and mark the member's slot as Vacant in PayoutOrder
-        let payout_order: Vec<Address> = env
+        let mut pending_penalties: Map<Address, u32> = env
             .storage()
             .instance()
-            .get(&DataKey::PayoutOrder)
-            .expect("Not initialized");
+            .get(&DataKey2::PendingPenalties)
+            .unwrap_or(Map::new(&env));
+        pending_penalties.remove(member.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey2::PendingPenalties, &pending_penalties);
 
-        let mut vacant_slots: Vec<u32> = env
+        Self::apply_penalty(&env, member, penalty_amount, current_round);
+    }
+
+    fn process_pending_penalties(env: &Env) {
+        let mut pending_penalties: Map<Address, u32> = env
             .storage()
             .instance()
-            .get(&DataKey3::VacantSlots)
-            .unwrap_or(Vec::new(&env));
-
-        let mut member_slot: u32 = u32::MAX;
-        for (i, addr) in payout_order.iter().enumerate() {
-            if addr == member {
-                member_slot = i as u32;
-                break;
-            }
+            .get(&DataKey2::PendingPenalties)
+            .unwrap_or(Map::new(env));
+        if pending_penalties.len() == 0 {
+            return;
         }
-        if member_slot != u32::MAX && !vacant_slots.contains(&member_slot) {
-            vacant_slots.push_back(member_slot);
+
+        let penalty_amount: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::PenaltyAmount)
+            .unwrap_or(0);
+        if penalty_amount == 0 {
+            pending_penalties = Map::new(env);
             env.storage()
                 .instance()
-                .set(&DataKey3::VacantSlots, &vacant_slots);
+                .set(&DataKey2::PendingPenalties, &pending_penalties);
+            return;
         }
 
-        // Also add to ExitedMembers so they are skipped in payout selection
-        let mut exited: Vec<Address> = env
+        let grace_period_ledgers: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey2::GracePeriodLedgers)
+            .unwrap_or(0);
+        let round_deadline: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey2::LastRoundDeadline)
+            .or(internals::round_state_get(env, &DataKey::RoundDeadline))
+            .unwrap_or(0);
+        let grace_expires_at = round_deadline.saturating_add(grace_period_ledgers as u64);
+        let current_ledger = env.ledger().timestamp();
+        let current_round: u32 = env
             .storage()
             .instance()
-            .get(&DataKey::ExitedMembers)
-            .unwrap_or(Vec::new(&env));
-        if !exited.contains(&member) {
-            exited.push_back(member.clone());
-            env.storage().instance().set(&DataKey::ExitedMembers, &exited);
-        }
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
 
-        // Mark request as executed
-        req.state = MigrationApprovalState::Executed;
-        requests.set(member.clone(), req.clone());
-        env.storage()
-            .instance()
-            .set(&DataKey3::MigrationRequests, &requests);
+        let mut still_pending: Map<Address, u32> = Map::new(env);
+        for (member, pending_round) in pending_penalties.iter() {
+            if current_ledger > grace_expires_at || current_round > pending_round {
+                Self::apply_penalty(env, member, penalty_amount, current_round);
+            } else {
+                still_pending.set(member, pending_round);
+            }
+        }
 
         env.storage()
             .instance()
-            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-
-        MigratedMemberRecord {
-            from_group: src_contract,
-            rounds_completed,
-            on_time_count,
-            slot_index: req.target_slot,
-            migrated_at: env.ledger().timestamp(),
-        }
+            .set(&DataKey2::PendingPenalties, &still_pending);
     }
 
-    /// Cancels a pending cross-group migration request that has timed out.
-    /// Callable by the migrating member or this group's admin.
-    pub fn cancel_migration(env: Env, caller: Address, member: Address) {
-        internals::check_not_paused(&env);
-        caller.require_auth();
+    fn apply_penalty(env: &Env, member: Address, penalty_amount: i128, round: u32) {
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
+        let client = token::Client::new(env, &token_addr);
 
-        let admin: Address = env
+        member.require_auth();
+        client.transfer(&member, &env.current_contract_address(), &penalty_amount);
+        internals::record_fund_flow(
+            env,
+            "slash",
+            &member,
+            &env.current_contract_address(),
+            penalty_amount,
+        );
+
+        let mut default_count: Map<Address, u32> = env
             .storage()
             .instance()
-            .get(&DataKey::Admin)
-            .expect("Not initialized");
-
+            .get(&DataKey::DefaultCount)
+            .unwrap_or(Map::new(env));
+        let current_defaults = default_count.get(member.clone()).unwrap_or(0);
+        let new_default_count = current_defaults + 1;
+        default_count.set(member.clone(), new_default_count);
+        env.storage()
+            .instance()
+            .set(&DataKey::DefaultCount, &default_count);
 
-        if caller != member && caller != admin {
-            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
-        }
+        events::emit_defaulted(
+            env,
+            member.clone(),
+            round,
+            penalty_amount,
+            new_default_count,
+        );
+        // Confirmed default is applied here (not when merely pending).
+        Self::apply_reputation_delta(env, member.clone(), -20, "defaulted");
+        // Late-but-paid: member settled after defaulting.
+        Self::apply_reputation_delta(env, member.clone(), 5, "late_paid");
 
-        // Try to clear outbound migration requests
-        let mut requests: Map<Address, MigrationRequest> = env
+        let max_defaults: u32 = env
             .storage()
             .instance()
-            .get(&DataKey3::MigrationRequests)
-            .unwrap_or(Map::new(&env));
+            .get(&DataKey::MaxDefaults)
+            .unwrap_or(3);
 
-        if let Some(req) = requests.get(member.clone()) {
-            if env.ledger().timestamp() > req.created_at + crate::MIGRATION_TIMEOUT_SECONDS {
-                requests.remove(member.clone());
+        if new_default_count >= max_defaults {
+            let mut suspended_members: Vec<Address> = env
+                .storage()
+                .instance()
+                .get(&DataKey::SuspendedMembers)
+                .unwrap_or(Vec::new(env));
+            if !suspended_members.contains(&member) {
+                suspended_members.push_back(member.clone());
                 env.storage()
                     .instance()
-                    .set(&DataKey3::MigrationRequests, &requests);
+                    .set(&DataKey::SuspendedMembers, &suspended_members);
+                events::emit_suspended(env, member.clone(), new_default_count);
+                Self::try_promote_from_waitlist(env, &member);
             }
         }
+    }
 
-        // Try to clear incoming migration requests (only on destination contract)
-        let mut incoming: Map<Address, IncomingMigration> = env
+    fn apply_reputation_delta(env: &Env, member: Address, delta: i128, reason: &str) {
+        let mut scores: Map<Address, i128> = env
             .storage()
-            .instance()
-            .get(&DataKey3::IncomingMigrations)
-            .unwrap_or(Map::new(&env));
-            
-        if incoming.contains_key(member.clone()) {
-            let inc = incoming.get(member.clone()).unwrap();
-            let src_client = RoscaMigrationClient::new(&env, &inc.from_group);
-            let req_opt = src_client.get_migration_request(&member);
-            let mut is_timed_out = false;
-            
-            if let Some(req) = req_opt {
-                if env.ledger().timestamp() > req.created_at + crate::MIGRATION_TIMEOUT_SECONDS {
-                    is_timed_out = true;
-                }
-            } else {
-                is_timed_out = true;
-            }
-
-            if is_timed_out {
-                incoming.remove(member.clone());
-                env.storage()
-                    .instance()
-                    .set(&DataKey3::IncomingMigrations, &incoming);
-            }
+            .persistent()
+            .get(&PersistentKey::ReputationScores)
+            .unwrap_or(Map::new(env));
+        let old_score = scores.get(member.clone()).unwrap_or(0);
+        let mut new_score = old_score + delta;
+        if new_score < 0 {
+            new_score = 0;
         }
+        scores.set(member.clone(), new_score);
+        env.storage()
+            .persistent()
+            .set(&PersistentKey::ReputationScores, &scores);
+        env.storage().persistent().extend_ttl(
+            &PersistentKey::ReputationScores,
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+        events::emit_reputation_updated(
+            env,
+            member,
+            old_score,
+            new_score,
+            Symbol::new(env, reason),
+        );
     }
 
-    /// Returns the pending outbound migration request for `member`, if any.
-    pub fn get_migration_request(env: Env, member: Address) -> Option<MigrationRequest> {
-        let requests: Map<Address, MigrationRequest> = env
+    pub fn get_reputation_score(env: Env, member: Address) -> i128 {
+        let scores: Map<Address, i128> = env
             .storage()
-            .instance()
-            .get(&DataKey3::MigrationRequests)
+            .persistent()
+            .get(&PersistentKey::ReputationScores)
             .unwrap_or(Map::new(&env));
-        requests.get(member)
+        env.storage().persistent().extend_ttl(
+            &PersistentKey::ReputationScores,
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+        scores.get(member).unwrap_or(0)
     }
 
-    /// Returns the migration annotation for a member who joined via migration, if any.
-    pub fn get_migrated_member_record(env: Env, member: Address) -> Option<MigratedMemberRecord> {
-        let migrated: Map<Address, MigratedMemberRecord> = env
+    pub fn get_group_avg_reputation(env: Env) -> i128 {
+        let members: Vec<Address> = env
             .storage()
-            .instance()
-            .get(&DataKey3::MigratedMembers)
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or(Vec::new(&env));
+        if members.is_empty() {
+            return 0;
+        }
+        let scores: Map<Address, i128> = env
+            .storage()
+            .persistent()
+            .get(&PersistentKey::ReputationScores)
             .unwrap_or(Map::new(&env));
-        migrated.get(member)
+        let mut total = 0i128;
+        for member in members.iter() {
+            total += scores.get(member).unwrap_or(0);
+        }
+        env.storage().persistent().extend_ttl(
+            &PersistentKey::ReputationScores,
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+        total / (members.len() as i128)
     }
 
-    /// Returns the list of vacant slot indices in this group's payout order.
-    pub fn get_vacant_slots(env: Env) -> Vec<u32> {
-        env.storage()
+    pub fn add_member(env: Env, new_member: Address) {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
+        let admin: Address = env
+            .storage()
             .instance()
-            .get(&DataKey3::VacantSlots)
-            .unwrap_or(Vec::new(&env))
-    }
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
 
-    // ─── #227: Round Duration Update ─────────────────────────────────────────
+        Self::append_member(&env, &new_member);
+    }
 
-    /// Admin schedules a round duration change that takes effect from the next round.    /// `new_duration_seconds` must be within [min_round_duration, max_round_duration].
-    pub fn update_round_duration(env: Env, admin: Address, new_duration_seconds: u64) {
+    /// Self-service enrollment: `member` adds themselves to the group, subject
+    /// to the same checks as `add_member`. Joining closes once the first round
+    /// has ended (to keep the rotation intact) unless open enrollment is on.
+    /// If a join deposit is configured it is collected into the member's
+    /// security deposit.
+    pub fn join(env: Env, member: Address) {
         internals::check_not_paused(&env);
-        admin.require_auth();
-        let a: Address = env.storage().instance().get(&DataKey::Admin).expect("No admin");
-        if admin != a { panic_with_error!(&env, ExtError::OnlyAdminAllowed); }
+        internals::check_not_frozen(&env);
+        internals::require_initialized(&env);
+        member.require_auth();
 
-        let min_dur: u64 = env.storage().instance().get(&DataKey4::MinRoundDuration).unwrap_or(60);
-        let max_dur: u64 = env.storage().instance().get(&DataKey4::MaxRoundDuration).unwrap_or(u64::MAX);
-        if new_duration_seconds < min_dur || new_duration_seconds > max_dur {
-            panic_with_error!(&env, ExtError::RoundDurationOutOfBounds);
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        let open_enrollment: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey4::OpenEnrollment)
+            .unwrap_or(false);
+        if current_round > 0 && !open_enrollment {
+            panic_with_error!(&env, ExtError2::EnrollmentClosed);
         }
 
-        let old_duration: u64 = env.storage().instance().get(&DataKey::RoundDuration).unwrap_or(0);
-        let current_round: u32 = env.storage().instance().get(&DataKey::CurrentRound).unwrap_or(0);
+        Self::append_member(&env, &member);
 
-        env.storage().instance().set(&DataKey4::PendingRoundDuration, &new_duration_seconds);
-        events::emit_round_duration_update_scheduled(&env, old_duration, new_duration_seconds, current_round + 1);
-        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let deposit: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::JoinDeposit)
+            .unwrap_or(0);
+        if deposit > 0 {
+            Self::collect_deposit(&env, &member, deposit);
+        }
     }
 
-    /// Admin configures the min/max bounds for round duration.
-    pub fn set_round_duration_bounds(env: Env, admin: Address, min_seconds: u64, max_seconds: u64) {
-        internals::check_not_paused(&env);
+    /// Admin allows (or stops allowing) `join` after the first payout.
+    pub fn set_open_enrollment(env: Env, enabled: bool) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
-        let a: Address = env.storage().instance().get(&DataKey::Admin).expect("No admin");
-        if admin != a { panic_with_error!(&env, ExtError::OnlyAdminAllowed); }
-        if min_seconds == 0 || min_seconds > max_seconds { panic_with_error!(&env, ExtError::InvalidAmount); }
-        env.storage().instance().set(&DataKey4::MinRoundDuration, &min_seconds);
-        env.storage().instance().set(&DataKey4::MaxRoundDuration, &max_seconds);
-        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        internals::note_admin_action(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey4::OpenEnrollment, &enabled);
+    }
+
+    /// Admin sets the security deposit collected from members who `join`.
+    pub fn set_join_deposit(env: Env, amount: i128) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        if amount < 0 {
+            panic_with_error!(&env, ExtError::InvalidAmount);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey4::JoinDeposit, &amount);
     }
 
-    /// Admin manually penalises a specific defaulter from the current round's
-    /// defaulters list. Transfers the penalty amount from the member to the
-    /// contract and updates their default count and suspension status.
-    pub fn penalise_defaulter(env: Env, member: Address) {
-        internals::check_not_paused(&env);
-        internals::check_not_frozen(&env);
+    /// Shared by `add_member` and `join`: rejects mid-round changes, full
+    /// groups, existing members and (when enabled) non-whitelisted addresses,
+    /// then appends `new_member` to the members and the end of the payout order.
+    fn append_member(env: &Env, new_member: &Address) {
+        // Reject mid-round: paid_members must be empty
+        let paid_members: Vec<Address> = internals::round_state_get(env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(env));
+        if !paid_members.is_empty() {
+            panic_with_error!(env, Error::CannotChangeMidRound);
+        }
 
-        let defaulters: Vec<Address> = env
+        let whitelist_enabled: bool = env
             .storage()
             .instance()
-            .get(&DataKey::Defaulters)
-            .unwrap_or(Vec::new(&env));
-        if !defaulters.contains(&member) {
-            panic_with_error!(&env, Error::NotADefaulter);
+            .get(&DataKey4::WhitelistEnabled)
+            .unwrap_or(false);
+        if whitelist_enabled {
+            let whitelist: Vec<Address> = env
+                .storage()
+                .instance()
+                .get(&DataKey4::MemberWhitelist)
+                .unwrap_or(Vec::new(env));
+            if !whitelist.contains(new_member) {
+                panic_with_error!(env, ExtError2::NotWhitelisted);
+            }
         }
 
-        let penalty_amount: i128 = env
+        let mut members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
+
+        let max_members: u32 = env
             .storage()
             .instance()
-            .get(&DataKey::PenaltyAmount)
-            .unwrap_or(0);
+            .get(&DataKey::MaxMembers)
+            .unwrap_or(50);
 
-        if penalty_amount == 0 {
-            panic_with_error!(&env, Error::PenaltyDisabled);
+        if (members.len() as u32) >= max_members {
+            panic_with_error!(env, Error::GroupFull);
         }
 
-        Self::process_pending_penalties(&env);
+        if members.contains(new_member) {
+            panic_with_error!(env, Error::AlreadyAMember);
+        }
+        members.push_back(new_member.clone());
+        env.storage().persistent().set(&DataKey::Members, &members);
 
-        let current_round: u32 = env
+        // Recalculate payout order: append new member to the end
+        let mut payout_order: Vec<Address> = env
             .storage()
-            .instance()
-            .get(&DataKey::CurrentRound)
-            .unwrap_or(0);
-        let round_deadline: u64 = env
+            .persistent()
+            .get(&DataKey::PayoutOrder)
+            .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
+        payout_order.push_back(new_member.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::PayoutOrder, &payout_order);
+
+        events::emit_mem_add(env, new_member.clone(), members.len());
+    }
+
+    // ─── Member Whitelist ────────────────────────────────────────────────────
+
+    /// Admin turns the member whitelist on or off. While on, `add_member` and
+    /// `join` only accept addresses added with `whitelist_add`.
+    pub fn set_whitelist_enabled(env: Env, enabled: bool) {
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey4::LastRoundDeadline)
-            .or(env.storage().instance().get(&DataKey::RoundDeadline))
-            .unwrap_or(0);
-        let grace_period_ledgers: u32 = env
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey4::WhitelistEnabled, &enabled);
+    }
+
+    /// Admin pre-approves `addr` to join the group.
+    pub fn whitelist_add(env: Env, addr: Address) {
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey4::GracePeriodLedgers)
-            .unwrap_or(0);
-        let grace_expires_at = round_deadline.saturating_add(grace_period_ledgers as u64);
-        let current_ledger = env.ledger().timestamp();
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
 
-        if current_ledger <= grace_expires_at {
-            let mut pending_penalties: Map<Address, u32> = env
-                .storage()
-                .instance()
-                .get(&DataKey4::PendingPenalties)
-                .unwrap_or(Map::new(&env));
-            pending_penalties.set(member.clone(), current_round);
+        let mut whitelist: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey4::MemberWhitelist)
+            .unwrap_or(Vec::new(&env));
+        if !whitelist.contains(&addr) {
+            whitelist.push_back(addr);
             env.storage()
                 .instance()
-                .set(&DataKey4::PendingPenalties, &pending_penalties);
-            events::emit_grace_period_warning(
-                &env,
-                member,
-                current_round,
-                grace_expires_at,
-            );
-            return;
+                .set(&DataKey4::MemberWhitelist, &whitelist);
         }
-
-        Self::apply_penalty(&env, member, penalty_amount, current_round);
     }
 
-    /// Admin sets the number of ledgers a co-signer has to fulfil a missed
-    /// contribution on behalf of a member before the penalty is applied.
-    pub fn set_co_signer_window(env: Env, admin: Address, window_ledgers: u32) {
-        internals::check_not_paused(&env);
-        admin.require_auth();
-        let stored_admin: Address = env
+    /// Admin withdraws `addr`'s approval. Existing members are unaffected.
+    pub fn whitelist_remove(env: Env, addr: Address) {
+        let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
-        if admin != stored_admin {
-            panic_with_error!(&env, Error::NotAMember);
-        }
-        env.storage()
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        let mut whitelist: Vec<Address> = env
+            .storage()
             .instance()
-            .set(&DataKey4::CoSignerWindowLedgers, &window_ledgers);
-        env.storage()
+            .get(&DataKey4::MemberWhitelist)
+            .unwrap_or(Vec::new(&env));
+        if let Some(idx) = whitelist.first_index_of(&addr) {
+            whitelist.remove(idx);
+            env.storage()
+                .instance()
+                .set(&DataKey4::MemberWhitelist, &whitelist);
+        }
+    }
+
+    /// Returns true if `addr` is on the member whitelist.
+    pub fn is_whitelisted(env: Env, addr: Address) -> bool {
+        let whitelist: Vec<Address> = env
+            .storage()
             .instance()
-            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+            .get(&DataKey4::MemberWhitelist)
+            .unwrap_or(Vec::new(&env));
+        whitelist.contains(&addr)
     }
 
-    /// Member requests a grace-period deferral of their pending penalty.
-    /// Admin must approve; if within the grace window, the penalty is queued;
-    /// otherwise it is applied immediately.
-    pub fn request_penalty_grace(env: Env, member: Address) {
+    pub fn remove_member(env: Env, member: Address) {
         internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
-        Self::process_pending_penalties(&env);
+        internals::note_admin_action(&env);
 
-        let penalty_amount: i128 = env
+        // Reject mid-round: paid_members must be empty
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        if !paid_members.is_empty() {
+            panic_with_error!(&env, Error::CannotChangeMidRound);
+        }
+
+        let members: Vec<Address> = env
             .storage()
-            .instance()
-            .get(&DataKey::PenaltyAmount)
-            .unwrap_or(0);
-        if penalty_amount == 0 {
-            panic_with_error!(&env, Error::PenaltyDisabled);
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if !members.contains(&member) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+        if internals::scheduled_recipient(&env) == member {
+            panic_with_error!(&env, ExtError3::RemovesScheduledRecipient);
         }
 
-        let defaulters: Vec<Address> = env
+        internals::drop_member(&env, &member);
+    }
+
+    /// Hand `from`'s whole position to `to`: turn in the payout order, this
+    /// round's payment status, deposit, debt and payout history. Used when a
+    /// member rotates keys or sells their slot.
+    pub fn transfer_position(env: Env, from: Address, to: Address) {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
+        from.require_auth();
+
+        if to == env.current_contract_address() {
+            panic_with_error!(&env, ExtError2::ContractAddressNotAllowed);
+        }
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if !members.contains(&from) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+        if members.contains(&to) {
+            panic_with_error!(&env, Error::AlreadyAMember);
+        }
+        let exited: Vec<Address> = env
             .storage()
             .instance()
-            .get(&DataKey::Defaulters)
+            .get(&DataKey::ExitedMembers)
             .unwrap_or(Vec::new(&env));
-        if !defaulters.contains(&member) {
-            panic_with_error!(&env, Error::NotADefaulter);
+        if exited.contains(&from) {
+            panic_with_error!(&env, Error::MemberHasExited);
         }
 
-        let current_round: u32 = env
-            .storage()
+        internals::execute_position_transfer(&env, &from, &to);
+
+        env.storage()
             .instance()
-            .get(&DataKey::CurrentRound)
-            .unwrap_or(0);
-        let round_deadline: u64 = env
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        events::emit_position_transferred(&env, from, to);
+    }
+
+    pub fn add_approved_token(env: Env, token: Address) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey4::LastRoundDeadline)
-            .or(env.storage().instance().get(&DataKey::RoundDeadline))
-            .unwrap_or(0);
-        let grace_period_ledgers: u32 = env
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        let mut approved_tokens: Vec<Address> = env
             .storage()
             .instance()
-            .get(&DataKey4::GracePeriodLedgers)
-            .unwrap_or(0);
-        let grace_expires_at = round_deadline.saturating_add(grace_period_ledgers as u64);
-        let current_ledger = env.ledger().timestamp();
-        if current_ledger <= grace_expires_at {
-            let mut pending_penalties: Map<Address, u32> = env
-                .storage()
-                .instance()
-                .get(&DataKey4::PendingPenalties)
-                .unwrap_or(Map::new(&env));
-            pending_penalties.set(member.clone(), current_round);
+            .get(&DataKey::ApprovedTokens)
+            .unwrap_or(Vec::new(&env));
+
+        if !approved_tokens.contains(&token) {
+            approved_tokens.push_back(token.clone());
             env.storage()
                 .instance()
-                .set(&DataKey4::PendingPenalties, &pending_penalties);
-            events::emit_grace_period_warning(
-                &env,
-                member,
-                current_round,
-                grace_expires_at,
-            );
-            return;
+                .set(&DataKey::ApprovedTokens, &approved_tokens);
+            events::emit_tok_add(&env, token);
         }
+    }
 
-        let mut pending_penalties: Map<Address, u32> = env
+    pub fn remove_approved_token(env: Env, token: Address) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey4::PendingPenalties)
-            .unwrap_or(Map::new(&env));
-        pending_penalties.remove(member.clone());
-        env.storage()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        let approved_tokens: Vec<Address> = env
+            .storage()
             .instance()
-            .set(&DataKey4::PendingPenalties, &pending_penalties);
+            .get(&DataKey::ApprovedTokens)
+            .unwrap_or(Vec::new(&env));
 
-        Self::apply_penalty(&env, member, penalty_amount, current_round);
+        if approved_tokens.contains(&token) {
+            let mut new_approved_tokens: Vec<Address> = Vec::new(&env);
+            for t in approved_tokens.iter() {
+                if t != token {
+                    new_approved_tokens.push_back(t);
+                }
+            }
+            env.storage()
+                .instance()
+                .set(&DataKey::ApprovedTokens, &new_approved_tokens);
+            events::emit_tok_rmv(&env, token);
+        }
     }
 
-    fn process_pending_penalties(env: &Env) {
-        let mut pending_penalties: Map<Address, u32> = env
+    pub fn set_exchange_rate(env: Env, token: Address, rate: i128) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey4::PendingPenalties)
-            .unwrap_or(Map::new(env));
-        if pending_penalties.len() == 0 {
-            return;
-        }
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
 
-        let penalty_amount: i128 = env
+        let mut rates: Map<Address, i128> = env
             .storage()
             .instance()
-            .get(&DataKey::PenaltyAmount)
-            .unwrap_or(0);
-        if penalty_amount == 0 {
-            pending_penalties = Map::new(env);
-            env.storage()
-                .instance()
-                .set(&DataKey4::PendingPenalties, &pending_penalties);
-            return;
-        }
+            .get(&DataKey::ExchangeRates)
+            .unwrap_or(Map::new(&env));
 
-        let grace_period_ledgers: u32 = env
-            .storage()
+        rates.set(token.clone(), rate);
+        env.storage()
             .instance()
-            .get(&DataKey4::GracePeriodLedgers)
-            .unwrap_or(0);
-        let round_deadline: u64 = env
+            .set(&DataKey::ExchangeRates, &rates);
+        events::emit_rate_set(&env, token, rate);
+    }
+
+    pub fn set_token_limit(env: Env, token: Address, limit: i128) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey4::LastRoundDeadline)
-            .or(env.storage().instance().get(&DataKey::RoundDeadline))
-            .unwrap_or(0);
-        let grace_expires_at = round_deadline.saturating_add(grace_period_ledgers as u64);
-        let current_ledger = env.ledger().timestamp();
-        let current_round: u32 = env
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        let mut limits: Map<Address, i128> = env
             .storage()
             .instance()
-            .get(&DataKey::CurrentRound)
-            .unwrap_or(0);
+            .get(&DataKey::TokenLimits)
+            .unwrap_or(Map::new(&env));
 
-        let mut still_pending: Map<Address, u32> = Map::new(env);
-        for (member, pending_round) in pending_penalties.iter() {
-            if current_ledger > grace_expires_at || current_round > pending_round {
-                Self::apply_penalty(env, member, penalty_amount, current_round);
-            } else {
-                still_pending.set(member, pending_round);
-            }
-        }
+        limits.set(token.clone(), limit);
+        env.storage().instance().set(&DataKey::TokenLimits, &limits);
+        events::emit_lim_set(&env, token, limit);
+    }
 
+    pub fn bump_storage(env: Env) {
         env.storage()
             .instance()
-            .set(&DataKey4::PendingPenalties, &still_pending);
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        internals::extend_member_ttl(&env);
+        internals::extend_round_state_ttl(&env);
+        internals::record_ttl_floor(&env, INSTANCE_LIFETIME_THRESHOLD, PERSISTENT_LIFETIME_THRESHOLD);
     }
 
-    fn apply_penalty(env: &Env, member: Address, penalty_amount: i128, round: u32) {
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(env, &token_addr);
-
-        member.require_auth();
-        client.transfer(&member, &env.current_contract_address(), &penalty_amount);
-
-        let mut default_count: Map<Address, u32> = env
+    /// Extend the instance and long-lived persistent entries so they stay live
+    /// for at least `ledgers` more ledgers. Admin only; lets quiet groups that
+    /// go months without a write keep their state from being archived.
+    /// Rejects 0 and caps `ledgers` at the network's maximum TTL.
+    pub fn bump_ttl(env: Env, ledgers: u32) {
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey::DefaultCount)
-            .unwrap_or(Map::new(env));
-        let current_defaults = default_count.get(member.clone()).unwrap_or(0);
-        let new_default_count = current_defaults + 1;
-        default_count.set(member.clone(), new_default_count);
-        env.storage()
-            .instance()
-            .set(&DataKey::DefaultCount, &default_count);
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        if ledgers == 0 {
+            panic_with_error!(&env, ExtError::InvalidAmount);
+        }
+        let ledgers = ledgers.min(env.storage().max_ttl());
 
-        events::emit_defaulted(
-            env,
-            member.clone(),
-            round,
-            penalty_amount,
-            new_default_count,
-        );
-        // Confirmed default is applied here (not when merely pending).
-        Self::apply_reputation_delta(env, member.clone(), -20, "defaulted");
-        // Late-but-paid: member settled after defaulting.
-        Self::apply_reputation_delta(env, member.clone(), 5, "late_paid");
+        env.storage().instance().extend_ttl(ledgers, ledgers);
+        internals::extend_persistent_ttl(&env, ledgers, ledgers);
+        internals::record_ttl_floor(&env, ledgers, ledgers);
+    }
 
-        let max_defaults: u32 = env
+    /// Ledgers left before instance and persistent storage expire, so keepers
+    /// can call `bump_ttl` ahead of time. Contracts cannot read TTLs directly,
+    /// so these are floors derived from the last `init`, `bump_storage` or
+    /// `bump_ttl`; the real TTL may be longer.
+    pub fn storage_ttl(env: Env) -> StorageTtl {
+        let (instance_until, persistent_until): (u32, u32) = env
             .storage()
             .instance()
-            .get(&DataKey::MaxDefaults)
-            .unwrap_or(3);
-
-        if new_default_count >= max_defaults {
-            let mut suspended_members: Vec<Address> = env
-                .storage()
-                .instance()
-                .get(&DataKey::SuspendedMembers)
-                .unwrap_or(Vec::new(env));
-            if !suspended_members.contains(&member) {
-                suspended_members.push_back(member.clone());
-                env.storage()
-                    .instance()
-                    .set(&DataKey::SuspendedMembers, &suspended_members);
-                events::emit_suspended(env, member.clone(), new_default_count);
-                Self::try_promote_from_waitlist(env, &member);
-            }
+            .get(&DataKey4::TtlFloor)
+            .unwrap_or((0, 0));
+        let now = env.ledger().sequence();
+        StorageTtl {
+            instance: instance_until.saturating_sub(now),
+            persistent: persistent_until.saturating_sub(now),
         }
     }
 
-    fn apply_reputation_delta(env: &Env, member: Address, delta: i128, reason: &str) {
-        let mut scores: Map<Address, i128> = env
+    /// Moves the per-round paid list written by older versions from instance
+    /// storage into temporary storage. Admin only; returns how many entries
+    /// were moved, so a second call returns 0.
+    pub fn migrate_storage(env: Env) -> u32 {
+        let admin: Address = env
             .storage()
-            .persistent()
-            .get(&PersistentKey::ReputationScores)
-            .unwrap_or(Map::new(env));
-        let old_score = scores.get(member.clone()).unwrap_or(0);
-        let mut new_score = old_score + delta;
-        if new_score < 0 {
-            new_score = 0;
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        let mut moved = 0u32;
+        for key in internals::ROUND_STATE_KEYS {
+            if let Some(value) = env.storage().instance().get::<_, Val>(&key) {
+                internals::round_state_set(&env, &key, &value);
+                moved += 1;
+            }
         }
-        scores.set(member.clone(), new_score);
         env.storage()
-            .persistent()
-            .set(&PersistentKey::ReputationScores, &scores);
-        env.storage().persistent().extend_ttl(
-            &PersistentKey::ReputationScores,
-            PERSISTENT_LIFETIME_THRESHOLD,
-            PERSISTENT_BUMP_AMOUNT,
-        );
-        events::emit_reputation_updated(
-            env,
-            member,
-            old_score,
-            new_score,
-            Symbol::new(env, reason),
-        );
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        moved
     }
 
-    pub fn get_reputation_score(env: Env, member: Address) -> i128 {
-        let scores: Map<Address, i128> = env
+    /// Returns the contract's balance of the configured base token.
+    pub fn contract_balance(env: Env) -> i128 {
+        let token_addr: Address = env
             .storage()
-            .persistent()
-            .get(&PersistentKey::ReputationScores)
-            .unwrap_or(Map::new(&env));
-        env.storage().persistent().extend_ttl(
-            &PersistentKey::ReputationScores,
-            PERSISTENT_LIFETIME_THRESHOLD,
-            PERSISTENT_BUMP_AMOUNT,
-        );
-        scores.get(member).unwrap_or(0)
+            .instance()
+            .get(&DataKey::Token)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        token::Client::new(&env, &token_addr).balance(&env.current_contract_address())
     }
 
-    pub fn get_group_avg_reputation(env: Env) -> i128 {
-        let members: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::Members)
-            .unwrap_or(Vec::new(&env));
-        if members.is_empty() {
-            return 0;
-        }
-        let scores: Map<Address, i128> = env
-            .storage()
-            .persistent()
-            .get(&PersistentKey::ReputationScores)
-            .unwrap_or(Map::new(&env));
-        let mut total = 0i128;
-        for member in members.iter() {
-            total += scores.get(member).unwrap_or(0);
-        }
-        env.storage().persistent().extend_ttl(
-            &PersistentKey::ReputationScores,
-            PERSISTENT_LIFETIME_THRESHOLD,
-            PERSISTENT_BUMP_AMOUNT,
-        );
-        total / (members.len() as i128)
+    /// Current round's contributions held by the contract, kept apart from
+    /// security deposits so a pot never draws on deposit funds.
+    pub fn get_contribution_balance(env: Env) -> i128 {
+        internals::member_contributions_total(&env)
     }
 
-    pub fn add_member(env: Env, new_member: Address) {
+    /// Total security deposits held by the contract.
+    pub fn get_deposit_balance(env: Env) -> i128 {
+        internals::member_deposits_total(&env)
+    }
+
+    /// Admin caps the funds the contract may track at once (contributions,
+    /// deposits, prepayments and every pool). `contribute`, `post_deposit`
+    /// and `prepay` reject inflows that would exceed it. 0 removes the cap.
+    pub fn set_max_tvl(env: Env, max_tvl: i128) {
         internals::check_not_paused(&env);
-        internals::check_not_frozen(&env);
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
+        internals::note_admin_action(&env);
 
-        // Reject mid-round: paid_members must be empty
-        let paid_members: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::PaidMembers)
-            .unwrap_or(Vec::new(&env));
-        if !paid_members.is_empty() {
-            panic_with_error!(&env, Error::CannotChangeMidRound);
+        if max_tvl < 0 {
+            panic_with_error!(&env, ExtError::InvalidAmount);
         }
-
-        let mut members: Vec<Address> = env
-            .storage()
+        env.storage().instance().set(&DataKey5::MaxTvl, &max_tvl);
+        env.storage()
             .instance()
-            .get(&DataKey::Members)
-            .expect("Not initialized");
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
 
-        let max_members: u32 = env
+    /// Get the TVL cap (default: 0, no cap).
+    pub fn get_max_tvl(env: Env) -> i128 {
+        env.storage().instance().get(&DataKey5::MaxTvl).unwrap_or(0)
+    }
+
+    /// Funds the contract currently tracks: the total `set_max_tvl` caps.
+    pub fn current_tvl(env: Env) -> i128 {
+        internals::accounted_balance(&env)
+    }
+
+    /// Read-only health check for keepers: reports whether core accounting
+    /// and round invariants hold. See `InvariantReport`.
+    pub fn check_invariants(env: Env) -> InvariantReport {
+        let token_addr: Address = env
             .storage()
             .instance()
-            .get(&DataKey::MaxMembers)
-            .unwrap_or(50);
-
-        if (members.len() as u32) >= max_members {
-            panic_with_error!(&env, Error::GroupFull);
-        }
+            .get(&DataKey::Token)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let balance = token::Client::new(&env, &token_addr).balance(&env.current_contract_address());
 
-        if members.contains(&new_member) {
-            panic_with_error!(&env, Error::AlreadyAMember);
-        }
-        members.push_back(new_member.clone());
-        env.storage().instance().set(&DataKey::Members, &members);
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or(Vec::new(&env));
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        let paid_members_are_members = paid_members.iter().all(|m| members.contains(&m));
 
-        // Recalculate payout order: append new member to the end
-        let mut payout_order: Vec<Address> = env
+        let max_rounds: u32 = env
             .storage()
             .instance()
-            .get(&DataKey::PayoutOrder)
-            .expect("Payout order not set");
-        payout_order.push_back(new_member.clone());
-        env.storage()
+            .get(&DataKey4::MaxRounds)
+            .unwrap_or(0);
+        let group_status: GroupStatus = env
+            .storage()
             .instance()
-            .set(&DataKey::PayoutOrder, &payout_order);
+            .get(&DataKey2::GroupStatus)
+            .unwrap_or(GroupStatus::Active);
+        // The final payout brings the count to `max_rounds` and completes the group.
+        let paid_rounds = internals::rounds_paid_out(&env);
+        let round_within_max = max_rounds == 0
+            || paid_rounds < max_rounds
+            || (paid_rounds == max_rounds && group_status == GroupStatus::Completed);
 
-        events::emit_mem_add(&env, new_member, members.len() as u32);
+        InvariantReport {
+            balance_covers_accounted: balance >= internals::accounted_balance(&env),
+            paid_members_are_members,
+            round_within_max,
+        }
     }
 
-    pub fn remove_member(env: Env, member: Address) {
-        internals::check_not_paused(&env);
-        internals::check_not_frozen(&env);
+    /// Repair the round state if `PaidMembers` lists addresses that are no
+    /// longer in `Members`. Admin only.
+    ///
+    /// Stray entries are dropped from `PaidMembers` and their recorded
+    /// contribution is refunded, so the tracked contribution balance again
+    /// covers members only. Emits `Reconciled` when anything was fixed and
+    /// returns the number of entries removed; a healthy contract is left
+    /// untouched.
+    pub fn reconcile(env: Env) -> u32 {
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
-
-        // Reject mid-round: paid_members must be empty
-        let paid_members: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::PaidMembers)
-            .unwrap_or(Vec::new(&env));
-        if !paid_members.is_empty() {
-            panic_with_error!(&env, Error::CannotChangeMidRound);
-        }
+        internals::note_admin_action(&env);
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
-        if !members.contains(&member) {
-            panic_with_error!(&env, Error::NotAMember);
-        }
-
-        // Remove from members list
-        let mut new_members: Vec<Address> = Vec::new(&env);
-        for m in members.iter() {
-            if m != member {
-                new_members.push_back(m);
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        let mut kept: Vec<Address> = Vec::new(&env);
+        let mut removed: Vec<Address> = Vec::new(&env);
+        for m in paid_members.iter() {
+            if members.contains(&m) {
+                kept.push_back(m);
+            } else {
+                removed.push_back(m);
             }
         }
-        env.storage()
-            .instance()
-            .set(&DataKey::Members, &new_members);
+        if removed.is_empty() {
+            return 0;
+        }
+        internals::round_state_set(&env, &DataKey::PaidMembers, &kept);
 
-        // Recalculate payout order: filter out the member
-        let old_order: Vec<Address> = env
+        let token_addr: Address = env
             .storage()
             .instance()
-            .get(&DataKey::PayoutOrder)
-            .expect("Payout order not set");
-        let mut new_order: Vec<Address> = Vec::new(&env);
-        for m in old_order.iter() {
-            if m != member {
-                new_order.push_back(m);
+            .get(&DataKey::Token)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let token_client = token::Client::new(&env, &token_addr);
+        let mut member_contributions: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MemberContributions)
+            .unwrap_or(Map::new(&env));
+        let mut refunded: i128 = 0;
+        for m in removed.iter() {
+            let amount = member_contributions.get(m.clone()).unwrap_or(0);
+            member_contributions.remove(m.clone());
+            if amount > 0 {
+                token_client.transfer(&env.current_contract_address(), &m, &amount);
+                internals::record_fund_flow(
+                    &env,
+                    "refund",
+                    &env.current_contract_address(),
+                    &m,
+                    amount,
+                );
+                refunded += amount;
             }
         }
         env.storage()
             .instance()
-            .set(&DataKey::PayoutOrder, &new_order);
+            .set(&DataKey::MemberContributions, &member_contributions);
 
-        events::emit_mem_rmv(&env, member, new_members.len() as u32);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        let count = removed.len();
+        events::emit_reconciled(&env, removed, refunded);
+        count
     }
 
-    pub fn add_approved_token(env: Env, token: Address) {
-        internals::check_not_paused(&env);
+    /// Admin returns `member`'s contribution to the current round and marks
+    /// them unpaid, leaving everyone else's payment in place. Only possible
+    /// before the round pays out; afterwards the contribution is part of the
+    /// recipient's pot. Returns the amount refunded.
+    pub fn admin_refund(env: Env, member: Address) -> i128 {
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
+        internals::note_admin_action(&env);
 
-        let mut approved_tokens: Vec<Address> = env
+        let current_round: u32 = env
             .storage()
             .instance()
-            .get(&DataKey::ApprovedTokens)
-            .unwrap_or(Vec::new(&env));
-
-        if !approved_tokens.contains(&token) {
-            approved_tokens.push_back(token.clone());
-            env.storage()
-                .instance()
-                .set(&DataKey::ApprovedTokens, &approved_tokens);
-            events::emit_tok_add(&env, token);
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::RoundSettled(current_round))
+        {
+            panic_with_error!(&env, ExtError2::RoundAlreadySettled);
         }
-    }
-
-    pub fn remove_approved_token(env: Env, token: Address) {
-        internals::check_not_paused(&env);
-        let admin: Address = env
+        let mut member_contributions: Map<Address, i128> = env
             .storage()
             .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+            .get(&DataKey::MemberContributions)
+            .unwrap_or(Map::new(&env));
+        let amount = member_contributions.get(member.clone()).unwrap_or(0);
+        if amount <= 0 {
+            panic_with_error!(&env, ExtError3::NothingToRefund);
+        }
+        member_contributions.remove(member.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::MemberContributions, &member_contributions);
+        let mut paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        if let Some(index) = paid_members.first_index_of(&member) {
+            paid_members.remove(index);
+            internals::round_state_set(&env, &DataKey::PaidMembers, &paid_members);
+        }
 
-        let approved_tokens: Vec<Address> = env
+        let token_addr: Address = env
             .storage()
             .instance()
-            .get(&DataKey::ApprovedTokens)
-            .unwrap_or(Vec::new(&env));
+            .get(&DataKey::Token)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        token::Client::new(&env, &token_addr).transfer(
+            &env.current_contract_address(),
+            &member,
+            &amount,
+        );
+        internals::record_fund_flow(
+            &env,
+            "refund",
+            &env.current_contract_address(),
+            &member,
+            amount,
+        );
 
-        if approved_tokens.contains(&token) {
-            let mut new_approved_tokens: Vec<Address> = Vec::new(&env);
-            for t in approved_tokens.iter() {
-                if t != token {
-                    new_approved_tokens.push_back(t);
-                }
-            }
-            env.storage()
-                .instance()
-                .set(&DataKey::ApprovedTokens, &new_approved_tokens);
-            events::emit_tok_rmv(&env, token);
-        }
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        events::emit_admin_refund(&env, member, current_round, amount);
+        amount
     }
 
-    pub fn set_exchange_rate(env: Env, token: Address, rate: i128) {
-        internals::check_not_paused(&env);
+    /// Transfer any base-token balance not accounted for by the current round's
+    /// contributions or tracked reserves to `to`. Admin only.
+    ///
+    /// Recovers dust left by fee-on-transfer tokens, rounding, or direct transfers
+    /// so orphaned balance does not silently accumulate. Returns the amount swept.
+    pub fn sweep_dust(env: Env, to: Address) -> i128 {
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
+        internals::note_admin_action(&env);
 
-        let mut rates: Map<Address, i128> = env
-            .storage()
-            .instance()
-            .get(&DataKey::ExchangeRates)
-            .unwrap_or(Map::new(&env));
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let client = token::Client::new(&env, &token_addr);
+        let balance = client.balance(&env.current_contract_address());
+        let surplus = balance - internals::accounted_balance(&env);
+        if surplus <= 0 {
+            panic_with_error!(&env, ExtError2::NoSurplusBalance);
+        }
+
+        client.transfer(&env.current_contract_address(), &to, &surplus);
+        internals::record_fund_flow(&env, "rescue", &env.current_contract_address(), &to, surplus);
+        events::emit_dust_swept(&env, to, surplus);
 
-        rates.set(token.clone(), rate);
         env.storage()
             .instance()
-            .set(&DataKey::ExchangeRates, &rates);
-        events::emit_rate_set(&env, token, rate);
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        surplus
     }
 
-    pub fn set_token_limit(env: Env, token: Address, limit: i128) {
-        internals::check_not_paused(&env);
+    /// Transfer `amount` of a token sent to the contract by mistake to `to`.
+    /// Admin only. The contribution token and any approved contribution token
+    /// hold member funds and cannot be rescued; use `sweep_dust` for surplus
+    /// in the contribution token.
+    pub fn rescue_token(env: Env, token: Address, to: Address, amount: i128) {
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
+        internals::note_admin_action(&env);
 
-        let mut limits: Map<Address, i128> = env
+        let base_token: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let approved_tokens: Vec<Address> = env
             .storage()
             .instance()
-            .get(&DataKey::TokenLimits)
-            .unwrap_or(Map::new(&env));
+            .get(&DataKey::ApprovedTokens)
+            .unwrap_or(Vec::new(&env));
+        if token == base_token || approved_tokens.contains(&token) {
+            panic_with_error!(&env, ExtError3::CannotRescueContributionToken);
+        }
+        if amount <= 0 {
+            panic_with_error!(&env, Error::AmountMustBePositive);
+        }
 
-        limits.set(token.clone(), limit);
-        env.storage().instance().set(&DataKey::TokenLimits, &limits);
-        events::emit_lim_set(&env, token, limit);
-    }
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &to, &amount);
+        internals::record_fund_flow(&env, "rescue", &env.current_contract_address(), &to, amount);
+        events::emit_token_rescued(&env, token, to, amount);
 
-    pub fn bump_storage(env: Env) {
         env.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
@@ -3836,10 +7780,17 @@ impl AhjoorContract {
         internals::check_not_paused(&env);
         depositor.require_auth();
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         let client = token::Client::new(&env, &token_addr);
 
         client.transfer(&depositor, &env.current_contract_address(), &amount);
+        internals::record_fund_flow(
+            &env,
+            "reward",
+            &depositor,
+            &env.current_contract_address(),
+            amount,
+        );
 
         let mut reward_pool: i128 = env
             .storage()
@@ -3864,8 +7815,9 @@ impl AhjoorContract {
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
+        internals::note_admin_action(&env);
 
         env.storage()
             .instance()
@@ -3898,10 +7850,17 @@ impl AhjoorContract {
             .instance()
             .set(&DataKey::ClaimedRewards, &claimed_rewards);
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         let client = token::Client::new(&env, &token_addr);
 
         client.transfer(&env.current_contract_address(), &member, &claimable);
+        internals::record_fund_flow(
+            &env,
+            "reward",
+            &env.current_contract_address(),
+            &member,
+            claimable,
+        );
 
         events::emit_rew_clm(&env, member, claimable);
     }
@@ -3909,9 +7868,9 @@ impl AhjoorContract {
     pub fn get_claimable_reward(env: Env, member: Address) -> i128 {
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
             return 0;
         }
@@ -3983,6 +7942,122 @@ impl AhjoorContract {
         share - already_claimed
     }
 
+    /// Admin sets the reward paid per round of a member's on-time streak.
+    /// Zero disables streak rewards.
+    pub fn set_streak_reward(env: Env, per_round: i128) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        if per_round < 0 {
+            panic_with_error!(&env, ExtError::InvalidAmount);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey4::StreakReward, &per_round);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Admin chooses whether payout fees fund the reward pool instead of
+    /// being sent to the fee recipient. `set_fee_to_reserve` takes precedence.
+    pub fn set_fee_to_rewards(env: Env, enabled: bool) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        env.storage()
+            .instance()
+            .set(&DataKey4::FeeToRewards, &enabled);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Consecutive rounds `member` has paid in full before the deadline.
+    /// Paying late or defaulting resets it to zero.
+    pub fn get_on_time_streak(env: Env, member: Address) -> u32 {
+        let streaks: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey4::OnTimeStreaks)
+            .unwrap_or(Map::new(&env));
+        streaks.get(member).unwrap_or(0)
+    }
+
+    /// Pays `member` the streak reward (`streak × per-round reward`, capped at
+    /// the reward pool) from the reward pool. Claimable once per round; the
+    /// streak itself keeps running. Returns the amount paid.
+    pub fn claim_streak_reward(env: Env, member: Address) -> i128 {
+        internals::check_not_paused(&env);
+        member.require_auth();
+
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let mut claims: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey4::StreakClaims)
+            .unwrap_or(Map::new(&env));
+        if claims.get(member.clone()) == Some(current_round) {
+            panic_with_error!(&env, ExtError3::StreakRewardAlreadyClaimed);
+        }
+
+        let streak = Self::get_on_time_streak(env.clone(), member.clone());
+        let per_round: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::StreakReward)
+            .unwrap_or(0);
+        let reward_pool: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::RewardPool)
+            .unwrap_or(0);
+        let amount = (per_round * streak as i128).min(reward_pool);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::NoRewardsToClaim);
+        }
+
+        claims.set(member.clone(), current_round);
+        env.storage()
+            .instance()
+            .set(&DataKey4::StreakClaims, &claims);
+        env.storage()
+            .instance()
+            .set(&DataKey::RewardPool, &(reward_pool - amount));
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        token::Client::new(&env, &token_addr).transfer(&env.current_contract_address(), &member, &amount);
+        internals::record_fund_flow(
+            &env,
+            "reward",
+            &env.current_contract_address(),
+            &member,
+            amount,
+        );
+
+        events::emit_streak_reward_claimed(&env, member, streak, amount);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        amount
+    }
+
     // --- GOVERNANCE FUNCTIONS ---
 
     /// Member-initiated emergency freeze proposal.
@@ -3993,9 +8068,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
             panic_with_error!(&env, Error::OnlyMembersAllowed);
         }
@@ -4099,9 +8174,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&creator) {
             panic_with_error!(&env, Error::OnlyMembersAllowed);
         }
@@ -4215,9 +8290,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
             panic_with_error!(&env, Error::NotAMember);
         }
@@ -4229,15 +8304,11 @@ impl AhjoorContract {
             .unwrap_or(false);
 
         let deadline: u64 = if use_timestamp {
-            env.storage()
-                .instance()
-                .get(&DataKey::RoundDeadlineTimestamp)
-                .expect("Timestamp deadline not set")
+            internals::round_state_get(&env, &DataKey::RoundDeadlineTimestamp)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
         } else {
-            env.storage()
-                .instance()
-                .get(&DataKey::RoundDeadline)
-                .expect("Deadline not set")
+            internals::round_state_get(&env, &DataKey::RoundDeadline)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
         };
 
         if env.ledger().timestamp() > deadline {
@@ -4269,15 +8340,135 @@ impl AhjoorContract {
         preferences.get(member).unwrap_or(false)
     }
 
+    /// Attach an opaque blob (e.g. an encrypted contact handle or push topic)
+    /// to the caller's membership. Frontends read it back to send reminders.
+    pub fn set_member_metadata(env: Env, member: Address, data: Bytes) {
+        member.require_auth();
+
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if !members.contains(&member) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+        if data.len() > MAX_MEMBER_METADATA_LEN {
+            panic_with_error!(&env, ExtError2::MetadataTooLarge);
+        }
+
+        let key = PersistentKey::MemberMeta(member);
+        env.storage().persistent().set(&key, &data);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    /// Send the caller's payouts to `payout_to` (e.g. a cold wallet) instead
+    /// of the address they contribute from. Passing their own address goes
+    /// back to the default.
+    pub fn set_payout_address(env: Env, member: Address, payout_to: Address) {
+        member.require_auth();
+
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if !members.contains(&member) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+        if payout_to == env.current_contract_address() {
+            panic_with_error!(&env, ExtError2::ContractAddressNotAllowed);
+        }
+
+        let key = PersistentKey::PayoutAddress(member.clone());
+        if payout_to == member {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &payout_to);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+        events::emit_payout_address_set(&env, member, payout_to);
+    }
+
+    /// Admin splits the payout of rotation `slot` (a position in the payout
+    /// order) between co-owners. Shares are in basis points and must sum to
+    /// exactly 10000; an empty list restores the single-recipient payout.
+    /// Rounds paid through weights, reinvestment or a payout delay are not split.
+    pub fn set_slot_beneficiaries(env: Env, slot: u32, beneficiaries: Vec<SlotBeneficiary>) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        let payout_order: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PayoutOrder)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if slot >= payout_order.len() {
+            panic_with_error!(&env, ExtError2::InvalidSlotIndex);
+        }
+        let key = PersistentKey::SlotBeneficiaries(slot);
+        if beneficiaries.is_empty() {
+            env.storage().persistent().remove(&key);
+            return;
+        }
+        let mut total_bps: u32 = 0;
+        for entry in beneficiaries.iter() {
+            if entry.share_bps == 0 || entry.beneficiary == env.current_contract_address() {
+                panic_with_error!(&env, ExtError3::InvalidShares);
+            }
+            total_bps = total_bps.saturating_add(entry.share_bps);
+        }
+        if total_bps != 10_000 {
+            panic_with_error!(&env, ExtError3::InvalidShares);
+        }
+
+        env.storage().persistent().set(&key, &beneficiaries);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        events::emit_slot_beneficiaries_set(&env, slot, beneficiaries);
+    }
+
+    pub fn get_slot_beneficiaries(env: Env, slot: u32) -> Vec<SlotBeneficiary> {
+        env.storage()
+            .persistent()
+            .get(&PersistentKey::SlotBeneficiaries(slot))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Where `member`'s payouts go: their configured payout address, or the
+    /// member address itself.
+    pub fn get_payout_address(env: Env, member: Address) -> Address {
+        internals::payout_destination(&env, &member)
+    }
+
+    /// Metadata a member attached with `set_member_metadata`; empty if none.
+    pub fn get_member_metadata(env: Env, member: Address) -> Bytes {
+        env.storage()
+            .persistent()
+            .get(&PersistentKey::MemberMeta(member))
+            .unwrap_or(Bytes::new(&env))
+    }
+
     pub fn vote_on_proposal(env: Env, voter: Address, proposal_id: u32, vote_for: bool) {
         internals::check_not_paused(&env);
         voter.require_auth();
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&voter) {
             panic_with_error!(&env, Error::OnlyMembersAllowed);
         }
@@ -4430,9 +8621,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
 
         let mut proposals: Map<u32, Proposal> = env
             .storage()
@@ -4527,6 +8718,17 @@ impl AhjoorContract {
             ProposalType::MaxMembersUpdate => {
                 internals::execute_max_members_update(&env, proposal.execution_data);
             }
+            // Like `set_contribution_amount`, fails until the round has no
+            // payments yet; the proposal stays pending and can be retried.
+            ProposalType::ContributionAmountChange => {
+                let new_amount = proposal
+                    .execution_data
+                    .unwrap_or_else(|| panic_with_error!(&env, ExtError::InvalidAmount));
+                Self::apply_contribution_amount(&env, new_amount);
+            }
+            ProposalType::MemberAddition => {
+                Self::append_member(&env, &proposal.target_member);
+            }
             // #404: Reinstatement side-effects are executed by `reinstate_member`.
             // Save the proposal as Approved and return; the suspended member must
             // call reinstate_member to complete the process.
@@ -4604,17 +8806,18 @@ impl AhjoorContract {
         quorum_bps: u32,
     ) {
         admin.require_auth();
+        internals::note_admin_action(&env);
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
-            panic!("Only admin can set quorum per type");
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
 
         if quorum_bps < 100 || quorum_bps > 10000 {
-            panic!("Quorum must be between 1% and 100%");
+            panic_with_error!(&env, ExtError3::InvalidThreshold);
         }
 
         let mut quorum_config: Map<ProposalType, u32> = env
@@ -4646,13 +8849,14 @@ impl AhjoorContract {
         max_emergency_per_cycle: u32,
     ) {
         admin.require_auth();
+        internals::note_admin_action(&env);
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
-            panic!("Only admin can set emergency payout config");
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
 
         if emergency_quorum_bps < 1000 || emergency_quorum_bps > 10000 {
@@ -4702,9 +8906,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
             panic_with_error!(&env, Error::NotAMember);
         }
@@ -4733,9 +8937,9 @@ impl AhjoorContract {
             .unwrap_or(Map::new(&env));
         let payout_order: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::PayoutOrder)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         let cycle_index = current_round / (payout_order.len() as u32);
         if approved.get((cycle_index, member.clone())).unwrap_or(false) {
             panic_with_error!(&env, ExtError::EmergencyPayoutAlreadyExecuted);
@@ -4803,14 +9007,14 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&voter) {
             panic_with_error!(&env, Error::OnlyMembersAllowed);
         }
         if voter == requester {
-            panic!("Cannot vote on your own emergency payout request");
+            panic_with_error!(&env, ExtError3::CannotVoteOwnRequest);
         }
 
         let current_round: u32 = env
@@ -4825,12 +9029,12 @@ impl AhjoorContract {
             .get(&DataKey2::EmergencyPayoutRequests)
             .unwrap_or(Map::new(&env));
         if !requests.contains_key((current_round, requester.clone())) {
-            panic!("Emergency payout request not found");
+            panic_with_error!(&env, ExtError3::EmergencyPayoutNotFound);
         }
 
         let mut request = requests.get((current_round, requester.clone())).unwrap();
         if request.executed {
-            panic!("Emergency payout already executed");
+            panic_with_error!(&env, ExtError::EmergencyPayoutAlreadyExecuted);
         }
 
         let now = env.ledger().timestamp();
@@ -4845,7 +9049,7 @@ impl AhjoorContract {
             .get(&DataKey2::EmergencyPayoutVotes)
             .unwrap_or(Map::new(&env));
         if votes.get((current_round, requester.clone(), voter.clone())).unwrap_or(false) {
-            panic!("Already voted on this emergency payout request");
+            panic_with_error!(&env, Error::AlreadyVoted);
         }
 
         // Record vote
@@ -4907,7 +9111,7 @@ impl AhjoorContract {
             .get(&DataKey2::EmergencyPayoutRequests)
             .unwrap_or(Map::new(&env));
         if !requests.contains_key((current_round, requester.clone())) {
-            panic!("Emergency payout request not found");
+            panic_with_error!(&env, ExtError3::EmergencyPayoutNotFound);
         }
 
         let mut request = requests.get((current_round, requester.clone())).unwrap();
@@ -4933,9 +9137,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         let voting_mode: VotingMode = env
             .storage()
             .instance()
@@ -4985,9 +9189,9 @@ impl AhjoorContract {
         // Mark as approved for this cycle
         let payout_order: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::PayoutOrder)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         let cycle_index = current_round / (payout_order.len() as u32);
         let mut approved: Map<(u32, Address), bool> = env
             .storage()
@@ -5017,23 +9221,26 @@ impl AhjoorContract {
             .instance()
             .get(&DataKey::ContributionAmt)
             .unwrap_or(0);
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
 
         // Transfer funds to requester
         let client = token::Client::new(&env, &token_addr);
         client.transfer(&env.current_contract_address(), &requester, &contribution_amount);
+        internals::record_fund_flow(
+            &env,
+            "payout",
+            &env.current_contract_address(),
+            &requester,
+            contribution_amount,
+        );
 
         // Mark requester as paid for this round
-        let mut paid_members: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::PaidMembers)
-            .expect("Not initialized");
+        let mut paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !paid_members.contains(&requester) {
+            internals::note_round_opened(&env, &paid_members);
             paid_members.push_back(requester.clone());
-            env.storage()
-                .instance()
-                .set(&DataKey::PaidMembers, &paid_members);
+            internals::round_state_set(&env, &DataKey::PaidMembers, &paid_members);
         }
 
         events::emit_emergency_payout_executed(&env, requester.clone(), current_round, contribution_amount);
@@ -5053,13 +9260,14 @@ impl AhjoorContract {
         vote_window_seconds: u64,
     ) {
         admin.require_auth();
+        internals::note_admin_action(&env);
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
-            panic!("Only admin can set dissolution config");
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
 
         if dissolution_quorum_bps < 1000 || dissolution_quorum_bps > 10000 {
@@ -5084,16 +9292,24 @@ impl AhjoorContract {
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
     }
 
-    /// Admin initiates group dissolution.
+    /// Admin initiates group dissolution. With a co-admin threshold above 1
+    /// it only runs as a `propose_action` proposal (action 4).
     pub fn dissolve_group(env: Env, admin: Address, reason_hash: BytesN<32>) {
+        Self::require_no_quorum(&env);
+        Self::dissolve_group_internal(env, admin, reason_hash);
+    }
+
+    /// Body of `dissolve_group`; also run by a passed member dissolution vote.
+    fn dissolve_group_internal(env: Env, admin: Address, reason_hash: BytesN<32>) {
         admin.require_auth();
+        internals::note_admin_action(&env);
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
-            panic!("Only admin can dissolve group");
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
 
         let group_status: GroupStatus = env
@@ -5107,12 +9323,12 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
 
         // Calculate total pool
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         let client = token::Client::new(&env, &token_addr);
         let total_pool = client.balance(&env.current_contract_address());
 
@@ -5144,6 +9360,13 @@ impl AhjoorContract {
                 let share = (contribution * total_pool) / total_contributions;
                 if share > 0 {
                     client.transfer(&env.current_contract_address(), &member, &share);
+                    internals::record_fund_flow(
+                        &env,
+                        "refund",
+                        &env.current_contract_address(),
+                        &member,
+                        share,
+                    );
                     events::emit_member_refunded(&env, member.clone(), share, contribution, total_pool);
                 }
             }
@@ -5158,8 +9381,22 @@ impl AhjoorContract {
                 .get::<DataKey2, Address>(&DataKey2::FeeRecipient)
             {
                 client.transfer(&env.current_contract_address(), &fee_recipient, &remaining);
+                internals::record_fund_flow(
+                    &env,
+                    "refund",
+                    &env.current_contract_address(),
+                    &fee_recipient,
+                    remaining,
+                );
             } else if let Some(first_member) = members.get(0) {
                 client.transfer(&env.current_contract_address(), &first_member, &remaining);
+                internals::record_fund_flow(
+                    &env,
+                    "refund",
+                    &env.current_contract_address(),
+                    &first_member,
+                    remaining,
+                );
             }
         }
 
@@ -5192,9 +9429,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
             panic_with_error!(&env, Error::NotAMember);
         }
@@ -5263,9 +9500,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&delegator) {
             panic_with_error!(&env, Error::NotAMember);
         }
@@ -5292,10 +9529,10 @@ impl AhjoorContract {
         };
 
         if expiry_ledger == 0 {
-            panic!("expiry cannot be 0; infinite delegation is not allowed");
+            panic_with_error!(&env, ExtError3::InvalidExpiry);
         }
         if expiry_ledger <= current_time {
-            panic!("expiry must be in the future");
+            panic_with_error!(&env, ExtError3::InvalidExpiry);
         }
 
         let mut contrib_delegations: Map<Address, ContribDelegationRecord> = env
@@ -5359,9 +9596,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&voter) {
             panic_with_error!(&env, Error::OnlyMembersAllowed);
         }
@@ -5379,7 +9616,7 @@ impl AhjoorContract {
             .unwrap_or(Map::new(&env));
         let deadline: u64 = dissolution_deadlines.get(current_round).unwrap_or(0);
         if deadline == 0 {
-            panic!("No dissolution vote in progress");
+            panic_with_error!(&env, ExtError3::NoDissolutionVote);
         }
 
         if env.ledger().timestamp() > deadline {
@@ -5393,7 +9630,7 @@ impl AhjoorContract {
             .get(&DataKey2::DissolutionVotes)
             .unwrap_or(Map::new(&env));
         if votes.get((current_round, voter.clone())).unwrap_or(false) {
-            panic!("Already voted on dissolution");
+            panic_with_error!(&env, Error::AlreadyVoted);
         }
 
         // Record vote
@@ -5417,117 +9654,385 @@ impl AhjoorContract {
         vote_counts.set(current_round, votes_for);
         env.storage()
             .instance()
-            .set(&DataKey2::DissolutionVoteCount, &vote_counts);
-
-        events::emit_dissolution_vote_cast(&env, current_round, voter, approve, votes_for);
-
+            .set(&DataKey2::DissolutionVoteCount, &vote_counts);
+
+        events::emit_dissolution_vote_cast(&env, current_round, voter, approve, votes_for);
+
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Execute dissolution if quorum is met.
+    pub fn execute_dissolution(env: Env) {
+        internals::check_not_paused(&env);
+
+        let group_status: GroupStatus = env
+            .storage()
+            .instance()
+            .get(&DataKey2::GroupStatus)
+            .unwrap_or(GroupStatus::Active);
+        if group_status == GroupStatus::Dissolved {
+            panic_with_error!(&env, ExtError::GroupAlreadyDissolved);
+        }
+
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+
+        let dissolution_deadlines: Map<u32, u64> = env
+            .storage()
+            .instance()
+            .get(&DataKey2::DissolutionDeadline)
+            .unwrap_or(Map::new(&env));
+        let deadline: u64 = dissolution_deadlines.get(current_round).unwrap_or(0);
+        if deadline == 0 {
+            panic_with_error!(&env, ExtError3::NoDissolutionVote);
+        }
+
+        if env.ledger().timestamp() <= deadline {
+            panic_with_error!(&env, Error::VotingNotEnded);
+        }
+
+        let config: DissolutionConfig = env
+            .storage()
+            .instance()
+            .get(&DataKey2::DissolutionConfig)
+            .unwrap_or(DissolutionConfig {
+                dissolution_quorum_bps: 7500,
+                vote_window_seconds: 14 * 24 * 60 * 60,
+            });
+
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let voting_mode: VotingMode = env
+            .storage()
+            .instance()
+            .get(&DataKey2::VotingMode)
+            .unwrap_or(VotingMode::Equal);
+
+        let total_possible_votes = match voting_mode {
+            VotingMode::Equal => members.len() as i128,
+            VotingMode::WeightedByContributions => {
+                let contributions: Map<Address, i128> = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::MemberContributions)
+                    .unwrap_or(Map::new(&env));
+                let mut total = 0i128;
+                for member in members.iter() {
+                    total += contributions.get(member).unwrap_or(0);
+                }
+                total
+            }
+        };
+
+        let vote_counts: Map<u32, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey2::DissolutionVoteCount)
+            .unwrap_or(Map::new(&env));
+        let votes_for: i128 = vote_counts.get(current_round).unwrap_or(0);
+
+        let required_votes = ((total_possible_votes * config.dissolution_quorum_bps as i128) + 9999) / 10000;
+
+        if votes_for < required_votes {
+            panic_with_error!(&env, ExtError::DissolutionQuorumNotMet);
+        }
+
+        events::emit_dissolution_quorum_reached(&env, current_round, votes_for);
+
+        // Execute dissolution with empty reason hash
+        let reason_hash = BytesN::<32>::from_array(&env, &[0u8; 32]);
+        Self::dissolve_group_internal(
+            env.clone(),
+            env.storage()
+                .instance()
+                .get(&DataKey::Admin)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized)),
+            reason_hash,
+        );
+    }
+
+    /// Admin cancels the group mid-cycle: refunds every contribution made in the
+    /// current round, any pot carried over from earlier rounds, open
+    /// slot-auction deposits, pending boosts and members' security deposits,
+    /// releases held payouts, splits the shared pools (reserves, rewards,
+    /// insurance, treasury, late fees) equally across remaining members,
+    /// clears round state, and marks the group dissolved so
+    /// `contribute` and `close_round` reject. `init` is blocked for
+    /// `REINIT_COOLDOWN_SECONDS` afterwards. With a co-admin threshold above 1
+    /// it only runs as a `propose_action` proposal (action 4).
+    pub fn dissolve(env: Env) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        Self::require_no_quorum(&env);
+        Self::dissolve_internal(env);
+    }
+
+    /// Body of `dissolve`; callers have already authorized the admin.
+    fn dissolve_internal(env: Env) {
+        let group_status: GroupStatus = env
+            .storage()
+            .instance()
+            .get(&DataKey2::GroupStatus)
+            .unwrap_or(GroupStatus::Active);
+        if group_status == GroupStatus::Dissolved {
+            panic_with_error!(&env, ExtError::GroupAlreadyDissolved);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let client = token::Client::new(&env, &token_addr);
+
+        let member_contributions: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MemberContributions)
+            .unwrap_or(Map::new(&env));
+        let bids: Vec<SlotBid> = env
+            .storage()
+            .instance()
+            .get(&DataKey3::AuctionBids)
+            .unwrap_or(Vec::new(&env));
+
+        let mut total_refunded: i128 = 0;
+        for (_, amount) in member_contributions.iter() {
+            total_refunded += amount;
+        }
+        for bid in bids.iter() {
+            total_refunded += bid.amount;
+        }
+        let deposits: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey4::MemberDeposits)
+            .unwrap_or(Map::new(&env));
+        for (_, amount) in deposits.iter() {
+            total_refunded += amount;
+        }
+        let boosts = internals::take_boosts(&env);
+        for (_, amount) in boosts.iter() {
+            total_refunded += amount;
+        }
+        let carried = internals::take_carry_over(&env);
+        for (_, amount) in carried.iter() {
+            total_refunded += amount;
+        }
+        // Held pots were already earned, so they are released now instead of
+        // waiting out the payout delay.
+        let pending: Vec<PendingPayout> = env
+            .storage()
+            .instance()
+            .get(&DataKey4::PendingPayouts)
+            .unwrap_or(Vec::new(&env));
+        for payout in pending.iter() {
+            total_refunded += payout.amounts.get(token_addr.clone()).unwrap_or(0);
+        }
+        let mut prepaid: Map<Address, i128> = Map::new(&env);
+        let group_members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or(Vec::new(&env));
+        for member in group_members.iter() {
+            let amount: i128 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Prepaid(member.clone()))
+                .unwrap_or(0);
+            if amount > 0 {
+                total_refunded += amount;
+                prepaid.set(member, amount);
+            }
+        }
+        // Shared pools have no single owner: they are split equally across the
+        // members who have not exited, the indivisible remainder going to the
+        // first of them.
+        let pooled = internals::shared_pools_total(&env);
+        total_refunded += pooled;
+        let mut pool_shares: Map<Address, i128> = Map::new(&env);
+        if pooled > 0 {
+            let exited_members: Vec<Address> = env
+                .storage()
+                .instance()
+                .get(&DataKey::ExitedMembers)
+                .unwrap_or(Vec::new(&env));
+            let mut recipients: Vec<Address> = Vec::new(&env);
+            for member in group_members.iter() {
+                if !exited_members.contains(&member) {
+                    recipients.push_back(member);
+                }
+            }
+            if recipients.is_empty() {
+                recipients = group_members.clone();
+            }
+            let share = pooled / recipients.len() as i128;
+            let remainder = pooled - share * recipients.len() as i128;
+            for (i, member) in recipients.iter().enumerate() {
+                let amount = if i == 0 { share + remainder } else { share };
+                if amount > 0 {
+                    pool_shares.set(member, amount);
+                }
+            }
+        }
+
+        for (member, amount) in member_contributions.iter() {
+            if amount > 0 {
+                client.transfer(&env.current_contract_address(), &member, &amount);
+                internals::record_fund_flow(
+                    &env,
+                    "refund",
+                    &env.current_contract_address(),
+                    &member,
+                    amount,
+                );
+                events::emit_member_refunded(&env, member, amount, amount, total_refunded);
+            }
+        }
+        for bid in bids.iter() {
+            if bid.amount > 0 {
+                client.transfer(&env.current_contract_address(), &bid.bidder, &bid.amount);
+                internals::record_fund_flow(
+                    &env,
+                    "refund",
+                    &env.current_contract_address(),
+                    &bid.bidder,
+                    bid.amount,
+                );
+                events::emit_member_refunded(&env, bid.bidder, bid.amount, 0, total_refunded);
+            }
+        }
+        for (member, amount) in deposits.iter() {
+            if amount > 0 {
+                client.transfer(&env.current_contract_address(), &member, &amount);
+                internals::record_fund_flow(
+                    &env,
+                    "refund",
+                    &env.current_contract_address(),
+                    &member,
+                    amount,
+                );
+                events::emit_member_refunded(&env, member, amount, 0, total_refunded);
+            }
+        }
+        for (member, amount) in prepaid.iter() {
+            client.transfer(&env.current_contract_address(), &member, &amount);
+            internals::record_fund_flow(
+                &env,
+                "refund",
+                &env.current_contract_address(),
+                &member,
+                amount,
+            );
+            events::emit_member_refunded(&env, member.clone(), amount, 0, total_refunded);
+            env.storage().persistent().remove(&DataKey::Prepaid(member));
+        }
+        for (member, amount) in carried.iter() {
+            client.transfer(&env.current_contract_address(), &member, &amount);
+            internals::record_fund_flow(
+                &env,
+                "refund",
+                &env.current_contract_address(),
+                &member,
+                amount,
+            );
+            events::emit_member_refunded(&env, member, amount, amount, total_refunded);
+        }
+        Self::refund_boosts(&env, &boosts);
+        for payout in pending.iter() {
+            let to = internals::payout_destination(&env, &payout.recipient);
+            for (payout_token, amount) in payout.amounts.iter() {
+                token::Client::new(&env, &payout_token).transfer(
+                    &env.current_contract_address(),
+                    &to,
+                    &amount,
+                );
+                internals::record_fund_flow(
+                    &env,
+                    "payout",
+                    &env.current_contract_address(),
+                    &to,
+                    amount,
+                );
+            }
+            events::emit_payout_released(&env, payout.round, payout.recipient);
+        }
+        for (member, amount) in pool_shares.iter() {
+            client.transfer(&env.current_contract_address(), &member, &amount);
+            internals::record_fund_flow(
+                &env,
+                "refund",
+                &env.current_contract_address(),
+                &member,
+                amount,
+            );
+            events::emit_member_refunded(&env, member, amount, 0, total_refunded);
+        }
+
+        internals::round_state_set(&env, &DataKey::PaidMembers, &Vec::<Address>::new(&env));
+        env.storage()
+            .instance()
+            .set(&DataKey::MemberContributions, &Map::<Address, i128>::new(&env));
+        env.storage()
+            .instance()
+            .set(&DataKey::Defaulters, &Vec::<Address>::new(&env));
+        env.storage()
+            .instance()
+            .set(&DataKey3::AuctionBids, &Vec::<SlotBid>::new(&env));
         env.storage()
             .instance()
-            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-    }
-
-    /// Execute dissolution if quorum is met.
-    pub fn execute_dissolution(env: Env) {
-        internals::check_not_paused(&env);
-
-        let group_status: GroupStatus = env
-            .storage()
+            .remove(&DataKey4::MemberDeposits);
+        env.storage().instance().remove(&DataKey4::PendingPayouts);
+        env.storage().instance().remove(&DataKey4::Disputed);
+        internals::clear_pools(&env);
+        env.storage()
             .instance()
-            .get(&DataKey2::GroupStatus)
-            .unwrap_or(GroupStatus::Active);
-        if group_status == GroupStatus::Dissolved {
-            panic_with_error!(&env, ExtError::GroupAlreadyDissolved);
-        }
+            .set(&DataKey2::GroupStatus, &GroupStatus::Dissolved);
+        env.storage()
+            .instance()
+            .set(&DataKey4::DissolvedAt, &env.ledger().timestamp());
 
         let current_round: u32 = env
             .storage()
             .instance()
             .get(&DataKey::CurrentRound)
             .unwrap_or(0);
-
-        let dissolution_deadlines: Map<u32, u64> = env
-            .storage()
-            .instance()
-            .get(&DataKey2::DissolutionDeadline)
-            .unwrap_or(Map::new(&env));
-        let deadline: u64 = dissolution_deadlines.get(current_round).unwrap_or(0);
-        if deadline == 0 {
-            panic!("No dissolution vote in progress");
-        }
-
-        if env.ledger().timestamp() <= deadline {
-            panic!("Voting period not ended");
-        }
-
-        let config: DissolutionConfig = env
-            .storage()
-            .instance()
-            .get(&DataKey2::DissolutionConfig)
-            .unwrap_or(DissolutionConfig {
-                dissolution_quorum_bps: 7500,
-                vote_window_seconds: 14 * 24 * 60 * 60,
-            });
-
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
-        let voting_mode: VotingMode = env
-            .storage()
-            .instance()
-            .get(&DataKey2::VotingMode)
-            .unwrap_or(VotingMode::Equal);
-
-        let total_possible_votes = match voting_mode {
-            VotingMode::Equal => members.len() as i128,
-            VotingMode::WeightedByContributions => {
-                let contributions: Map<Address, i128> = env
-                    .storage()
-                    .instance()
-                    .get(&DataKey::MemberContributions)
-                    .unwrap_or(Map::new(&env));
-                let mut total = 0i128;
-                for member in members.iter() {
-                    total += contributions.get(member).unwrap_or(0);
-                }
-                total
-            }
-        };
+            .unwrap_or(Vec::new(&env));
+        // A re-initialized group starts again from round 0.
+        internals::clear_round_settlements(&env, current_round + 1);
+        events::emit_group_dissolved(
+            &env,
+            current_round,
+            BytesN::<32>::from_array(&env, &[0u8; 32]),
+            total_refunded,
+            members.len(),
+        );
 
-        let vote_counts: Map<u32, i128> = env
-            .storage()
+        env.storage()
             .instance()
-            .get(&DataKey2::DissolutionVoteCount)
-            .unwrap_or(Map::new(&env));
-        let votes_for: i128 = vote_counts.get(current_round).unwrap_or(0);
-
-        let required_votes = ((total_possible_votes * config.dissolution_quorum_bps as i128) + 9999) / 10000;
-
-        if votes_for < required_votes {
-            panic_with_error!(&env, ExtError::DissolutionQuorumNotMet);
-        }
-
-        events::emit_dissolution_quorum_reached(&env, current_round, votes_for);
-
-        // Execute dissolution with empty reason hash
-        let reason_hash = BytesN::<32>::from_array(&env, &[0u8; 32]);
-        Self::dissolve_group(
-            env.clone(),
-            env.storage()
-                .instance()
-                .get(&DataKey::Admin)
-                .expect("Not initialized"),
-            reason_hash,
-        );
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
     }
 
     // --- READ INTERFACE ---
 
     pub fn get_group_info(env: Env) -> GroupInfo {
-        let members: Vec<Address> = env.storage().instance().get(&DataKey::Members).unwrap();
+        let members: Vec<Address> = env.storage().persistent().get(&DataKey::Members).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         let payout_order: Vec<Address> =
-            env.storage().instance().get(&DataKey::PayoutOrder).unwrap();
+            env.storage().persistent().get(&DataKey::PayoutOrder).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         let current_round: u32 = env
             .storage()
             .instance()
@@ -5544,134 +10049,452 @@ impl AhjoorContract {
                 .instance()
                 .get(&DataKey::ContributionAmt)
                 .unwrap_or(0),
-            token: env.storage().instance().get(&DataKey::Token).unwrap(),
+            token: env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized)),
             current_round,
             total_rounds: payout_order.len(),
-            paid_members: env
-                .storage()
-                .instance()
-                .get(&DataKey::PaidMembers)
+            paid_members: internals::round_state_get(&env, &DataKey::PaidMembers)
                 .unwrap_or(Vec::new(&env)),
             next_recipient,
             round_deadline: {
                 let use_timestamp: bool = env
                     .storage()
                     .instance()
-                    .get(&DataKey2::UseTimestampSchedule)
-                    .unwrap_or(false);
-                if use_timestamp {
-                    env.storage()
-                        .instance()
-                        .get(&DataKey::RoundDeadlineTimestamp)
-                        .unwrap_or(0)
-                } else {
+                    .get(&DataKey2::UseTimestampSchedule)
+                    .unwrap_or(false);
+                if use_timestamp {
+                    internals::round_state_get(&env, &DataKey::RoundDeadlineTimestamp)
+                        .unwrap_or(0)
+                } else {
+                    internals::round_state_get(&env, &DataKey::RoundDeadline)
+                        .unwrap_or(0)
+                }
+            },
+        }
+    }
+
+    /// Returns aggregate on-chain analytics for the group in a single view call.
+    /// Reads members, suspended/exited sets, round state, credit scores, and
+    /// reputation scores — all from existing storage; no writes performed.
+    pub fn get_group_analytics(env: Env) -> GroupAnalytics {
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or(Vec::new(&env));
+        let suspended: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::SuspendedMembers)
+            .unwrap_or(Vec::new(&env));
+        let exited: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ExitedMembers)
+            .unwrap_or(Vec::new(&env));
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        let defaulters: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Defaulters)
+            .unwrap_or(Vec::new(&env));
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        let payout_order: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PayoutOrder)
+            .unwrap_or(Vec::new(&env));
+        let total_collected: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalCollected)
+            .unwrap_or(0);
+        let fee_bps: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::FeeBps)
+            .unwrap_or(0);
+
+        let total_members = members.len() as u32;
+        let suspended_count = suspended.len() as u32;
+        let exited_count = exited.len() as u32;
+        let active_members = total_members
+            .saturating_sub(suspended_count)
+            .saturating_sub(exited_count);
+
+        let credit_scores: Map<Address, MemberScore> = env
+            .storage()
+            .persistent()
+            .get(&PersistentKey::MemberCreditScores)
+            .unwrap_or(Map::new(&env));
+        let rep_scores: Map<Address, i128> = env
+            .storage()
+            .persistent()
+            .get(&PersistentKey::ReputationScores)
+            .unwrap_or(Map::new(&env));
+
+        let mut total_credit = 0i128;
+        let mut total_rep = 0i128;
+        for m in members.iter() {
+            total_credit += credit_scores.get(m.clone()).map(|ms| ms.score).unwrap_or(0);
+            total_rep += rep_scores.get(m.clone()).unwrap_or(0);
+        }
+        let n = members.len() as i128;
+        let avg_credit_score = if n > 0 { total_credit / n } else { 0 };
+        let avg_reputation_score = if n > 0 { total_rep / n } else { 0 };
+
+        GroupAnalytics {
+            total_members,
+            active_members,
+            suspended_count,
+            exited_count,
+            current_round,
+            total_rounds: payout_order.len() as u32,
+            paid_this_round: paid_members.len() as u32,
+            defaulters_this_round: defaulters.len() as u32,
+            total_contributions_collected: total_collected,
+            avg_credit_score,
+            avg_reputation_score,
+            fee_bps,
+        }
+    }
+
+    /// Returns true once `init` has run. Safe to poll on a fresh deployment.
+    pub fn is_initialized(env: Env) -> bool {
+        env.storage().persistent().has(&DataKey::Members)
+    }
+
+    /// Returns true once every round up to `MaxRounds` has been paid out.
+    pub fn is_complete(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey2::GroupStatus)
+            .unwrap_or(GroupStatus::Active)
+            == GroupStatus::Completed
+    }
+
+    /// Returns true when every active member has received exactly one payout
+    /// this cycle. Suspended and exited members are not required to have
+    /// been paid, but no recipient may appear in the history more than once.
+    /// A member who declined their turn still counts once paid at the end.
+    pub fn rotation_complete(env: Env) -> bool {
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or(Vec::new(&env));
+        let history: Vec<PayoutRecord> = env
+            .storage()
+            .persistent()
+            .get(&PersistentKey::RoundHistory)
+            .unwrap_or(Vec::new(&env));
+        let mut paid_out: Vec<Address> = Vec::new(&env);
+        for record in history.iter() {
+            if paid_out.contains(&record.recipient) {
+                return false;
+            }
+            paid_out.push_back(record.recipient);
+        }
+
+        let suspended_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::SuspendedMembers)
+            .unwrap_or(Vec::new(&env));
+        let exited_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ExitedMembers)
+            .unwrap_or(Vec::new(&env));
+        let admin_suspended = internals::admin_suspended(&env);
+        members.iter().all(|member| {
+            suspended_members.contains(&member)
+                || exited_members.contains(&member)
+                || admin_suspended.contains(&member)
+                || paid_out.contains(&member)
+        })
+    }
+
+    /// Admin ejects members who miss `max_consecutive` rounds in a row at
+    /// `close_round`, suspending or removing them per `policy`. Zero turns
+    /// ejection off.
+    pub fn set_auto_eject(env: Env, max_consecutive: u32, policy: EjectionPolicy) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        env.storage()
+            .instance()
+            .set(&DataKey5::MaxConsecutiveDefaults, &max_consecutive);
+        env.storage()
+            .instance()
+            .set(&DataKey5::EjectionPolicy, &policy);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Rounds in a row `member` has been closed out as a defaulter.
+    pub fn get_consecutive_defaults(env: Env, member: Address) -> u32 {
+        let streaks: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey5::ConsecutiveDefaults)
+            .unwrap_or(Map::new(&env));
+        streaks.get(member).unwrap_or(0)
+    }
+
+    fn eject_member(env: &Env, member: &Address) {
+        let policy: EjectionPolicy = env
+            .storage()
+            .instance()
+            .get(&DataKey5::EjectionPolicy)
+            .unwrap_or(EjectionPolicy::Suspend);
+        let count = Self::get_consecutive_defaults(env.clone(), member.clone());
+        match policy {
+            EjectionPolicy::Suspend => {
+                let mut suspended_members: Vec<Address> = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::SuspendedMembers)
+                    .unwrap_or(Vec::new(env));
+                if !suspended_members.contains(member) {
+                    suspended_members.push_back(member.clone());
                     env.storage()
                         .instance()
-                        .get(&DataKey::RoundDeadline)
-                        .unwrap_or(0)
+                        .set(&DataKey::SuspendedMembers, &suspended_members);
                 }
-            },
+            }
+            EjectionPolicy::Remove => internals::drop_member(env, member),
         }
+        events::emit_member_ejected(env, member.clone(), count, policy);
     }
 
-    /// Returns aggregate on-chain analytics for the group in a single view call.
-    /// Reads members, suspended/exited sets, round state, credit scores, and
-    /// reputation scores — all from existing storage; no writes performed.
-    pub fn get_group_analytics(env: Env) -> GroupAnalytics {
-        let members: Vec<Address> = env
+    /// Admin chooses what `finalize_cycle` does with leftover funds. Defaults
+    /// to `RolloverToNextCycle`.
+    pub fn set_end_of_cycle_policy(env: Env, policy: EndOfCyclePolicy) {
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey::Members)
-            .unwrap_or(Vec::new(&env));
-        let suspended: Vec<Address> = env
-            .storage()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        env.storage()
             .instance()
-            .get(&DataKey::SuspendedMembers)
-            .unwrap_or(Vec::new(&env));
-        let exited: Vec<Address> = env
+            .set(&DataKey4::EndOfCyclePolicy, &policy);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    pub fn get_end_of_cycle_policy(env: Env) -> EndOfCyclePolicy {
+        env.storage()
+            .instance()
+            .get(&DataKey4::EndOfCyclePolicy)
+            .unwrap_or(EndOfCyclePolicy::RolloverToNextCycle)
+    }
+
+    /// Settles leftover funds once the cycle is complete, following
+    /// `EndOfCyclePolicy`. Leftovers are everything except security deposits,
+    /// held payouts and auction escrow: reserve, reward, insurance and
+    /// treasury balances, late fees and any untracked surplus. Callable by
+    /// anyone. Returns the base-token amount settled.
+    pub fn finalize_cycle(env: Env) -> i128 {
+        if !Self::is_complete(env.clone()) {
+            panic_with_error!(&env, ExtError2::CycleNotComplete);
+        }
+        let base_token: Address = env
             .storage()
             .instance()
-            .get(&DataKey::ExitedMembers)
-            .unwrap_or(Vec::new(&env));
-        let paid_members: Vec<Address> = env
+            .get(&DataKey::Token)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let approved_tokens: Vec<Address> = env
             .storage()
             .instance()
-            .get(&DataKey::PaidMembers)
+            .get(&DataKey::ApprovedTokens)
             .unwrap_or(Vec::new(&env));
-        let defaulters: Vec<Address> = env
+        let residual = internals::residual_balance(&env, &base_token);
+        let policy = Self::get_end_of_cycle_policy(env.clone());
+
+        // Other approved tokens are swept the same way; under rollover they
+        // stay put and go out with the next cycle's first pot.
+        let mut transfers: Vec<(Address, Address, i128)> = Vec::new(&env);
+        match policy {
+            EndOfCyclePolicy::RolloverToNextCycle => {
+                internals::clear_pools(&env);
+                env.storage()
+                    .instance()
+                    .set(&DataKey4::ReservePool, &residual);
+            }
+            EndOfCyclePolicy::SendToAdmin => {
+                let admin: Address = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::Admin)
+                    .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+                for token_addr in approved_tokens.iter() {
+                    let amount = internals::residual_balance(&env, &token_addr);
+                    if amount > 0 {
+                        transfers.push_back((token_addr, admin.clone(), amount));
+                    }
+                }
+                internals::clear_pools(&env);
+            }
+            EndOfCyclePolicy::DistributeEqually => {
+                let members: Vec<Address> = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::Members)
+                    .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+                let exited_members: Vec<Address> = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::ExitedMembers)
+                    .unwrap_or(Vec::new(&env));
+                let mut recipients: Vec<Address> = Vec::new(&env);
+                for member in members.iter() {
+                    if !exited_members.contains(&member) {
+                        recipients.push_back(member);
+                    }
+                }
+                if recipients.is_empty() {
+                    panic_with_error!(&env, ExtError::NoFundsToDistribute);
+                }
+                // The indivisible remainder goes to the first recipient so
+                // nothing is left behind.
+                for token_addr in approved_tokens.iter() {
+                    let amount = internals::residual_balance(&env, &token_addr);
+                    let share = amount / recipients.len() as i128;
+                    let remainder = amount - share * recipients.len() as i128;
+                    for (i, member) in recipients.iter().enumerate() {
+                        let payout = if i == 0 { share + remainder } else { share };
+                        if payout > 0 {
+                            transfers.push_back((token_addr.clone(), member, payout));
+                        }
+                    }
+                }
+                internals::clear_pools(&env);
+            }
+        }
+
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        events::emit_cycle_finalized(&env, policy, residual);
+        for (token_addr, to, amount) in transfers.iter() {
+            token::Client::new(&env, &token_addr).transfer(
+                &env.current_contract_address(),
+                &to,
+                &amount,
+            );
+            internals::record_fund_flow(
+                &env,
+                "payout",
+                &env.current_contract_address(),
+                &to,
+                amount,
+            );
+        }
+        residual
+    }
+
+    /// Admin starts another cycle on the same terms once a finite cycle is
+    /// complete: the round counter returns to 0, payout history and defaulters
+    /// are cleared, and a fresh deadline is set. Members, amount, token and
+    /// `MaxRounds` carry over.
+    pub fn start_new_cycle(env: Env) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey::Defaulters)
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        if !Self::is_complete(env.clone()) {
+            panic_with_error!(&env, ExtError2::CycleNotComplete);
+        }
+        if !Self::rotation_complete(env.clone()) {
+            panic_with_error!(&env, ExtError3::RotationIncomplete);
+        }
+
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
             .unwrap_or(Vec::new(&env));
-        let current_round: u32 = env
+        for member in members.iter() {
+            env.storage()
+                .persistent()
+                .remove(&PersistentKey::PayoutRounds(member));
+        }
+        env.storage().persistent().remove(&PersistentKey::RoundHistory);
+        let finished_rounds: u32 = env
             .storage()
             .instance()
             .get(&DataKey::CurrentRound)
             .unwrap_or(0);
-        let payout_order: Vec<Address> = env
-            .storage()
+        internals::clear_round_settlements(&env, finished_rounds);
+        env.storage()
             .instance()
-            .get(&DataKey::PayoutOrder)
-            .unwrap_or(Vec::new(&env));
-        let total_collected: i128 = env
-            .storage()
+            .set(&DataKey::Defaulters, &Vec::<Address>::new(&env));
+        env.storage().instance().set(&DataKey::CurrentRound, &0u32);
+        env.storage().instance().remove(&DataKey5::RoundsPaidOut);
+        env.storage().instance().remove(&DataKey5::LastClosedRound);
+        env.storage()
             .instance()
-            .get(&DataKey::TotalCollected)
-            .unwrap_or(0);
-        let fee_bps: u32 = env
+            .set(&DataKey2::GroupStatus, &GroupStatus::Active);
+        // Also refunds anything reinvested into the round after the final payout.
+        Self::refund_round(&env, None);
+
+        let cycle_number: u32 = env
             .storage()
             .instance()
-            .get(&DataKey::FeeBps)
-            .unwrap_or(0);
+            .get(&DataKey4::CycleNumber)
+            .unwrap_or(0)
+            + 1;
+        env.storage()
+            .instance()
+            .set(&DataKey4::CycleNumber, &cycle_number);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        events::emit_cycle(&env, cycle_number);
+    }
 
-        let total_members = members.len() as u32;
-        let suspended_count = suspended.len() as u32;
-        let exited_count = exited.len() as u32;
-        let active_members = total_members
-            .saturating_sub(suspended_count)
-            .saturating_sub(exited_count);
+    /// Number of cycles started with `start_new_cycle` (0 during the first).
+    pub fn get_cycle_number(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey4::CycleNumber)
+            .unwrap_or(0)
+    }
 
-        let credit_scores: Map<Address, MemberScore> = env
-            .storage()
-            .persistent()
-            .get(&PersistentKey::MemberCreditScores)
-            .unwrap_or(Map::new(&env));
-        let rep_scores: Map<Address, i128> = env
-            .storage()
+    /// Returns true if `addr` is in the member list. Returns false (rather than
+    /// panicking) when the contract has not been initialized.
+    pub fn is_member(env: Env, addr: Address) -> bool {
+        env.storage()
             .persistent()
-            .get(&PersistentKey::ReputationScores)
-            .unwrap_or(Map::new(&env));
-
-        let mut total_credit = 0i128;
-        let mut total_rep = 0i128;
-        for m in members.iter() {
-            total_credit += credit_scores.get(m.clone()).map(|ms| ms.score).unwrap_or(0);
-            total_rep += rep_scores.get(m.clone()).unwrap_or(0);
-        }
-        let n = members.len() as i128;
-        let avg_credit_score = if n > 0 { total_credit / n } else { 0 };
-        let avg_reputation_score = if n > 0 { total_rep / n } else { 0 };
-
-        GroupAnalytics {
-            total_members,
-            active_members,
-            suspended_count,
-            exited_count,
-            current_round,
-            total_rounds: payout_order.len() as u32,
-            paid_this_round: paid_members.len() as u32,
-            defaulters_this_round: defaulters.len() as u32,
-            total_contributions_collected: total_collected,
-            avg_credit_score,
-            avg_reputation_score,
-            fee_bps,
-        }
+            .get::<_, Vec<Address>>(&DataKey::Members)
+            .map(|members| members.contains(&addr))
+            .unwrap_or(false)
     }
 
     pub fn get_member_status(env: Env, member: Address) -> MemberStatus {
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
             .unwrap_or(Vec::new(&env));
         let is_member = members.contains(&member);
@@ -5697,10 +10520,7 @@ impl AhjoorContract {
             .unwrap_or(Map::new(&env));
         let contributions_this_round = member_contributions.get(member.clone()).unwrap_or(0);
 
-        let paid_members: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::PaidMembers)
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
             .unwrap_or(Vec::new(&env));
         let has_paid_this_round = paid_members.contains(&member);
 
@@ -5732,49 +10552,239 @@ impl AhjoorContract {
         }
     }
 
+    /// Every member's full state in one call, in member-list order, for
+    /// reports and migrations. Bounded by the group's member cap.
+    pub fn member_report(env: Env) -> Vec<MemberReport> {
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or(Vec::new(&env));
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        let member_collected: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MemberCollected)
+            .unwrap_or(Map::new(&env));
+        let default_count: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::DefaultCount)
+            .unwrap_or(Map::new(&env));
+        let debts: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey4::MemberDebt)
+            .unwrap_or(Map::new(&env));
+        let deposits: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey4::MemberDeposits)
+            .unwrap_or(Map::new(&env));
+        let streaks: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey4::OnTimeStreaks)
+            .unwrap_or(Map::new(&env));
+
+        let mut report = Vec::new(&env);
+        for member in members.iter() {
+            report.push_back(MemberReport {
+                member: member.clone(),
+                has_paid_this_round: paid_members.contains(&member),
+                lifetime_contributions: member_collected.get(member.clone()).unwrap_or(0),
+                default_count: default_count.get(member.clone()).unwrap_or(0),
+                debt: debts.get(member.clone()).unwrap_or(0),
+                deposit: deposits.get(member.clone()).unwrap_or(0),
+                on_time_streak: streaks.get(member.clone()).unwrap_or(0),
+                payouts_received: Self::get_payout_rounds(env.clone(), member).len(),
+            });
+        }
+        report
+    }
+
     /// Returns `(amount_contributed_so_far, amount_remaining)` for `member`
     /// in the current round.
     /// Returns the full MemberContributions map for the current round.
     pub fn get_round_contributions(env: Env) -> Map<Address, i128> {
         env.storage()
             .instance()
-            .get(&DataKey::MemberContributions)
-            .unwrap_or(Map::new(&env))
+            .get(&DataKey::MemberContributions)
+            .unwrap_or(Map::new(&env))
+    }
+
+    pub fn get_member_contribution_status(env: Env, member: Address) -> (i128, i128) {
+        let target = internals::member_required_amount(&env, &member);
+        let member_contributions: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MemberContributions)
+            .unwrap_or(Map::new(&env));
+        let contributed = member_contributions.get(member).unwrap_or(0);
+        let remaining = target - contributed;
+        (contributed, remaining)
+    }
+
+    pub fn get_round_history(env: Env) -> Vec<PayoutRecord> {
+        env.storage()
+            .persistent()
+            .get(&PersistentKey::RoundHistory)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Returns the persisted outcome of a finished round.
+    pub fn get_round_record(env: Env, round: u32) -> RoundRecord {
+        env.storage()
+            .persistent()
+            .get(&PersistentKey::RoundRecord(round))
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::RoundNotFound))
+    }
+
+    /// Number of `RoundRecord` entries held in persistent storage. Each one
+    /// costs rent until it is removed with `prune_history`.
+    pub fn history_size(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey5::HistorySize)
+            .unwrap_or(0)
+    }
+
+    /// Admin deletes the round records of every round before `before_round`
+    /// to reclaim storage. A `HistoryPruned` event summarising the deleted
+    /// rounds is emitted first so indexers keep the totals. Returns the number
+    /// of records removed.
+    pub fn prune_history(env: Env, before_round: u32) -> u32 {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        if before_round > current_round {
+            panic_with_error!(&env, ExtError2::RoundNotFound);
+        }
+
+        let mut removed: u32 = 0;
+        let mut total_pot: i128 = 0;
+        let mut total_defaults: u32 = 0;
+        let mut doomed: Vec<u32> = Vec::new(&env);
+        for round in 0..before_round {
+            if let Some(record) = env
+                .storage()
+                .persistent()
+                .get::<_, RoundRecord>(&PersistentKey::RoundRecord(round))
+            {
+                removed += 1;
+                total_pot += record.pot;
+                total_defaults += record.defaulters.len();
+                doomed.push_back(round);
+            }
+        }
+        if removed == 0 {
+            return 0;
+        }
+        events::emit_history_pruned(&env, before_round, removed, total_pot, total_defaults);
+        for round in doomed.iter() {
+            env.storage()
+                .persistent()
+                .remove(&PersistentKey::RoundRecord(round));
+        }
+
+        let size = Self::history_size(env.clone()).saturating_sub(removed);
+        env.storage().instance().set(&DataKey5::HistorySize, &size);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        removed
     }
 
-    pub fn get_member_contribution_status(env: Env, member: Address) -> (i128, i128) {
-        let target: i128 = env
+    /// Everything `member` did or received in each finished round, from the
+    /// persisted round records, in ascending round order. Within a round a
+    /// payment is listed before the payout it completed.
+    pub fn member_timeline(env: Env, member: Address) -> Vec<TimelineEntry> {
+        let current_round: u32 = env
             .storage()
             .instance()
-            .get(&DataKey::ContributionAmt)
+            .get(&DataKey::CurrentRound)
             .unwrap_or(0);
-        let member_contributions: Map<Address, i128> = env
+        let mut timeline: Vec<TimelineEntry> = Vec::new(&env);
+        for round in 0..current_round {
+            let Some(record) = env
+                .storage()
+                .persistent()
+                .get::<_, RoundRecord>(&PersistentKey::RoundRecord(round))
+            else {
+                continue;
+            };
+            let mut actions: Vec<Symbol> = Vec::new(&env);
+            if record.paid.contains(&member) {
+                actions.push_back(symbol_short!("paid"));
+            }
+            if record.recipient.as_ref() == Some(&member) {
+                actions.push_back(symbol_short!("payout"));
+            }
+            if record.defaulters.contains(&member) {
+                actions.push_back(symbol_short!("defaulted"));
+            }
+            if record.excused.contains(&member) {
+                actions.push_back(symbol_short!("excused"));
+            }
+            for action in actions.iter() {
+                timeline.push_back(TimelineEntry { round, action });
+            }
+        }
+        timeline
+    }
+
+    /// Outcome of the most recently settled round: shorthand for
+    /// `get_round_record(current_round - 1)`. Panics with `RoundNotFound`
+    /// before any round has settled.
+    pub fn last_round_outcome(env: Env) -> RoundRecord {
+        let current_round: u32 = env
             .storage()
             .instance()
-            .get(&DataKey::MemberContributions)
-            .unwrap_or(Map::new(&env));
-        let contributed = member_contributions.get(member).unwrap_or(0);
-        let remaining = target - contributed;
-        (contributed, remaining)
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        if current_round == 0 {
+            panic_with_error!(&env, ExtError2::RoundNotFound);
+        }
+        env.storage()
+            .persistent()
+            .get(&PersistentKey::RoundRecord(current_round - 1))
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::RoundNotFound))
     }
 
-    pub fn get_round_history(env: Env) -> Vec<PayoutRecord> {
-        env.storage()
+    /// Defaulters recorded for a finished `round` (empty if everyone paid).
+    /// Panics with `RoundNotFound` for rounds that have not finished.
+    pub fn get_round_defaulters(env: Env, round: u32) -> Vec<Address> {
+        let record: RoundRecord = env
+            .storage()
             .persistent()
-            .get(&PersistentKey::RoundHistory)
-            .unwrap_or(Vec::new(&env))
+            .get(&PersistentKey::RoundRecord(round))
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::RoundNotFound));
+        record.defaulters
     }
 
+    /// Returns `(round, paid_members, deadline, strategy, token)`.
+    ///
+    /// `paid_members` is in canonical member order rather than arrival order,
+    /// so two members contributing in the same ledger always resolve the same
+    /// way regardless of transaction ordering.
     pub fn get_state(env: Env) -> (u32, Vec<Address>, u64, PayoutStrategy, Address) {
         let current_round: u32 = env
             .storage()
             .instance()
             .get(&DataKey::CurrentRound)
             .unwrap_or(0);
-        let paid_members: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::PaidMembers)
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
             .unwrap_or(Vec::new(&env));
 
         let use_timestamp: bool = env
@@ -5784,14 +10794,10 @@ impl AhjoorContract {
             .unwrap_or(false);
 
         let deadline: u64 = if use_timestamp {
-            env.storage()
-                .instance()
-                .get(&DataKey::RoundDeadlineTimestamp)
+            internals::round_state_get(&env, &DataKey::RoundDeadlineTimestamp)
                 .unwrap_or(0)
         } else {
-            env.storage()
-                .instance()
-                .get(&DataKey::RoundDeadline)
+            internals::round_state_get(&env, &DataKey::RoundDeadline)
                 .unwrap_or(0)
         };
 
@@ -5800,10 +10806,119 @@ impl AhjoorContract {
             .instance()
             .get(&DataKey::Strategy)
             .unwrap_or(PayoutStrategy::RoundRobin);
-        let token: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let paid_members = internals::canonical_paid_order(&env, &paid_members);
         (current_round, paid_members, deadline, strategy, token)
     }
 
+    /// Returns the group's configuration and current round state in one read.
+    pub fn get_full_state(env: Env) -> FullState {
+        let (round, paid_members, deadline, _, token) = Self::get_state(env.clone());
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let contribution_amount: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ContributionAmt)
+            .unwrap_or(0);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let defaulters: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Defaulters)
+            .unwrap_or(Vec::new(&env));
+
+        FullState {
+            round,
+            paid_members,
+            deadline,
+            members,
+            contribution_amount,
+            token,
+            admin,
+            defaulters,
+        }
+    }
+
+    /// Aggregate statistics for a summary card. A cycle is `MaxRounds` long
+    /// when set, otherwise one round per entry in the payout order.
+    pub fn get_stats(env: Env) -> GroupStats {
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+
+        let mut total_defaults: u32 = 0;
+        for round in 0..current_round {
+            let record: Option<RoundRecord> = env
+                .storage()
+                .persistent()
+                .get(&PersistentKey::RoundRecord(round));
+            if let Some(record) = record {
+                total_defaults += record.defaulters.len();
+            }
+        }
+
+        let max_rounds: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::MaxRounds)
+            .unwrap_or(0);
+        let completion_pct = if Self::is_complete(env.clone()) {
+            100
+        } else if let Some(pct) =
+            (internals::rounds_paid_out(&env).min(max_rounds) * 100).checked_div(max_rounds)
+        {
+            pct
+        } else {
+            let order_len = Self::get_payout_order(env.clone()).len();
+            current_round
+                .checked_rem(order_len)
+                .map_or(0, |r| r * 100 / order_len)
+        };
+
+        GroupStats {
+            total_members: members.len(),
+            current_round,
+            total_distributed: Self::total_distributed(env.clone()),
+            reserve_balance: Self::get_reserve(env.clone()),
+            total_defaults,
+            completion_pct,
+        }
+    }
+
+    /// Everything a frontend needs for the current round in one read: state,
+    /// who has paid, the scheduled recipient, time left and summary stats.
+    /// History is left out to keep the result small.
+    pub fn snapshot(env: Env) -> Snapshot {
+        let state = Self::get_full_state(env.clone());
+        let mut paid = Map::new(&env);
+        for member in state.members.iter() {
+            paid.set(member.clone(), state.paid_members.contains(&member));
+        }
+        let time_remaining = state.deadline.saturating_sub(env.ledger().timestamp());
+        Snapshot {
+            paid,
+            recipient: internals::scheduled_recipient(&env),
+            time_remaining,
+            stats: Self::get_stats(env.clone()),
+            state,
+        }
+    }
+
     pub fn emit_deadline_reminder(env: Env, interval: Symbol) {
         internals::check_not_paused(&env);
 
@@ -5819,25 +10934,18 @@ impl AhjoorContract {
             .unwrap_or(false);
 
         let deadline: u64 = if use_timestamp {
-            env.storage()
-                .instance()
-                .get(&DataKey::RoundDeadlineTimestamp)
+            internals::round_state_get(&env, &DataKey::RoundDeadlineTimestamp)
                 .unwrap_or(0)
         } else {
-            env.storage()
-                .instance()
-                .get(&DataKey::RoundDeadline)
+            internals::round_state_get(&env, &DataKey::RoundDeadline)
                 .unwrap_or(0)
         };
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
-        let paid_members: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::PaidMembers)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
             .unwrap_or(Vec::new(&env));
         let exited_members: Vec<Address> = env
             .storage()
@@ -5881,14 +10989,10 @@ impl AhjoorContract {
             .unwrap_or(false);
 
         let current_deadline: u64 = if use_timestamp {
-            env.storage()
-                .instance()
-                .get(&DataKey::RoundDeadlineTimestamp)
+            internals::round_state_get(&env, &DataKey::RoundDeadlineTimestamp)
                 .unwrap_or(0)
         } else {
-            env.storage()
-                .instance()
-                .get(&DataKey::RoundDeadline)
+            internals::round_state_get(&env, &DataKey::RoundDeadline)
                 .unwrap_or(0)
         };
 
@@ -5918,9 +11022,7 @@ impl AhjoorContract {
     }
 
     pub fn get_next_deadline_timestamp(env: Env) -> u64 {
-        env.storage()
-            .instance()
-            .get(&DataKey::RoundDeadlineTimestamp)
+        internals::round_state_get(&env, &DataKey::RoundDeadlineTimestamp)
             .unwrap_or(0)
     }
 
@@ -5967,99 +11069,375 @@ impl AhjoorContract {
     pub fn get_exchange_rates(env: Env) -> Map<Address, i128> {
         env.storage()
             .instance()
-            .get(&DataKey::ExchangeRates)
-            .unwrap_or(Map::new(&env))
+            .get(&DataKey::ExchangeRates)
+            .unwrap_or(Map::new(&env))
+    }
+
+    pub fn get_token_limits(env: Env) -> Map<Address, i128> {
+        env.storage()
+            .instance()
+            .get(&DataKey::TokenLimits)
+            .unwrap_or(Map::new(&env))
+    }
+
+    pub fn get_approved_tokens(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ApprovedTokens)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_proposal(env: Env, proposal_id: u32) -> Option<Proposal> {
+        let proposals: Map<u32, Proposal> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Proposals)
+            .unwrap_or(Map::new(&env));
+        proposals.get(proposal_id)
+    }
+
+    pub fn get_proposal_counter(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ProposalCounter)
+            .unwrap_or(0)
+    }
+
+    pub fn get_member_vote(env: Env, proposal_id: u32, member: Address) -> bool {
+        let proposal_votes: Map<u32, Map<Address, bool>> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ProposalVotes)
+            .unwrap_or(Map::new(&env));
+        if let Some(votes) = proposal_votes.get(proposal_id) {
+            votes.get(member).unwrap_or(false)
+        } else {
+            false
+        }
+    }
+
+    /// Admin opens a yes/no poll under `topic` that members can answer until
+    /// `deadline`. Lighter than proposals: nothing executes on the outcome.
+    /// A topic can be reused once its previous poll has closed.
+    pub fn quick_poll(env: Env, topic: Symbol, deadline: u64) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        let now = env.ledger().timestamp();
+        if deadline <= now {
+            panic_with_error!(&env, Error::VotingDeadlinePassed);
+        }
+        let key = PersistentKey::Poll(topic.clone());
+        if let Some(existing) = env.storage().persistent().get::<_, QuickPoll>(&key) {
+            if now <= existing.deadline {
+                panic_with_error!(&env, ExtError3::PollAlreadyOpen);
+            }
+        }
+        let poll = QuickPoll {
+            deadline,
+            yes: 0,
+            no: 0,
+            voters: Vec::new(&env),
+        };
+        env.storage().persistent().set(&key, &poll);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        events::emit_poll_opened(&env, topic, deadline);
+    }
+
+    /// Member answers the poll under `topic`; one vote each, before the deadline.
+    pub fn cast(env: Env, member: Address, topic: Symbol, yes: bool) {
+        internals::check_not_paused(&env);
+        member.require_auth();
+
+        if !Self::is_member(env.clone(), member.clone()) {
+            panic_with_error!(&env, Error::OnlyMembersAllowed);
+        }
+        let key = PersistentKey::Poll(topic.clone());
+        let mut poll: QuickPoll = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError3::PollNotFound));
+        if env.ledger().timestamp() > poll.deadline {
+            panic_with_error!(&env, Error::VotingDeadlinePassed);
+        }
+        if poll.voters.contains(&member) {
+            panic_with_error!(&env, Error::AlreadyVoted);
+        }
+
+        poll.voters.push_back(member.clone());
+        if yes {
+            poll.yes += 1;
+        } else {
+            poll.no += 1;
+        }
+        env.storage().persistent().set(&key, &poll);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        events::emit_poll_vote_cast(&env, topic, member, yes);
+    }
+
+    /// (yes, no) tally of the poll under `topic`, open or closed.
+    pub fn poll_result(env: Env, topic: Symbol) -> (u32, u32) {
+        let poll: QuickPoll = env
+            .storage()
+            .persistent()
+            .get(&PersistentKey::Poll(topic))
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError3::PollNotFound));
+        (poll.yes, poll.no)
+    }
+
+    pub fn get_quorum_percentage(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::QuorumPercentage)
+            .unwrap_or(51)
+    }
+
+    /// Update the protocol fee configuration. Admin only.
+    /// Fee is capped at 500 bps (5%).
+    pub fn update_fee(env: Env, new_fee_bps: u32) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        if new_fee_bps > 500 {
+            panic_with_error!(&env, Error::FeeExceedsMaximum);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::FeeBps, &new_fee_bps);
+
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Get the current protocol fee in basis points.
+    pub fn get_fee_bps(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::FeeBps)
+            .unwrap_or(0)
+    }
+
+    /// Get the protocol fee recipient address.
+    pub fn get_fee_recipient(env: Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey2::FeeRecipient)
+    }
+
+    /// Admin chooses whether payout fees accumulate in the on-chain reserve
+    /// (which tops up short pots) instead of being sent to the fee recipient.
+    pub fn set_fee_to_reserve(env: Env, enabled: bool) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        env.storage()
+            .instance()
+            .set(&DataKey4::FeeToReserve, &enabled);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
     }
 
-    pub fn get_token_limits(env: Env) -> Map<Address, i128> {
+    /// Get the fee-funded reserve balance.
+    pub fn get_reserve(env: Env) -> i128 {
         env.storage()
             .instance()
-            .get(&DataKey::TokenLimits)
-            .unwrap_or(Map::new(&env))
+            .get(&DataKey4::ReservePool)
+            .unwrap_or(0)
     }
 
-    pub fn get_approved_tokens(env: Env) -> Vec<Address> {
+    /// Admin splits the reserve equally among current (non-exited) members.
+    /// Only the tracked reserve is paid out, never round contributions or
+    /// deposits; the indivisible remainder stays in the reserve.
+    pub fn distribute_reserve(env: Env) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let exited_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ExitedMembers)
+            .unwrap_or(Vec::new(&env));
+        let mut recipients: Vec<Address> = Vec::new(&env);
+        for member in members.iter() {
+            if !exited_members.contains(&member) {
+                recipients.push_back(member);
+            }
+        }
+
+        let reserve_pool: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::ReservePool)
+            .unwrap_or(0);
+        if recipients.is_empty() {
+            panic_with_error!(&env, ExtError::NoFundsToDistribute);
+        }
+        let share = reserve_pool / recipients.len() as i128;
+        if share <= 0 {
+            panic_with_error!(&env, ExtError::NoFundsToDistribute);
+        }
+        let distributed = share * recipients.len() as i128;
         env.storage()
             .instance()
-            .get(&DataKey::ApprovedTokens)
-            .unwrap_or(Vec::new(&env))
-    }
+            .set(&DataKey4::ReservePool, &(reserve_pool - distributed));
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 
-    pub fn get_proposal(env: Env, proposal_id: u32) -> Option<Proposal> {
-        let proposals: Map<u32, Proposal> = env
+        let token_addr: Address = env
             .storage()
             .instance()
-            .get(&DataKey::Proposals)
-            .unwrap_or(Map::new(&env));
-        proposals.get(proposal_id)
+            .get(&DataKey::Token)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let client = token::Client::new(&env, &token_addr);
+        for member in recipients.iter() {
+            client.transfer(&env.current_contract_address(), &member, &share);
+            internals::record_fund_flow(
+                &env,
+                "payout",
+                &env.current_contract_address(),
+                &member,
+                share,
+            );
+        }
+        events::emit_reserve_distributed(&env, distributed, share, recipients.len());
     }
 
-    pub fn get_proposal_counter(env: Env) -> u32 {
+    /// Member posts a security deposit. Deposits are held outside the pot;
+    /// `close_round` slashes `SlashBps` of a defaulter's deposit into the
+    /// reserve; whatever remains is refunded when the member exits, the cycle
+    /// finishes or the group is dissolved.
+    pub fn post_deposit(env: Env, member: Address, amount: i128) {
+        member.require_auth();
+        internals::check_not_paused(&env);
+        if !Self::is_member(env.clone(), member.clone()) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+        if amount <= 0 {
+            panic_with_error!(&env, Error::AmountMustBePositive);
+        }
+
+        Self::collect_deposit(&env, &member, amount);
         env.storage()
             .instance()
-            .get(&DataKey::ProposalCounter)
-            .unwrap_or(0)
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
     }
 
-    pub fn get_member_vote(env: Env, proposal_id: u32, member: Address) -> bool {
-        let proposal_votes: Map<u32, Map<Address, bool>> = env
+    /// Transfers `amount` from `member` and adds it to their security deposit.
+    fn collect_deposit(env: &Env, member: &Address, amount: i128) {
+        internals::check_deposits_open(env);
+        internals::check_tvl(env, amount);
+        let token_addr: Address = env
             .storage()
             .instance()
-            .get(&DataKey::ProposalVotes)
-            .unwrap_or(Map::new(&env));
-        if let Some(votes) = proposal_votes.get(proposal_id) {
-            votes.get(member).unwrap_or(false)
-        } else {
-            false
-        }
-    }
+            .get(&DataKey::Token)
+            .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
+        token::Client::new(env, &token_addr).transfer(
+            member,
+            env.current_contract_address(),
+            &amount,
+        );
+        internals::record_fund_flow(
+            env,
+            "deposit",
+            member,
+            &env.current_contract_address(),
+            amount,
+        );
 
-    pub fn get_quorum_percentage(env: Env) -> u32 {
+        let mut deposits: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey4::MemberDeposits)
+            .unwrap_or(Map::new(env));
+        let balance = deposits.get(member.clone()).unwrap_or(0) + amount;
+        deposits.set(member.clone(), balance);
         env.storage()
             .instance()
-            .get(&DataKey::QuorumPercentage)
-            .unwrap_or(51)
+            .set(&DataKey4::MemberDeposits, &deposits);
+
+        events::emit_deposit_posted(env, member.clone(), amount, balance);
     }
 
-    /// Update the protocol fee configuration. Admin only.
-    /// Fee is capped at 500 bps (5%).
-    pub fn update_fee(env: Env, new_fee_bps: u32) {
+    /// Get a member's current security deposit.
+    pub fn get_deposit(env: Env, member: Address) -> i128 {
+        let deposits: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey4::MemberDeposits)
+            .unwrap_or(Map::new(&env));
+        deposits.get(member).unwrap_or(0)
+    }
+
+    /// Admin sets the share of a defaulter's deposit (in basis points) that
+    /// `close_round` moves into the reserve.
+    pub fn set_slash_bps(env: Env, slash_bps: u32) {
         internals::check_not_paused(&env);
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
+        internals::note_admin_action(&env);
 
-        if new_fee_bps > 500 {
-            panic_with_error!(&env, Error::FeeExceedsMaximum);
+        if slash_bps > 10_000 {
+            panic_with_error!(&env, ExtError::InvalidAmount);
         }
 
         env.storage()
             .instance()
-            .set(&DataKey::FeeBps, &new_fee_bps);
-
+            .set(&DataKey4::SlashBps, &slash_bps);
         env.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
     }
 
-    /// Get the current protocol fee in basis points.
-    pub fn get_fee_bps(env: Env) -> u32 {
+    /// Get the deposit slash rate in basis points.
+    pub fn get_slash_bps(env: Env) -> u32 {
         env.storage()
             .instance()
-            .get(&DataKey::FeeBps)
+            .get(&DataKey4::SlashBps)
             .unwrap_or(0)
     }
 
-    /// Get the protocol fee recipient address.
-    pub fn get_fee_recipient(env: Env) -> Option<Address> {
-        env.storage()
-            .instance()
-            .get(&DataKey2::FeeRecipient)
-    }
-
     /// Admin configures the reputation-gated fee discount.
     /// Members whose on-chain credit score >= `threshold` receive a `discount_bps`
     /// reduction on the protocol fee applied to their payout round. The effective
@@ -6075,8 +11453,9 @@ impl AhjoorContract {
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
+        internals::note_admin_action(&env);
         if admin != stored_admin {
             panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
@@ -6112,8 +11491,9 @@ impl AhjoorContract {
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
+        internals::note_admin_action(&env);
 
         if new_max < 1 || new_max > 100 {
             panic_with_error!(&env, Error::InvalidMaxMembers);
@@ -6121,7 +11501,7 @@ impl AhjoorContract {
 
         let current_members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
             .unwrap_or(Vec::new(&env));
 
@@ -6150,6 +11530,16 @@ impl AhjoorContract {
             .unwrap_or(50)
     }
 
+    /// Number of members that can still be added before the group is full.
+    pub fn remaining_slots(env: Env) -> u32 {
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or(Vec::new(&env));
+        Self::get_max_members(env).saturating_sub(members.len())
+    }
+
     // --- EMERGENCY EXIT ---
 
     pub fn pause_group(env: Env, reason: soroban_sdk::String) {
@@ -6157,8 +11547,9 @@ impl AhjoorContract {
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
+        internals::note_admin_action(&env);
 
         if Self::is_paused(env.clone()) {
             panic_with_error!(&env, Error::AlreadyPaused);
@@ -6170,6 +11561,7 @@ impl AhjoorContract {
         env.storage()
             .instance()
             .set(&DataKey::PauseTimestamp, &env.ledger().timestamp());
+        internals::extend_round_state_ttl(&env);
 
         events::emit_paused(&env, reason);
     }
@@ -6179,8 +11571,9 @@ impl AhjoorContract {
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
+        internals::note_admin_action(&env);
 
         if !Self::is_paused(env.clone()) {
             panic_with_error!(&env, Error::NotPaused);
@@ -6195,30 +11588,18 @@ impl AhjoorContract {
         let pause_duration = current_timestamp - pause_timestamp;
 
         // Extend the round deadline
-        let current_deadline: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::RoundDeadline)
+        let current_deadline: u64 = internals::round_state_get(&env, &DataKey::RoundDeadline)
             .unwrap_or(0);
         if current_deadline > 0 {
-            env.storage().instance().set(
-                &DataKey::RoundDeadline,
-                &(current_deadline + pause_duration),
-            );
+            internals::round_state_set(&env, &DataKey::RoundDeadline, &(current_deadline + pause_duration));
         }
 
         // Extend the timestamp-based deadline if enabled
-        let current_timestamp_deadline: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::RoundDeadlineTimestamp)
+        let current_timestamp_deadline: u64 = internals::round_state_get(&env, &DataKey::RoundDeadlineTimestamp)
             .unwrap_or(0);
         if current_timestamp_deadline > 0 {
             let next_deadline = current_timestamp_deadline + pause_duration;
-            env.storage().instance().set(
-                &DataKey::RoundDeadlineTimestamp,
-                &next_deadline,
-            );
+            internals::round_state_set(&env, &DataKey::RoundDeadlineTimestamp, &next_deadline);
             let current_round: u32 = env
                 .storage()
                 .instance()
@@ -6233,6 +11614,7 @@ impl AhjoorContract {
         // Clean up Reason and Timestamp to save storage space
         env.storage().instance().remove(&DataKey::PauseReason);
         env.storage().instance().remove(&DataKey::PauseTimestamp);
+        internals::extend_round_state_ttl(&env);
 
         events::emit_resumed(&env, reason);
     }
@@ -6245,6 +11627,33 @@ impl AhjoorContract {
             .unwrap_or(false)
     }
 
+    /// All mode switches in one read; see `Flags`.
+    pub fn get_flags(env: Env) -> Flags {
+        let storage = env.storage().instance();
+        Flags {
+            paused: Self::is_paused(env.clone()),
+            contributions_paused: storage
+                .get(&DataKey4::ContributionsPaused)
+                .unwrap_or(false),
+            deposits_paused: storage.get(&DataKey4::DepositsPaused).unwrap_or(false),
+            frozen: storage.get(&DataKey3::IsFrozen).unwrap_or(false),
+            timestamp_schedule: storage
+                .get(&DataKey2::UseTimestampSchedule)
+                .unwrap_or(false),
+            fixed_schedule: storage.get(&DataKey4::FixedSchedule).unwrap_or(false),
+            finite: storage.get::<_, u32>(&DataKey4::MaxRounds).unwrap_or(0) > 0,
+            whitelist_enabled: storage.get(&DataKey4::WhitelistEnabled).unwrap_or(false),
+            open_enrollment: storage.get(&DataKey4::OpenEnrollment).unwrap_or(false),
+            randomize_payout_order: storage
+                .get(&DataKey3::RandomizePayoutOrder)
+                .unwrap_or(false),
+            carry_over_enabled: storage.get(&DataKey5::CarryOverEnabled).unwrap_or(false),
+            fee_to_reserve: storage.get(&DataKey4::FeeToReserve).unwrap_or(false),
+            fee_to_rewards: storage.get(&DataKey4::FeeToRewards).unwrap_or(false),
+            overpay_policy: Self::get_overpay_policy(env.clone()),
+        }
+    }
+
     pub fn get_pause_reason(env: Env) -> soroban_sdk::String {
         env.storage()
             .instance()
@@ -6252,16 +11661,199 @@ impl AhjoorContract {
             .unwrap_or(soroban_sdk::String::from_str(&env, ""))
     }
 
+    /// Admin pauses or resumes contributions without pausing the rest of the
+    /// group, e.g. during a dispute. Deposits are unaffected.
+    pub fn set_contributions_paused(env: Env, paused: bool) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey4::ContributionsPaused, &paused);
+        internals::extend_round_state_ttl(&env);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Admin restricts contributions to `[open, close)` seconds after each
+    /// round starts, e.g. the last few days of the round. `None` accepts
+    /// contributions for the whole round again.
+    pub fn set_contribution_window(env: Env, window: Option<(u64, u64)>) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        match window {
+            Some((open, close)) => {
+                if open >= close {
+                    panic_with_error!(&env, ExtError3::InvalidContributionWindow);
+                }
+                env.storage().instance().set(&DataKey4::WindowOpen, &open);
+                env.storage().instance().set(&DataKey4::WindowClose, &close);
+            }
+            None => {
+                env.storage().instance().remove(&DataKey4::WindowOpen);
+                env.storage().instance().remove(&DataKey4::WindowClose);
+            }
+        }
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Admin bounds the size of a single contribution. Zero disables either
+    /// side. The floor does not apply to a payment that settles the member's
+    /// remaining balance, so installments can always be finished.
+    pub fn set_contribution_bounds(env: Env, min: i128, max: i128) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        if min < 0 || max < 0 || (max > 0 && min > max) {
+            panic_with_error!(&env, ExtError3::InvalidContributionBounds);
+        }
+        env.storage().instance().set(&DataKey4::MinContribution, &min);
+        env.storage().instance().set(&DataKey5::MaxContribution, &max);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// The `(min, max)` single-contribution bounds; zero means unbounded.
+    pub fn get_contribution_bounds(env: Env) -> (i128, i128) {
+        let min: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::MinContribution)
+            .unwrap_or(0);
+        let max: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey5::MaxContribution)
+            .unwrap_or(0);
+        (min, max)
+    }
+
+    /// The contribution window as `(open, close)` offsets, if one is set.
+    pub fn get_contribution_window(env: Env) -> Option<(u64, u64)> {
+        let close: u64 = env.storage().instance().get(&DataKey4::WindowClose)?;
+        let open: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::WindowOpen)
+            .unwrap_or(0);
+        Some((open, close))
+    }
+
+    /// Admin stops the round clock, e.g. during an outage, so nobody is made
+    /// late by time they could not use. While frozen, contribution and close
+    /// deadlines are judged as of the freeze.
+    pub fn freeze_clock(env: Env) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        if env.storage().instance().has(&DataKey5::ClockFrozenAt) {
+            panic_with_error!(&env, Error::AlreadyPaused);
+        }
+
+        let now = env.ledger().timestamp();
+        env.storage().instance().set(&DataKey5::ClockFrozenAt, &now);
+        internals::extend_round_state_ttl(&env);
+        events::emit_clock_frozen(&env, now);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Admin restarts the round clock, pushing the current deadline back by
+    /// however long it was frozen.
+    pub fn unfreeze_clock(env: Env) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        let frozen_at: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey5::ClockFrozenAt)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotPaused));
+
+        let frozen_for = env.ledger().timestamp().saturating_sub(frozen_at);
+        for key in [DataKey::RoundDeadline, DataKey::RoundDeadlineTimestamp] {
+            if let Some(deadline) = internals::round_state_get::<u64>(&env, &key) {
+                internals::round_state_set(&env, &key, &deadline.saturating_add(frozen_for));
+            }
+        }
+        env.storage().instance().remove(&DataKey5::ClockFrozenAt);
+        internals::extend_round_state_ttl(&env);
+        events::emit_clock_unfrozen(&env, frozen_for);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Admin pauses or resumes security deposits. Contributions are unaffected.
+    pub fn set_deposits_paused(env: Env, paused: bool) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey4::DepositsPaused, &paused);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    pub fn is_contributions_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey4::ContributionsPaused)
+            .unwrap_or(false)
+    }
+
+    pub fn is_deposits_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey4::DepositsPaused)
+            .unwrap_or(false)
+    }
+
     pub fn pause_contract(env: Env, admin: Address, reason: soroban_sdk::String) {
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
-            panic!("Only admin can pause contract");
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
         admin.require_auth();
+        internals::note_admin_action(&env);
         Self::pause_group(env, reason);
     }
 
@@ -6270,11 +11862,12 @@ impl AhjoorContract {
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
-            panic!("Only admin can resume contract");
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
         admin.require_auth();
+        internals::note_admin_action(&env);
         Self::resume_group(env.clone(), soroban_sdk::String::from_str(&env, "Resumed"));
     }
 
@@ -6308,18 +11901,15 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
             panic_with_error!(&env, Error::NotAMember);
         }
 
         // Prevent exit mid-round
-        let paid_members: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::PaidMembers)
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
             .unwrap_or(Vec::new(&env));
         if !paid_members.is_empty() {
             panic_with_error!(&env, Error::ExitNotAllowedMidRound);
@@ -6372,8 +11962,9 @@ impl AhjoorContract {
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
+        internals::note_admin_action(&env);
 
         let mut requests: Map<Address, ExitRequest> = env
             .storage()
@@ -6418,15 +12009,22 @@ impl AhjoorContract {
         let refund_amount = if net > 0 { net } else { 0 };
 
         if refund_amount > 0 {
-            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
             let client = token::Client::new(&env, &token_addr);
             client.transfer(&env.current_contract_address(), &member, &refund_amount);
+            internals::record_fund_flow(
+                &env,
+                "refund",
+                &env.current_contract_address(),
+                &member,
+                refund_amount,
+            );
         }
 
         // Remove from Members list
         let old_members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
             .unwrap_or(Vec::new(&env));
         let mut new_members: Vec<Address> = Vec::new(&env);
@@ -6436,7 +12034,7 @@ impl AhjoorContract {
             }
         }
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::Members, &new_members);
 
         // Add to ExitedMembers
@@ -6449,6 +12047,7 @@ impl AhjoorContract {
         env.storage()
             .instance()
             .set(&DataKey::ExitedMembers, &exited_members);
+        internals::refund_deposit(&env, &member);
 
         requests.remove(member.clone());
         env.storage()
@@ -6482,8 +12081,9 @@ impl AhjoorContract {
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
+        internals::note_admin_action(&env);
 
         let mut requests: Map<Address, ExitRequest> = env
             .storage()
@@ -6529,9 +12129,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&delegator) {
             panic_with_error!(&env, Error::NotAMember);
         }
@@ -6613,8 +12213,9 @@ impl AhjoorContract {
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
+        internals::note_admin_action(&env);
 
         env.storage()
             .temporary()
@@ -6642,14 +12243,15 @@ impl AhjoorContract {
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
+        internals::note_admin_action(&env);
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if members.contains(&invitee) {
             panic_with_error!(&env, Error::AlreadyAMember);
         }
@@ -6706,10 +12308,7 @@ impl AhjoorContract {
             .set(&Symbol::new(&env, "approved_invitees"), &new_approved);
 
         // Add member
-        let paid_members: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::PaidMembers)
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
             .unwrap_or(Vec::new(&env));
         if !paid_members.is_empty() {
             panic_with_error!(&env, Error::CannotChangeMidRound);
@@ -6717,9 +12316,9 @@ impl AhjoorContract {
 
         let mut members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
 
         let max_members: u32 = env
             .storage()
@@ -6736,16 +12335,16 @@ impl AhjoorContract {
         }
 
         members.push_back(invitee.clone());
-        env.storage().instance().set(&DataKey::Members, &members);
+        env.storage().persistent().set(&DataKey::Members, &members);
 
         let mut payout_order: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::PayoutOrder)
-            .expect("Payout order not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         payout_order.push_back(invitee.clone());
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::PayoutOrder, &payout_order);
 
         events::emit_invite_redeemed(&env, invitee.clone());
@@ -6765,29 +12364,19 @@ impl AhjoorContract {
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
+        internals::note_admin_action(&env);
 
         if threshold < 1 || threshold > (co_admins.len() as u32 + 1) {
-            panic!("Invalid multisig threshold");
+            panic_with_error!(&env, ExtError3::InvalidThreshold);
         }
 
+        // Instance storage: the quorum must not lapse back to a lone admin.
+        env.storage().instance().set(&DataKey5::Admins, &co_admins);
         env.storage()
-            .temporary()
-            .set(&Symbol::new(&env, "co_admins"), &co_admins);
-        env.storage()
-            .temporary()
-            .set(&Symbol::new(&env, "multisig_threshold"), &threshold);
-        env.storage().temporary().extend_ttl(
-            &Symbol::new(&env, "co_admins"),
-            TEMP_LIFETIME_THRESHOLD,
-            TEMP_BUMP_AMOUNT,
-        );
-        env.storage().temporary().extend_ttl(
-            &Symbol::new(&env, "multisig_threshold"),
-            TEMP_LIFETIME_THRESHOLD,
-            TEMP_BUMP_AMOUNT,
-        );
+            .instance()
+            .set(&DataKey5::AdminThreshold, &threshold);
 
         env.storage()
             .instance()
@@ -6797,7 +12386,7 @@ impl AhjoorContract {
     /// Propose a critical admin action (remove member, penalize, update fee)
     pub fn propose_admin_action(
         env: Env,
-        action_type: u32, // 0: RemoveMember, 1: PenaliseDefaulter, 2: UpdateFee
+        action_type: u32, // 0: RemoveMember, 1: PenaliseDefaulter, 2: UpdateFee, 3: CloseRound, 4: Dissolve
         target_member: Option<Address>,
         payload: Option<i128>,
     ) {
@@ -6806,40 +12395,19 @@ impl AhjoorContract {
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         admin.require_auth();
+        internals::note_admin_action(&env);
 
         let threshold: u32 = env
             .storage()
-            .temporary()
-            .get(&Symbol::new(&env, "multisig_threshold"))
+            .instance()
+            .get(&DataKey5::AdminThreshold)
             .unwrap_or(1);
 
         // If threshold is 1, execute immediately (single admin)
         if threshold == 1 {
-            match action_type {
-                0 => {
-                    // RemoveMember
-                    if let Some(member) = target_member {
-                        Self::remove_member(env.clone(), member);
-                    }
-                }
-                1 => {
-                    // PenaliseDefaulter
-                    if let Some(member) = target_member {
-                        let penalty: i128 = env.storage().instance().get(&DataKey::PenaltyAmount).unwrap_or(0);
-                        let round: u32 = env.storage().instance().get(&DataKey::CurrentRound).unwrap_or(0);
-                        Self::apply_penalty(&env, member, penalty, round);
-                    }
-                }
-                2 => {
-                    // UpdateFee
-                    if let Some(fee_bps) = payload {
-                        Self::update_fee(env.clone(), fee_bps as u32);
-                    }
-                }
-                _ => panic!("Invalid action type"),
-            }
+            Self::execute_admin_action(&env, action_type, target_member, payload);
             return;
         }
 
@@ -6847,12 +12415,7 @@ impl AhjoorContract {
         events::emit_admin_action_proposed(
             &env,
             0, // action_id not used in simplified version
-            Symbol::new(&env, match action_type {
-                0 => "RemoveMember",
-                1 => "PenaliseDefaulter",
-                2 => "UpdateFee",
-                _ => "Unknown",
-            }),
+            Self::admin_action_name(&env, action_type),
             admin,
         );
 
@@ -6868,20 +12431,232 @@ impl AhjoorContract {
 
         let co_admins: Vec<Address> = env
             .storage()
-            .temporary()
-            .get(&Symbol::new(&env, "co_admins"))
+            .instance()
+            .get(&DataKey5::Admins)
             .unwrap_or(Vec::new(&env));
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
 
         if !co_admins.contains(&approver) && approver != admin {
             panic_with_error!(&env, Error::NotACoAdmin);
         }
 
         // Execute the action
+        Self::execute_admin_action(&env, action_type, target_member, payload);
+
+        events::emit_admin_action_executed(
+            &env,
+            0,
+            Self::admin_action_name(&env, action_type),
+        );
+
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Open a quorum-gated admin action (admin or co-admin). The proposer's
+    /// approval counts toward `multisig_threshold`; the action runs as soon as
+    /// the threshold is reached. Returns the proposal id.
+    pub fn propose_action(
+        env: Env,
+        proposer: Address,
+        action_type: u32,
+        target_member: Option<Address>,
+        payload: Option<i128>,
+    ) -> u32 {
+        internals::check_not_paused(&env);
+        proposer.require_auth();
+        Self::require_admin_signer(&env, &proposer);
+        if action_type > 4 {
+            panic_with_error!(&env, ExtError::InvalidAmount);
+        }
+
+        let action_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::NextAdminProposalId)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey4::NextAdminProposalId, &(action_id + 1));
+        let mut approvals = Vec::new(&env);
+        approvals.push_back(proposer.clone());
+        let proposal = AdminProposal {
+            action_type,
+            target_member,
+            payload,
+            approvals,
+            executed: false,
+            wasm_hash: None,
+        };
+        events::emit_admin_action_proposed(
+            &env,
+            action_id,
+            Self::admin_action_name(&env, action_type),
+            proposer,
+        );
+        Self::store_or_execute_proposal(&env, action_id, proposal);
+        action_id
+    }
+
+    /// Open a quorum-gated upgrade to `new_wasm_hash` (admin or co-admin).
+    /// Approved with `approve_action` like any other proposal; the upgrade
+    /// runs once `multisig_threshold` approvals are in. Returns the proposal id.
+    pub fn propose_upgrade(env: Env, proposer: Address, new_wasm_hash: BytesN<32>) -> u32 {
+        internals::check_not_paused(&env);
+        proposer.require_auth();
+        Self::require_admin_signer(&env, &proposer);
+
+        let action_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::NextAdminProposalId)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey4::NextAdminProposalId, &(action_id + 1));
+        let proposal = AdminProposal {
+            action_type: 5,
+            target_member: None,
+            payload: None,
+            approvals: Vec::from_array(&env, [proposer.clone()]),
+            executed: false,
+            wasm_hash: Some(new_wasm_hash),
+        };
+        events::emit_admin_action_proposed(
+            &env,
+            action_id,
+            Self::admin_action_name(&env, 5),
+            proposer,
+        );
+        Self::store_or_execute_proposal(&env, action_id, proposal);
+        action_id
+    }
+
+    /// Approve a proposal from `propose_action` (admin or co-admin, once each).
+    pub fn approve_action(env: Env, approver: Address, action_id: u32) {
+        internals::check_not_paused(&env);
+        approver.require_auth();
+        Self::require_admin_signer(&env, &approver);
+
+        let proposals: Map<u32, AdminProposal> = env
+            .storage()
+            .instance()
+            .get(&DataKey4::AdminProposals)
+            .unwrap_or(Map::new(&env));
+        let mut proposal = proposals
+            .get(action_id)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::AdminActionNotFound));
+        if proposal.executed {
+            panic_with_error!(&env, Error::AdminActionAlreadyExecuted);
+        }
+        if proposal.approvals.contains(&approver) {
+            panic_with_error!(&env, Error::AdminAlreadyApproved);
+        }
+        proposal.approvals.push_back(approver.clone());
+        events::emit_admin_action_approved(&env, action_id, approver, proposal.approvals.len());
+        Self::store_or_execute_proposal(&env, action_id, proposal);
+    }
+
+    /// Get a proposal opened with `propose_action`.
+    pub fn get_admin_proposal(env: Env, action_id: u32) -> Option<AdminProposal> {
+        let proposals: Map<u32, AdminProposal> = env
+            .storage()
+            .instance()
+            .get(&DataKey4::AdminProposals)
+            .unwrap_or(Map::new(&env));
+        proposals.get(action_id)
+    }
+
+    /// Panics unless `signer` is the admin or a co-admin.
+    fn require_admin_signer(env: &Env, signer: &Address) {
+        let co_admins: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey5::Admins)
+            .unwrap_or(Vec::new(env));
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
+        if !co_admins.contains(signer) && *signer != admin {
+            panic_with_error!(env, Error::NotACoAdmin);
+        }
+    }
+
+    /// Co-admin approvals an admin action needs (1 = the admin alone).
+    fn multisig_threshold(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey5::AdminThreshold)
+            .unwrap_or(1)
+    }
+
+    /// Panics once a threshold above 1 is configured: `close_round`,
+    /// `dissolve` and `upgrade` then only run through `propose_action` /
+    /// `propose_upgrade` and `approve_action`.
+    fn require_no_quorum(env: &Env) {
+        if Self::multisig_threshold(env) > 1 {
+            panic_with_error!(env, Error::InsufficientApprovals);
+        }
+    }
+
+    /// Runs `proposal` if it has reached the threshold, then stores it.
+    fn store_or_execute_proposal(env: &Env, action_id: u32, mut proposal: AdminProposal) {
+        let ready = proposal.approvals.len() >= Self::multisig_threshold(env);
+        proposal.executed = ready;
+
+        let mut proposals: Map<u32, AdminProposal> = env
+            .storage()
+            .instance()
+            .get(&DataKey4::AdminProposals)
+            .unwrap_or(Map::new(env));
+        proposals.set(action_id, proposal.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey4::AdminProposals, &proposals);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        if ready {
+            match proposal.wasm_hash {
+                Some(wasm_hash) => {
+                    let admin: Address = env
+                        .storage()
+                        .instance()
+                        .get(&DataKey::Admin)
+                        .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
+                    Self::upgrade_internal(env, wasm_hash, admin);
+                }
+                None => Self::execute_admin_action(
+                    env,
+                    proposal.action_type,
+                    proposal.target_member,
+                    proposal.payload,
+                ),
+            }
+            events::emit_admin_action_executed(
+                env,
+                action_id,
+                Self::admin_action_name(env, proposal.action_type),
+            );
+        }
+    }
+
+    /// Dispatch for the admin action types shared by `propose_admin_action`
+    /// and `propose_action`.
+    fn execute_admin_action(
+        env: &Env,
+        action_type: u32,
+        target_member: Option<Address>,
+        payload: Option<i128>,
+    ) {
         match action_type {
             0 => {
                 // RemoveMember
@@ -6894,7 +12669,7 @@ impl AhjoorContract {
                 if let Some(member) = target_member {
                     let penalty: i128 = env.storage().instance().get(&DataKey::PenaltyAmount).unwrap_or(0);
                     let round: u32 = env.storage().instance().get(&DataKey::CurrentRound).unwrap_or(0);
-                    Self::apply_penalty(&env, member, penalty, round);
+                    Self::apply_penalty(env, member, penalty, round);
                 }
             }
             2 => {
@@ -6903,31 +12678,31 @@ impl AhjoorContract {
                     Self::update_fee(env.clone(), fee_bps as u32);
                 }
             }
-            _ => panic!("Invalid action type"),
+            3 => Self::close_round_internal(env.clone(), None),
+            4 => Self::dissolve_internal(env.clone()),
+            _ => panic_with_error!(env, Error::AdminActionNotFound),
         }
+    }
 
-        events::emit_admin_action_executed(
-            &env,
-            0,
-            Symbol::new(&env, match action_type {
-                0 => "RemoveMember",
-                1 => "PenaliseDefaulter",
-                2 => "UpdateFee",
-                _ => "Unknown",
-            }),
-        );
-
-        env.storage()
-            .instance()
-            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    fn admin_action_name(env: &Env, action_type: u32) -> Symbol {
+        Symbol::new(env, match action_type {
+            0 => "RemoveMember",
+            1 => "PenaliseDefaulter",
+            2 => "UpdateFee",
+            3 => "CloseRound",
+            4 => "Dissolve",
+            5 => "Upgrade",
+            _ => "Unknown",
+        })
     }
 
     // ─── #213: Payout Slot Swap ───────────────────────────────────────────────
 
     pub fn set_slot_swap_config(env: Env, admin: Address, requires_admin: bool, expiry_seconds: u64) {
         admin.require_auth();
+        internals::note_admin_action(&env);
         internals::check_not_paused(&env);
-        let a: Address = env.storage().instance().get(&DataKey::Admin).expect("No admin");
+        let a: Address = env.storage().instance().get(&DataKey::Admin).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != a { panic_with_error!(&env, ExtError::OnlyAdminAllowed); }
         env.storage().instance().set(&DataKey2::SlotSwapRequiresAdmin, &requires_admin);
         env.storage().instance().set(&DataKey2::SlotSwapExpirySeconds, &expiry_seconds);
@@ -6936,10 +12711,10 @@ impl AhjoorContract {
     pub fn request_slot_swap(env: Env, initiator: Address, round_a: u32, round_b: u32, counterparty: Address) -> u32 {
         initiator.require_auth();
         internals::check_not_paused(&env);
-        let members: Vec<Address> = env.storage().instance().get(&DataKey::Members).expect("Not init");
+        let members: Vec<Address> = env.storage().persistent().get(&DataKey::Members).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&initiator) || !members.contains(&counterparty) { panic_with_error!(&env, Error::OnlyMembersAllowed); }
         let current_round: u32 = env.storage().instance().get(&DataKey::CurrentRound).unwrap_or(0);
-        let payout_order: Vec<Address> = env.storage().instance().get(&DataKey::PayoutOrder).unwrap();
+        let payout_order: Vec<Address> = env.storage().persistent().get(&DataKey::PayoutOrder).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         let order_len = payout_order.len() as u32;
         if round_a >= order_len || round_b >= order_len || round_a <= current_round || round_b <= current_round { panic_with_error!(&env, ExtError::InvalidAmount); }
         if payout_order.get(round_a).unwrap() != initiator || payout_order.get(round_b).unwrap() != counterparty { panic_with_error!(&env, Error::OnlyMembersAllowed); }
@@ -6959,7 +12734,7 @@ impl AhjoorContract {
         counterparty.require_auth();
         internals::check_not_paused(&env);
         let mut swaps: Map<u32, SlotSwap> = env.storage().instance().get(&DataKey2::SlotSwaps).unwrap_or(Map::new(&env));
-        let mut swap = swaps.get(swap_id).expect("Swap not found");
+        let mut swap = swaps.get(swap_id).unwrap_or_else(|| panic_with_error!(&env, Error::ProposalNotFound));
         if swap.counterparty != counterparty { panic_with_error!(&env, Error::OnlyMembersAllowed); }
         if swap.status != SlotSwapStatus::Pending { panic_with_error!(&env, Error::ProposalNotPending); }
         if env.ledger().timestamp() > swap.expiry_at {
@@ -6980,7 +12755,7 @@ impl AhjoorContract {
     pub fn reject_slot_swap(env: Env, counterparty: Address, swap_id: u32) {
         counterparty.require_auth();
         let mut swaps: Map<u32, SlotSwap> = env.storage().instance().get(&DataKey2::SlotSwaps).unwrap_or(Map::new(&env));
-        let mut swap = swaps.get(swap_id).expect("Swap not found");
+        let mut swap = swaps.get(swap_id).unwrap_or_else(|| panic_with_error!(&env, Error::ProposalNotFound));
         if swap.counterparty != counterparty { panic_with_error!(&env, Error::OnlyMembersAllowed); }
         if swap.status != SlotSwapStatus::Pending { panic_with_error!(&env, Error::ProposalNotPending); }
         swap.status = SlotSwapStatus::Rejected;
@@ -6991,18 +12766,63 @@ impl AhjoorContract {
 
     pub fn approve_slot_swap(env: Env, admin: Address, swap_id: u32) {
         admin.require_auth();
-        let a: Address = env.storage().instance().get(&DataKey::Admin).expect("No admin");
+        internals::note_admin_action(&env);
+        let a: Address = env.storage().instance().get(&DataKey::Admin).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != a { panic_with_error!(&env, ExtError::OnlyAdminAllowed); }
         let mut swaps: Map<u32, SlotSwap> = env.storage().instance().get(&DataKey2::SlotSwaps).unwrap_or(Map::new(&env));
-        let swap = swaps.get(swap_id).expect("Swap not found");
+        let swap = swaps.get(swap_id).unwrap_or_else(|| panic_with_error!(&env, Error::ProposalNotFound));
         if swap.status != SlotSwapStatus::Accepted { panic_with_error!(&env, Error::ProposalNotPending); }
         Self::execute_slot_swap_inner(&env, swap_id);
     }
 
+    /// Propose trading payout slots with `counterparty`. Both members' upcoming
+    /// positions are looked up in the payout order; slots that have already
+    /// been paid out cannot be swapped. Returns the swap id.
+    pub fn request_swap(env: Env, requester: Address, counterparty: Address) -> u32 {
+        let payout_order: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PayoutOrder)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let slot_a = payout_order
+            .first_index_of(&requester)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotAMember));
+        let slot_b = payout_order
+            .first_index_of(&counterparty)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotAMember));
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        if slot_a <= current_round || slot_b <= current_round {
+            panic_with_error!(&env, ExtError::SlotAlreadyPaidOut);
+        }
+        Self::request_slot_swap(env, requester, slot_a, slot_b, counterparty)
+    }
+
+    /// Accept swap `swap_id`, which must be pending and addressed to
+    /// `counterparty`, exchanging the two members' entries in the payout
+    /// order.
+    pub fn accept_swap(env: Env, counterparty: Address, swap_id: u32) {
+        let swaps: Map<u32, SlotSwap> = env
+            .storage()
+            .instance()
+            .get(&DataKey2::SlotSwaps)
+            .unwrap_or(Map::new(&env));
+        let pending = swaps.get(swap_id).is_some_and(|swap| {
+            swap.counterparty == counterparty && swap.status == SlotSwapStatus::Pending
+        });
+        if !pending {
+            panic_with_error!(&env, ExtError2::NoPendingSwap);
+        }
+        Self::accept_slot_swap(env, counterparty, swap_id);
+    }
+
     fn execute_slot_swap_inner(env: &Env, swap_id: u32) {
         let mut swaps: Map<u32, SlotSwap> = env.storage().instance().get(&DataKey2::SlotSwaps).unwrap_or(Map::new(env));
         let mut swap = swaps.get(swap_id).unwrap();
-        let mut payout_order: Vec<Address> = env.storage().instance().get(&DataKey::PayoutOrder).unwrap();
+        let payout_order: Vec<Address> = env.storage().persistent().get(&DataKey::PayoutOrder).unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
         let addr_a = payout_order.get(swap.round_a).unwrap();
         let addr_b = payout_order.get(swap.round_b).unwrap();
         let mut new_order: Vec<Address> = Vec::new(env);
@@ -7011,7 +12831,7 @@ impl AhjoorContract {
             else if i as u32 == swap.round_b { new_order.push_back(addr_a.clone()); }
             else { new_order.push_back(addr); }
         }
-        env.storage().instance().set(&DataKey::PayoutOrder, &new_order);
+        env.storage().persistent().set(&DataKey::PayoutOrder, &new_order);
         swap.status = SlotSwapStatus::Executed;
         swaps.set(swap_id, swap.clone());
         env.storage().instance().set(&DataKey2::SlotSwaps, &swaps);
@@ -7022,8 +12842,9 @@ impl AhjoorContract {
 
     pub fn set_insurance_coverage_mode(env: Env, admin: Address, mode: InsuranceCoverageMode) {
         admin.require_auth();
+        internals::note_admin_action(&env);
         internals::check_not_paused(&env);
-        let a: Address = env.storage().instance().get(&DataKey::Admin).expect("No admin");
+        let a: Address = env.storage().instance().get(&DataKey::Admin).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != a { panic_with_error!(&env, ExtError::OnlyAdminAllowed); }
         env.storage().instance().set(&DataKey2::InsuranceCoverageMode, &mode);
         events::emit_insurance_coverage_mode_set(&env, mode as u32);
@@ -7034,12 +12855,80 @@ impl AhjoorContract {
         claims.get(round).unwrap_or(Vec::new(&env))
     }
 
+    // ─── Admin Member Suspension ─────────────────────────────────────────────
+
+    /// Admin temporarily excludes `member` (e.g. during a dispute) without
+    /// pausing the group. While suspended the member cannot contribute, is
+    /// skipped in the payout rotation and is not counted as a defaulter.
+    pub fn suspend_member(env: Env, member: Address) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        if !Self::is_member(env.clone(), member.clone()) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+        let mut suspended = internals::admin_suspended(&env);
+        if suspended.contains(&member) {
+            panic_with_error!(&env, ExtError2::MemberSuspended);
+        }
+        suspended.push_back(member.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey4::AdminSuspended, &suspended);
+
+        events::emit_member_admin_suspended(&env, member);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Admin lifts a suspension placed with `suspend_member`.
+    pub fn unsuspend_member(env: Env, member: Address) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        if !Self::is_member(env.clone(), member.clone()) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+        let mut suspended = internals::admin_suspended(&env);
+        let idx = suspended
+            .first_index_of(&member)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError::MemberNotSuspended));
+        suspended.remove(idx);
+        env.storage()
+            .instance()
+            .set(&DataKey4::AdminSuspended, &suspended);
+
+        events::emit_member_admin_unsuspended(&env, member);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Returns true if the admin has suspended `member`.
+    pub fn is_suspended(env: Env, member: Address) -> bool {
+        internals::admin_suspended(&env).contains(&member)
+    }
+
     // ─── #218: Suspended Member Reinstatement ────────────────────────────────
 
     pub fn set_reinstatement_fee(env: Env, admin: Address, fee: i128) {
         admin.require_auth();
+        internals::note_admin_action(&env);
         internals::check_not_paused(&env);
-        let a: Address = env.storage().instance().get(&DataKey::Admin).expect("No admin");
+        let a: Address = env.storage().instance().get(&DataKey::Admin).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != a { panic_with_error!(&env, ExtError::OnlyAdminAllowed); }
         if fee < 0 { panic_with_error!(&env, Error::AmountMustBePositive); }
         env.storage().instance().set(&DataKey2::ReinstatementFee, &fee);
@@ -7138,12 +13027,12 @@ impl AhjoorContract {
         // Re-append to payout order
         let mut po: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::PayoutOrder)
             .unwrap_or(Vec::new(&env));
         if !po.contains(&member) {
             po.push_back(member.clone());
-            env.storage().instance().set(&DataKey::PayoutOrder, &po);
+            env.storage().persistent().set(&DataKey::PayoutOrder, &po);
         }
 
         // Queue reinstatement fee if configured
@@ -7192,13 +13081,13 @@ impl AhjoorContract {
     pub fn get_multisig_config(env: Env) -> (Vec<Address>, u32) {
         let co_admins: Vec<Address> = env
             .storage()
-            .temporary()
-            .get(&Symbol::new(&env, "co_admins"))
+            .instance()
+            .get(&DataKey5::Admins)
             .unwrap_or(Vec::new(&env));
         let threshold: u32 = env
             .storage()
-            .temporary()
-            .get(&Symbol::new(&env, "multisig_threshold"))
+            .instance()
+            .get(&DataKey5::AdminThreshold)
             .unwrap_or(1);
         (co_admins, threshold)
     }
@@ -7213,9 +13102,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if members.contains(&caller) {
             panic_with_error!(&env, Error::AlreadyAMember);
         }
@@ -7239,7 +13128,7 @@ impl AhjoorContract {
         for i in 0..waitlist.len() {
             let (addr, _) = waitlist.get(i).unwrap();
             if addr == caller {
-                panic!("Already on waitlist");
+                panic_with_error!(&env, ExtError3::AlreadyOnWaitlist);
             }
         }
 
@@ -7284,7 +13173,7 @@ impl AhjoorContract {
             }
         }
         if !found {
-            panic!("Not on waitlist");
+            panic_with_error!(&env, ExtError3::NotOnWaitlist);
         }
 
         env.storage().instance().set(&DataKey2::Waitlist, &new_waitlist);
@@ -7299,13 +13188,14 @@ impl AhjoorContract {
     pub fn remove_from_waitlist(env: Env, admin: Address, target: Address) {
         internals::check_not_paused(&env);
         admin.require_auth();
+        internals::note_admin_action(&env);
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
-            panic!("Only admin can remove from waitlist");
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
 
         let waitlist: Vec<(Address, u64)> = env
@@ -7325,7 +13215,7 @@ impl AhjoorContract {
             }
         }
         if !found {
-            panic!("Address not on waitlist");
+            panic_with_error!(&env, ExtError3::NotOnWaitlist);
         }
 
         env.storage().instance().set(&DataKey2::Waitlist, &new_waitlist);
@@ -7349,13 +13239,14 @@ impl AhjoorContract {
     /// highest-score waitlist candidate first on the next `enroll_from_waitlist` call.
     pub fn set_waitlist_priority_mode(env: Env, admin: Address, mode: WaitlistMode) {
         admin.require_auth();
+        internals::note_admin_action(&env);
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
-            panic!("Only admin can set waitlist priority mode");
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
 
         env.storage()
@@ -7380,10 +13271,7 @@ impl AhjoorContract {
     /// Blocked within 24 hours of the next scheduled payout (round deadline).
     fn try_rebalance_contribution(env: &Env, reason: Symbol) {
         // Guard: blocked within 24 hours of next scheduled payout
-        let round_deadline: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::RoundDeadline)
+        let round_deadline: u64 = internals::round_state_get(env, &DataKey::RoundDeadline)
             .unwrap_or(0);
         let now = env.ledger().timestamp();
         const TWENTY_FOUR_HOURS: u64 = 24 * 60 * 60;
@@ -7402,7 +13290,7 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
             .unwrap_or(Vec::new(env));
         let active_count = members.len() as i128;
@@ -7490,7 +13378,7 @@ impl AhjoorContract {
             }
         }
 
-        // Emit WaitlistUpdated with a hash of the new order for traceability (#456)
+        // Publish a hash of the new order for traceability (#456)
         let order_hash: BytesN<32> = env
             .crypto()
             .sha256(&{
@@ -7501,30 +13389,27 @@ impl AhjoorContract {
                 buf
             })
             .into();
-        env.events().publish(
-            (Symbol::new(env, "WaitlistUpdated"),),
-            (order_hash,),
-        );
+        events::emit_waitlist_order_updated(env, order_hash);
 
         env.storage().instance().set(&DataKey2::Waitlist, &new_waitlist);
 
         // Add to members
         let mut members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
         members.push_back(new_member.clone());
-        env.storage().instance().set(&DataKey::Members, &members);
+        env.storage().persistent().set(&DataKey::Members, &members);
 
         // Add to payout order at the end
         let mut payout_order: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::PayoutOrder)
-            .expect("Payout order not set");
+            .unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
         payout_order.push_back(new_member.clone());
-        env.storage().instance().set(&DataKey::PayoutOrder, &payout_order);
+        env.storage().persistent().set(&DataKey::PayoutOrder, &payout_order);
 
         // Calculate catch-up contribution: rounds already elapsed × contribution_amount
         let current_round: u32 = env
@@ -7541,9 +13426,16 @@ impl AhjoorContract {
 
         // Collect catch-up immediately (new_member must have authorized this call chain)
         if catch_up_amount > 0 {
-            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(env, ExtError2::NotInitialized));
             let client = token::Client::new(env, &token_addr);
             client.transfer(&new_member, &env.current_contract_address(), &catch_up_amount);
+            internals::record_fund_flow(
+                env,
+                "contribution",
+                &new_member,
+                &env.current_contract_address(),
+                catch_up_amount,
+            );
         }
 
         // #352: Rebalance contributions now that active member count has changed
@@ -7571,12 +13463,19 @@ impl AhjoorContract {
 
         let amount = debts.get(member.clone()).unwrap_or(0);
         if amount == 0 {
-            panic!("No catch-up contribution owed");
+            panic_with_error!(&env, ExtError2::NoDebtRecorded);
         }
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         let client = token::Client::new(&env, &token_addr);
         client.transfer(&member, &env.current_contract_address(), &amount);
+        internals::record_fund_flow(
+            &env,
+            "contribution",
+            &member,
+            &env.current_contract_address(),
+            amount,
+        );
 
         debts.remove(member.clone());
         env.storage().instance().set(&DataKey2::CatchUpDebt, &debts);
@@ -7604,11 +13503,12 @@ impl AhjoorContract {
     pub fn propose_merge(env: Env, admin: Address, group_b_id: u32) -> u32 {
         internals::check_not_paused(&env);
         admin.require_auth();
+        internals::note_admin_action(&env);
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
             panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
@@ -7620,17 +13520,14 @@ impl AhjoorContract {
             .get(&DataKey2::GroupStatus)
             .unwrap_or(GroupStatus::Active);
         if group_status != GroupStatus::Active {
-            panic!("Group is not active");
+            panic_with_error!(&env, ExtError3::GroupNotActive);
         }
 
         // Merges are only permitted between rounds (PaidMembers must be empty)
-        let paid_members: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::PaidMembers)
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
             .unwrap_or(Vec::new(&env));
         if !paid_members.is_empty() {
-            panic!("Merge only permitted between rounds");
+            panic_with_error!(&env, Error::CannotChangeMidRound);
         }
 
         let proposal_id: u32 = env
@@ -7679,11 +13576,12 @@ impl AhjoorContract {
     ) {
         internals::check_not_paused(&env);
         admin.require_auth();
+        internals::note_admin_action(&env);
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
             panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
@@ -7693,10 +13591,10 @@ impl AhjoorContract {
             .instance()
             .get(&DataKey4::MergeProposals)
             .unwrap_or(Map::new(&env));
-        let mut proposal = proposals.get(merge_proposal_id).expect("Merge proposal not found");
+        let mut proposal = proposals.get(merge_proposal_id).unwrap_or_else(|| panic_with_error!(&env, Error::ProposalNotFound));
 
         if proposal.accepted {
-            panic!("Merge proposal already accepted");
+            panic_with_error!(&env, Error::ProposalNotPending);
         }
 
         proposal.accepted = true;
@@ -7725,11 +13623,12 @@ impl AhjoorContract {
     ) {
         internals::check_not_paused(&env);
         admin.require_auth();
+        internals::note_admin_action(&env);
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
             panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
@@ -7741,7 +13640,7 @@ impl AhjoorContract {
             .get(&DataKey2::GroupStatus)
             .unwrap_or(GroupStatus::Active);
         if group_status == GroupStatus::Merged {
-            panic!("Group already merged");
+            panic_with_error!(&env, ExtError3::GroupNotActive);
         }
 
         let mut proposals: Map<u32, MergeProposal> = env
@@ -7749,7 +13648,7 @@ impl AhjoorContract {
             .instance()
             .get(&DataKey4::MergeProposals)
             .unwrap_or(Map::new(&env));
-        let proposal = proposals.get(merge_proposal_id).expect("Merge proposal not found");
+        let proposal = proposals.get(merge_proposal_id).unwrap_or_else(|| panic_with_error!(&env, Error::ProposalNotFound));
 
         // Guard: Group B admin must have accepted the proposal first
         if !proposal.accepted {
@@ -7757,13 +13656,10 @@ impl AhjoorContract {
         }
 
         // Merges are only permitted between rounds
-        let paid_members: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::PaidMembers)
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
             .unwrap_or(Vec::new(&env));
         if !paid_members.is_empty() {
-            panic!("Merge only permitted between rounds");
+            panic_with_error!(&env, Error::CannotChangeMidRound);
         }
 
         let max_members: u32 = env
@@ -7774,20 +13670,20 @@ impl AhjoorContract {
 
         let mut members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
 
         let combined_count = members.len() as u32 + new_members.len() as u32;
         if combined_count > max_members {
-            panic!("Combined member count exceeds max_members");
+            panic_with_error!(&env, Error::GroupFull);
         }
 
         let mut payout_order: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::PayoutOrder)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
 
         for m in new_members.iter() {
             if !members.contains(&m) {
@@ -7796,8 +13692,8 @@ impl AhjoorContract {
             }
         }
 
-        env.storage().instance().set(&DataKey::Members, &members);
-        env.storage().instance().set(&DataKey::PayoutOrder, &payout_order);
+        env.storage().persistent().set(&DataKey::Members, &members);
+        env.storage().persistent().set(&DataKey::PayoutOrder, &payout_order);
 
         // Permanently mark the source group as merged — prevents re-execution
         env.storage()
@@ -7828,7 +13724,7 @@ impl AhjoorContract {
             .instance()
             .get(&DataKey4::MergeProposals)
             .unwrap_or(Map::new(&env));
-        proposals.get(proposal_id).expect("Merge proposal not found")
+        proposals.get(proposal_id).unwrap_or_else(|| panic_with_error!(&env, Error::ProposalNotFound))
     }
 
     /// Returns the current GroupStatus for this group.
@@ -7846,11 +13742,12 @@ impl AhjoorContract {
     /// add_member, remove_member) are blocked while frozen.
     pub fn freeze_group(env: Env, admin: Address, group_id: u32, reason_hash: BytesN<32>) {
         admin.require_auth();
+        internals::note_admin_action(&env);
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
             panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
@@ -7897,9 +13794,9 @@ impl AhjoorContract {
         member.require_auth();
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
             panic_with_error!(&env, Error::NotAMember);
         }
@@ -7907,7 +13804,7 @@ impl AhjoorContract {
         let mut co_signers: Map<Address, CoSignerRecord> = env
             .storage()
             .instance()
-            .get(&DataKey4::CoSigners)
+            .get(&DataKey2::CoSigners)
             .unwrap_or(Map::new(&env));
         if co_signers.contains_key(member.clone()) {
             panic_with_error!(&env, ExtError::CoSignerAlreadySet);
@@ -7917,7 +13814,7 @@ impl AhjoorContract {
             co_signer: co_signer.clone(),
             status: CoSignerStatus::Pending,
         });
-        env.storage().instance().set(&DataKey4::CoSigners, &co_signers);
+        env.storage().instance().set(&DataKey2::CoSigners, &co_signers);
 
         events::emit_co_signer_set(&env, group_id, member, co_signer);
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
@@ -7926,11 +13823,12 @@ impl AhjoorContract {
     /// Contract-level admin unfreezes the group, logging the resolution on-chain.
     pub fn unfreeze_group(env: Env, admin: Address, group_id: u32, resolution_hash: BytesN<32>) {
         admin.require_auth();
+        internals::note_admin_action(&env);
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
             panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
@@ -7977,7 +13875,7 @@ impl AhjoorContract {
         let mut co_signers: Map<Address, CoSignerRecord> = env
             .storage()
             .instance()
-            .get(&DataKey4::CoSigners)
+            .get(&DataKey2::CoSigners)
             .unwrap_or(Map::new(&env));
         let mut record = co_signers.get(member.clone()).unwrap_or_else(|| {
             panic_with_error!(&env, ExtError::NoCoSignerFound)
@@ -7987,7 +13885,7 @@ impl AhjoorContract {
         }
         record.status = CoSignerStatus::Active;
         co_signers.set(member.clone(), record);
-        env.storage().instance().set(&DataKey4::CoSigners, &co_signers);
+        env.storage().instance().set(&DataKey2::CoSigners, &co_signers);
 
         events::emit_co_signer_accepted(&env, group_id, member, co_signer);
         env.storage()
@@ -8010,9 +13908,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
             panic_with_error!(&env, Error::NotAMember);
         }
@@ -8074,15 +13972,11 @@ impl AhjoorContract {
             .get(&DataKey2::UseTimestampSchedule)
             .unwrap_or(false);
         let deadline: u64 = if use_timestamp {
-            env.storage()
-                .instance()
-                .get(&DataKey::RoundDeadlineTimestamp)
-                .expect("Timestamp deadline not set")
+            internals::round_state_get(&env, &DataKey::RoundDeadlineTimestamp)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
         } else {
-            env.storage()
-                .instance()
-                .get(&DataKey::RoundDeadline)
-                .expect("Deadline not set")
+            internals::round_state_get(&env, &DataKey::RoundDeadline)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
         };
         if env.ledger().timestamp() > deadline {
             panic_with_error!(&env, Error::ContributionWindowClosed);
@@ -8099,9 +13993,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
             panic_with_error!(&env, Error::NotAMember);
         }
@@ -8109,14 +14003,11 @@ impl AhjoorContract {
         let activation_emitted: bool = env
             .storage()
             .instance()
-            .get(&DataKey4::GroupActivationEmitted)
+            .get(&DataKey2::GroupActivationEmitted)
             .unwrap_or(false);
 
-        let mut paid_members: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::PaidMembers)
-            .expect("Not initialized");
+        let mut paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if paid_members.contains(&member) {
             panic_with_error!(&env, Error::AlreadyContributed);
         }
@@ -8136,7 +14027,7 @@ impl AhjoorContract {
         }
 
         if auth.used_rounds >= auth.max_rounds {
-            panic_with_error!(&env, ExtError2::ProxyRoundsExhausted);
+            panic_with_error!(&env, ExtError::ProxyRoundsExhausted);
         }
 
         let approved_tokens: Vec<Address> = env
@@ -8149,24 +14040,12 @@ impl AhjoorContract {
         }
         Self::require_token_allowed(&env, &token);
 
-        let base_token: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let base_token: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if token != base_token {
             panic_with_error!(&env, ExtError2::IncorrectContributionAmount);
         }
 
-        let base_amount: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::ContributionAmt)
-            .unwrap();
-
-        let tiers: Map<Address, u32> = env
-            .storage()
-            .instance()
-            .get(&DataKey2::MemberTiers)
-            .unwrap_or(Map::new(&env));
-        let tier_bps = tiers.get(member.clone()).unwrap_or(10_000);
-        let member_required_amount = (base_amount * tier_bps as i128) / 10_000;
+        let member_required_amount = internals::member_required_amount(&env, &member);
 
         let mut member_contributions: Map<Address, i128> = env
             .storage()
@@ -8205,6 +14084,13 @@ impl AhjoorContract {
 
         let client = token::Client::new(&env, &token);
         client.transfer(&proxy, &env.current_contract_address(), &total_transfer_amount);
+        internals::record_fund_flow(
+            &env,
+            "contribution",
+            &proxy,
+            &env.current_contract_address(),
+            total_transfer_amount,
+        );
 
         if insurance_deduction > 0 {
             let mut insurance_pool: i128 = env
@@ -8242,8 +14128,9 @@ impl AhjoorContract {
 
         Self::apply_reputation_delta(&env, member.clone(), 10, "on_time_full");
         Self::update_credit_score_internal(&env, &member, Symbol::new(&env, "on_time"));
+        internals::note_round_opened(&env, &paid_members);
         paid_members.push_back(member.clone());
-        env.storage().instance().set(&DataKey::PaidMembers, &paid_members);
+        internals::round_state_set(&env, &DataKey::PaidMembers, &paid_members);
 
         let mut total_participations: u32 = env
             .storage()
@@ -8265,7 +14152,9 @@ impl AhjoorContract {
             .instance()
             .set(&DataKey::MemberParticipation, &member_participation);
 
-        if paid_members.len() == members.len() {
+        if paid_members.len() >= internals::active_member_count(&env)
+            && !Self::carry_below_min_pot(&env)
+        {
             internals::complete_round_payout(&env, &paid_members);
 
             let auto_close_enabled: bool = env
@@ -8351,7 +14240,7 @@ impl AhjoorContract {
             events::emit_group_activated(&env, start_at);
             env.storage()
                 .instance()
-                .set(&DataKey4::GroupActivationEmitted, &true);
+                .set(&DataKey2::GroupActivationEmitted, &true);
         }
 
         env.storage()
@@ -8403,7 +14292,7 @@ impl AhjoorContract {
         let co_signers: Map<Address, CoSignerRecord> = env
             .storage()
             .instance()
-            .get(&DataKey4::CoSigners)
+            .get(&DataKey2::CoSigners)
             .unwrap_or(Map::new(&env));
         let record = co_signers.get(member.clone()).unwrap_or_else(|| {
             panic_with_error!(&env, ExtError::NoCoSignerFound)
@@ -8427,7 +14316,7 @@ impl AhjoorContract {
         let co_signer_window: u32 = env
             .storage()
             .instance()
-            .get(&DataKey4::CoSignerWindowLedgers)
+            .get(&DataKey2::CoSignerWindowLedgers)
             .unwrap_or(0);
         if env.ledger().sequence() >= start + co_signer_window {
             panic_with_error!(&env, ExtError::CoSignerWindowExpired);
@@ -8436,17 +14325,22 @@ impl AhjoorContract {
         // Transfer from co-signer to contract on behalf of member
         let token_client = token::Client::new(&env, &token);
         token_client.transfer(&co_signer, &env.current_contract_address(), &amount);
+        internals::record_fund_flow(
+            &env,
+            "contribution",
+            &co_signer,
+            &env.current_contract_address(),
+            amount,
+        );
 
         // Record contribution under member's name
-        let mut paid_members: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::PaidMembers)
+        let mut paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
             .unwrap_or(Vec::new(&env));
         if !paid_members.contains(&member) {
+            internals::note_round_opened(&env, &paid_members);
             paid_members.push_back(member.clone());
         }
-        env.storage().instance().set(&DataKey::PaidMembers, &paid_members);
+        internals::round_state_set(&env, &DataKey::PaidMembers, &paid_members);
 
         // Clear the window
         let mut window_starts_mut: Map<Address, u32> = env
@@ -8470,10 +14364,7 @@ impl AhjoorContract {
         member.require_auth();
 
         // Only allowed between rounds (paid_members must be empty)
-        let paid_members: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::PaidMembers)
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
             .unwrap_or(Vec::new(&env));
         if !paid_members.is_empty() {
             panic_with_error!(&env, Error::CannotChangeMidRound);
@@ -8482,13 +14373,13 @@ impl AhjoorContract {
         let mut co_signers: Map<Address, CoSignerRecord> = env
             .storage()
             .instance()
-            .get(&DataKey4::CoSigners)
+            .get(&DataKey2::CoSigners)
             .unwrap_or(Map::new(&env));
         if !co_signers.contains_key(member.clone()) {
             panic_with_error!(&env, ExtError::NoCoSignerFound);
         }
         co_signers.remove(member.clone());
-        env.storage().instance().set(&DataKey4::CoSigners, &co_signers);
+        env.storage().instance().set(&DataKey2::CoSigners, &co_signers);
 
         let _ = group_id; // used in event
         env.storage()
@@ -8510,24 +14401,26 @@ impl AhjoorContract {
     pub fn set_min_snapshot_interval(env: Env, admin: Address, interval_ledgers: u32) {
         internals::check_not_paused(&env);
         admin.require_auth();
-        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Not initialized");
-        if admin != stored_admin { panic!("Only admin can set snapshot interval"); }
+        internals::note_admin_action(&env);
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if admin != stored_admin { panic_with_error!(&env, ExtError::OnlyAdminAllowed); }
 
         env.storage().persistent().set(&PersistentKey::MinSnapshotIntervalLedgers, &interval_ledgers);
         env.storage().persistent().extend_ttl(&PersistentKey::MinSnapshotIntervalLedgers, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
     }
 
-    /// Any member or admin takes a snapshot of the current group state.
+    /// Any member, operator or the admin takes a snapshot of the current group state.
     /// Appends to the append-only SnapshotLog in persistent storage.
     pub fn take_snapshot(env: Env, caller: Address) -> u32 {
         internals::check_not_paused(&env);
         caller.require_auth();
 
-        // Caller must be a member or admin
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Not initialized");
-        let members: Vec<Address> = env.storage().instance().get(&DataKey::Members).unwrap_or(Vec::new(&env));
-        if caller != admin && !members.contains(&caller) {
+        // Caller must be a member, an operator or the admin
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let members: Vec<Address> = env.storage().persistent().get(&DataKey::Members).unwrap_or(Vec::new(&env));
+        let operators = Self::get_operators(env.clone());
+        if caller != admin && !members.contains(&caller) && !operators.contains(&caller) {
             panic_with_error!(&env, Error::OnlyMembersAllowed);
         }
 
@@ -8544,7 +14437,7 @@ impl AhjoorContract {
 
         // Collect current state
         let current_round: u32 = env.storage().instance().get(&DataKey::CurrentRound).unwrap_or(0);
-        let payout_order: Vec<Address> = env.storage().instance().get(&DataKey::PayoutOrder).unwrap_or(Vec::new(&env));
+        let payout_order: Vec<Address> = env.storage().persistent().get(&DataKey::PayoutOrder).unwrap_or(Vec::new(&env));
 
         // Compute pooled balance: sum of member contributions this round
         let member_contributions: Map<Address, i128> = env.storage().instance().get(&DataKey::MemberContributions).unwrap_or(Map::new(&env));
@@ -8598,7 +14491,7 @@ impl AhjoorContract {
     /// Returns a specific snapshot by ID.
     pub fn get_snapshot(env: Env, snapshot_id: u32) -> GroupSnapshot {
         let log: Vec<GroupSnapshot> = env.storage().persistent().get(&PersistentKey::SnapshotLog).unwrap_or(Vec::new(&env));
-        log.get(snapshot_id).expect("Snapshot not found")
+        log.get(snapshot_id).unwrap_or_else(|| panic_with_error!(&env, ExtError3::SnapshotNotFound))
     }
 
     /// Returns the total number of snapshots taken.
@@ -8631,7 +14524,7 @@ impl AhjoorContract {
     ) {
         // Validate tiers
         if tiers.is_empty() {
-            panic!("At least one tier is required");
+            panic_with_error!(&env, ExtError::InvalidTierDefinition);
         }
         for i in 0..tiers.len() {
             let t = tiers.get(i).unwrap();
@@ -8665,15 +14558,15 @@ impl AhjoorContract {
         member.require_auth();
 
         // Validate that the group has tiers configured
-        let tiers: Vec<Tier> = env.storage().instance().get(&DataKey3::GroupTiers).expect("Group has no tiers; use create_group_tiered");
+        let tiers: Vec<Tier> = env.storage().instance().get(&DataKey3::GroupTiers).unwrap_or_else(|| panic_with_error!(&env, ExtError::TierNotFound));
 
         if tier_id >= tiers.len() {
             panic_with_error!(&env, ExtError::TierNotFound);
         }
 
-        let members: Vec<Address> = env.storage().instance().get(&DataKey::Members).expect("Not initialized");
+        let members: Vec<Address> = env.storage().persistent().get(&DataKey::Members).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
-            panic!("Only existing members can join with a tier; use add_member first");
+            panic_with_error!(&env, Error::NotAMember);
         }
 
         // Check min credit score before allowing join
@@ -8697,7 +14590,7 @@ impl AhjoorContract {
         internals::check_not_paused(&env);
         member.require_auth();
 
-        let tiers: Vec<Tier> = env.storage().instance().get(&DataKey3::GroupTiers).expect("Group has no tiers");
+        let tiers: Vec<Tier> = env.storage().instance().get(&DataKey3::GroupTiers).unwrap_or_else(|| panic_with_error!(&env, ExtError::TierNotFound));
         if new_tier_id >= tiers.len() {
             panic_with_error!(&env, ExtError::TierNotFound);
         }
@@ -8710,7 +14603,7 @@ impl AhjoorContract {
         let current_tier_id = member_tier_index.get(member.clone()).unwrap_or(0);
 
         if current_tier_id == new_tier_id {
-            panic!("New tier is the same as current tier");
+            panic_with_error!(&env, ExtError::InvalidTier);
         }
 
         let mut pending: Map<Address, u32> = env
@@ -8722,7 +14615,7 @@ impl AhjoorContract {
         env.storage().instance().set(&DataKey3::PendingTierChange, &pending);
 
         let current_round: u32 = env.storage().instance().get(&DataKey::CurrentRound).unwrap_or(0);
-        let payout_order: Vec<Address> = env.storage().instance().get(&DataKey::PayoutOrder).unwrap_or(Vec::new(&env));
+        let payout_order: Vec<Address> = env.storage().persistent().get(&DataKey::PayoutOrder).unwrap_or(Vec::new(&env));
         let cycle_len = payout_order.len() as u32;
         let effective_cycle = if cycle_len > 0 { (current_round / cycle_len) + 1 } else { 1 };
 
@@ -8733,8 +14626,9 @@ impl AhjoorContract {
     /// Admin applies any queued tier changes (called at the start of each new cycle).
     pub fn apply_pending_tier_changes(env: Env, admin: Address) {
         admin.require_auth();
-        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Not initialized");
-        if admin != stored_admin { panic!("Only admin can apply tier changes"); }
+        internals::note_admin_action(&env);
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if admin != stored_admin { panic_with_error!(&env, ExtError::OnlyAdminAllowed); }
 
         let pending: Map<Address, u32> = env
             .storage()
@@ -8751,7 +14645,7 @@ impl AhjoorContract {
             .unwrap_or(Map::new(&env));
 
         let current_round: u32 = env.storage().instance().get(&DataKey::CurrentRound).unwrap_or(0);
-        let payout_order: Vec<Address> = env.storage().instance().get(&DataKey::PayoutOrder).unwrap_or(Vec::new(&env));
+        let payout_order: Vec<Address> = env.storage().persistent().get(&DataKey::PayoutOrder).unwrap_or(Vec::new(&env));
         let cycle_len = payout_order.len() as u32;
         let current_cycle = if cycle_len > 0 { current_round / cycle_len } else { 0 };
 
@@ -8797,8 +14691,9 @@ impl AhjoorContract {
         completion_weight: i128,
     ) {
         admin.require_auth();
-        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Not initialized");
-        if admin != stored_admin { panic!("Only admin can set score weights"); }
+        internals::note_admin_action(&env);
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if admin != stored_admin { panic_with_error!(&env, ExtError::OnlyAdminAllowed); }
 
         let weights = ScoreWeights {
             on_time_weight,
@@ -8814,8 +14709,9 @@ impl AhjoorContract {
     /// Admin sets the minimum credit score required to join this group.
     pub fn set_min_credit_score(env: Env, admin: Address, min_score: i128) {
         admin.require_auth();
-        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Not initialized");
-        if admin != stored_admin { panic!("Only admin can set minimum credit score"); }
+        internals::note_admin_action(&env);
+        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if admin != stored_admin { panic_with_error!(&env, ExtError::OnlyAdminAllowed); }
 
         env.storage().instance().set(&DataKey3::MinCreditScore, &min_score);
         env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
@@ -8865,13 +14761,14 @@ impl AhjoorContract {
     /// Enable group treasury for collective purchases (#314)
     pub fn enable_group_treasury(env: Env, admin: Address, treasury_admin: Address) {
         admin.require_auth();
+        internals::note_admin_action(&env);
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
-            panic!("Only admin can enable treasury");
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
 
         let config = TreasuryConfig {
@@ -8903,11 +14800,11 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
-            panic!("Only members can propose treasury rounds");
+            panic_with_error!(&env, Error::OnlyMembersAllowed);
         }
 
         let proposal = TreasuryRoundProposal {
@@ -8940,9 +14837,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
             panic_with_error!(&env, Error::NotAMember);
         }
@@ -8954,14 +14851,14 @@ impl AhjoorContract {
             .get::<_, bool>(&DataKey3::TreasuryRoundVotes(round_index, member.clone()))
             .is_some()
         {
-            panic!("Member already voted on this round");
+            panic_with_error!(&env, Error::AlreadyVoted);
         }
 
         let mut proposal: TreasuryRoundProposal = env
             .storage()
             .instance()
             .get(&DataKey3::TreasuryRoundProposal(round_index))
-            .expect("Proposal not found");
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ProposalNotFound));
 
         if vote_for {
             proposal.votes_for = proposal.votes_for.saturating_add(1);
@@ -8976,7 +14873,7 @@ impl AhjoorContract {
             .get(&DataKey2::QuorumConfig)
             .unwrap_or(Map::new(&env));
         let quorum_bps = quorum_config.get(ProposalType::RuleChange).unwrap_or(5_100);
-        let quorum_required = (members.len() as u32 * quorum_bps) / 10_000;
+        let quorum_required = (members.len() * quorum_bps).div_ceil(10_000);
         if proposal.votes_for >= quorum_required as i128 {
             proposal.confirmed = true;
             events::emit_treasury_round_confirmed(&env, round_index);
@@ -8998,7 +14895,7 @@ impl AhjoorContract {
         env.storage()
             .instance()
             .get(&DataKey3::TreasuryRoundProposal(round_index))
-            .expect("Proposal not found")
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ProposalNotFound))
     }
 
     /// Execute treasury payment with member vote approval (#314)
@@ -9015,10 +14912,10 @@ impl AhjoorContract {
             .storage()
             .instance()
             .get(&DataKey3::TreasuryConfig)
-            .expect("Treasury not enabled");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError3::TreasuryNotEnabled));
 
         if treasury_admin != config.treasury_admin {
-            panic!("Only treasury admin can execute payments");
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
 
         let balance: i128 = env
@@ -9028,17 +14925,24 @@ impl AhjoorContract {
             .unwrap_or(0);
 
         if amount > balance {
-            panic!("Insufficient treasury balance");
+            panic_with_error!(&env, ExtError2::InsufficientBalance);
         }
 
         let token: Address = env
             .storage()
             .instance()
             .get(&DataKey::Token)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
 
         let client = token::Client::new(&env, &token);
         client.transfer(&env.current_contract_address(), &recipient, &amount);
+        internals::record_fund_flow(
+            &env,
+            "treasury",
+            &env.current_contract_address(),
+            &recipient,
+            amount,
+        );
 
         let new_balance = balance - amount;
         env.storage()
@@ -9154,9 +15058,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
             panic_with_error!(&env, Error::NotAMember);
         }
@@ -9179,10 +15083,10 @@ impl AhjoorContract {
         };
 
         if expiry_ledger == 0 {
-            panic!("expiry_ledger cannot be 0; infinite delegation is not allowed");
+            panic_with_error!(&env, ExtError3::InvalidExpiry);
         }
         if expiry_ledger <= current_time {
-            panic!("expiry_ledger must be in the future");
+            panic_with_error!(&env, ExtError3::InvalidExpiry);
         }
 
         let mut delegations: Map<Address, ContribDelegationRecord> = env
@@ -9290,9 +15194,9 @@ impl AhjoorContract {
         // Proxy pays via transfer_from (proxy must have allowance from member)
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
             panic_with_error!(&env, Error::NotAMember);
         }
@@ -9303,20 +15207,14 @@ impl AhjoorContract {
             .instance()
             .get(&DataKey::CurrentRound)
             .unwrap_or(0);
-        let round_deadline: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::RoundDeadline)
+        let round_deadline: u64 = internals::round_state_get(&env, &DataKey::RoundDeadline)
             .unwrap_or(0);
         if env.ledger().timestamp() > round_deadline && round_deadline != 0 {
             panic_with_error!(&env, Error::ContributionWindowClosed);
         }
 
         // Ensure not already contributed this round
-        let mut paid_members: Vec<Address> = env
-            .storage()
-            .instance()
-            .get(&DataKey::PaidMembers)
+        let mut paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
             .unwrap_or(Vec::new(&env));
         if paid_members.contains(&member) {
             panic_with_error!(&env, Error::AlreadyContributed);
@@ -9326,7 +15224,7 @@ impl AhjoorContract {
             .storage()
             .instance()
             .get(&DataKey::ContributionAmt)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if amount < contribution_amount {
             panic_with_error!(&env, Error::AmountMustBePositive);
         }
@@ -9339,9 +15237,17 @@ impl AhjoorContract {
             &env.current_contract_address(),
             &amount,
         );
+        internals::record_fund_flow(
+            &env,
+            "contribution",
+            &member,
+            &env.current_contract_address(),
+            amount,
+        );
 
+        internals::note_round_opened(&env, &paid_members);
         paid_members.push_back(member.clone());
-        env.storage().instance().set(&DataKey::PaidMembers, &paid_members);
+        internals::round_state_set(&env, &DataKey::PaidMembers, &paid_members);
 
         let mut member_contributions: Map<Address, i128> = env
             .storage()
@@ -9393,9 +15299,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
             panic_with_error!(&env, Error::NotAMember);
         }
@@ -9446,11 +15352,12 @@ impl AhjoorContract {
     pub fn set_split_confirmation_window(env: Env, admin: Address, window_ledgers: u32) {
         internals::check_not_paused(&env);
         admin.require_auth();
+        internals::note_admin_action(&env);
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
             panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
@@ -9474,11 +15381,12 @@ impl AhjoorContract {
     ) -> u32 {
         internals::check_not_paused(&env);
         admin.require_auth();
+        internals::note_admin_action(&env);
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
             panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
@@ -9492,14 +15400,14 @@ impl AhjoorContract {
             panic_with_error!(&env, ExtError::SourceGroupAlreadySplit);
         }
         if group_status != GroupStatus::Active {
-            panic!("Group is not active");
+            panic_with_error!(&env, ExtError3::GroupNotActive);
         }
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
 
         // Every current member must be in exactly one sub-list
         for m in members.iter() {
@@ -9572,9 +15480,9 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
             panic_with_error!(&env, Error::NotAMember);
         }
@@ -9589,7 +15497,7 @@ impl AhjoorContract {
             .unwrap_or_else(|| panic_with_error!(&env, ExtError::SplitProposalNotFound));
 
         if proposal.status != SplitProposalStatus::Pending {
-            panic!("Proposal is not pending");
+            panic_with_error!(&env, Error::ProposalNotPending);
         }
         if env.ledger().sequence() > proposal.expiry_ledger {
             panic_with_error!(&env, ExtError::SplitConfirmationWindowClosed);
@@ -9599,7 +15507,7 @@ impl AhjoorContract {
         let in_a = proposal.group_a_members.contains(&member);
         let in_b = proposal.group_b_members.contains(&member);
         if !in_a && !in_b {
-            panic!("Member not part of this split proposal");
+            panic_with_error!(&env, ExtError::SplitMembersInvalid);
         }
         if proposal.confirmations.contains(&member) {
             panic_with_error!(&env, ExtError::SplitAlreadyConfirmed);
@@ -9623,11 +15531,12 @@ impl AhjoorContract {
     pub fn execute_group_split(env: Env, admin: Address, group_id: u32, proposal_id: u32) {
         internals::check_not_paused(&env);
         admin.require_auth();
+        internals::note_admin_action(&env);
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
             panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
@@ -9651,19 +15560,19 @@ impl AhjoorContract {
 
         let members: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Members)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         let token: Address = env
             .storage()
             .instance()
             .get(&DataKey::Token)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         let contribution_amount: i128 = env
             .storage()
             .instance()
             .get(&DataKey::ContributionAmt)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
 
         // Separate confirmed vs unconfirmed members
         let mut confirmed_a: Vec<Address> = Vec::new(&env);
@@ -9696,6 +15605,13 @@ impl AhjoorContract {
                         &m,
                         &per_member_share,
                     );
+                    internals::record_fund_flow(
+                        &env,
+                        "payout",
+                        &env.current_contract_address(),
+                        &m,
+                        per_member_share,
+                    );
                 }
             }
         }
@@ -9804,7 +15720,7 @@ impl AhjoorContract {
         member.require_auth();
 
         if amount <= 0 {
-            panic!("Loan amount must be positive");
+            panic_with_error!(&env, Error::AmountMustBePositive);
         }
 
         // Check if reserve is enabled
@@ -9814,7 +15730,7 @@ impl AhjoorContract {
             .get(&DataKey3::ReserveEnabled)
             .unwrap_or(false);
         if !reserve_enabled {
-            panic!("Emergency reserve is not enabled for this group");
+            panic_with_error!(&env, ExtError3::ReserveNotEnabled);
         }
 
         // Check if member already has an outstanding loan
@@ -9835,14 +15751,14 @@ impl AhjoorContract {
             .unwrap_or(0);
 
         if reserve_balance < amount {
-            panic!("Insufficient reserve balance for loan");
+            panic_with_error!(&env, ExtError3::InsufficientReserve);
         }
 
         // Check MAX_LOAN_FRACTION_BPS (default 50% of reserve)
         const MAX_LOAN_FRACTION_BPS: u32 = 5_000; // 50%
         let max_loan = (reserve_balance * MAX_LOAN_FRACTION_BPS as i128) / 10_000;
         if amount > max_loan {
-            panic!("Loan amount exceeds maximum allowed fraction of reserve");
+            panic_with_error!(&env, ExtError3::InsufficientReserve);
         }
 
         // Create loan record
@@ -9894,9 +15810,10 @@ impl AhjoorContract {
             .storage()
             .instance()
             .get(&DataKey::Token)
-            .expect("Token not configured");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         let client = token::Client::new(&env, &token);
         client.transfer(&env.current_contract_address(), &member, &amount);
+        internals::record_fund_flow(&env, "loan", &env.current_contract_address(), &member, amount);
 
         events::emit_emergency_loan_granted(&env, 0, member, loan_id, amount, repayment_deadline);
 
@@ -9912,26 +15829,26 @@ impl AhjoorContract {
         member.require_auth();
 
         if amount <= 0 {
-            panic!("Repayment amount must be positive");
+            panic_with_error!(&env, Error::AmountMustBePositive);
         }
 
         let mut loan: EmergencyLoan = env
             .storage()
             .persistent()
             .get(&DataKey3::EmergencyLoan(loan_id))
-            .expect("Loan not found");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError3::LoanNotFound));
 
         if loan.borrower != member {
-            panic!("Only the borrower can repay this loan");
+            panic_with_error!(&env, ExtError3::NotTheBorrower);
         }
 
         if loan.defaulted {
-            panic!("Loan has defaulted and cannot be repaid");
+            panic_with_error!(&env, ExtError3::LoanDefaulted);
         }
 
         let remaining_owed = loan.amount - loan.repaid_amount;
         if amount > remaining_owed {
-            panic!("Repayment amount exceeds remaining balance");
+            panic_with_error!(&env, ExtError::InvalidAmount);
         }
 
         loan.repaid_amount += amount;
@@ -9957,9 +15874,16 @@ impl AhjoorContract {
             .storage()
             .instance()
             .get(&DataKey::Token)
-            .expect("Token not configured");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         let client = token::Client::new(&env, &token);
         client.transfer(&member, &env.current_contract_address(), &amount);
+        internals::record_fund_flow(
+            &env,
+            "repayment",
+            &member,
+            &env.current_contract_address(),
+            amount,
+        );
 
         // Add to reserve balance
         let reserve_balance: i128 = env
@@ -9990,7 +15914,7 @@ impl AhjoorContract {
         env.storage()
             .persistent()
             .get(&DataKey3::EmergencyLoan(loan_id))
-            .expect("Loan not found")
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError3::LoanNotFound))
     }
 
     /// Get member's active loan ID (0 if none)
@@ -10037,13 +15961,14 @@ impl AhjoorContract {
     /// as the seed for deterministic, reproducible randomization.
     pub fn finalize_payout_order(env: Env, admin: Address) {
         admin.require_auth();
+        internals::note_admin_action(&env);
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
-            panic!("Only admin can finalize payout order");
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
 
         // Check if randomization is enabled
@@ -10053,7 +15978,7 @@ impl AhjoorContract {
             .get(&DataKey3::RandomizePayoutOrder)
             .unwrap_or(false);
         if !randomize_enabled {
-            panic!("Payout order randomization not enabled for this group");
+            panic_with_error!(&env, ExtError3::RandomizationNotEnabled);
         }
 
         // Check if already finalized
@@ -10063,15 +15988,15 @@ impl AhjoorContract {
             .get(&DataKey3::PayoutOrderFinalized)
             .unwrap_or(false);
         if already_finalized {
-            panic!("Payout order already finalized");
+            panic_with_error!(&env, ExtError3::PayoutOrderFinalized);
         }
 
         // Get current payout order
         let mut payout_order: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::PayoutOrder)
-            .expect("Payout order not initialized");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
 
         // Generate seed from ledger hash + group_id + member_count
         let ledger_hash = env.ledger().sequence();
@@ -10096,7 +16021,7 @@ impl AhjoorContract {
 
         // Store finalized order
         env.storage()
-            .instance()
+            .persistent()
             .set(&DataKey::PayoutOrder, &payout_order.clone());
         env.storage()
             .instance()
@@ -10114,82 +16039,465 @@ impl AhjoorContract {
         let current_round: u32 = env
             .storage()
             .instance()
-            .get(&DataKey::CurrentRound)
-            .unwrap_or(1);
-        events::emit_payout_order_finalized(&env, current_round, payout_order);
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(1);
+        events::emit_payout_order_finalized(&env, current_round, payout_order);
+    }
+
+    /// Fisher-Yates shuffle using seed-derived randomness.
+    /// Deterministic: same seed produces same shuffle.
+    fn fisher_yates_shuffle(
+        env: &Env,
+        mut items: Vec<Address>,
+        seed: &BytesN<32>,
+    ) -> Vec<Address> {
+        let n = items.len();
+        if n <= 1 {
+            return items;
+        }
+
+        // Use seed bytes as pseudo-random source
+        let mut seed_index = 0u32;
+
+        for i in (1..n).rev() {
+            // Get next pseudo-random byte from seed
+            let rand_byte = seed.get(seed_index % 32).unwrap_or(0);
+            seed_index = seed_index.wrapping_add(1);
+
+            // Compute j = random index in [0, i]
+            let j = (rand_byte as u32) % (i + 1);
+
+            // Swap items[i] and items[j]
+            if i != j {
+                let mut new_items = Vec::new(env);
+                for idx in 0..n {
+                    if idx == i {
+                        new_items.push_back(items.get(j).unwrap());
+                    } else if idx == j {
+                        new_items.push_back(items.get(i).unwrap());
+                    } else {
+                        new_items.push_back(items.get(idx).unwrap());
+                    }
+                }
+                items = new_items;
+            }
+        }
+
+        items
+    }
+
+    // ── #364: Cycle Snapshot Versioning ──────────────────────────────────────
+
+    /// Returns the immutable cycle snapshot stored at cycle end for the given cycle number.
+    /// `group_id` is accepted for interface consistency but ignored (each contract is one group).
+    pub fn get_cycle_snapshot(env: Env, _group_id: u32, cycle_number: u32) -> CycleSnapshotData {
+        let snapshot: CycleSnapshotData = env
+            .storage()
+            .persistent()
+            .get(&PersistentKey::CycleSnapshot(cycle_number))
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError3::SnapshotNotFound));
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        snapshot
+    }
+
+    /// Admin reshuffles the rounds not yet paid out in this pass of the payout
+    /// order. `new_order` lists payout-order positions for the remaining slots
+    /// (from the current round on) and must permute exactly those positions;
+    /// completed slots stay fixed. Rejected mid-round.
+    pub fn reorder_remaining(env: Env, new_order: Vec<u32>) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        if !paid_members.is_empty() {
+            panic_with_error!(&env, Error::CannotChangeMidRound);
+        }
+
+        let payout_order: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PayoutOrder)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        let start = current_round % payout_order.len();
+        if new_order.len() != payout_order.len() - start {
+            panic_with_error!(&env, Error::CustomOrderLengthMismatch);
+        }
+
+        let mut reordered: Vec<Address> = payout_order.slice(0..start);
+        let mut seen: Vec<u32> = Vec::new(&env);
+        for position in new_order.iter() {
+            // Completed slots (before `start`) cannot move.
+            if position < start || position >= payout_order.len() || seen.contains(position) {
+                panic_with_error!(&env, ExtError2::InvalidSlotIndex);
+            }
+            seen.push_back(position);
+            reordered.push_back(payout_order.get(position).unwrap());
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::PayoutOrder, &reordered);
+        internals::extend_member_ttl(&env);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// The current round's scheduled recipient passes their turn: they move to
+    /// the last slot of this pass of the payout order, so the next member is
+    /// paid this round and the decliner is still paid before the pass ends.
+    pub fn decline_turn(env: Env, member: Address) {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
+        member.require_auth();
+
+        if member != internals::scheduled_recipient(&env) {
+            panic_with_error!(&env, ExtError2::NotScheduledRecipient);
+        }
+
+        let payout_order: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PayoutOrder)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let position = payout_order
+            .first_index_of(&member)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotAMember));
+        if position + 1 >= payout_order.len() {
+            panic_with_error!(&env, ExtError3::NoLaterTurn);
+        }
+
+        let mut reordered = payout_order.clone();
+        reordered.remove(position);
+        reordered.push_back(member.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::PayoutOrder, &reordered);
+        internals::extend_member_ttl(&env);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        let new_recipient = internals::scheduled_recipient(&env);
+        events::emit_turn_declined(&env, member, current_round, new_recipient);
+    }
+
+    /// Get the current payout order (randomized if enabled and finalized).
+    pub fn get_payout_order(env: Env) -> Vec<Address> {
+        let payout_order: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PayoutOrder)
+            .unwrap_or(Vec::new(&env));
+
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        payout_order
+    }
+
+    /// Returns the member scheduled to receive the current round's payout,
+    /// following the stored payout order and skipping suspended, exited and
+    /// skipping members exactly as the payout itself does.
+    pub fn current_recipient(env: Env) -> Address {
+        internals::scheduled_recipient(&env)
+    }
+
+    /// Number of rounds until `member` is next in the payout order; 0 means
+    /// this round is their slot. Wraps around to the next cycle.
+    pub fn rounds_until_payout(env: Env, member: Address) -> u32 {
+        let payout_order: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PayoutOrder)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        let slot = payout_order
+            .first_index_of(&member)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NotAMember));
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        let len = payout_order.len();
+        (slot + len - current_round % len) % len
+    }
+
+    /// Preview the next payout without changing state: the scheduled recipient
+    /// and what they would receive if the round paid out now, i.e. the pot
+    /// collected so far (contributions, late fees, boosts and any carried-over
+    /// pot, less anything already claimed early) minus the protocol fee.
+    pub fn preview_payout(env: Env) -> (Address, i128) {
+        let recipient = internals::scheduled_recipient(&env);
+        let pot = internals::collected_pot(&env).max(0);
+
+        let fee_bps = internals::payout_fee_bps(&env);
+        let effective_fee_bps = internals::rep_fee_discount(&env, &recipient, fee_bps)
+            .map(|(discounted, _)| discounted)
+            .unwrap_or(fee_bps);
+        let fee = internals::fee_for(pot, effective_fee_bps);
+
+        (recipient, pot - fee)
+    }
+
+    /// Target pot for the current round: what every active member owes (at
+    /// their tier amount) minus the protocol fee, regardless of who has paid.
+    /// Compare `preview_payout`, which reflects only what has been paid.
+    pub fn expected_pot(env: Env) -> i128 {
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or(Vec::new(&env));
+        let suspended_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::SuspendedMembers)
+            .unwrap_or(Vec::new(&env));
+        let exited_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ExitedMembers)
+            .unwrap_or(Vec::new(&env));
+        let admin_suspended = internals::admin_suspended(&env);
+        let mut pot: i128 = 0;
+        for member in members.iter() {
+            if suspended_members.contains(&member)
+                || exited_members.contains(&member)
+                || admin_suspended.contains(&member)
+            {
+                continue;
+            }
+            pot += internals::member_required_amount(&env, &member);
+        }
+
+        let recipient = internals::scheduled_recipient(&env);
+        let fee_bps = internals::payout_fee_bps(&env);
+        let effective_fee_bps = internals::rep_fee_discount(&env, &recipient, fee_bps)
+            .map(|(discounted, _)| discounted)
+            .unwrap_or(fee_bps);
+        pot - internals::fee_for(pot, effective_fee_bps)
+    }
+
+    /// How much more the current round needs before every active member has
+    /// paid in full. Partial payments count, so this is gross of the fee and
+    /// never negative.
+    pub fn pot_remaining(env: Env) -> i128 {
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or(Vec::new(&env));
+        let suspended_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::SuspendedMembers)
+            .unwrap_or(Vec::new(&env));
+        let exited_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ExitedMembers)
+            .unwrap_or(Vec::new(&env));
+        let member_contributions: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MemberContributions)
+            .unwrap_or(Map::new(&env));
+        let admin_suspended = internals::admin_suspended(&env);
+        let mut remaining: i128 = 0;
+        for member in members.iter() {
+            if suspended_members.contains(&member)
+                || exited_members.contains(&member)
+                || admin_suspended.contains(&member)
+            {
+                continue;
+            }
+            let owed = internals::member_required_amount(&env, &member)
+                - member_contributions.get(member.clone()).unwrap_or(0);
+            remaining += owed.max(0);
+        }
+        remaining
     }
 
-    /// Fisher-Yates shuffle using seed-derived randomness.
-    /// Deterministic: same seed produces same shuffle.
-    fn fisher_yates_shuffle(
-        env: &Env,
-        mut items: Vec<Address>,
-        seed: &BytesN<32>,
-    ) -> Vec<Address> {
-        let n = items.len();
-        if n <= 1 {
-            return items;
+    /// What `member` can expect from their turn under the current
+    /// configuration: the full pot from every active member, minus the fee
+    /// (after any reputation discount), scaled by their payout weight when
+    /// weights are set, minus the debt they still have to settle. Returns 0
+    /// once the member has been paid in a finite cycle.
+    pub fn projected_payout(env: Env, member: Address) -> i128 {
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or(Vec::new(&env));
+        if !members.contains(&member) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+        let max_rounds: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::MaxRounds)
+            .unwrap_or(0);
+        let payout_rounds: Vec<u32> = env
+            .storage()
+            .persistent()
+            .get(&PersistentKey::PayoutRounds(member.clone()))
+            .unwrap_or(Vec::new(&env));
+        if max_rounds > 0 && !payout_rounds.is_empty() {
+            return 0;
         }
 
-        // Use seed bytes as pseudo-random source
-        let mut seed_index = 0u32;
-
-        for i in (1..n).rev() {
-            // Get next pseudo-random byte from seed
-            let rand_byte = seed.get(seed_index % 32).unwrap_or(0);
-            seed_index = seed_index.wrapping_add(1);
+        let suspended_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::SuspendedMembers)
+            .unwrap_or(Vec::new(&env));
+        let exited_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ExitedMembers)
+            .unwrap_or(Vec::new(&env));
+        let admin_suspended = internals::admin_suspended(&env);
+        let mut pot: i128 = 0;
+        for m in members.iter() {
+            if suspended_members.contains(&m)
+                || exited_members.contains(&m)
+                || admin_suspended.contains(&m)
+            {
+                continue;
+            }
+            pot += internals::member_required_amount(&env, &m);
+        }
 
-            // Compute j = random index in [0, i]
-            let j = (rand_byte as u32) % (i + 1);
+        let fee_bps = internals::payout_fee_bps(&env);
+        let effective_fee_bps = internals::rep_fee_discount(&env, &member, fee_bps)
+            .map(|(discounted, _)| discounted)
+            .unwrap_or(fee_bps);
+        let mut projected = pot - internals::fee_for(pot, effective_fee_bps);
 
-            // Swap items[i] and items[j]
-            if i != j {
-                let mut new_items = Vec::new(env);
-                for idx in 0..n {
-                    if idx == i {
-                        new_items.push_back(items.get(j).unwrap());
-                    } else if idx == j {
-                        new_items.push_back(items.get(i).unwrap());
-                    } else {
-                        new_items.push_back(items.get(idx).unwrap());
-                    }
-                }
-                items = new_items;
+        // Weighted groups split each pot across non-exited members.
+        let weights: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey4::Weights)
+            .unwrap_or(Map::new(&env));
+        let mut total_weight: i128 = 0;
+        for (m, weight) in weights.iter() {
+            if members.contains(&m) && !exited_members.contains(&m) {
+                total_weight += weight as i128;
             }
         }
+        if total_weight > 0 {
+            let weight = weights.get(member.clone()).unwrap_or(0) as i128;
+            projected = projected * weight / total_weight;
+        }
 
-        items
+        (projected - Self::get_debt(env, member)).max(0)
     }
 
-    // ── #364: Cycle Snapshot Versioning ──────────────────────────────────────
-
-    /// Returns the immutable cycle snapshot stored at cycle end for the given cycle number.
-    /// `group_id` is accepted for interface consistency but ignored (each contract is one group).
-    pub fn get_cycle_snapshot(env: Env, _group_id: u32, cycle_number: u32) -> CycleSnapshotData {
-        let snapshot: CycleSnapshotData = env
+    /// Every member's contribution so far this round, including partial
+    /// installments; members who have not paid map to 0.
+    pub fn round_contributions(env: Env) -> Map<Address, i128> {
+        let members: Vec<Address> = env
             .storage()
             .persistent()
-            .get(&PersistentKey::CycleSnapshot(cycle_number))
-            .expect("CycleSnapshot not found");
-        env.storage()
+            .get(&DataKey::Members)
+            .unwrap_or(Vec::new(&env));
+        let member_contributions: Map<Address, i128> = env
+            .storage()
             .instance()
-            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        snapshot
+            .get(&DataKey::MemberContributions)
+            .unwrap_or(Map::new(&env));
+        let mut contributions: Map<Address, i128> = Map::new(&env);
+        for member in members.iter() {
+            let paid = member_contributions.get(member.clone()).unwrap_or(0);
+            contributions.set(member, paid);
+        }
+        contributions
     }
 
-    /// Get the current payout order (randomized if enabled and finalized).
-    pub fn get_payout_order(env: Env) -> Vec<Address> {
-        let payout_order: Vec<Address> = env
+    /// Projected `(round, recipient, pot)` for every remaining round, assuming
+    /// every active member pays in full under the current order and amounts.
+    /// Finite cycles stop at `MaxRounds`; open-ended groups project one full
+    /// rotation from the current round.
+    pub fn simulate_cycle(env: Env) -> Vec<(u32, Address, i128)> {
+        let mut schedule: Vec<(u32, Address, i128)> = Vec::new(&env);
+        if Self::is_complete(env.clone()) {
+            return schedule;
+        }
+        let current_round: u32 = env
             .storage()
             .instance()
-            .get(&DataKey::PayoutOrder)
-            .unwrap_or(Vec::new(&env));
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        let max_rounds: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::MaxRounds)
+            .unwrap_or(0);
+        let end_round = if max_rounds > 0 {
+            current_round + max_rounds.saturating_sub(internals::rounds_paid_out(&env))
+        } else {
+            current_round + Self::get_payout_order(env.clone()).len()
+        };
 
-        env.storage()
+        let members: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Members)
+            .unwrap_or(Vec::new(&env));
+        let suspended_members: Vec<Address> = env
+            .storage()
             .instance()
-            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+            .get(&DataKey::SuspendedMembers)
+            .unwrap_or(Vec::new(&env));
+        let exited_members: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ExitedMembers)
+            .unwrap_or(Vec::new(&env));
+        let admin_suspended = internals::admin_suspended(&env);
+        let mut gross_pot: i128 = 0;
+        for member in members.iter() {
+            if suspended_members.contains(&member)
+                || exited_members.contains(&member)
+                || admin_suspended.contains(&member)
+            {
+                continue;
+            }
+            gross_pot += internals::member_required_amount(&env, &member);
+        }
+        let fee_bps = internals::payout_fee_bps(&env);
 
-        payout_order
+        for round in current_round..end_round {
+            let recipient = internals::scheduled_recipient_for(&env, round);
+            let effective_fee_bps = internals::rep_fee_discount(&env, &recipient, fee_bps)
+                .map(|(discounted, _)| discounted)
+                .unwrap_or(fee_bps);
+            let pot = gross_pot - internals::fee_for(gross_pot, effective_fee_bps);
+            schedule.push_back((round, recipient, pot));
+        }
+        schedule
     }
 
     // ── #356: Penalty-Based Slot Demotion ─────────────────────────────────────
@@ -10208,11 +16516,12 @@ impl AhjoorContract {
     ) {
         internals::check_not_paused(&env);
         admin.require_auth();
+        internals::note_admin_action(&env);
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
-            .expect("Admin not set");
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if admin != stored_admin {
             panic_with_error!(&env, ExtError::OnlyAdminAllowed);
         }
@@ -10246,6 +16555,199 @@ impl AhjoorContract {
             .unwrap_or(0)
     }
 
+    /// Admin sets the flat fee charged on top of the contribution by `contribute_late`.
+    pub fn set_late_fee(env: Env, admin: Address, fee: i128) {
+        internals::check_not_paused(&env);
+        admin.require_auth();
+        internals::note_admin_action(&env);
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if admin != stored_admin {
+            panic_with_error!(&env, ExtError::OnlyAdminAllowed);
+        }
+        if fee < 0 {
+            panic_with_error!(&env, ExtError::InvalidAmount);
+        }
+        env.storage().instance().set(&DataKey::LateFee, &fee);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Get the flat late-contribution fee (default: 0).
+    pub fn get_late_fee(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::LateFee)
+            .unwrap_or(0)
+    }
+
+    /// Admin sets the daily interest rate, in basis points, that `contribute_late`
+    /// charges on the outstanding contribution, prorated per second late.
+    pub fn set_interest_rate_bps(env: Env, rate_bps: u32) {
+        internals::check_not_paused(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        admin.require_auth();
+        internals::note_admin_action(&env);
+
+        if rate_bps > 10_000 {
+            panic_with_error!(&env, ExtError::InvalidAmount);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey4::InterestRateBps, &rate_bps);
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    /// Get the daily late-contribution interest rate in basis points (default: 0).
+    pub fn get_interest_rate_bps(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey4::InterestRateBps)
+            .unwrap_or(0)
+    }
+
+    /// Pay the outstanding contribution after the round deadline but within the
+    /// grace period, plus the configured late fee. The fee goes into the round's
+    /// pot and is paid out to the recipient with the contributions. Any interest
+    /// set by `set_interest_rate_bps` is charged on top and moved to the reserve.
+    pub fn contribute_late(env: Env, contributor: Address) {
+        internals::check_not_paused(&env);
+        internals::check_not_frozen(&env);
+        contributor.require_auth();
+
+        let use_timestamp: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey2::UseTimestampSchedule)
+            .unwrap_or(false);
+        let deadline: u64 = if use_timestamp {
+            internals::round_state_get(&env, &DataKey::RoundDeadlineTimestamp)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
+        } else {
+            internals::round_state_get(&env, &DataKey::RoundDeadline)
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized))
+        };
+        let grace_period_seconds: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey3::GracePeriodSeconds)
+            .unwrap_or(0);
+        let now_ts = env.ledger().timestamp();
+        if now_ts <= deadline {
+            panic_with_error!(&env, Error::DeadlineNotPassed);
+        }
+        if now_ts > deadline.saturating_add(grace_period_seconds) {
+            panic_with_error!(&env, Error::ContributionWindowClosed);
+        }
+
+        if !Self::is_member(env.clone(), contributor.clone()) {
+            panic_with_error!(&env, Error::NotAMember);
+        }
+        let paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers)
+            .unwrap_or(Vec::new(&env));
+        if paid_members.contains(&contributor) {
+            panic_with_error!(&env, Error::AlreadyContributed);
+        }
+
+        let member_contributions: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MemberContributions)
+            .unwrap_or(Map::new(&env));
+        let remaining = internals::member_required_amount(&env, &contributor)
+            - member_contributions.get(contributor.clone()).unwrap_or(0);
+
+        // Collect the fee first so it is already in the pot if this payment
+        // completes the round and triggers the payout.
+        let late_fee: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::LateFee)
+            .unwrap_or(0);
+        let base_token: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if late_fee > 0 {
+            token::Client::new(&env, &base_token).transfer(
+                &contributor,
+                env.current_contract_address(),
+                &late_fee,
+            );
+            internals::record_fund_flow(
+                &env,
+                "fee",
+                &contributor,
+                &env.current_contract_address(),
+                late_fee,
+            );
+            let late_fee_pot: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey4::LateFeePot)
+                .unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&DataKey4::LateFeePot, &(late_fee_pot + late_fee));
+        }
+        let current_round: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CurrentRound)
+            .unwrap_or(0);
+        events::emit_late_fee_paid(&env, contributor.clone(), current_round, late_fee);
+
+        // Interest accrues per second late at a daily rate and goes to the
+        // reserve rather than the pot.
+        let rate_bps: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey4::InterestRateBps)
+            .unwrap_or(0);
+        if rate_bps > 0 && remaining > 0 {
+            let seconds_late = (now_ts - deadline) as i128;
+            let interest = remaining
+                .checked_mul(rate_bps as i128)
+                .and_then(|v| v.checked_mul(seconds_late))
+                .unwrap_or_else(|| panic_with_error!(&env, ExtError::InvalidAmount))
+                / (10_000 * 86_400);
+            if interest > 0 {
+                token::Client::new(&env, &base_token).transfer(
+                    &contributor,
+                    env.current_contract_address(),
+                    &interest,
+                );
+                internals::record_fund_flow(
+                    &env,
+                    "interest",
+                    &contributor,
+                    &env.current_contract_address(),
+                    interest,
+                );
+                let reserve_pool: i128 = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey4::ReservePool)
+                    .unwrap_or(0);
+                let reserve_pool = reserve_pool + interest;
+                env.storage()
+                    .instance()
+                    .set(&DataKey4::ReservePool, &reserve_pool);
+                events::emit_reserve_credited(&env, current_round, interest, reserve_pool);
+            }
+        }
+
+        Self::apply_contribution(env, contributor.clone(), contributor, false, base_token, remaining, false);
+    }
+
     /// Get the current late contribution counts for all members.
     pub fn get_late_contribution_counts(env: Env) -> Map<Address, u32> {
         env.storage()
@@ -10261,14 +16763,22 @@ impl AhjoorContract {
     pub fn fund_savings_reward_pool(env: Env, admin: Address, amount: i128) {
         internals::check_not_paused(&env);
         admin.require_auth();
+        internals::note_admin_action(&env);
         let stored_admin: Address = env
-            .storage().instance().get(&DataKey::Admin).expect("Not initialized");
-        if admin != stored_admin { panic!("Only admin can fund the savings reward pool"); }
-        if amount <= 0 { panic!("Amount must be positive"); }
+            .storage().instance().get(&DataKey::Admin).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
+        if admin != stored_admin { panic_with_error!(&env, ExtError::OnlyAdminAllowed); }
+        if amount <= 0 { panic_with_error!(&env, Error::AmountMustBePositive); }
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).expect("Token not set");
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         let client = token::Client::new(&env, &token_addr);
         client.transfer(&admin, &env.current_contract_address(), &amount);
+        internals::record_fund_flow(
+            &env,
+            "reward",
+            &admin,
+            &env.current_contract_address(),
+            amount,
+        );
 
         let pool: i128 = env.storage().instance().get(&DataKey::RewardPool).unwrap_or(0);
         env.storage().instance().set(&DataKey::RewardPool, &(pool + amount));
@@ -10347,7 +16857,7 @@ impl AhjoorContract {
         internals::check_not_frozen(&env);
         member.require_auth();
 
-        let members: Vec<Address> = env.storage().instance().get(&DataKey::Members).expect("Not initialized");
+        let members: Vec<Address> = env.storage().persistent().get(&DataKey::Members).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
             panic_with_error!(&env, Error::NotAMember);
         }
@@ -10361,10 +16871,7 @@ impl AhjoorContract {
         }
 
         // Determine required contribution for member (accounting for tiers).
-        let base_amount: i128 = env.storage().instance().get(&DataKey::ContributionAmt).unwrap();
-        let tiers: Map<Address, u32> = env.storage().instance().get(&DataKey2::MemberTiers).unwrap_or(Map::new(&env));
-        let tier_bps = tiers.get(member.clone()).unwrap_or(10_000);
-        let required = (base_amount * tier_bps as i128) / 10_000;
+        let required = internals::member_required_amount(&env, &member);
 
         let mut total: i128 = 0;
         for split in splits.iter() {
@@ -10393,7 +16900,7 @@ impl AhjoorContract {
         internals::check_not_paused(&env);
         member.require_auth();
 
-        let members: Vec<Address> = env.storage().instance().get(&DataKey::Members).expect("Not initialized");
+        let members: Vec<Address> = env.storage().persistent().get(&DataKey::Members).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
             panic_with_error!(&env, Error::NotAMember);
         }
@@ -10419,12 +16926,12 @@ impl AhjoorContract {
         internals::check_not_frozen(&env);
         member.require_auth();
 
-        let start_at: u64 = env.storage().instance().get(&DataKey4::StartAt).unwrap_or(env.ledger().timestamp());
+        let start_at: u64 = env.storage().instance().get(&DataKey2::StartAt).unwrap_or(env.ledger().timestamp());
         if env.ledger().timestamp() < start_at {
             panic_with_error!(&env, ExtError::GroupNotYetActive);
         }
 
-        let members: Vec<Address> = env.storage().instance().get(&DataKey::Members).expect("Not initialized");
+        let members: Vec<Address> = env.storage().persistent().get(&DataKey::Members).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if !members.contains(&member) {
             panic_with_error!(&env, Error::NotAMember);
         }
@@ -10433,7 +16940,7 @@ impl AhjoorContract {
             panic_with_error!(&env, Error::MemberHasExited);
         }
 
-        let mut paid_members: Vec<Address> = env.storage().instance().get(&DataKey::PaidMembers).expect("Not initialized");
+        let mut paid_members: Vec<Address> = internals::round_state_get(&env, &DataKey::PaidMembers).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         if paid_members.contains(&member) {
             panic_with_error!(&env, Error::AlreadyContributed);
         }
@@ -10451,19 +16958,27 @@ impl AhjoorContract {
             .get(&DataKey3::CoPayerSplits(member.clone()))
             .unwrap_or_else(|| panic_with_error!(&env, ExtError2::NoCopayersRegistered));
 
-        let current_round: u32 = env.storage().instance().get(&DataKey::CurrentRound).unwrap();
+        let current_round: u32 = env.storage().instance().get(&DataKey::CurrentRound).unwrap_or_else(|| panic_with_error!(&env, ExtError2::NotInitialized));
         let token_client = token::Client::new(&env, &token);
         let mut total_transferred: i128 = 0;
 
         for split in splits.iter() {
             token_client.transfer(&split.co_payer, &env.current_contract_address(), &split.amount);
+            internals::record_fund_flow(
+                &env,
+                "contribution",
+                &split.co_payer,
+                &env.current_contract_address(),
+                split.amount,
+            );
             events::emit_co_payer_contributed(&env, member.clone(), split.co_payer.clone(), split.amount, current_round);
             total_transferred += split.amount;
         }
 
         // Mark member as paid and record contribution amount.
+        internals::note_round_opened(&env, &paid_members);
         paid_members.push_back(member.clone());
-        env.storage().instance().set(&DataKey::PaidMembers, &paid_members);
+        internals::round_state_set(&env, &DataKey::PaidMembers, &paid_members);
 
         let mut member_contributions: Map<Address, i128> = env
             .storage()
@@ -10516,6 +17031,30 @@ impl AhjoorContract {
             .unwrap_or(0)
     }
 
+    /// Deterministic proof that `member` contributed in `round`: sha256 over
+    /// (contract address, member, round, amount) taken from the round's audit
+    /// record. Other contracts can recompute it to verify participation.
+    /// Panics with `ContributionNotRecorded` if no such contribution exists.
+    pub fn get_contribution_proof(env: Env, member: Address, round: u32) -> BytesN<32> {
+        let record = audit_trail::get_cycle_record(&env, round)
+            .unwrap_or_else(|| panic_with_error!(&env, ExtError2::ContributionNotRecorded));
+        let mut amount: Option<i128> = None;
+        for entry in record.contributions.iter() {
+            if entry.member == member {
+                amount = Some(entry.amount);
+            }
+        }
+        let amount =
+            amount.unwrap_or_else(|| panic_with_error!(&env, ExtError2::ContributionNotRecorded));
+
+        let mut preimage = Bytes::new(&env);
+        preimage.append(&env.current_contract_address().to_xdr(&env));
+        preimage.append(&member.to_xdr(&env));
+        preimage.extend_from_array(&round.to_be_bytes());
+        preimage.extend_from_array(&amount.to_be_bytes());
+        env.crypto().sha256(&preimage).into()
+    }
+
 }
 
 mod test;
@@ -10530,6 +17069,7 @@ mod test_proxy;
 mod test_group_freeze;
 mod test_snapshot;
 mod test_emergency_reserve;
+mod test_reentrancy;
 #[cfg(test)]
 mod test_savings_milestone_rewards;
 pub use events::*;
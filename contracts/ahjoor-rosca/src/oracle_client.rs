@@ -0,0 +1,10 @@
+use soroban_sdk::{contractclient, Env};
+
+/// Minimal price-feed interface read when contributions are pegged to an
+/// external value.
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracleInterface {
+    /// Price of one whole base token in the pegged unit, with 7 decimals
+    /// (e.g. 1.5 = 15_000_000), and the timestamp it was published at.
+    fn price(env: Env) -> (i128, u64);
+}
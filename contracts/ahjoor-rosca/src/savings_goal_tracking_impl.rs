@@ -288,6 +288,13 @@ impl SavingsGoalTrackingImpl {
                             &goal.member,
                             &reward_amount,
                         );
+                        crate::internals::record_fund_flow(
+                            env,
+                            "reward",
+                            &env.current_contract_address(),
+                            &goal.member,
+                            reward_amount,
+                        );
                         env.storage().instance().set(&DataKey::RewardPool, &(pool - reward_amount));
 
                         crate::events::emit_milestone_reached(
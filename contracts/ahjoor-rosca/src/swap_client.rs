@@ -0,0 +1,18 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// DEX router used to pay a recipient in their preferred token.
+#[contractclient(name = "SwapRouterClient")]
+pub trait SwapRouterInterface {
+    /// Pulls `amount_in` of `token_in` from `from`, sends at least `min_out`
+    /// of `token_out` to `to` and returns the amount sent. Must fail rather
+    /// than deliver less than `min_out`.
+    fn swap(
+        env: Env,
+        from: Address,
+        token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        min_out: i128,
+        to: Address,
+    ) -> i128;
+}
@@ -1,6 +1,7 @@
 #![cfg(test)]
 extern crate alloc;
 use super::*;
+use crate::testutils::default_config;
 use proptest::prelude::*;
 use soroban_sdk::token::Client as TokenClient;
 use soroban_sdk::token::StellarAssetClient as TokenAdminClient;
@@ -110,11 +111,12 @@ fn setup_with_members<'a>(n: usize, mint_amount: i128) -> TestSetup<'a> {
 
 // test_datakey_variant_count removed: std::mem::variant_count is unavailable in #![no_std]
 
+
 /// Calls `client.init(...)` on `setup` using sensible defaults:
 /// - All addresses in `setup.members` as the member list
 /// - `contribution_amount = 100`
 /// - `round_duration = 3600` seconds
-/// - `RoscaConfig { strategy: RoundRobin, penalty_amount: 0, exit_penalty_bps: 0, grace_period_ledgers: 0, grace_period_seconds: 0, ... }`
+/// - `default_config()`
 fn default_init(setup: &TestSetup<'_>) {
     setup.client.init(
         &setup.admin,
@@ -122,31 +124,7 @@ fn default_init(setup: &TestSetup<'_>) {
         &100,
         &setup.token_admin,
         &3600,
-        &RoscaConfig {
-            strategy: PayoutStrategy::RoundRobin,
-            custom_order: None,
-            penalty_amount: 0,
-            exit_penalty_bps: 0,
-            collective_goal: None,
-            member_goals: None,
-            fee_bps: 0,
-            fee_recipient: None,
-            max_defaults: 3,
-            grace_period_ledgers: 0,
-            use_timestamp_schedule: false,
-            round_duration_seconds: 0,
-            max_members: None,
-            skip_fee: 0,
-            max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal,
-        late_fee_bps: 0,
-        grace_period_seconds: 0,
-        auction_enabled: false,
-        auction_window_ledgers: 0,
-        randomize_payout_order: false,
-        reserve_enabled: false,
-        reserve_contribution_bps: 0,
-        },
+        &default_config(),
         &None,
     );
 }
@@ -187,6 +165,10 @@ fn test_delayed_start_blocks_then_allows_contribution() {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
         },
         &Some(start_at),
     );
@@ -244,6 +226,10 @@ fn test_cancel_pending_group_refunds_reward_deposit() {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
         },
         &Some(start_at),
     );
@@ -384,7 +370,7 @@ fn test_admin_assigned_strategy_execution() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -430,7 +416,7 @@ fn test_invalid_admin_order_validation() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
     assert_eq!(
@@ -493,7 +479,7 @@ fn test_admin_assigned_e2e_all_rounds() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -542,7 +528,7 @@ fn test_single_defaulter_penalty() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -600,6 +586,10 @@ fn test_penalty_deferred_within_grace_period() {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
         },
         &None,
     );
@@ -648,6 +638,10 @@ fn test_penalty_applied_after_grace_boundary() {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
         },
         &None,
     );
@@ -701,6 +695,10 @@ fn test_reputation_score_lifecycle_and_bounds() {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
         },
         &None,
     );
@@ -776,7 +774,7 @@ fn test_multiple_defaulters_penalty() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -828,7 +826,7 @@ fn test_member_suspension_after_two_defaults() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -903,7 +901,7 @@ fn test_suspended_member_skipped_in_payout() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -954,6 +952,9 @@ fn test_cannot_penalise_before_deadline() {
     let client = AhjoorContractClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
     let user1 = Address::generate(&env);
     let members = vec![&env, user1.clone()];
 
@@ -961,7 +962,7 @@ fn test_cannot_penalise_before_deadline() {
         &admin,
         &members,
         &100,
-        &Address::generate(&env),
+        &token,
         &3600,
         &RoscaConfig {
             strategy: PayoutStrategy::RoundRobin,
@@ -981,7 +982,7 @@ fn test_cannot_penalise_before_deadline() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -1000,6 +1001,9 @@ fn test_penalty_disabled_when_amount_zero() {
     let client = AhjoorContractClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
     let user1 = Address::generate(&env);
     let members = vec![&env, user1.clone()];
 
@@ -1007,7 +1011,7 @@ fn test_penalty_disabled_when_amount_zero() {
         &admin,
         &members,
         &100,
-        &Address::generate(&env),
+        &token,
         &3600,
         &RoscaConfig {
             strategy: PayoutStrategy::RoundRobin,
@@ -1027,7 +1031,7 @@ fn test_penalty_disabled_when_amount_zero() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -1082,7 +1086,7 @@ fn test_cannot_penalise_non_defaulter() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -1141,7 +1145,7 @@ fn test_read_interface_lifecycle() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -1224,7 +1228,7 @@ fn test_member_status_resets_after_round() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -1291,7 +1295,7 @@ fn test_add_member_before_round() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -1354,7 +1358,7 @@ fn test_add_member_mid_round_panics() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -1415,7 +1419,7 @@ fn test_remove_member_between_rounds() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -1480,7 +1484,7 @@ fn test_remove_member_mid_round_panics() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -1543,7 +1547,7 @@ fn test_remove_member_who_already_received_payout() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -1650,7 +1654,7 @@ fn test_init_with_approved_token() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 }
@@ -1699,7 +1703,7 @@ fn test_init_with_unapproved_token_panics() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
     assert_eq!(res.unwrap_err().unwrap(), Error::TokenNotApproved.into());
@@ -1741,7 +1745,7 @@ fn test_init_twice_panics() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -1770,7 +1774,7 @@ fn test_init_twice_panics() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
     assert_eq!(res.unwrap_err().unwrap(), Error::AlreadyInitialized.into());
@@ -1808,7 +1812,7 @@ fn test_contribute_non_member_panics() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -1852,7 +1856,7 @@ fn test_contribute_twice_panics() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -1901,7 +1905,7 @@ fn test_payout_correct_member_n_group() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -1980,7 +1984,7 @@ fn test_contract_balance_zero_after_round() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -2029,7 +2033,7 @@ fn test_single_member_rosca() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -2082,7 +2086,7 @@ fn test_large_group_rosca() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -2140,7 +2144,7 @@ fn test_get_state_lifecycle_details() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -2201,7 +2205,7 @@ fn test_bump_storage() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -2255,7 +2259,7 @@ fn test_reward_distribution_scenarios() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -2344,7 +2348,7 @@ fn test_contribution_pot_separation() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -2427,7 +2431,7 @@ fn setup_exit_env(
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -2715,7 +2719,7 @@ fn test_exit_with_zero_penalty() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -2754,7 +2758,7 @@ fn test_exit_request_event_emitted() {
     let last = all_events.last().unwrap();
 
     // Verify topics
-    let expected_topics = (Symbol::new(&env, "exit_requested"),).into_val(&env);
+    let expected_topics = (Symbol::new(&env, "exit_requested"), EVENT_SCHEMA_VERSION).into_val(&env);
     assert_eq!(last.1, expected_topics);
 
     // Verify data (it's a Map for struct events)
@@ -2784,7 +2788,7 @@ fn test_exit_approval_event_emitted() {
     let last = all_events.last().unwrap();
 
     // Verify topics
-    let expected_topics = (Symbol::new(&env, "exit_approved"),).into_val(&env);
+    let expected_topics = (Symbol::new(&env, "exit_approved"), EVENT_SCHEMA_VERSION).into_val(&env);
     assert_eq!(last.1, expected_topics);
 
     // Verify data
@@ -2841,7 +2845,7 @@ fn test_pause_and_resume_flow() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -2917,7 +2921,7 @@ fn test_paused_blocks_contribute() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -2963,7 +2967,7 @@ fn test_cannot_pause_already_paused() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -3010,7 +3014,7 @@ fn test_cannot_resume_not_paused() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -3024,6 +3028,25 @@ fn test_cannot_resume_not_paused() {
 
 /// A member can split their contribution across multiple calls and the payout
 /// fires only once the total equals contribution_amount.
+#[test]
+fn test_two_half_payments_complete_contribution() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+
+    setup.client.contribute(&user1, &setup.token_admin, &50);
+    let (round, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 0);
+    assert_eq!(paid, vec![&setup.env, user2.clone()]);
+
+    setup.client.contribute(&user1, &setup.token_admin, &50);
+    let (round, _, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+    assert_eq!(setup.token_client.balance(&user1), 1100);
+}
 
 /// Payout is blocked until ALL members have reached their full contribution.
 
@@ -3074,7 +3097,7 @@ fn test_get_member_contribution_status() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -3148,7 +3171,7 @@ fn test_overpayment_rejected() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -3192,7 +3215,7 @@ fn test_emit_deadline_reminder() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -3209,7 +3232,7 @@ fn test_emit_deadline_reminder() {
     let reminder_event = events.get(events.len() - 1).unwrap();
 
     // Topic check: (deadline_reminder,)
-    let expected_topics = (Symbol::new(&setup.env, "deadline_reminder"),).into_val(&setup.env);
+    let expected_topics = (Symbol::new(&setup.env, "deadline_reminder"), EVENT_SCHEMA_VERSION).into_val(&setup.env);
     assert_eq!(reminder_event.1, expected_topics);
 
     // Data check via map
@@ -3270,7 +3293,7 @@ fn test_get_upcoming_deadlines() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -3319,7 +3342,7 @@ fn test_create_proposal() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -3379,7 +3402,7 @@ fn test_vote_on_proposal() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -3437,7 +3460,7 @@ fn test_execute_proposal_with_quorum() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -3503,7 +3526,7 @@ fn test_proposal_insufficient_quorum() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -3566,7 +3589,7 @@ fn test_proposal_voted_down() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -3630,7 +3653,7 @@ fn test_penalty_appeal_execution() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -3704,7 +3727,7 @@ fn test_member_removal_execution() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -3769,7 +3792,7 @@ fn test_non_member_cannot_create_proposal() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -3821,7 +3844,7 @@ fn test_cannot_vote_after_deadline() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -3881,7 +3904,7 @@ fn test_cannot_vote_twice() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -3931,7 +3954,7 @@ fn test_get_member_status_non_member() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -3979,7 +4002,7 @@ fn test_get_member_status_active_member() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -4031,7 +4054,7 @@ fn test_get_member_status_suspended_member() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -4087,7 +4110,7 @@ fn test_get_member_status_exited_member() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -4218,7 +4241,7 @@ fn test_accept_admin_role() {
 }
 
 #[test]
-#[should_panic(expected = "No admin transfer proposed")]
+#[should_panic(expected = "HostError: Error(Contract, #174)")] // NoAdminTransferProposed
 fn test_accept_admin_role_without_proposal_panics() {
     let env = Env::default();
     let (client, _admin, _u1, _u2, _u3, _tc, _ta) = setup_exit_env(&env);
@@ -4456,7 +4479,7 @@ fn setup_finalize_env(
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -4595,7 +4618,7 @@ fn setup_exit_penalty_env(
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -4729,6 +4752,10 @@ fn setup_grace_env<'a>(
             randomize_payout_order: false,
             reserve_enabled: false,
             reserve_contribution_bps: 0,
+            member_amounts: None,
+            defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+            guardian: None,
+            recovery_delay: 0,
         },
         &None,
     );
@@ -4847,6 +4874,10 @@ fn test_overflow_guard_on_large_contribution() {
             randomize_payout_order: false,
             reserve_enabled: false,
             reserve_contribution_bps: 0,
+            member_amounts: None,
+            defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+            guardian: None,
+            recovery_delay: 0,
         },
         &None,
     );
@@ -4916,6 +4947,10 @@ fn test_reinstatement_requires_approved_proposal() {
             randomize_payout_order: false,
             reserve_enabled: false,
             reserve_contribution_bps: 0,
+            member_amounts: None,
+            defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+            guardian: None,
+            recovery_delay: 0,
         },
         &None,
     );
@@ -4975,3 +5010,5261 @@ fn test_reinstatement_requires_approved_proposal() {
     assert_eq!(err2, Error::ProposalNotFound.into());
 }
 
+
+#[test]
+fn test_close_round_rejected_on_fresh_round_after_full_payout() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    setup.env.ledger().set_timestamp(100);
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+
+    // Everyone paid, so the payout already advanced the round.
+    let (round, paid, deadline, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+    assert_eq!(paid.len(), 0);
+    assert_eq!(deadline, 3700);
+
+    // Past the original deadline but not the fresh round's deadline.
+    setup.env.ledger().set_timestamp(3601);
+    let res = setup.client.try_close_round();
+    assert_eq!(res.unwrap_err().unwrap(), Error::DeadlineNotPassed.into());
+
+    let (round, _, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+}
+
+#[test]
+fn test_close_round_rejected_when_all_members_paid() {
+    let setup = setup_with_members(1, 1000);
+    default_init(&setup);
+
+    let user = setup.members.get(0).unwrap();
+    setup.client.set_max_rounds(&0);
+    setup.client.set_reinvest_preference(&user, &true);
+
+    // The reinvested payout pre-pays the next round for the only member.
+    setup.env.ledger().set_timestamp(100);
+    setup.client.contribute(&user, &setup.token_admin, &100);
+    let (round, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+    assert!(paid.contains(&user));
+
+    setup.env.ledger().set_timestamp(3701);
+    let res = setup.client.try_close_round();
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ExtError2::RoundAlreadyComplete.into()
+    );
+}
+
+#[test]
+fn test_migrate_backfills_missing_keys() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    // Simulate an instance deployed before PenaltyAmount / PendingPenalties existed.
+    setup.env.as_contract(&setup.client.address, || {
+        let storage = setup.env.storage().instance();
+        storage.remove(&DataKey::PenaltyAmount);
+        storage.remove(&DataKey2::PendingPenalties);
+    });
+
+    setup.client.migrate(&setup.admin);
+
+    setup.env.as_contract(&setup.client.address, || {
+        let storage = setup.env.storage().instance();
+        let penalty: i128 = storage.get(&DataKey::PenaltyAmount).unwrap();
+        assert_eq!(penalty, 0);
+        let pending: Map<Address, u32> = storage.get(&DataKey2::PendingPenalties).unwrap();
+        assert!(pending.is_empty());
+    });
+
+    // Idempotent: a second run leaves existing values alone.
+    setup.env.as_contract(&setup.client.address, || {
+        setup.env.storage().instance().set(&DataKey::PenaltyAmount, &25i128);
+    });
+    setup.client.migrate(&setup.admin);
+    setup.client.migrate(&setup.admin);
+    setup.env.as_contract(&setup.client.address, || {
+        let penalty: i128 = setup.env.storage().instance().get(&DataKey::PenaltyAmount).unwrap();
+        assert_eq!(penalty, 25);
+    });
+
+    let user = setup.members.get(0).unwrap();
+    setup.client.contribute(&user, &setup.token_admin, &100);
+}
+
+#[test]
+fn test_migrate_moves_members_to_persistent_storage() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    // Simulate an instance deployed while Members and PayoutOrder lived in
+    // instance storage.
+    setup.env.as_contract(&setup.client.address, || {
+        let members: Vec<Address> = setup.env.storage().persistent().get(&DataKey::Members).unwrap();
+        let order: Vec<Address> = setup.env.storage().persistent().get(&DataKey::PayoutOrder).unwrap();
+        setup.env.storage().persistent().remove(&DataKey::Members);
+        setup.env.storage().persistent().remove(&DataKey::PayoutOrder);
+        setup.env.storage().instance().set(&DataKey::Members, &members);
+        setup.env.storage().instance().set(&DataKey::PayoutOrder, &order);
+    });
+
+    setup.client.migrate(&setup.admin);
+
+    setup.env.as_contract(&setup.client.address, || {
+        assert!(!setup.env.storage().instance().has(&DataKey::Members));
+        assert!(!setup.env.storage().instance().has(&DataKey::PayoutOrder));
+        let members: Vec<Address> = setup.env.storage().persistent().get(&DataKey::Members).unwrap();
+        assert_eq!(members, setup.members);
+        assert!(setup.env.storage().persistent().has(&DataKey::PayoutOrder));
+    });
+
+    let user = setup.members.get(0).unwrap();
+    setup.client.contribute(&user, &setup.token_admin, &100);
+}
+
+fn init_with_member_amounts(setup: &TestSetup<'_>, amounts: soroban_sdk::Vec<i128>) {
+    setup.client.init(
+        &setup.admin,
+        &setup.members,
+        &100,
+        &setup.token_admin,
+        &3600,
+        &RoscaConfig {
+            member_amounts: Some(amounts),
+            ..default_config()
+        },
+        &None,
+    );
+}
+
+#[test]
+fn test_variable_member_contribution_amounts() {
+    let setup = setup_with_members(2, 1000);
+    init_with_member_amounts(&setup, vec![&setup.env, 200i128, 100i128]);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    assert_eq!(setup.client.get_member_contribution_status(&user1), (0, 200));
+    assert_eq!(setup.client.get_member_contribution_status(&user2), (0, 100));
+
+    // user2 cannot pay more than their own amount
+    let res = setup.client.try_contribute(&user2, &setup.token_admin, &200);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        Error::ExceedsRemainingContribution.into()
+    );
+
+    setup.client.contribute(&user1, &setup.token_admin, &200);
+    assert_eq!(setup.token_client.balance(&user1), 800);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    assert_eq!(setup.token_client.balance(&user2), 900);
+
+    // Pot is the sum of the two different amounts and goes to user1.
+    let history = setup.client.get_round_history();
+    assert_eq!(history.get(0).unwrap().amount, 300);
+    assert_eq!(setup.token_client.balance(&user1), 1100);
+}
+
+#[test]
+fn test_payout_is_collected_amount_not_required_sum() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.contribute_to_insurance(&user2, &setup.token_admin, &50);
+
+    // user1's tier rises after they paid: they still paid in full, so the
+    // round has no shortfall for insurance to top up.
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.set_member_tier(&setup.admin, &user1, &15_000);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+
+    assert_eq!(setup.client.get_round_history().get(0).unwrap().amount, 200);
+    assert_eq!(setup.token_client.balance(&user1), 1000 - 100 + 200);
+    assert_eq!(setup.client.get_insurance_pool(), 50);
+}
+
+#[test]
+fn test_payout_leaves_treasury_reserve_and_auction_escrow() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    // 70 of treasury funds, 20 of emergency reserve and a 30 bid held for
+    // an open slot auction.
+    setup.token_admin_client.mint(&setup.client.address, &120);
+    setup.env.as_contract(&setup.client.address, || {
+        let storage = setup.env.storage();
+        storage.instance().set(&DataKey3::TreasuryBalance, &70i128);
+        storage
+            .persistent()
+            .set(&DataKey3::EmergencyReserveBalance, &20i128);
+        storage.instance().set(
+            &DataKey3::AuctionBids,
+            &vec![
+                &setup.env,
+                SlotBid {
+                    bidder: user2.clone(),
+                    desired_slot: 1,
+                    amount: 30,
+                    placed_at: 0,
+                },
+            ],
+        );
+    });
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+
+    assert_eq!(setup.client.get_round_history().get(0).unwrap().amount, 200);
+    assert_eq!(setup.token_client.balance(&user1), 1000 - 100 + 200);
+    assert_eq!(setup.token_client.balance(&setup.client.address), 120);
+    assert_eq!(setup.client.get_treasury_balance(), 70);
+}
+
+#[test]
+fn test_member_amounts_length_mismatch_rejected() {
+    let setup = setup_with_members(2, 0);
+    let res = setup.client.try_init(
+        &setup.admin,
+        &setup.members,
+        &100,
+        &setup.token_admin,
+        &3600,
+        &RoscaConfig {
+            member_amounts: Some(vec![&setup.env, 150i128]),
+            ..default_config()
+        },
+        &None,
+    );
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ExtError2::MemberAmountsLengthMismatch.into()
+    );
+}
+
+#[test]
+fn test_sweep_dust_moves_only_unaccounted_surplus() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let treasury = Address::generate(&setup.env);
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.token_admin_client.mint(&setup.client.address, &37);
+    assert_eq!(setup.token_client.balance(&setup.client.address), 137);
+
+    let swept = setup.client.sweep_dust(&treasury);
+    assert_eq!(swept, 37);
+    assert_eq!(setup.token_client.balance(&treasury), 37);
+    assert_eq!(setup.token_client.balance(&setup.client.address), 100);
+
+    let res = setup.client.try_sweep_dust(&treasury);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::NoSurplusBalance.into());
+}
+
+#[test]
+fn test_is_member() {
+    let setup = setup_with_members(2, 0);
+    let member = setup.members.get(0).unwrap();
+    let outsider = Address::generate(&setup.env);
+
+    // Not initialized yet: no panic, just false.
+    assert!(!setup.client.is_member(&member));
+
+    default_init(&setup);
+    assert!(setup.client.is_member(&member));
+    assert!(!setup.client.is_member(&outsider));
+}
+
+#[test]
+fn test_claim_early_pays_partial_pot() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    let recipient = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+
+    // Only the scheduled recipient may claim.
+    let res = setup.client.try_claim_early(&user2);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::NotScheduledRecipient.into());
+
+    let claimed = setup.client.claim_early(&recipient);
+    assert_eq!(claimed, 200);
+    assert_eq!(setup.token_client.balance(&recipient), 1200);
+    assert_eq!(setup.token_client.balance(&setup.client.address), 0);
+
+    let claim = setup.client.get_early_claim(&0).unwrap();
+    assert_eq!(claim.recipient, recipient);
+    assert_eq!(claim.claimed, 200);
+    assert_eq!(claim.forgone, 100);
+
+    let (round, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+    assert_eq!(paid.len(), 0);
+}
+
+#[test]
+fn test_claim_early_does_not_draw_insurance() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    let recipient = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+    setup.client.contribute_to_insurance(&user3, &setup.token_admin, &50);
+
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+
+    // The shortfall is forgone, not covered from the insurance pool.
+    assert_eq!(setup.client.claim_early(&recipient), 200);
+    assert_eq!(setup.token_client.balance(&recipient), 1200);
+    assert_eq!(setup.client.get_insurance_pool(), 50);
+    assert_eq!(setup.token_client.balance(&setup.client.address), 50);
+}
+
+#[test]
+fn test_claim_early_rejects_empty_pot() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    let recipient = setup.members.get(0).unwrap();
+    let res = setup.client.try_claim_early(&recipient);
+    assert_eq!(res.unwrap_err().unwrap(), Error::AmountMustBePositive.into());
+    assert!(setup.client.get_early_claim(&0).is_none());
+}
+
+#[test]
+fn test_claim_early_records_unpaid_members_as_defaulters() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    let recipient = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    setup.client.contribute(&recipient, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.client.claim_early(&recipient);
+
+    assert_eq!(setup.client.get_defaulters(), vec![&setup.env, user3.clone()]);
+    assert_eq!(setup.client.get_round_record(&0).defaulters, vec![&setup.env, user3.clone()]);
+    assert_eq!(setup.client.get_debt(&user3), 100);
+    assert_eq!(setup.client.get_debt(&user2), 0);
+}
+
+#[test]
+fn test_claim_early_records_net_payout_and_skips_suspended() {
+    let setup = setup_with_members(4, 1000);
+    let fee_recipient = Address::generate(&setup.env);
+    setup.client.init(
+        &setup.admin,
+        &setup.members,
+        &100,
+        &setup.token_admin,
+        &3600,
+        &RoscaConfig {
+            fee_bps: 500,
+            fee_recipient: Some(fee_recipient.clone()),
+            ..default_config()
+        },
+        &None,
+    );
+
+    let recipient = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+    let user4 = setup.members.get(3).unwrap();
+    setup.client.suspend_member(&user4);
+
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+
+    // The 5% fee leaves the recipient 190 of the 200 contributed, and only
+    // the recipient's own unpaid share is forgone: the suspended member
+    // never owed the pot.
+    assert_eq!(setup.client.claim_early(&recipient), 190);
+    assert_eq!(setup.token_client.balance(&recipient), 1190);
+    assert_eq!(setup.token_client.balance(&fee_recipient), 10);
+    let claim = setup.client.get_early_claim(&0).unwrap();
+    assert_eq!(claim.claimed, 190);
+    assert_eq!(claim.forgone, 100);
+}
+
+#[test]
+fn test_preview_payout_excludes_early_claim() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    let recipient = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+    assert_eq!(setup.client.preview_payout(), (recipient.clone(), 200));
+
+    // The preview matches what the claim pays, and the claimed pot is gone
+    // from the next round's preview.
+    assert_eq!(setup.client.claim_early(&recipient), 200);
+    assert_eq!(setup.client.preview_payout(), (user2.clone(), 0));
+
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+    assert_eq!(setup.client.preview_payout(), (user2, 100));
+}
+
+#[test]
+fn test_current_recipient_follows_rotation() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    // Open-ended, so the rotation wraps around after the third payout.
+    setup.client.set_max_rounds(&0);
+
+    for round in 0..6u32 {
+        let expected = setup.members.get(round % 3).unwrap();
+        assert_eq!(setup.client.current_recipient(), expected);
+        for member in setup.members.iter() {
+            setup.client.contribute(&member, &setup.token_admin, &100);
+        }
+    }
+}
+
+#[test]
+fn test_close_round_emits_round_summary() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.close_round();
+
+    let closed_topics: soroban_sdk::Vec<soroban_sdk::Val> =
+        (Symbol::new(&setup.env, "round_closed"), EVENT_SCHEMA_VERSION).into_val(&setup.env);
+    let event = setup
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|ev| ev.1 == closed_topics)
+        .expect("round_closed event not emitted");
+
+    let data: soroban_sdk::Map<Symbol, soroban_sdk::Val> = event.2.into_val(&setup.env);
+    let summary: RoundSummary = data
+        .get(Symbol::new(&setup.env, "summary"))
+        .unwrap()
+        .into_val(&setup.env);
+    assert_eq!(
+        summary,
+        RoundSummary {
+            round: 0,
+            paid: 1,
+            defaulted: 2,
+            pot: 100,
+        }
+    );
+}
+
+#[test]
+fn test_contribute_late_within_grace_charges_fee() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.set_late_fee(&setup.admin, &25);
+    setup.client.configure_late_demotion(&setup.admin, &3, &600);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+
+    // Too early: the regular window is still open.
+    let res = setup.client.try_contribute_late(&user2);
+    assert_eq!(res.unwrap_err().unwrap(), Error::DeadlineNotPassed.into());
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 3700);
+    setup.client.contribute_late(&user2);
+
+    // Late fee was added to the pot and paid out with the round.
+    assert_eq!(setup.token_client.balance(&user2), 875);
+    assert_eq!(setup.token_client.balance(&user1), 1125);
+    let (round, _, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+}
+
+#[test]
+fn test_late_fee_pot_survives_close_without_payout() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    setup.client.set_late_fee(&setup.admin, &25);
+    setup.client.configure_late_demotion(&setup.admin, &3, &600);
+
+    let user2 = setup.members.get(1).unwrap();
+    setup.env.ledger().with_mut(|li| li.timestamp = 3700);
+    setup.client.contribute_late(&user2);
+
+    // Nothing was paid out, so the contribution and the fee stay in the pot
+    // for the next payout.
+    setup.client.close_round();
+    let (_, pot) = setup.client.preview_payout();
+    assert_eq!(pot, 125);
+}
+
+#[test]
+fn test_contribute_late_rejected_after_grace() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.set_late_fee(&setup.admin, &25);
+    setup.client.configure_late_demotion(&setup.admin, &3, &600);
+
+    let user2 = setup.members.get(1).unwrap();
+    setup.env.ledger().with_mut(|li| li.timestamp = 4201);
+    let res = setup.client.try_contribute_late(&user2);
+    assert_eq!(res.unwrap_err().unwrap(), Error::ContributionWindowClosed.into());
+    assert_eq!(setup.token_client.balance(&user2), 1000);
+}
+
+#[test]
+fn test_round_records_persist_across_rounds() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    // Round 0: both pay, user1 receives the pot.
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+
+    // Round 1: only user1 pays and the admin closes the round.
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.close_round();
+
+    let first = setup.client.get_round_record(&0);
+    assert_eq!(first.recipient, Some(user1.clone()));
+    assert_eq!(first.pot, 200);
+    assert_eq!(first.defaulters.len(), 0);
+
+    let second = setup.client.get_round_record(&1);
+    assert_eq!(second.recipient, None);
+    assert_eq!(second.pot, 100);
+    assert_eq!(second.defaulters, vec![&setup.env, user2.clone()]);
+}
+
+#[test]
+fn test_bump_ttl_keeps_state_alive() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+
+    setup.client.bump_ttl(&500_000);
+    setup
+        .env
+        .ledger()
+        .with_mut(|li| li.sequence_number += 400_000);
+
+    assert!(setup.client.is_member(&user1));
+    assert_eq!(setup.client.get_payout_order(), setup.members);
+    let (round, _, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 0);
+}
+
+#[test]
+fn test_bump_ttl_rejects_zero_and_caps_at_max_ttl() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    let res = setup.client.try_bump_ttl(&0);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError::InvalidAmount.into());
+
+    setup.client.bump_ttl(&u32::MAX);
+    let max_ttl = setup.env.storage().max_ttl();
+    setup.env.as_contract(&setup.client.address, || {
+        use soroban_sdk::testutils::storage::Instance as _;
+        assert!(setup.env.storage().instance().get_ttl() <= max_ttl);
+    });
+}
+
+#[test]
+fn test_dissolve_refunds_current_round_contributions() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    assert_eq!(setup.token_client.balance(&user1), 900);
+
+    setup.client.dissolve();
+
+    assert_eq!(setup.token_client.balance(&user1), 1000);
+    assert_eq!(setup.token_client.balance(&setup.client.address), 0);
+    let (_, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(paid.len(), 0);
+
+    let res = setup.client.try_contribute(&user2, &setup.token_admin, &100);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError::GroupAlreadyDissolved.into());
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    let res = setup.client.try_close_round();
+    assert_eq!(res.unwrap_err().unwrap(), ExtError::GroupAlreadyDissolved.into());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #127)")] // InvalidToken
+fn test_init_rejects_non_token_address() {
+    let setup = setup_with_members(2, 0);
+    let bogus_token = Address::generate(&setup.env);
+    setup.client.init(
+        &setup.admin,
+        &setup.members,
+        &100,
+        &bogus_token,
+        &3600,
+        &default_config(),
+        &None,
+    );
+}
+
+#[test]
+fn test_contract_balance_tracks_contributions() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    assert_eq!(setup.client.contract_balance(), 0);
+    setup.client.contribute(&setup.members.get(0).unwrap(), &setup.token_admin, &100);
+    setup.client.contribute(&setup.members.get(1).unwrap(), &setup.token_admin, &100);
+    assert_eq!(setup.client.contract_balance(), 2 * 100);
+}
+
+#[test]
+fn test_set_contribution_amount_between_rounds() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    let res = setup.client.try_set_contribution_amount(&150);
+    assert_eq!(res.unwrap_err().unwrap(), Error::CannotChangeMidRound.into());
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.close_round();
+
+    let res = setup.client.try_set_contribution_amount(&0);
+    assert_eq!(res.unwrap_err().unwrap(), Error::AmountMustBePositive.into());
+    setup.client.set_contribution_amount(&150);
+
+    // The old amount no longer completes a contribution.
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    let (_, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(paid.len(), 0);
+    setup.client.contribute(&user2, &setup.token_admin, &50);
+    let (_, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(paid, vec![&setup.env, user2.clone()]);
+}
+
+#[test]
+fn test_auto_contribute_pulls_without_explicit_contribute() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    let res = setup.client.try_trigger_auto(&user1);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::AutoContributeNotEnabled.into());
+
+    setup.client.set_auto_contribute(&user1, &2);
+    assert_eq!(setup.client.get_auto_contribute(&user1), 2);
+
+    setup.client.trigger_auto(&user1);
+    assert_eq!(setup.token_client.balance(&user1), 900);
+    assert_eq!(setup.client.get_auto_contribute(&user1), 1);
+    let (_, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(paid, vec![&setup.env, user1.clone()]);
+
+    // Pulling twice in the same round is rejected.
+    let res = setup.client.try_trigger_auto(&user1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::AlreadyContributed.into());
+
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    assert_eq!(setup.token_client.balance(&user1), 1100);
+
+    // Round 1: last auto round is used up.
+    setup.client.trigger_auto(&user1);
+    assert_eq!(setup.client.get_auto_contribute(&user1), 0);
+    assert_eq!(setup.token_client.balance(&user1), 1000);
+}
+
+#[test]
+fn test_auto_contribute_completes_round_with_suspended_member() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    setup.client.suspend_member(&user3);
+    setup.client.set_auto_contribute(&user1, &1);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+
+    // user1's pull fills the round for the two active members.
+    setup.client.trigger_auto(&user1);
+    assert_eq!(setup.client.get_group_info().current_round, 1);
+    assert_eq!(setup.token_client.balance(&user1), 900 + 200);
+}
+
+#[test]
+fn test_is_initialized() {
+    let setup = setup_with_members(2, 0);
+    assert!(!setup.client.is_initialized());
+    default_init(&setup);
+    assert!(setup.client.is_initialized());
+}
+
+#[test]
+fn test_contribute_batch_funds_members_and_completes_round() {
+    let setup = setup_with_members(3, 0);
+    default_init(&setup);
+
+    let coordinator = Address::generate(&setup.env);
+    setup.token_admin_client.mint(&coordinator, &1000);
+    let recipient = setup.members.get(0).unwrap();
+
+    // Duplicates are rejected before any funds move.
+    let dup = vec![&setup.env, recipient.clone(), recipient.clone()];
+    let res = setup.client.try_contribute_batch(&coordinator, &dup);
+    assert_eq!(res.unwrap_err().unwrap(), Error::AlreadyContributed.into());
+
+    setup.client.contribute_batch(&coordinator, &setup.members);
+
+    assert_eq!(setup.token_client.balance(&coordinator), 700);
+    assert_eq!(setup.token_client.balance(&recipient), 300);
+    let (round, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+    assert_eq!(paid.len(), 0);
+}
+
+#[test]
+fn test_close_round_refunds_partial_contribution() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &40);
+    assert_eq!(setup.token_client.balance(&user2), 960);
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.close_round();
+
+    // user2 defaulted and got the short payment back; user1's full payment is untouched.
+    assert_eq!(setup.token_client.balance(&user2), 1000);
+    assert_eq!(setup.token_client.balance(&user1), 900);
+    assert_eq!(setup.client.get_round_record(&0).defaulters, vec![&setup.env, user2.clone()]);
+}
+
+#[test]
+fn test_rounds_until_payout() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    assert_eq!(setup.client.rounds_until_payout(&user1), 0);
+    assert_eq!(setup.client.rounds_until_payout(&user2), 1);
+    assert_eq!(setup.client.rounds_until_payout(&user3), 2);
+
+    // After one payout the rotation wraps: user1 is now furthest away.
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert_eq!(setup.client.rounds_until_payout(&user1), 2);
+    assert_eq!(setup.client.rounds_until_payout(&user2), 0);
+    assert_eq!(setup.client.rounds_until_payout(&user3), 1);
+
+    let outsider = Address::generate(&setup.env);
+    let res = setup.client.try_rounds_until_payout(&outsider);
+    assert_eq!(res.unwrap_err().unwrap(), Error::NotAMember.into());
+}
+
+#[test]
+fn test_fee_without_recipient_goes_to_admin() {
+    let setup = setup_with_members(2, 1000);
+    setup.client.init(
+        &setup.admin,
+        &setup.members,
+        &100,
+        &setup.token_admin,
+        &3600,
+        &RoscaConfig {
+            fee_bps: 250,
+            ..default_config()
+        },
+        &None,
+    );
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+
+    // 2.5% of the 200 pot goes to the admin, the rest to the recipient.
+    assert_eq!(setup.token_client.balance(&setup.admin), 5);
+    assert_eq!(setup.token_client.balance(&user1), 900 + 195);
+}
+
+fn init_with_fee(setup: &TestSetup<'_>, fee_bps: u32) {
+    setup.client.init(
+        &setup.admin,
+        &setup.members,
+        &100,
+        &setup.token_admin,
+        &3600,
+        &RoscaConfig {
+            fee_bps,
+            ..default_config()
+        },
+        &None,
+    );
+}
+
+#[test]
+fn test_fee_reserve_covers_shortfall_fully() {
+    let setup = setup_with_members(3, 1000);
+    init_with_fee(&setup, 500);
+    setup.client.set_fee_to_reserve(&true);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    // Round 0: full pot of 300, 5% fee stays in the reserve.
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert_eq!(setup.client.get_reserve(), 15);
+    assert_eq!(setup.token_client.balance(&user1), 900 + 285);
+    assert_eq!(setup.token_client.balance(&setup.admin), 0);
+
+    // Round 1: user3 is 10 short; the reserve tops the pot back up to 300.
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.client.contribute(&user3, &setup.token_admin, &90);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.finalize_round();
+
+    assert_eq!(setup.token_client.balance(&user2), 800 + 285);
+    assert_eq!(setup.client.get_reserve(), 5 + 15);
+}
+
+#[test]
+fn test_fee_reserve_covers_shortfall_partially() {
+    let setup = setup_with_members(3, 1000);
+    init_with_fee(&setup, 500);
+    setup.client.set_fee_to_reserve(&true);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert_eq!(setup.client.get_reserve(), 15);
+
+    // Round 1: user3 pays nothing; the 100 shortfall exceeds the reserve, so
+    // all 15 is drawn and the pot is 215 before the new fee.
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.finalize_round();
+
+    // fee = 215 * 5% = 10 (rounded down), recipient gets 205.
+    assert_eq!(setup.token_client.balance(&user2), 800 + 205);
+    assert_eq!(setup.client.get_reserve(), 10);
+}
+
+#[test]
+fn test_swap_payout_slots_by_consent() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    let swap_id = setup.client.request_swap(&user2, &user3);
+    setup.client.accept_swap(&user3, &swap_id);
+
+    let order = setup.client.get_payout_order();
+    assert_eq!(order.get(1).unwrap(), user3);
+    assert_eq!(order.get(2).unwrap(), user2);
+
+    // Round 0 still pays user1.
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert_eq!(setup.token_client.balance(&user1), 900 + 300);
+
+    // Round 1 now pays user3 instead of user2.
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert_eq!(setup.token_client.balance(&user3), 800 + 300);
+    assert_eq!(setup.token_client.balance(&user2), 800);
+}
+
+#[test]
+fn test_swap_rejects_paid_slot() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+
+    // user1's slot (round 0) has already been paid out.
+    let res = setup.client.try_request_swap(&user1, &user2);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError::SlotAlreadyPaidOut.into());
+}
+
+#[test]
+fn test_accept_swap_without_request_fails() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+    let res = setup.client.try_accept_swap(&user2, &1);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::NoPendingSwap.into());
+
+    // A swap addressed to someone else cannot be accepted by user2.
+    let swap_id = setup.client.request_swap(&user3, &user2);
+    let res = setup.client.try_accept_swap(&user3, &swap_id);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::NoPendingSwap.into());
+    setup.client.accept_swap(&user2, &swap_id);
+}
+
+#[test]
+fn test_close_round_slashes_defaulter_deposit() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.set_slash_bps(&2500);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.post_deposit(&user2, &200);
+    assert_eq!(setup.client.get_deposit(&user2), 200);
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.close_round();
+
+    assert_eq!(setup.client.get_deposit(&user2), 150);
+    assert_eq!(setup.client.get_reserve(), 50);
+    assert_eq!(setup.client.get_deposit(&user1), 0);
+}
+
+#[test]
+fn test_deposits_are_not_paid_out_with_pot() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.post_deposit(&user2, &200);
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+
+    assert_eq!(setup.token_client.balance(&user1), 900 + 200);
+    assert_eq!(setup.client.get_deposit(&user2), 200);
+    assert_eq!(setup.client.contract_balance(), 200);
+}
+
+#[test]
+fn test_deposits_refunded_when_cycle_finishes() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.set_join_deposit(&50);
+    let newcomer = Address::generate(&setup.env);
+    setup.token_admin_client.mint(&newcomer, &1000);
+    setup.client.join(&newcomer);
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.post_deposit(&user2, &200);
+    setup.client.set_max_rounds(&3);
+
+    let mut members = setup.members.clone();
+    members.push_back(newcomer);
+    for _ in 0..3 {
+        for member in members.iter() {
+            setup.client.contribute(&member, &setup.token_admin, &100);
+        }
+    }
+    assert!(setup.client.is_complete());
+
+    // Each member got the pot once and their deposit back.
+    for member in members.iter() {
+        assert_eq!(setup.client.get_deposit(&member), 0);
+        assert_eq!(setup.token_client.balance(&member), 1000);
+    }
+    assert_eq!(setup.client.contract_balance(), 0);
+}
+
+#[test]
+fn test_exit_refunds_deposit() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    setup.client.post_deposit(&user1, &200);
+    assert_eq!(setup.token_client.balance(&user1), 800);
+
+    setup.client.request_emergency_exit(&user1);
+    setup.client.approve_exit(&user1);
+
+    assert_eq!(setup.client.get_deposit(&user1), 0);
+    assert_eq!(setup.token_client.balance(&user1), 1000);
+    assert_eq!(setup.client.contract_balance(), 0);
+}
+
+#[test]
+fn test_preview_payout_reflects_partial_pot() {
+    let setup = setup_with_members(3, 1000);
+    init_with_fee(&setup, 500);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+
+    let (recipient, amount) = setup.client.preview_payout();
+    assert_eq!(recipient, user1);
+    assert_eq!(amount, 200 - 10);
+
+    // Previewing is read-only: the same contributions are still pending.
+    assert_eq!(setup.client.preview_payout(), (user1, 190));
+    assert_eq!(setup.client.contract_balance(), 200);
+}
+
+#[test]
+fn test_contribute_returns_typed_errors() {
+    let setup = setup_with_members(2, 1000);
+
+    let user1 = setup.members.get(0).unwrap();
+    let res = setup.client.try_contribute(&user1, &setup.token_admin, &100);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::NotInitialized.into());
+
+    default_init(&setup);
+
+    let res = setup.client.try_contribute(&user1, &setup.token_admin, &0);
+    assert_eq!(res.unwrap_err().unwrap(), Error::AmountMustBePositive.into());
+
+    let outsider = Address::generate(&setup.env);
+    let res = setup.client.try_contribute(&outsider, &setup.token_admin, &100);
+    assert_eq!(res.unwrap_err().unwrap(), Error::NotAMember.into());
+
+    let res = setup.client.try_contribute(&user1, &setup.token_admin, &150);
+    assert_eq!(res.unwrap_err().unwrap(), Error::ExceedsRemainingContribution.into());
+
+    let other_token = setup
+        .env
+        .register_stellar_asset_contract_v2(setup.admin.clone())
+        .address();
+    let res = setup.client.try_contribute(&user1, &other_token, &100);
+    assert_eq!(res.unwrap_err().unwrap(), Error::TokenNotApproved.into());
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    let res = setup.client.try_contribute(&user1, &setup.token_admin, &100);
+    assert_eq!(res.unwrap_err().unwrap(), Error::AlreadyContributed.into());
+
+    let user2 = setup.members.get(1).unwrap();
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    let res = setup.client.try_contribute(&user2, &setup.token_admin, &100);
+    assert_eq!(res.unwrap_err().unwrap(), Error::ContributionWindowClosed.into());
+}
+
+#[test]
+fn test_close_round_returns_typed_errors() {
+    let setup = setup_with_members(2, 1000);
+
+    let res = setup.client.try_close_round();
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::NotInitialized.into());
+
+    default_init(&setup);
+    let res = setup.client.try_close_round();
+    assert_eq!(res.unwrap_err().unwrap(), Error::DeadlineNotPassed.into());
+}
+
+/// Asserts that a `try_` client call failed with the typed contract error
+/// `expected`.
+#[track_caller]
+fn assert_contract_error<T: core::fmt::Debug, C: core::fmt::Debug>(
+    res: Result<Result<T, C>, Result<soroban_sdk::Error, soroban_sdk::InvokeError>>,
+    expected: impl Into<soroban_sdk::Error>,
+) {
+    assert_eq!(res.unwrap_err().unwrap(), expected.into());
+}
+
+#[test]
+fn test_uninitialized_calls_return_not_initialized() {
+    let setup = setup_env();
+    let env = &setup.env;
+    let c = &setup.client;
+    let a = Address::generate(env);
+    let hash = BytesN::from_array(env, &[0; 32]);
+    let not_init = ExtError2::NotInitialized;
+
+    // Every entry point that used to panic with a message on missing config.
+    assert_contract_error(c.try_preview_payout(), not_init);
+    assert_contract_error(c.try_accept_merge(&a, &1), not_init);
+    assert_contract_error(c.try_add_approved_token(&a), not_init);
+    assert_contract_error(c.try_add_member(&a), not_init);
+    assert_contract_error(c.try_apply_pending_tier_changes(&a), not_init);
+    assert_contract_error(c.try_approve_admin_action(&a, &1, &None, &None), not_init);
+    assert_contract_error(c.try_approve_exit(&a), not_init);
+    assert_contract_error(c.try_approve_migration_entry(&a, &a, &1), not_init);
+    assert_contract_error(c.try_approve_migration_exit(&a), not_init);
+    assert_contract_error(c.try_approve_slot_swap(&a, &1), not_init);
+    assert_contract_error(c.try_authorize_proxy(&a, &1, &a, &1), not_init);
+    assert_contract_error(c.try_bump_ttl(&1), not_init);
+    assert_contract_error(c.try_cancel_migration(&a, &a), not_init);
+    assert_contract_error(c.try_cancel_pending_group(&a), not_init);
+    assert_contract_error(c.try_claim_early(&a), not_init);
+    assert_contract_error(c.try_claim_rewards(&a), not_init);
+    assert_contract_error(c.try_close_round(), not_init);
+    assert_contract_error(c.try_complete_merge(&a, &1, &vec![env, a.clone()]), not_init);
+    assert_contract_error(c.try_configure_late_demotion(&a, &1, &1), not_init);
+    assert_contract_error(c.try_configure_sealed_slot_auction(&a, &1, &1, &100), not_init);
+    assert_contract_error(c.try_confirm_split_participation(&a, &1, &1), not_init);
+    assert_contract_error(c.try_contract_balance(), not_init);
+    assert_contract_error(c.try_contribute(&a, &a, &100), not_init);
+    assert_contract_error(c.try_contribute_as_proxy(&a, &1, &a, &a, &100), not_init);
+    assert_contract_error(c.try_contribute_batch(&a, &vec![env, a.clone()]), not_init);
+    assert_contract_error(c.try_contribute_late(&a), not_init);
+    assert_contract_error(c.try_contribute_split(&a, &a), not_init);
+    assert_contract_error(c.try_contribute_to_insurance(&a, &a, &100), not_init);
+    assert_contract_error(
+        c.try_create_proposal(&a, &ProposalType::RuleChange, &soroban_sdk::String::from_str(env, "x"), &a, &1, &None),
+        not_init,
+    );
+    assert_contract_error(c.try_delegate_contribution_rights(&a, &1, &a, &1), not_init);
+    assert_contract_error(c.try_delegate_contribution_vote(&a, &a, &1), not_init);
+    assert_contract_error(c.try_delegate_vote(&a, &a), not_init);
+    assert_contract_error(c.try_deposit_rewards(&a, &100), not_init);
+    assert_contract_error(c.try_dissolve(), not_init);
+    assert_contract_error(c.try_dissolve_group(&a, &hash), not_init);
+    assert_contract_error(c.try_emit_deadline_reminder(&symbol_short!("x")), not_init);
+    assert_contract_error(c.try_enable_group_treasury(&a, &a), not_init);
+    assert_contract_error(c.try_execute_group_split(&a, &1, &1), not_init);
+    assert_contract_error(c.try_execute_proposal(&1), not_init);
+    assert_contract_error(c.try_finalize_payout_order(&a), not_init);
+    assert_contract_error(c.try_finalize_round(), not_init);
+    assert_contract_error(c.try_freeze_group(&a, &1, &hash), not_init);
+    assert_contract_error(c.try_fund_savings_reward_pool(&a, &100), not_init);
+    assert_contract_error(c.try_generate_invite(&a), not_init);
+    assert_contract_error(c.try_get_admin(), not_init);
+    assert_contract_error(c.try_get_claimable_reward(&a), not_init);
+    assert_contract_error(c.try_get_group_info(), not_init);
+    assert_contract_error(c.try_get_state(), not_init);
+    assert_contract_error(c.try_get_token(), not_init);
+    assert_contract_error(c.try_init_multisig(&vec![env, a.clone()], &1), not_init);
+    assert_contract_error(c.try_join_waitlist(&a), not_init);
+    assert_contract_error(c.try_migrate(&a), not_init);
+    assert_contract_error(c.try_open_sealed_slot_auction(&a, &1), not_init);
+    assert_contract_error(
+        c.try_pause_contract(&a, &soroban_sdk::String::from_str(env, "x")),
+        not_init,
+    );
+    assert_contract_error(c.try_pause_group(&soroban_sdk::String::from_str(env, "x")), not_init);
+    assert_contract_error(c.try_propose_admin_action(&1, &None, &None), not_init);
+    assert_contract_error(c.try_propose_admin_transfer(&a), not_init);
+    assert_contract_error(
+        c.try_propose_group_split(&a, &1, &vec![env, a.clone()], &vec![env, a.clone()], &hash),
+        not_init,
+    );
+    assert_contract_error(c.try_propose_member_freeze(&a, &hash), not_init);
+    assert_contract_error(c.try_propose_merge(&a, &1), not_init);
+    assert_contract_error(c.try_propose_treasury_round(&a, &1, &hash), not_init);
+    assert_contract_error(c.try_register_co_payer_splits(&a, &Vec::new(env)), not_init);
+    assert_contract_error(c.try_reject_exit(&a), not_init);
+    assert_contract_error(c.try_remove_approved_token(&a), not_init);
+    assert_contract_error(c.try_remove_from_waitlist(&a, &a), not_init);
+    assert_contract_error(c.try_remove_member(&a), not_init);
+    assert_contract_error(c.try_request_emergency_exit(&a), not_init);
+    assert_contract_error(c.try_request_emergency_payout(&a, &hash), not_init);
+    assert_contract_error(c.try_request_group_migration(&a, &a, &1), not_init);
+    assert_contract_error(c.try_request_penalty_grace(&a), not_init);
+    assert_contract_error(c.try_request_skip(&a, &1), not_init);
+    assert_contract_error(c.try_request_slot_swap(&a, &1, &1, &a), not_init);
+    assert_contract_error(c.try_request_swap(&a, &a), not_init);
+    assert_contract_error(c.try_resolve_slot_auction(), not_init);
+    assert_contract_error(c.try_resume_contract(&a), not_init);
+    assert_contract_error(c.try_resume_group(&soroban_sdk::String::from_str(env, "x")), not_init);
+    assert_contract_error(c.try_revoke_co_payer_splits(&a), not_init);
+    assert_contract_error(c.try_rounds_until_payout(&a), not_init);
+    assert_contract_error(c.try_set_auto_close_enabled(&true), not_init);
+    assert_contract_error(c.try_set_co_signer(&a, &1, &a), not_init);
+    assert_contract_error(c.try_set_co_signer_window(&a, &1), not_init);
+    assert_contract_error(c.try_set_contribution_amount(&100), not_init);
+    assert_contract_error(c.try_set_cycle_bonus(&a, &100), not_init);
+    assert_contract_error(c.try_set_cycle_retention_window(&1), not_init);
+    assert_contract_error(c.try_set_dissolution_config(&a, &1, &1), not_init);
+    assert_contract_error(c.try_set_emergency_payout_config(&a, &1, &1, &1), not_init);
+    assert_contract_error(c.try_set_exchange_rate(&a, &100), not_init);
+    assert_contract_error(c.try_set_fee_to_reserve(&true), not_init);
+    assert_contract_error(
+        c.try_set_insurance_coverage_mode(&a, &InsuranceCoverageMode::Full),
+        not_init,
+    );
+    assert_contract_error(c.try_set_late_fee(&a, &100), not_init);
+    assert_contract_error(c.try_set_member_tier(&a, &a, &1), not_init);
+    assert_contract_error(c.try_set_min_credit_score(&a, &100), not_init);
+    assert_contract_error(c.try_set_min_snapshot_interval(&a, &1), not_init);
+    assert_contract_error(c.try_set_quorum_per_type(&a, &ProposalType::RuleChange, &1), not_init);
+    assert_contract_error(c.try_set_reinstatement_fee(&a, &100), not_init);
+    assert_contract_error(c.try_set_reinvest_preference(&a, &true), not_init);
+    assert_contract_error(c.try_set_reputation_fee_discount(&a, &100, &1), not_init);
+    assert_contract_error(c.try_set_reward_dist_params(&DistributionType::Equal, &None), not_init);
+    assert_contract_error(c.try_set_round_duration_bounds(&a, &1, &1), not_init);
+    assert_contract_error(c.try_set_score_weights(&a, &100, &100, &100, &100, &100), not_init);
+    assert_contract_error(c.try_set_slash_bps(&1), not_init);
+    assert_contract_error(c.try_set_slot_swap_config(&a, &true, &1), not_init);
+    assert_contract_error(c.try_set_split_confirmation_window(&a, &1), not_init);
+    assert_contract_error(c.try_set_token_limit(&a, &100), not_init);
+    assert_contract_error(c.try_set_token_whitelist_contract(&a, &a), not_init);
+    assert_contract_error(c.try_set_use_timestamp_schedule(&a, &true), not_init);
+    assert_contract_error(c.try_set_waitlist_priority_mode(&a, &WaitlistMode::Fifo), not_init);
+    assert_contract_error(c.try_settle_sealed_slot_auction(), not_init);
+    assert_contract_error(c.try_start_dissolution_vote(&a), not_init);
+    assert_contract_error(c.try_sweep_dust(&a), not_init);
+    assert_contract_error(c.try_take_snapshot(&a), not_init);
+    assert_contract_error(c.try_unfreeze_group(&a, &1, &hash), not_init);
+    assert_contract_error(c.try_update_fee(&1), not_init);
+    assert_contract_error(c.try_update_max_members(&1), not_init);
+    assert_contract_error(c.try_update_round_duration(&a, &1), not_init);
+    assert_contract_error(c.try_upgrade(&a, &hash), not_init);
+    assert_contract_error(c.try_vote_dissolve_group(&a, &true), not_init);
+    assert_contract_error(c.try_vote_emergency_payout(&a, &a, &true), not_init);
+    assert_contract_error(c.try_vote_on_proposal(&a, &1, &true), not_init);
+    assert_contract_error(c.try_vote_treasury_round(&a, &1, &true), not_init);
+
+    // Entry points that look up a record or a feature before any config
+    // fail with the matching typed error instead.
+    assert_contract_error(c.try_accept_admin_role(), ExtError3::NoAdminTransferProposed);
+    assert_contract_error(c.try_accept_slot_swap(&a, &1), Error::ProposalNotFound);
+    assert_contract_error(c.try_commit_slot_bid(&a, &hash, &100), ExtError2::AuctionNotEnabled);
+    assert_contract_error(c.try_contribute_via_proxy(&a, &a, &a, &100), Error::NoDelegationFound);
+    assert_contract_error(
+        c.try_create_group_tiered(
+            &a,
+            &vec![env, a.clone()],
+            &100,
+            &a,
+            &1,
+            &default_config(),
+            &None,
+            &Vec::new(env),
+        ),
+        ExtError::InvalidTierDefinition,
+    );
+    assert_contract_error(c.try_execute_dissolution(), ExtError3::NoDissolutionVote);
+    assert_contract_error(c.try_execute_emergency_payout(&a), ExtError3::EmergencyPayoutNotFound);
+    assert_contract_error(c.try_execute_migration(&a, &a), ExtError2::MigrationNotFound);
+    assert_contract_error(
+        c.try_execute_treasury_payment(&a, &a, &100, &hash),
+        ExtError3::TreasuryNotEnabled,
+    );
+    assert_contract_error(c.try_finalize_migration_exit(&a, &a), ExtError2::MigrationNotFound);
+    assert_contract_error(c.try_get_cycle_snapshot(&1, &1), ExtError3::SnapshotNotFound);
+    assert_contract_error(c.try_get_emergency_loan(&1), ExtError3::LoanNotFound);
+    assert_contract_error(c.try_get_merge_proposal(&1), Error::ProposalNotFound);
+    assert_contract_error(c.try_get_round_record(&1), ExtError2::RoundNotFound);
+    assert_contract_error(c.try_get_snapshot(&1), ExtError3::SnapshotNotFound);
+    assert_contract_error(c.try_get_treasury_round_proposal(&1), Error::ProposalNotFound);
+    assert_contract_error(
+        c.try_init(&a, &vec![env, a.clone()], &100, &a, &1, &default_config(), &None),
+        ExtError2::InvalidToken,
+    );
+    assert_contract_error(c.try_join_group_tiered(&a, &1), ExtError::TierNotFound);
+    assert_contract_error(c.try_join_with_invite(&a), Error::InviteNotFound);
+    assert_contract_error(c.try_leave_waitlist(&a), ExtError3::NotOnWaitlist);
+    assert_contract_error(c.try_pay_catch_up_contribution(&a), ExtError2::NoDebtRecorded);
+    assert_contract_error(c.try_place_slot_bid(&a, &1, &100), ExtError2::AuctionNotEnabled);
+    assert_contract_error(c.try_post_deposit(&a, &100), Error::NotAMember);
+    assert_contract_error(c.try_reject_slot_swap(&a, &1), Error::ProposalNotFound);
+    assert_contract_error(c.try_repay_emergency_loan(&a, &1, &100), ExtError3::LoanNotFound);
+    assert_contract_error(c.try_request_emergency_loan(&a, &100, &1), ExtError3::ReserveNotEnabled);
+    assert_contract_error(c.try_request_tier_change(&a, &1), ExtError::TierNotFound);
+    assert_contract_error(c.try_reveal_slot_bid(&a, &1, &100, &hash), ExtError2::AuctionNotEnabled);
+    assert_contract_error(c.try_set_auto_contribute(&a, &1), Error::NotAMember);
+    assert_contract_error(c.try_trigger_auto(&a), ExtError2::AutoContributeNotEnabled);
+    assert_contract_error(c.try_update_slot_bid(&a, &1, &100), ExtError2::AuctionNotEnabled);
+    assert_contract_error(
+        c.try_vote_proposal_via_proxy(&a, &a, &1, &true),
+        Error::NoDelegationFound,
+    );
+}
+
+#[test]
+fn test_admin_only_calls_return_only_admin_allowed() {
+    let setup = setup_with_members(2, 0);
+    default_init(&setup);
+    let outsider = Address::generate(&setup.env);
+
+    let res = setup
+        .client
+        .try_pause_contract(&outsider, &soroban_sdk::String::from_str(&setup.env, "x"));
+    assert_eq!(res.unwrap_err().unwrap(), ExtError::OnlyAdminAllowed.into());
+
+    let res = setup.client.try_set_waitlist_priority_mode(&outsider, &WaitlistMode::Fifo);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError::OnlyAdminAllowed.into());
+
+    let res = setup.client.try_set_slot_swap_config(&outsider, &false, &60);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError::OnlyAdminAllowed.into());
+}
+
+#[test]
+fn test_finite_cycle_completes_after_max_rounds() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.set_max_rounds(&2);
+    assert_eq!(setup.client.get_max_rounds(), 2);
+
+    for _ in 0..2 {
+        assert!(!setup.client.is_complete());
+        for member in setup.members.iter() {
+            setup.client.contribute(&member, &setup.token_admin, &100);
+        }
+    }
+    assert!(setup.client.is_complete());
+
+    // Each member received the pot exactly once.
+    for member in setup.members.iter() {
+        assert_eq!(setup.token_client.balance(&member), 1000);
+    }
+
+    let user1 = setup.members.get(0).unwrap();
+    let res = setup.client.try_contribute(&user1, &setup.token_admin, &100);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::CycleComplete.into());
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 10_000);
+    let res = setup.client.try_close_round();
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::CycleComplete.into());
+}
+
+#[test]
+fn test_max_rounds_defaults_to_member_count() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    assert_eq!(setup.client.get_max_rounds(), 2);
+
+    for _ in 0..2 {
+        assert!(!setup.client.is_complete());
+        for member in setup.members.iter() {
+            setup.client.contribute(&member, &setup.token_admin, &100);
+        }
+    }
+    assert!(setup.client.is_complete());
+}
+
+#[test]
+fn test_round_closed_without_payout_does_not_count_toward_max_rounds() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+
+    // Round 0 closes without a payout; only user1 paid.
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.close_round();
+    let (round, _, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+    assert!(!setup.client.is_complete());
+
+    // Two more rounds pay out, one per member, and complete the cycle.
+    for round in 1..3u32 {
+        assert!(!setup.client.is_complete());
+        for member in setup.members.iter() {
+            setup.client.contribute(&member, &setup.token_admin, &100);
+        }
+        let (current, _, _, _, _) = setup.client.get_state();
+        assert_eq!(current, round + 1);
+    }
+    assert!(setup.client.is_complete());
+}
+
+#[test]
+fn test_contribution_proof_is_stable() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+
+    let proof = setup.client.get_contribution_proof(&user1, &0);
+    assert_eq!(proof, setup.client.get_contribution_proof(&user1, &0));
+    assert_ne!(proof, setup.client.get_contribution_proof(&user2, &0));
+
+    let res = setup.client.try_get_contribution_proof(&user1, &1);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::ContributionNotRecorded.into());
+}
+
+#[test]
+fn test_poke_closes_expired_round_and_pays_keeper() {
+    let setup = setup_with_members(3, 1000);
+    init_with_fee(&setup, 500);
+    setup.client.set_fee_to_reserve(&true);
+    setup.client.set_keeper_reward(&5);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert_eq!(setup.client.get_reserve(), 15);
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+
+    let keeper = Address::generate(&setup.env);
+    let res = setup.client.try_poke(&keeper);
+    assert_eq!(res.unwrap_err().unwrap(), Error::DeadlineNotPassed.into());
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    assert_eq!(setup.client.poke(&keeper), 5);
+
+    assert_eq!(setup.client.get_group_info().current_round, 2);
+    assert_eq!(setup.token_client.balance(&user2), 800 + 205);
+    assert_eq!(setup.token_client.balance(&keeper), 5);
+    assert_eq!(setup.client.get_reserve(), 10 - 5);
+}
+
+#[test]
+fn test_suspended_member_is_skipped_and_cannot_contribute() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    let res = setup.client.try_unsuspend_member(&user2);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError::MemberNotSuspended.into());
+    setup.client.suspend_member(&user2);
+    assert!(setup.client.is_suspended(&user2));
+
+    let res = setup.client.try_contribute(&user2, &setup.token_admin, &100);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::MemberSuspended.into());
+
+    // Round 0: once both active members pay, the payout fires without
+    // waiting for the deadline; user2 is not a defaulter.
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+    assert_eq!(setup.token_client.balance(&user1), 900 + 200);
+    assert_eq!(setup.client.get_round_record(&0).defaulters.len(), 0);
+
+    // Round 1 would be user2's slot; the rotation skips to user3.
+    assert_eq!(setup.client.current_recipient(), user3);
+
+    setup.client.unsuspend_member(&user2);
+    assert!(!setup.client.is_suspended(&user2));
+    assert_eq!(setup.client.current_recipient(), user2);
+}
+
+#[test]
+fn test_contribute_batch_completes_round_with_suspended_member() {
+    let setup = setup_with_members(3, 0);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+    setup.client.suspend_member(&user2);
+
+    let coordinator = Address::generate(&setup.env);
+    setup.token_admin_client.mint(&coordinator, &1000);
+    setup
+        .client
+        .contribute_batch(&coordinator, &vec![&setup.env, user1.clone(), user3.clone()]);
+
+    assert_eq!(setup.token_client.balance(&user1), 200);
+    let (round, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+    assert_eq!(paid.len(), 0);
+}
+
+#[test]
+fn test_contribute_batch_rejects_beneficiary_listed_after_round_fills() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+
+    // user2 and user3 fill the round, so user1 would otherwise be charged
+    // for the next one after a mid-batch payout.
+    let coordinator = Address::generate(&setup.env);
+    setup.token_admin_client.mint(&coordinator, &1000);
+    let batch = vec![&setup.env, user2.clone(), user3.clone(), user1.clone()];
+    let res = setup.client.try_contribute_batch(&coordinator, &batch);
+    assert_eq!(res.unwrap_err().unwrap(), Error::AlreadyContributed.into());
+    assert_eq!(setup.token_client.balance(&coordinator), 1000);
+
+    // Without user1 the batch fills the round and pays out once, at the end.
+    setup
+        .client
+        .contribute_batch(&coordinator, &vec![&setup.env, user2.clone(), user3.clone()]);
+    assert_eq!(setup.token_client.balance(&coordinator), 800);
+    assert_eq!(setup.token_client.balance(&user1), 1200);
+    let (round, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+    assert_eq!(paid.len(), 0);
+}
+
+#[test]
+fn test_batch_and_auto_contribute_apply_contribute_rules() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.set_token_limit(&setup.token_admin, &50);
+
+    let coordinator = Address::generate(&setup.env);
+    setup.token_admin_client.mint(&coordinator, &1000);
+    let res = setup
+        .client
+        .try_contribute_batch(&coordinator, &vec![&setup.env, user1.clone(), user2.clone()]);
+    assert_eq!(res.unwrap_err().unwrap(), Error::ExceedsTokenLimit.into());
+
+    setup.client.set_auto_contribute(&user1, &1);
+    let res = setup.client.try_trigger_auto(&user1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::ExceedsTokenLimit.into());
+    assert_eq!(setup.token_client.balance(&coordinator), 1000);
+    assert_eq!(setup.token_client.balance(&user1), 1000);
+}
+
+#[test]
+fn test_reinit_blocked_during_dissolve_cooldown() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.dissolve();
+
+    let config = default_config();
+
+    let res = setup.client.try_init(
+        &setup.admin,
+        &setup.members,
+        &200,
+        &setup.token_admin,
+        &3600,
+        &config,
+        &None,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::ReinitCooldownActive.into());
+
+    setup
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = REINIT_COOLDOWN_SECONDS + 1);
+    setup.client.init(
+        &setup.admin,
+        &setup.members,
+        &200,
+        &setup.token_admin,
+        &3600,
+        &config,
+        &None,
+    );
+
+    assert!(setup.client.is_active());
+    let user1 = setup.members.get(0).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &200);
+}
+
+#[test]
+fn test_reinit_starts_from_clean_state() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    let oracle_id = setup.env.register(MockPriceOracle, ());
+    let oracle = MockPriceOracleClient::new(&setup.env, &oracle_id);
+    oracle.set_price(&20_000_000, &0);
+    setup.client.set_price_peg(&Some(oracle_id.clone()), &100, &60);
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.close_round_at(&0);
+    setup.client.dissolve();
+
+    setup
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = 3601 + REINIT_COOLDOWN_SECONDS + 1);
+    let config = default_config();
+    setup.client.init(
+        &setup.admin,
+        &setup.members,
+        &200,
+        &setup.token_admin,
+        &3600,
+        &config,
+        &None,
+    );
+
+    // The old peg and its stale price no longer price contributions.
+    let user1 = setup.members.get(0).unwrap();
+    assert_eq!(setup.client.required_balance(&user1), 200);
+    setup.client.contribute(&user1, &setup.token_admin, &200);
+    assert!(setup.client.contribution_status(&vec![&setup.env, user1.clone()]).get(0).unwrap());
+
+    // Round 0 of the new group is closable even though the old group closed its round 0.
+    setup.env.ledger().with_mut(|li| li.timestamp += 3601);
+    setup.client.close_round_at(&0);
+}
+
+#[test]
+fn test_set_round_duration_applies_from_next_round() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    setup.client.set_round_duration(&1800);
+    let (_, _, deadline, _, _) = setup.client.get_state();
+    assert_eq!(deadline, 3600);
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.close_round();
+
+    let (round, _, deadline, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+    assert_eq!(deadline, 3601 + 1800);
+
+    let res = setup.client.try_set_round_duration(&0);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError::InvalidAmount.into());
+}
+
+#[test]
+fn test_finished_event_emitted_once_with_total() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.set_max_rounds(&2);
+
+    let finished_topics: soroban_sdk::Vec<soroban_sdk::Val> =
+        (Symbol::new(&setup.env, "finished"), EVENT_SCHEMA_VERSION).into_val(&setup.env);
+
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert!(setup
+        .env
+        .events()
+        .all()
+        .iter()
+        .all(|ev| ev.1 != finished_topics));
+
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    let events = setup.env.events().all();
+    assert_eq!(
+        events.iter().filter(|ev| ev.1 == finished_topics).count(),
+        1
+    );
+    let event = events
+        .iter()
+        .find(|ev| ev.1 == finished_topics)
+        .expect("finished event not emitted");
+
+    let data: soroban_sdk::Map<Symbol, soroban_sdk::Val> = event.2.into_val(&setup.env);
+    let total: i128 = data
+        .get(Symbol::new(&setup.env, "total_distributed"))
+        .unwrap()
+        .into_val(&setup.env);
+    assert_eq!(total, 400);
+}
+
+#[test]
+fn test_get_full_state_matches_init_and_round() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+
+    let state = setup.client.get_full_state();
+    assert_eq!(
+        state,
+        FullState {
+            round: 0,
+            paid_members: vec![&setup.env, user1.clone()],
+            deadline: 3600,
+            members: setup.members.clone(),
+            contribution_amount: 100,
+            token: setup.token_admin.clone(),
+            admin: setup.admin.clone(),
+            defaulters: Vec::new(&setup.env),
+        }
+    );
+}
+
+#[test]
+fn test_whitelisted_address_can_join() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.set_whitelist_enabled(&true);
+
+    let newcomer = Address::generate(&setup.env);
+    setup.client.whitelist_add(&newcomer);
+    assert!(setup.client.is_whitelisted(&newcomer));
+
+    setup.client.join(&newcomer);
+    assert!(setup.client.is_member(&newcomer));
+    assert_eq!(setup.client.get_payout_order().last().unwrap(), newcomer);
+}
+
+#[test]
+fn test_non_whitelisted_address_cannot_join_or_be_added() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.set_whitelist_enabled(&true);
+
+    let outsider = Address::generate(&setup.env);
+    let res = setup.client.try_join(&outsider);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::NotWhitelisted.into());
+    let res = setup.client.try_add_member(&outsider);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::NotWhitelisted.into());
+
+    // Removing an approval closes the door again.
+    setup.client.whitelist_add(&outsider);
+    setup.client.whitelist_remove(&outsider);
+    let res = setup.client.try_join(&outsider);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::NotWhitelisted.into());
+}
+
+#[test]
+fn test_group_rejects_members_beyond_capacity() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.update_max_members(&3);
+    assert_eq!(setup.client.remaining_slots(), 1);
+
+    setup.client.add_member(&Address::generate(&setup.env));
+    assert_eq!(setup.client.remaining_slots(), 0);
+
+    let res = setup.client.try_add_member(&Address::generate(&setup.env));
+    assert_eq!(res.unwrap_err().unwrap(), Error::GroupFull.into());
+    let res = setup.client.try_join(&Address::generate(&setup.env));
+    assert_eq!(res.unwrap_err().unwrap(), Error::GroupFull.into());
+}
+
+#[test]
+fn test_mark_default_keeps_round_open() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+
+    setup.client.mark_default(&user1);
+    setup.client.mark_default(&user2);
+
+    assert_eq!(
+        setup.client.get_defaulters(),
+        vec![&setup.env, user1.clone(), user2.clone()]
+    );
+    let (round, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 0);
+    assert!(!paid.contains(&user2));
+}
+
+#[test]
+fn test_contribute_late_accrues_interest_to_reserve() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.configure_late_demotion(&setup.admin, &3, &3600);
+    setup.client.set_interest_rate_bps(&10_000);
+    assert_eq!(setup.client.get_interest_rate_bps(), 10_000);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    assert_eq!(setup.client.get_reserve(), 0);
+
+    // 1728 seconds late: 100 * 10_000 * 1728 / (10_000 * 86_400) = 2.
+    setup.env.ledger().with_mut(|li| li.timestamp = 3600 + 1728);
+    setup.client.contribute_late(&user2);
+    assert!(emitted_event_names(&setup.env)
+        .contains(Symbol::new(&setup.env, "reserve_credited")));
+
+    // The interest stays in the reserve; the pot paid out is the plain 200.
+    assert_eq!(setup.client.get_reserve(), 2);
+    assert_eq!(setup.token_client.balance(&setup.client.address), 2);
+    assert_eq!(setup.token_client.balance(&user2), 898);
+    assert_eq!(setup.token_client.balance(&user1), 1100);
+}
+
+#[test]
+fn test_set_interest_rate_bps_rejects_above_max() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    let res = setup.client.try_set_interest_rate_bps(&10_001);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError::InvalidAmount.into());
+}
+
+#[test]
+fn test_settle_debt_clears_default_record() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.finalize_round();
+    assert_eq!(setup.client.get_debt(&user2), 100);
+    assert_eq!(setup.client.get_member_status(&user2).default_count, 1);
+    assert!(setup.client.get_defaulters().contains(&user2));
+    assert!(setup.client.get_round_defaulters(&0).contains(&user2));
+
+    setup.client.settle_debt(&user2);
+    assert_eq!(setup.client.get_debt(&user2), 0);
+    assert_eq!(setup.client.get_member_status(&user2).default_count, 0);
+    assert!(!setup.client.get_defaulters().contains(&user2));
+    assert!(!setup.client.get_round_defaulters(&0).contains(&user2));
+    assert_eq!(setup.client.get_reserve(), 100);
+    assert_eq!(setup.token_client.balance(&user2), 900);
+}
+
+#[test]
+fn test_settle_debt_rejects_without_recorded_debt() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let res = setup.client.try_settle_debt(&user1);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::NoDebtRecorded.into());
+}
+
+#[test]
+fn test_get_unpaid_tracks_round_progress() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+    assert_eq!(setup.client.get_unpaid(), setup.members);
+
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    assert_eq!(
+        setup.client.get_unpaid(),
+        vec![&setup.env, user1.clone(), user3.clone()]
+    );
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+    // The round paid out and the next one starts with everyone unpaid again.
+    assert_eq!(setup.client.get_unpaid(), setup.members);
+}
+
+
+#[test]
+fn test_init_rejects_contract_address_as_member_or_admin() {
+    let setup = setup_with_members(2, 0);
+    let config = default_config();
+
+    let mut members = setup.members.clone();
+    members.push_back(setup.client.address.clone());
+    let res = setup.client.try_init(
+        &setup.admin,
+        &members,
+        &100,
+        &setup.token_admin,
+        &3600,
+        &config,
+        &None,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::ContractAddressNotAllowed.into());
+
+    let res = setup.client.try_init(
+        &setup.client.address,
+        &setup.members,
+        &100,
+        &setup.token_admin,
+        &3600,
+        &config,
+        &None,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::ContractAddressNotAllowed.into());
+}
+
+#[test]
+fn test_join_collects_deposit() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.set_join_deposit(&50);
+
+    let newcomer = Address::generate(&setup.env);
+    setup.token_admin_client.mint(&newcomer, &500);
+    setup.client.join(&newcomer);
+
+    assert!(setup.client.is_member(&newcomer));
+    assert_eq!(setup.client.get_deposit(&newcomer), 50);
+    assert_eq!(setup.token_client.balance(&newcomer), 450);
+}
+
+#[test]
+fn test_join_rejected_after_first_payout_unless_open() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+
+    let newcomer = Address::generate(&setup.env);
+    let res = setup.client.try_join(&newcomer);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::EnrollmentClosed.into());
+
+    setup.client.set_open_enrollment(&true);
+    setup.client.join(&newcomer);
+    assert!(setup.client.is_member(&newcomer));
+}
+
+fn init_with_recipient_policy(setup: &TestSetup<'_>, policy: DefaultingRecipientPolicy) {
+    setup.client.init(
+        &setup.admin,
+        &setup.members,
+        &100,
+        &setup.token_admin,
+        &3600,
+        &RoscaConfig {
+            defaulting_recipient_policy: policy,
+            ..default_config()
+        },
+        &None,
+    );
+}
+
+/// Round 0 where the scheduled recipient (user1) defaults and the others pay.
+fn finalize_with_defaulting_recipient(setup: &TestSetup<'_>) {
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.finalize_round();
+}
+
+#[test]
+fn test_defaulting_recipient_still_receives() {
+    let setup = setup_with_members(3, 1000);
+    init_with_recipient_policy(&setup, DefaultingRecipientPolicy::StillReceive);
+    finalize_with_defaulting_recipient(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    assert_eq!(setup.token_client.balance(&user1), 1200);
+    assert_eq!(setup.client.get_reserve(), 0);
+}
+
+#[test]
+fn test_defaulting_recipient_skipped_to_next_payer() {
+    let setup = setup_with_members(3, 1000);
+    init_with_recipient_policy(&setup, DefaultingRecipientPolicy::SkipToNext);
+    finalize_with_defaulting_recipient(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+    assert_eq!(setup.token_client.balance(&user1), 1000);
+    assert_eq!(setup.token_client.balance(&user2), 1100);
+    // The defaulter is re-queued into user2's old slot.
+    assert_eq!(
+        setup.client.get_payout_order(),
+        vec![&setup.env, user2, user1, user3]
+    );
+}
+
+#[test]
+fn test_defaulting_recipient_forfeits_to_reserve() {
+    let setup = setup_with_members(3, 1000);
+    init_with_recipient_policy(&setup, DefaultingRecipientPolicy::ForfeitToReserve);
+    finalize_with_defaulting_recipient(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    assert_eq!(setup.token_client.balance(&user1), 1000);
+    assert_eq!(setup.client.get_reserve(), 200);
+}
+
+#[test]
+fn test_contribution_status_batch() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+    let outsider = Address::generate(&setup.env);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+
+    let status = setup.client.contribution_status(&vec![
+        &setup.env,
+        user2.clone(),
+        outsider,
+        user1,
+        user3,
+        user2,
+    ]);
+    assert_eq!(status, vec![&setup.env, true, false, false, false, true]);
+}
+
+#[test]
+fn test_contribution_and_deposit_pause_flags_are_independent() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+
+    setup.client.set_contributions_paused(&true);
+    let res = setup
+        .client
+        .try_contribute(&user1, &setup.token_admin, &100);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::ContributionsPaused.into());
+    setup.client.post_deposit(&user1, &50);
+    assert_eq!(setup.client.get_deposit(&user1), 50);
+
+    setup.client.set_contributions_paused(&false);
+    setup.client.set_deposits_paused(&true);
+    let res = setup.client.try_post_deposit(&user1, &50);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::DepositsPaused.into());
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    assert!(setup.client.is_deposits_paused());
+    assert!(!setup.client.is_contributions_paused());
+}
+
+#[test]
+fn test_settle_round_pays_out_fully_paid_round() {
+    let setup = setup_with_members(3, 0);
+    default_init(&setup);
+
+    let coordinator = Address::generate(&setup.env);
+    setup.token_admin_client.mint(&coordinator, &1000);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    let res = setup.client.try_settle_round();
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::RoundNotFullyPaid.into());
+
+    // Suspending user3 after the others paid fills the round without a payout.
+    setup
+        .client
+        .contribute_batch(&coordinator, &vec![&setup.env, user1.clone(), user2]);
+    setup.client.suspend_member(&user3);
+    let (round, _, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 0);
+
+    setup.client.settle_round();
+    assert_eq!(setup.token_client.balance(&user1), 200);
+    let (round, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+    assert_eq!(paid.len(), 0);
+}
+
+#[test]
+fn test_close_round_with_duration_sets_next_deadline() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+
+    let res = setup.client.try_close_round_with_duration(&0);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError::InvalidAmount.into());
+
+    setup.client.close_round_with_duration(&600);
+    let (round, _, deadline, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+    assert_eq!(deadline, 3601 + 600);
+}
+
+#[test]
+fn test_event_topics_carry_schema_version() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    assert_eq!(setup.client.event_schema_version(), EVENT_SCHEMA_VERSION);
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.close_round();
+
+    let round_closed = Symbol::new(&setup.env, "round_closed");
+    let event = setup
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|ev| {
+            let name: Symbol = ev.1.get(0).unwrap().into_val(&setup.env);
+            name == round_closed
+        })
+        .expect("round_closed event not emitted");
+    let version: u32 = event.1.get(1).unwrap().into_val(&setup.env);
+    assert_eq!(version, EVENT_SCHEMA_VERSION);
+}
+
+#[test]
+fn test_waitlist_event_carries_schema_version() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    let waitlisted = Address::generate(&setup.env);
+    setup.client.join_waitlist(&waitlisted);
+
+    let expected_topics =
+        (Symbol::new(&setup.env, "waitlist_updated"), EVENT_SCHEMA_VERSION).into_val(&setup.env);
+    let event = setup.env.events().all().last().unwrap();
+    assert_eq!(event.1, expected_topics);
+}
+
+#[test]
+fn test_total_distributed_accumulates_across_rounds() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    assert_eq!(setup.client.total_distributed(), 0);
+
+    for _ in 0..2 {
+        for member in setup.members.iter() {
+            setup.client.contribute(&member, &setup.token_admin, &100);
+        }
+    }
+    assert_eq!(setup.client.total_distributed(), 400);
+}
+
+#[test]
+fn test_request_refund_after_deadline() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+
+    let res = setup.client.try_request_refund(&user1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::DeadlineNotPassed.into());
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    let res = setup.client.try_request_refund(&user2);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::ContributionNotRecorded.into());
+
+    setup.client.request_refund(&user1);
+    assert_eq!(setup.token_client.balance(&user1), 1000);
+    assert_eq!(setup.token_client.balance(&setup.client.address), 0);
+    let (_, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(paid.len(), 0);
+
+    // Closing the round shuts the window. The refunded member paid on time,
+    // so only user2 and user3 default.
+    setup.client.close_round();
+    let res = setup.client.try_request_refund(&user1);
+    assert_eq!(res.unwrap_err().unwrap(), Error::DeadlineNotPassed.into());
+    assert_eq!(setup.client.get_round_record(&0).defaulters.len(), 2);
+    assert_eq!(setup.client.get_debt(&user1), 0);
+    assert_eq!(setup.client.get_consecutive_defaults(&user1), 0);
+    assert_eq!(setup.client.get_debt(&user2), 100);
+}
+
+#[test]
+fn test_request_refund_rejects_non_base_token_contribution() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let env = &setup.env;
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    let other_token = env
+        .register_stellar_asset_contract_v2(setup.admin.clone())
+        .address();
+    TokenAdminClient::new(env, &other_token).mint(&user1, &1000);
+    setup.client.add_approved_token(&other_token);
+    setup.client.set_exchange_rate(&other_token, &5_000_000);
+
+    // 100 owed at 0.5 base per unit: 200 of `other_token` is taken.
+    setup.client.contribute(&user1, &other_token, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    assert_eq!(TokenClient::new(env, &other_token).balance(&user1), 800);
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    let res = setup.client.try_request_refund(&user1);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::RefundNotInBaseToken.into());
+    let (_, paid, _, _, _) = setup.client.get_state();
+    assert!(paid.contains(&user1));
+
+    // Base-token contributions are still refunded.
+    setup.client.request_refund(&user2);
+    assert_eq!(setup.token_client.balance(&user2), 1000);
+}
+
+#[test]
+fn test_close_round_below_min_participation_restarts_round() {
+    let setup = setup_with_members(4, 1000);
+    default_init(&setup);
+    setup.client.set_min_participation_bps(&5_000);
+
+    let user1 = setup.members.get(0).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+
+    // 1 of 4 paid (2500 bps): refunded, same round, fresh deadline.
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.close_round();
+    assert_eq!(setup.token_client.balance(&user1), 1000);
+    let (round, paid, deadline, _, _) = setup.client.get_state();
+    assert_eq!(round, 0);
+    assert_eq!(paid.len(), 0);
+    assert_eq!(deadline, 3601 + 3600);
+    assert_eq!(setup.client.get_debt(&setup.members.get(1).unwrap()), 0);
+}
+
+#[test]
+fn test_close_round_at_min_participation_proceeds() {
+    let setup = setup_with_members(4, 1000);
+    default_init(&setup);
+    setup.client.set_min_participation_bps(&5_000);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+
+    // 2 of 4 paid (5000 bps): the round closes as usual.
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.close_round();
+    let (round, _, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+    assert_eq!(setup.token_client.balance(&user1), 900);
+}
+
+#[test]
+fn test_get_timing_tracks_init_and_round_start() {
+    let setup = setup_with_members(2, 1000);
+    setup.env.ledger().with_mut(|li| li.timestamp = 100);
+    default_init(&setup);
+    assert_eq!(setup.client.get_timing(), (100, 100, 3700));
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 3800);
+    setup.client.close_round();
+    assert_eq!(setup.client.get_timing(), (100, 3800, 3800 + 3600));
+}
+
+#[test]
+fn test_contribute_rejects_underfunded_member() {
+    let setup = setup_with_members(2, 50);
+    default_init(&setup);
+
+    let user1 = setup.members.get(0).unwrap();
+    let res = setup
+        .client
+        .try_contribute(&user1, &setup.token_admin, &100);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::InsufficientBalance.into());
+    assert_eq!(setup.token_client.balance(&user1), 50);
+}
+
+#[test]
+fn test_init_stores_token_decimals() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    assert_eq!(
+        setup.client.get_token_decimals(),
+        setup.token_client.decimals()
+    );
+}
+
+#[test]
+fn test_payout_rounds_records_each_recipient() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    assert_eq!(setup.client.get_payout_rounds(&user1).len(), 0);
+
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+
+    assert_eq!(setup.client.get_payout_rounds(&user1), vec![&setup.env, 0u32]);
+    assert_eq!(setup.client.get_payout_rounds(&user2).len(), 0);
+}
+
+#[test]
+fn test_finite_cycle_rejects_second_payout_to_same_member() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.set_max_rounds(&3);
+    for _ in 0..2 {
+        for member in setup.members.iter() {
+            setup.client.contribute(&member, &setup.token_admin, &100);
+        }
+    }
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    let res = setup.client.try_contribute(&user2, &setup.token_admin, &100);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::PayoutAlreadyReceived.into());
+}
+
+#[test]
+fn test_skip_round_refunds_and_keeps_recipient() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let recipient = setup.client.current_recipient();
+
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    assert_eq!(setup.token_client.balance(&user2), 900);
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 500);
+    setup.client.skip_round();
+
+    assert_eq!(setup.token_client.balance(&user2), 1000);
+    let (round, paid, deadline, _, _) = setup.client.get_state();
+    assert_eq!(round, 0);
+    assert_eq!(paid.len(), 0);
+    assert!(deadline > 500);
+    assert_eq!(setup.client.current_recipient(), recipient);
+    assert_eq!(recipient, user1);
+}
+
+#[test]
+fn test_contribution_and_deposit_balances_are_separate() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.post_deposit(&user2, &250);
+
+    assert_eq!(setup.client.get_contribution_balance(), 100);
+    assert_eq!(setup.client.get_deposit_balance(), 250);
+    assert_eq!(setup.client.contract_balance(), 350);
+
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    assert_eq!(setup.client.get_contribution_balance(), 200);
+    assert_eq!(setup.client.get_deposit_balance(), 250);
+}
+
+#[test]
+fn test_dissolve_releases_held_payouts_and_splits_pools() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.configure_late_demotion(&setup.admin, &3, &3600);
+    setup.client.set_interest_rate_bps(&10_000);
+    setup.client.set_payout_delay(&3600);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3600 + 1728);
+    setup.client.contribute_late(&user2);
+    assert_eq!(setup.client.get_reserve(), 2);
+    assert_eq!(setup.client.get_pending_payouts().len(), 1);
+
+    // The held pot goes to user1; the reserve is split between both members.
+    setup.client.dissolve();
+    assert_eq!(setup.token_client.balance(&user1), 900 + 200 + 1);
+    assert_eq!(setup.token_client.balance(&user2), 898 + 1);
+    assert_eq!(setup.client.get_reserve(), 0);
+    assert_eq!(setup.client.get_pending_payouts().len(), 0);
+    assert_eq!(setup.token_client.balance(&setup.client.address), 0);
+}
+
+#[test]
+fn test_payout_delay_holds_pot_until_release() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    setup.client.set_payout_delay(&3600);
+    let user1 = setup.members.get(0).unwrap();
+
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+
+    // Round advanced but the pot stays in the contract.
+    let (round, _, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+    assert_eq!(setup.token_client.balance(&user1), 900);
+    assert_eq!(setup.client.get_pending_payouts().len(), 1);
+
+    let res = setup.client.try_release_payout();
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::PayoutStillHeld.into());
+
+    setup.env.ledger().with_mut(|li| li.timestamp += 3600);
+    setup.client.release_payout();
+    assert_eq!(setup.token_client.balance(&user1), 1200);
+    assert_eq!(setup.client.get_pending_payouts().len(), 0);
+
+    let res = setup.client.try_release_payout();
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::NoPendingPayout.into());
+}
+
+#[test]
+fn test_held_payout_not_paid_into_next_round() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.set_payout_delay(&3600);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert_eq!(setup.client.get_pending_payouts().len(), 2);
+
+    setup.env.ledger().with_mut(|li| li.timestamp += 3600);
+    setup.client.release_payout();
+    assert_eq!(setup.token_client.balance(&user1), 1000);
+    assert_eq!(setup.token_client.balance(&user2), 1000);
+}
+
+#[test]
+fn test_dispute_blocks_release_until_resolved() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    setup.client.set_payout_delay(&3600);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+
+    setup.client.raise_dispute(&user2);
+    assert_eq!(setup.client.get_dispute(), Some(user2.clone()));
+    setup.env.ledger().with_mut(|li| li.timestamp += 3600);
+    let res = setup.client.try_release_payout();
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::PayoutDisputed.into());
+
+    setup.client.resolve_dispute(&false);
+    assert_eq!(setup.client.get_dispute(), None);
+    setup.client.release_payout();
+    assert_eq!(setup.token_client.balance(&user1), 1200);
+}
+
+#[test]
+fn test_upheld_dispute_moves_pot_to_reserve() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    setup.client.set_payout_delay(&3600);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+
+    setup.client.raise_dispute(&user2);
+    setup.client.resolve_dispute(&true);
+
+    assert_eq!(setup.client.get_pending_payouts().len(), 0);
+    assert_eq!(setup.client.get_reserve(), 300);
+    assert_eq!(setup.token_client.balance(&user1), 900);
+
+    let res = setup.client.try_resolve_dispute(&true);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::NoOpenDispute.into());
+}
+
+#[test]
+fn test_expected_pot_counts_every_member() {
+    let setup = setup_with_members(4, 1000);
+    default_init(&setup);
+    assert_eq!(setup.client.expected_pot(), 400);
+
+    let user1 = setup.members.get(0).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    assert_eq!(setup.client.expected_pot(), 400);
+}
+
+#[test]
+fn test_expected_pot_net_of_fee() {
+    let setup = setup_with_members(4, 1000);
+    init_with_fee(&setup, 250);
+    assert_eq!(setup.client.expected_pot(), 390);
+}
+
+#[test]
+fn test_quorum_close_round_two_of_three_admins() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let co_admin1 = Address::generate(&setup.env);
+    let co_admin2 = Address::generate(&setup.env);
+    setup
+        .client
+        .init_multisig(&vec![&setup.env, co_admin1.clone(), co_admin2.clone()], &2);
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 10_000);
+    let action_id = setup.client.propose_action(&setup.admin, &3, &None, &None);
+    let (round, _, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 0);
+    assert!(!setup.client.get_admin_proposal(&action_id).unwrap().executed);
+
+    setup.client.approve_action(&co_admin1, &action_id);
+    let (round, _, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+    assert!(setup.client.get_admin_proposal(&action_id).unwrap().executed);
+
+    let res = setup.client.try_approve_action(&co_admin2, &action_id);
+    assert_eq!(res.unwrap_err().unwrap(), Error::AdminActionAlreadyExecuted.into());
+
+    let outsider = Address::generate(&setup.env);
+    let res = setup.client.try_propose_action(&outsider, &3, &None, &None);
+    assert_eq!(res.unwrap_err().unwrap(), Error::NotACoAdmin.into());
+}
+
+#[test]
+fn test_quorum_rejects_lone_admin_close_dissolve_and_upgrade() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let co_admin = Address::generate(&setup.env);
+    setup
+        .client
+        .init_multisig(&vec![&setup.env, co_admin.clone()], &2);
+    // Long enough for a temporary entry to expire; the quorum must outlive it.
+    setup.env.ledger().with_mut(|li| {
+        li.timestamp = 10_000;
+        li.sequence_number += 20_000;
+    });
+    assert_eq!(setup.client.get_multisig_config().1, 2);
+
+    let res = setup.client.try_close_round();
+    assert_eq!(res.unwrap_err().unwrap(), Error::InsufficientApprovals.into());
+    let res = setup.client.try_dissolve();
+    assert_eq!(res.unwrap_err().unwrap(), Error::InsufficientApprovals.into());
+    let hash = BytesN::from_array(&setup.env, &[0u8; 32]);
+    let res = setup.client.try_dissolve_group(&setup.admin, &hash);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InsufficientApprovals.into());
+    let res = setup.client.try_upgrade(&setup.admin, &hash);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InsufficientApprovals.into());
+    let (round, _, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 0);
+
+    // The upgrade is held as a proposal until the co-admin approves it.
+    let action_id = setup.client.propose_upgrade(&setup.admin, &hash);
+    let proposal = setup.client.get_admin_proposal(&action_id).unwrap();
+    assert_eq!(proposal.wasm_hash, Some(hash));
+    assert!(!proposal.executed);
+}
+
+#[test]
+fn test_round_at_timestamp_maps_schedule() {
+    let setup = setup_with_members(3, 1000);
+    setup.env.ledger().with_mut(|li| li.timestamp = 1_000);
+    default_init(&setup);
+    setup.client.set_max_rounds(&0);
+
+    assert_eq!(setup.client.round_at_timestamp(&0), 0);
+    assert_eq!(setup.client.round_at_timestamp(&1_000), 0);
+    assert_eq!(setup.client.round_at_timestamp(&4_599), 0);
+    assert_eq!(setup.client.round_at_timestamp(&4_600), 1);
+    assert_eq!(setup.client.round_at_timestamp(&(1_000 + 3600 * 5 + 1)), 5);
+
+    setup.client.set_max_rounds(&3);
+    assert_eq!(setup.client.round_at_timestamp(&(1_000 + 3600 * 5 + 1)), 2);
+}
+
+#[test]
+fn test_distribute_reserve_splits_equally() {
+    let setup = setup_with_members(3, 1000);
+    init_with_fee(&setup, 500);
+    setup.client.set_fee_to_reserve(&true);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert_eq!(setup.client.get_reserve(), 15);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+
+    setup.client.distribute_reserve();
+
+    assert_eq!(setup.client.get_reserve(), 0);
+    assert_eq!(setup.token_client.balance(&user1), 900 + 285 + 5);
+    assert_eq!(setup.token_client.balance(&user2), 800 + 5);
+    assert_eq!(setup.token_client.balance(&user3), 900 + 5);
+    // The open round's contribution is untouched.
+    assert_eq!(setup.client.get_contribution_balance(), 100);
+
+    let res = setup.client.try_distribute_reserve();
+    assert_eq!(res.unwrap_err().unwrap(), ExtError::NoFundsToDistribute.into());
+}
+
+#[test]
+fn test_reorder_remaining_swaps_future_slots() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    // user1 paid 100 and received the 300 pot for round 0.
+    assert_eq!(setup.token_client.balance(&user1), 1200);
+
+    // Slot 0 is done; moving it is rejected.
+    let res = setup.client.try_reorder_remaining(&vec![&setup.env, 0u32, 2u32]);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::InvalidSlotIndex.into());
+
+    setup.client.reorder_remaining(&vec![&setup.env, 2u32, 1u32]);
+    assert_eq!(
+        setup.client.get_payout_order(),
+        vec![&setup.env, user1.clone(), user3.clone(), user2.clone()]
+    );
+    assert_eq!(setup.client.current_recipient(), user3);
+
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert_eq!(setup.token_client.balance(&user3), 1100);
+    assert_eq!(setup.client.current_recipient(), user2);
+}
+
+#[test]
+fn test_check_invariants_healthy_mid_round() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.post_deposit(&user2, &50);
+
+    let report = setup.client.check_invariants();
+    assert!(report.balance_covers_accounted);
+    assert!(report.paid_members_are_members);
+    assert!(report.round_within_max);
+}
+
+#[test]
+fn test_check_invariants_healthy_after_finite_cycle() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.set_max_rounds(&2);
+    for _ in 0..2 {
+        for member in setup.members.iter() {
+            setup.client.contribute(&member, &setup.token_admin, &100);
+        }
+    }
+    assert!(setup.client.is_complete());
+    assert_eq!(
+        setup.client.check_invariants(),
+        InvariantReport {
+            balance_covers_accounted: true,
+            paid_members_are_members: true,
+            round_within_max: true,
+        }
+    );
+}
+
+#[test]
+fn test_start_new_cycle_after_completion() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.set_max_rounds(&2);
+    let user1 = setup.members.get(0).unwrap();
+
+    let res = setup.client.try_start_new_cycle();
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::CycleNotComplete.into());
+
+    for _ in 0..2 {
+        for member in setup.members.iter() {
+            setup.client.contribute(&member, &setup.token_admin, &100);
+        }
+    }
+    assert!(setup.client.is_complete());
+    assert_eq!(setup.client.get_cycle_number(), 0);
+
+    setup.client.start_new_cycle();
+    assert!(!setup.client.is_complete());
+    assert_eq!(setup.client.get_cycle_number(), 1);
+    let (round, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 0);
+    assert_eq!(paid.len(), 0);
+    assert_eq!(setup.client.get_payout_rounds(&user1).len(), 0);
+
+    // The new cycle pays out again, starting with the first member.
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert_eq!(setup.token_client.balance(&user1), 1100);
+    assert_eq!(setup.client.get_payout_rounds(&user1), vec![&setup.env, 0u32]);
+
+    // An early claim in one cycle does not block the same round of the next.
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    assert_eq!(setup.client.claim_early(&user2), 100);
+    setup.client.start_new_cycle();
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    assert_eq!(setup.client.claim_early(&user2), 100);
+    assert_eq!(setup.client.get_cycle_number(), 2);
+}
+
+#[test]
+fn test_contribute_returns_round_progress() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    let result = setup.client.contribute(&user1, &setup.token_admin, &100);
+    assert_eq!(
+        result,
+        ContributeResult { paid_count: 1, total_members: 3, triggered_payout: false }
+    );
+    let result = setup.client.contribute(&user2, &setup.token_admin, &100);
+    assert_eq!(result.paid_count, 2);
+    assert!(!result.triggered_payout);
+
+    let result = setup.client.contribute(&user3, &setup.token_admin, &100);
+    assert_eq!(result.paid_count, 3);
+    assert!(result.triggered_payout);
+}
+
+#[test]
+fn test_fee_split_remainder_goes_to_recipient() {
+    let setup = setup_with_members(3, 1000);
+    // 300 * 333 / 10_000 = 9.99: the fee rounds down to 9.
+    init_with_fee(&setup, 333);
+    let user1 = setup.members.get(0).unwrap();
+    assert_eq!(setup.client.expected_pot(), 291);
+
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+
+    let recipient_share = setup.token_client.balance(&user1) - 900;
+    let fee = setup.token_client.balance(&setup.admin);
+    assert_eq!(recipient_share, 291);
+    assert_eq!(fee, 9);
+    assert_eq!(recipient_share + fee, 300);
+    assert_eq!(setup.client.contract_balance(), 0);
+}
+
+#[test]
+fn test_upcoming_deadlines_projects_duration() {
+    let setup = setup_with_members(3, 1000);
+    setup.env.ledger().with_mut(|li| li.timestamp = 1_000);
+    default_init(&setup);
+
+    assert_eq!(
+        setup.client.upcoming_deadlines(&3),
+        vec![&setup.env, 4_600u64, 8_200u64, 11_800u64]
+    );
+    assert_eq!(setup.client.upcoming_deadlines(&0).len(), 0);
+
+    setup.client.set_max_rounds(&2);
+    assert_eq!(setup.client.upcoming_deadlines(&5).len(), 2);
+}
+
+#[test]
+fn test_member_vote_changes_contribution_amount() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let description = soroban_sdk::String::from_str(&setup.env, "Raise to 150");
+    setup.client.create_proposal(
+        &user1,
+        &ProposalType::ContributionAmountChange,
+        &description,
+        &user1,
+        &600,
+        &Some(150),
+    );
+    for member in setup.members.iter() {
+        setup.client.vote_on_proposal(&member, &0, &true);
+    }
+    setup.env.ledger().with_mut(|li| li.timestamp = 601);
+    setup.client.execute_proposal(&0);
+
+    assert_eq!(setup.client.get_proposal(&0).unwrap().status, ProposalStatus::Executed);
+    assert_eq!(setup.client.expected_pot(), 450);
+}
+
+#[test]
+fn test_member_vote_to_add_member_fails_quorum() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let newcomer = Address::generate(&setup.env);
+    let description = soroban_sdk::String::from_str(&setup.env, "Add newcomer");
+    setup.client.create_proposal(
+        &user1,
+        &ProposalType::MemberAddition,
+        &description,
+        &newcomer,
+        &600,
+        &None,
+    );
+    setup.client.vote_on_proposal(&user1, &0, &true);
+    setup.env.ledger().with_mut(|li| li.timestamp = 601);
+    setup.client.execute_proposal(&0);
+
+    assert_eq!(setup.client.get_proposal(&0).unwrap().status, ProposalStatus::Rejected);
+    assert!(!setup.client.is_member(&newcomer));
+}
+
+#[test]
+fn test_member_vote_adds_member() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let newcomer = Address::generate(&setup.env);
+    let description = soroban_sdk::String::from_str(&setup.env, "Add newcomer");
+    setup.client.create_proposal(
+        &user1,
+        &ProposalType::MemberAddition,
+        &description,
+        &newcomer,
+        &600,
+        &None,
+    );
+    setup.client.vote_on_proposal(&user1, &0, &true);
+    setup.client.vote_on_proposal(&user2, &0, &true);
+    setup.env.ledger().with_mut(|li| li.timestamp = 601);
+    setup.client.execute_proposal(&0);
+
+    assert!(setup.client.is_member(&newcomer));
+}
+
+#[test]
+fn test_get_round_defaulters_per_round() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    // Round 0: user3 misses.
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.close_round();
+
+    // Round 1: user1 and user2 miss.
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601 * 2);
+    setup.client.close_round();
+
+    // Round 2: everyone pays.
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+
+    assert_eq!(setup.client.get_round_defaulters(&0), vec![&setup.env, user3.clone()]);
+    assert_eq!(
+        setup.client.get_round_defaulters(&1),
+        vec![&setup.env, user1.clone(), user2.clone()]
+    );
+    assert_eq!(setup.client.get_round_defaulters(&2).len(), 0);
+
+    let res = setup.client.try_get_round_defaulters(&3);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::RoundNotFound.into());
+}
+
+#[test]
+fn test_init_native_uses_native_sac() {
+    let setup = setup_with_members(3, 0);
+    let native = setup
+        .env
+        .deployer()
+        .with_stellar_asset(soroban_sdk::Bytes::from_array(&setup.env, &[0u8; 4]))
+        .deploy();
+    assert_eq!(setup.client.native_asset_address(), native);
+
+    setup
+        .client
+        .init_native(&setup.admin, &setup.members, &100, &3600);
+    let (_, _, _, _, token) = setup.client.get_state();
+    assert_eq!(token, native);
+    assert_eq!(setup.client.get_token_decimals(), 7);
+
+    // Contributions go through the native SAC; unfunded members are rejected
+    // by the balance check rather than a failed transfer.
+    let user1 = setup.members.get(0).unwrap();
+    let res = setup.client.try_contribute(&user1, &native, &100);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::InsufficientBalance.into());
+}
+
+#[test]
+fn test_failed_payouts_trip_circuit_breaker() {
+    // A revocable asset, so the issuer can freeze balances mid-cycle.
+    let mut setup = setup_with_members(3, 0);
+    let sac = setup.env.register_stellar_asset_contract_v2(setup.admin.clone());
+    sac.issuer().set_flag(soroban_sdk::testutils::IssuerFlags::RevocableFlag);
+    setup.token_admin = sac.address();
+    setup.token_client = TokenClient::new(&setup.env, &setup.token_admin);
+    setup.token_admin_client = TokenAdminClient::new(&setup.env, &setup.token_admin);
+    for member in setup.members.iter() {
+        setup.token_admin_client.mint(&member, &1000);
+    }
+    default_init(&setup);
+    setup.client.set_payout_failure_threshold(&2);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    // Round 0: the token freezes user1's balance, so the payout reverts and
+    // is held instead.
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.token_admin_client.set_authorized(&user1, &false);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+    assert_eq!(setup.client.get_failed_payouts(), 1);
+    assert!(!setup.client.is_paused());
+    let (round, _, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+
+    // Round 1: user2 is frozen too; the second failure pauses the group.
+    setup.token_admin_client.set_authorized(&user1, &true);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.token_admin_client.set_authorized(&user2, &false);
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+    assert_eq!(setup.client.get_failed_payouts(), 2);
+    assert!(setup.client.is_paused());
+    assert_eq!(setup.client.get_pending_payouts().len(), 2);
+
+    // Once the token recovers, the held payouts can be released.
+    setup.token_admin_client.set_authorized(&user2, &true);
+    setup.client.resume_group(&soroban_sdk::String::from_str(&setup.env, "token fixed"));
+    setup.client.release_payout();
+    assert_eq!(setup.token_client.balance(&user1), 800 + 300);
+    assert_eq!(setup.token_client.balance(&user2), 800 + 300);
+}
+
+#[test]
+fn test_member_metadata_round_trip_and_size_cap() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+
+    assert_eq!(setup.client.get_member_metadata(&user1).len(), 0);
+
+    let handle = soroban_sdk::Bytes::from_array(&setup.env, &[7u8; 32]);
+    setup.client.set_member_metadata(&user1, &handle);
+    assert_eq!(setup.client.get_member_metadata(&user1), handle);
+
+    let oversized = soroban_sdk::Bytes::from_array(&setup.env, &[1u8; 257]);
+    let res = setup.client.try_set_member_metadata(&user1, &oversized);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::MetadataTooLarge.into());
+
+    let outsider = Address::generate(&setup.env);
+    let res = setup.client.try_set_member_metadata(&outsider, &handle);
+    assert_eq!(res.unwrap_err().unwrap(), Error::NotAMember.into());
+}
+
+#[test]
+fn test_transfer_position_moves_all_member_state() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    // Round 0 pays user1.
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    setup.client.post_deposit(&user1, &50);
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+
+    let buyer = Address::generate(&setup.env);
+    setup.client.transfer_position(&user1, &buyer);
+
+    assert!(!setup.client.is_member(&user1));
+    assert!(setup.client.is_member(&buyer));
+    assert_eq!(setup.client.get_payout_order().get(0).unwrap(), buyer);
+    let (_, paid, _, _, _) = setup.client.get_state();
+    assert!(paid.contains(&buyer));
+    assert!(!paid.contains(&user1));
+    assert_eq!(setup.client.get_deposit(&buyer), 50);
+    assert_eq!(setup.client.get_deposit(&user1), 0);
+    assert_eq!(setup.client.get_payout_rounds(&buyer), vec![&setup.env, 0u32]);
+    assert_eq!(setup.client.get_payout_rounds(&user1).len(), 0);
+
+    // Existing members cannot be the destination.
+    let res = setup.client.try_transfer_position(&user2, &user3);
+    assert_eq!(res.unwrap_err().unwrap(), Error::AlreadyAMember.into());
+}
+
+#[test]
+fn test_weighted_payout_splits_pot_by_weight() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    // Every member needs a positive weight.
+    let mut partial: Map<Address, u32> = Map::new(&setup.env);
+    partial.set(user1.clone(), 2);
+    let res = setup.client.try_set_payout_weights(&partial);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::InvalidPayoutWeights.into());
+
+    let mut weights: Map<Address, u32> = Map::new(&setup.env);
+    weights.set(user1.clone(), 2);
+    weights.set(user2.clone(), 1);
+    setup.client.set_payout_weights(&weights);
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+
+    // Pot of 200 split 2:1 is 133 / 66; the dust goes to the scheduled
+    // recipient, user1.
+    assert_eq!(setup.token_client.balance(&user1), 900 + 134);
+    assert_eq!(setup.token_client.balance(&user2), 900 + 66);
+    assert_eq!(setup.token_client.balance(&setup.client.address), 0);
+}
+
+#[test]
+fn test_weighted_payout_respects_payout_delay() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.set_payout_delay(&3600);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    let mut weights: Map<Address, u32> = Map::new(&setup.env);
+    weights.set(user1.clone(), 2);
+    weights.set(user2.clone(), 1);
+    setup.client.set_payout_weights(&weights);
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+
+    // Both weighted shares are held rather than sent.
+    assert_eq!(setup.token_client.balance(&user1), 900);
+    assert_eq!(setup.token_client.balance(&user2), 900);
+    assert_eq!(setup.client.get_pending_payouts().len(), 2);
+
+    setup.env.ledger().with_mut(|li| li.timestamp += 3600);
+    setup.client.release_payout();
+    assert_eq!(setup.token_client.balance(&user1), 900 + 134);
+    assert_eq!(setup.token_client.balance(&user2), 900 + 66);
+}
+
+#[test]
+fn test_get_stats_after_a_round() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    // user3 misses round 0; finalizing still pays the pot of 200 to user1.
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.finalize_round();
+
+    let stats = setup.client.get_stats();
+    assert_eq!(stats.total_members, 3);
+    assert_eq!(stats.current_round, 1);
+    assert_eq!(stats.total_distributed, 200);
+    assert_eq!(stats.reserve_balance, 0);
+    assert_eq!(stats.total_defaults, 1);
+    assert_eq!(stats.completion_pct, 33);
+}
+
+#[test]
+fn test_finalize_cycle_distributes_leftovers_equally() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.set_max_rounds(&2);
+    setup.client.set_end_of_cycle_policy(&EndOfCyclePolicy::DistributeEqually);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.post_deposit(&user2, &50);
+
+    let res = setup.client.try_finalize_cycle();
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::CycleNotComplete.into());
+
+    for _ in 0..2 {
+        for member in setup.members.iter() {
+            setup.client.contribute(&member, &setup.token_admin, &100);
+        }
+    }
+    assert!(setup.client.is_complete());
+
+    // Untracked surplus left in the contract after the last payout.
+    setup.token_admin_client.mint(&setup.client.address, &31);
+    assert_eq!(setup.client.finalize_cycle(), 31);
+
+    // The odd unit goes to the first member; the deposit was already
+    // refunded when the cycle finished, so it is not shared out.
+    assert_eq!(setup.token_client.balance(&user1), 1000 + 16);
+    assert_eq!(setup.token_client.balance(&user2), 1000 + 15);
+    assert_eq!(setup.client.get_deposit(&user2), 0);
+    assert_eq!(setup.token_client.balance(&setup.client.address), 0);
+}
+
+#[test]
+fn test_finalize_cycle_send_to_admin_and_rollover() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.set_max_rounds(&2);
+    for _ in 0..2 {
+        for member in setup.members.iter() {
+            setup.client.contribute(&member, &setup.token_admin, &100);
+        }
+    }
+
+    // Rollover (the default) keeps leftovers as reserve for the next cycle.
+    setup.token_admin_client.mint(&setup.client.address, &20);
+    assert_eq!(
+        setup.client.get_end_of_cycle_policy(),
+        EndOfCyclePolicy::RolloverToNextCycle
+    );
+    assert_eq!(setup.client.finalize_cycle(), 20);
+    assert_eq!(setup.client.get_reserve(), 20);
+    assert_eq!(setup.token_client.balance(&setup.client.address), 20);
+
+    setup.client.set_end_of_cycle_policy(&EndOfCyclePolicy::SendToAdmin);
+    let admin_before = setup.token_client.balance(&setup.admin);
+    assert_eq!(setup.client.finalize_cycle(), 20);
+    assert_eq!(setup.token_client.balance(&setup.admin), admin_before + 20);
+    assert_eq!(setup.client.get_reserve(), 0);
+    assert_eq!(setup.token_client.balance(&setup.client.address), 0);
+}
+
+/// Rounds carried by `opened` events from the last invocation.
+fn opened_rounds(setup: &TestSetup<'_>) -> alloc::vec::Vec<u32> {
+    let opened = Symbol::new(&setup.env, "opened");
+    setup
+        .env
+        .events()
+        .all()
+        .iter()
+        .filter(|ev| {
+            let name: Symbol = ev.1.get(0).unwrap().into_val(&setup.env);
+            name == opened
+        })
+        .map(|ev| ev.1.get(2).unwrap().into_val(&setup.env))
+        .collect()
+}
+
+#[test]
+fn test_opened_event_fires_once_per_round() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    assert_eq!(opened_rounds(&setup), [0u32]);
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    assert!(opened_rounds(&setup).is_empty());
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+    assert!(opened_rounds(&setup).is_empty());
+
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+    assert_eq!(opened_rounds(&setup), [1u32]);
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    assert!(opened_rounds(&setup).is_empty());
+}
+
+#[test]
+fn test_is_admin() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+
+    assert!(setup.client.is_admin(&setup.admin));
+    assert!(!setup.client.is_admin(&user1));
+
+    let co_admin = Address::generate(&setup.env);
+    setup.client.init_multisig(&vec![&setup.env, co_admin.clone()], &2);
+    assert!(setup.client.is_admin(&co_admin));
+}
+
+#[test]
+fn test_validate_config_reports_every_problem() {
+    let setup = setup_with_members(2, 0);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    let ok = setup.client.validate_config(&setup.members, &100, &3600);
+    assert!(ok.is_empty());
+
+    let members = vec![&setup.env, user1.clone(), user2.clone(), user1.clone()];
+    let problems = setup.client.validate_config(&members, &0, &3600);
+    assert_eq!(problems.len(), 2);
+    assert!(problems.contains(Symbol::new(&setup.env, "dup_member")));
+    assert!(problems.contains(Symbol::new(&setup.env, "bad_amount")));
+    assert!(!setup.client.is_initialized());
+
+    // `init` rejects what `validate_config` reports as `dup_member`.
+    let res = setup.client.try_init(
+        &setup.admin,
+        &members,
+        &100,
+        &setup.token_admin,
+        &3600,
+        &default_config(),
+        &None,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), Error::AlreadyAMember.into());
+    assert!(!setup.client.is_initialized());
+}
+
+#[test]
+fn test_prepay_covers_upcoming_rounds() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    setup.client.prepay(&user1, &2);
+    assert_eq!(setup.client.get_prepaid(&user1), 200);
+
+    // Round 0: the escrow is not part of the pot.
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert_eq!(setup.token_client.balance(&user1), 1000 - 200 - 100 + 300);
+
+    // Rounds 1 and 2 open with user1 already paid from the escrow.
+    let (round, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+    assert_eq!(paid, vec![&setup.env, user1.clone()]);
+    assert_eq!(setup.client.get_prepaid(&user1), 100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+    assert_eq!(setup.token_client.balance(&user2), 1000 - 200 + 300);
+
+    let (round, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 2);
+    assert_eq!(paid, vec![&setup.env, user1.clone()]);
+    assert_eq!(setup.client.get_prepaid(&user1), 0);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+    assert_eq!(setup.token_client.balance(&user3), 1000 - 300 + 300);
+    assert_eq!(setup.token_client.balance(&user1), 1000);
+    assert_eq!(setup.token_client.balance(&setup.client.address), 0);
+}
+
+#[test]
+fn test_round_cannot_be_settled_twice() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user3 = setup.members.get(2).unwrap();
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+
+    // Wind the round counter back to the round that already paid out.
+    setup.env.as_contract(&setup.client.address, || {
+        setup.env.storage().instance().set(&DataKey::CurrentRound, &0u32);
+    });
+    setup.client.contribute(&setup.members.get(0).unwrap(), &setup.token_admin, &100);
+    setup.client.contribute(&setup.members.get(1).unwrap(), &setup.token_admin, &100);
+    let res = setup.client.try_contribute(&user3, &setup.token_admin, &100);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::RoundAlreadySettled.into());
+
+    // The rejected call left nothing behind: user3 is still unpaid.
+    let (round, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 0);
+    assert_eq!(paid.len(), 2);
+    assert_eq!(setup.token_client.balance(&user3), 900);
+}
+
+#[test]
+fn test_version_matches_constant() {
+    let setup = setup_with_members(2, 0);
+    assert_eq!(setup.client.version(), VERSION);
+    assert_eq!(VERSION, (0, 1, 0));
+}
+
+#[test]
+fn test_contribute_with_memo_publishes_memo() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+
+    let memo = soroban_sdk::Bytes::from_slice(&setup.env, b"INV-2026-0042");
+    let result = setup
+        .client
+        .contribute_with_memo(&user1, &setup.token_admin, &100, &memo);
+    assert_eq!(result.paid_count, 1);
+
+    let memo_event = Symbol::new(&setup.env, "contribution_memo");
+    let event = setup
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|ev| {
+            let name: Symbol = ev.1.get(0).unwrap().into_val(&setup.env);
+            name == memo_event
+        })
+        .expect("contribution_memo event not emitted");
+    let contributor: Address = event.1.get(2).unwrap().into_val(&setup.env);
+    assert_eq!(contributor, user1);
+    let data: soroban_sdk::Map<Symbol, soroban_sdk::Val> = event.2.into_val(&setup.env);
+    let published: soroban_sdk::Bytes = data
+        .get(Symbol::new(&setup.env, "memo"))
+        .unwrap()
+        .into_val(&setup.env);
+    assert_eq!(published, memo);
+
+    let long_memo = soroban_sdk::Bytes::from_array(&setup.env, &[b'x'; 65]);
+    let user2 = setup.members.get(1).unwrap();
+    let res = setup
+        .client
+        .try_contribute_with_memo(&user2, &setup.token_admin, &100, &long_memo);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::MemoTooLong.into());
+}
+
+#[test]
+fn test_contribution_memo_reports_accepted_amount() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    setup.client.set_overpay_policy(&OverpayPolicy::Refund);
+    let user1 = setup.members.get(0).unwrap();
+
+    let memo = soroban_sdk::Bytes::from_slice(&setup.env, b"INV-2026-0043");
+    setup
+        .client
+        .contribute_with_memo(&user1, &setup.token_admin, &150, &memo);
+
+    let memo_event = Symbol::new(&setup.env, "contribution_memo");
+    let event = setup
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|ev| {
+            let name: Symbol = ev.1.get(0).unwrap().into_val(&setup.env);
+            name == memo_event
+        })
+        .expect("contribution_memo event not emitted");
+    let data: soroban_sdk::Map<Symbol, soroban_sdk::Val> = event.2.into_val(&setup.env);
+    let amount: i128 = data
+        .get(Symbol::new(&setup.env, "amount"))
+        .unwrap()
+        .into_val(&setup.env);
+    assert_eq!(amount, 100);
+    assert_eq!(setup.token_client.balance(&user1), 900);
+}
+
+#[test]
+fn test_at_risk_sums_tracked_balances() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    assert_eq!(setup.client.at_risk(&user1), 0);
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.post_deposit(&user1, &40);
+    setup.client.prepay(&user1, &1);
+    assert_eq!(setup.client.at_risk(&user1), 100 + 40 + 100);
+}
+
+#[test]
+fn test_rolling_schedule_drifts_after_late_close() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    assert!(!setup.client.is_fixed_schedule());
+
+    // Round 0 is due at 3600 but closed at 5000; round 1 gets a full
+    // duration from the close.
+    setup.env.ledger().with_mut(|li| li.timestamp = 5000);
+    setup.client.close_round();
+    let (_, _, deadline) = setup.client.get_timing();
+    assert_eq!(deadline, 5000 + 3600);
+}
+
+#[test]
+fn test_fixed_schedule_keeps_calendar_after_late_close() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.set_fixed_schedule(&true);
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 5000);
+    setup.client.close_round();
+    let (_, _, deadline) = setup.client.get_timing();
+    assert_eq!(deadline, 2 * 3600);
+
+    // Round 1 closes on time; round 2 still lands on the calendar.
+    setup.env.ledger().with_mut(|li| li.timestamp = 2 * 3600 + 1);
+    setup.client.close_round();
+    let (_, _, deadline) = setup.client.get_timing();
+    assert_eq!(deadline, 3 * 3600);
+}
+
+#[test]
+fn test_operator_can_run_upkeep_but_not_admin_actions() {
+    use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
+
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let operator = Address::generate(&setup.env);
+
+    let res = setup.client.try_operator_close_round(&operator);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::NotAnOperator.into());
+
+    setup.client.add_operator(&operator);
+    assert_eq!(setup.client.get_operators(), vec![&setup.env, operator.clone()]);
+
+    // Round 0 expires: the operator closes it, then pokes round 1.
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.operator_close_round(&operator);
+    assert_eq!(setup.client.get_group_info().current_round, 1);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601 * 2 + 1);
+    setup.client.poke(&operator);
+    assert_eq!(setup.client.get_group_info().current_round, 2);
+
+    let wasm_hash = setup.env.deployer().upload_contract_wasm(UPGRADE_WASM);
+    let res = setup.client.try_upgrade(&operator, &wasm_hash);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError::OnlyAdminAllowed.into());
+
+    // Only the operator signs: dissolve still needs the admin.
+    setup.env.mock_auths(&[MockAuth {
+        address: &operator,
+        invoke: &MockAuthInvoke {
+            contract: &setup.client.address,
+            fn_name: "dissolve",
+            args: ().into_val(&setup.env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(setup.client.try_dissolve().is_err());
+}
+
+#[test]
+fn test_operator_close_round_requires_quorum() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let operator = Address::generate(&setup.env);
+    setup.client.add_operator(&operator);
+    let co_admin = Address::generate(&setup.env);
+    setup
+        .client
+        .init_multisig(&vec![&setup.env, co_admin.clone()], &2);
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    let res = setup.client.try_operator_close_round(&operator);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InsufficientApprovals.into());
+    assert_eq!(setup.client.get_group_info().current_round, 0);
+}
+
+#[test]
+fn test_simulate_cycle_projects_remaining_rounds() {
+    let setup = setup_with_members(3, 1000);
+    init_with_fee(&setup, 100);
+    setup.client.set_max_rounds(&3);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    // 300 gross, less a 1% fee.
+    assert_eq!(
+        setup.client.simulate_cycle(),
+        vec![
+            &setup.env,
+            (0u32, user1.clone(), 297i128),
+            (1u32, user2.clone(), 297i128),
+            (2u32, user3.clone(), 297i128),
+        ]
+    );
+
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    let schedule = setup.client.simulate_cycle();
+    assert_eq!(schedule.len(), 2);
+    assert_eq!(schedule.get(0).unwrap(), (1u32, user2, 297i128));
+}
+
+#[test]
+fn test_paid_members_resolve_to_canonical_order() {
+    let setup = setup_with_members(4, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+
+    let (_, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(paid, vec![&setup.env, user1, user2, user3]);
+}
+
+#[test]
+fn test_streak_reward_scales_with_on_time_rounds() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    setup.client.set_streak_reward(&10);
+    setup.token_admin_client.mint(&setup.admin, &200);
+    setup.client.deposit_rewards(&setup.admin, &200);
+    let user1 = setup.members.get(0).unwrap();
+
+    for _ in 0..2 {
+        for member in setup.members.iter() {
+            setup.client.contribute(&member, &setup.token_admin, &100);
+        }
+    }
+    assert_eq!(setup.client.get_on_time_streak(&user1), 2);
+
+    let before = setup.token_client.balance(&user1);
+    assert_eq!(setup.client.claim_streak_reward(&user1), 20);
+    assert_eq!(setup.token_client.balance(&user1), before + 20);
+
+    let res = setup.client.try_claim_streak_reward(&user1);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::StreakRewardAlreadyClaimed.into());
+}
+
+#[test]
+fn test_pot_remaining_counts_partial_payments() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    assert_eq!(setup.client.pot_remaining(), 300);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &40);
+
+    assert_eq!(setup.client.pot_remaining(), 160);
+}
+
+#[test]
+fn test_remove_member_keeps_upcoming_recipients() {
+    let setup = setup_with_members(4, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert_eq!(setup.client.current_recipient(), user2);
+
+    // The current recipient cannot be removed.
+    let res = setup.client.try_remove_member(&user2);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::RemovesScheduledRecipient.into());
+
+    // Removing user1 (already paid) would shift every turn with plain filtering.
+    setup.client.remove_member(&user1);
+    assert_eq!(setup.client.current_recipient(), user2);
+
+    for member in setup.client.get_group_info().members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert_eq!(setup.client.current_recipient(), user3);
+}
+
+#[test]
+fn test_storage_ttl_reports_floor_after_init() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    let ttl = setup.client.storage_ttl();
+    assert!(ttl.instance > 0);
+    assert!(ttl.persistent > 0);
+
+    setup.env.ledger().with_mut(|li| li.sequence_number += 1_000);
+    assert_eq!(setup.client.storage_ttl().instance, ttl.instance - 1_000);
+}
+
+#[test]
+fn test_contribution_window_limits_when_members_can_pay() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    setup.client.set_contribution_window(&Some((1800u64, 3000u64)));
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    let res = setup.client.try_contribute(&user1, &setup.token_admin, &100);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::ContributionWindowNotOpen.into());
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 1800);
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 3000);
+    let res = setup.client.try_contribute(&user2, &setup.token_admin, &100);
+    assert_eq!(res.unwrap_err().unwrap(), Error::ContributionWindowClosed.into());
+}
+
+#[test]
+fn test_rescue_token_recovers_foreign_token() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+
+    let stray = setup
+        .env
+        .register_stellar_asset_contract_v2(setup.admin.clone())
+        .address();
+    TokenAdminClient::new(&setup.env, &stray).mint(&setup.client.address, &500);
+    let treasury = Address::generate(&setup.env);
+
+    setup.client.rescue_token(&stray, &treasury, &500);
+    let stray_client = TokenClient::new(&setup.env, &stray);
+    assert_eq!(stray_client.balance(&treasury), 500);
+    assert_eq!(stray_client.balance(&setup.client.address), 0);
+}
+
+#[test]
+fn test_rescue_token_protects_contribution_token() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+
+    let res = setup
+        .client
+        .try_rescue_token(&setup.token_admin, &setup.admin, &100);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::CannotRescueContributionToken.into());
+    assert_eq!(setup.token_client.balance(&setup.client.address), 100);
+}
+
+#[test]
+fn test_contribution_bounds_reject_out_of_band_amounts() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    let res = setup.client.try_set_contribution_bounds(&70, &30);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::InvalidContributionBounds.into());
+    setup.client.set_contribution_bounds(&30, &60);
+    let user1 = setup.members.get(0).unwrap();
+
+    let res = setup.client.try_contribute(&user1, &setup.token_admin, &20);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::ContributionBelowMinimum.into());
+    let res = setup.client.try_contribute(&user1, &setup.token_admin, &80);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::ContributionAboveMaximum.into());
+
+    setup.client.contribute(&user1, &setup.token_admin, &45);
+    assert_eq!(setup.client.pot_remaining(), 155);
+}
+
+fn emitted_event_names(env: &Env) -> Vec<Symbol> {
+    let mut names = Vec::new(env);
+    for ev in env.events().all().iter() {
+        names.push_back(ev.1.get(0).unwrap().into_val(env));
+    }
+    names
+}
+
+fn assert_events_in_order(env: &Env, expected: &[&str]) {
+    let names = emitted_event_names(env);
+    let mut from = 0;
+    for name in expected {
+        let symbol = Symbol::new(env, name);
+        let found = (from..names.len()).find(|&i| names.get(i).unwrap() == symbol);
+        assert!(found.is_some(), "{} not emitted in order", name);
+        from = found.unwrap() + 1;
+    }
+}
+
+#[test]
+fn test_lifecycle_is_replayable_from_events() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    assert_events_in_order(&setup.env, &["rosca_initialized", "group_configured"]);
+
+    let newcomer = Address::generate(&setup.env);
+    setup.client.add_member(&newcomer);
+    assert_events_in_order(&setup.env, &["member_added"]);
+    setup.client.remove_member(&newcomer);
+    assert_events_in_order(&setup.env, &["member_removed"]);
+
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    assert_events_in_order(&setup.env, &["contribution_received"]);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+    assert_events_in_order(&setup.env, &["contribution_received", "round_completed"]);
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.close_round();
+    assert_events_in_order(&setup.env, &["round_closed"]);
+
+    let new_admin = Address::generate(&setup.env);
+    setup.client.propose_admin_transfer(&new_admin);
+    setup.client.accept_admin_role();
+    assert_events_in_order(&setup.env, &["admin_transferred"]);
+}
+
+#[test]
+fn test_total_outstanding_debt_sums_members() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+    assert_eq!(setup.client.total_outstanding_debt(), 0);
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &40);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.finalize_round();
+
+    assert_eq!(setup.client.get_debt(&user2), 60);
+    assert_eq!(setup.client.get_debt(&user3), 100);
+    assert_eq!(setup.client.total_outstanding_debt(), 160);
+}
+
+#[test]
+fn test_member_ejected_after_consecutive_defaults() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    setup.client.set_auto_eject(&2, &EjectionPolicy::Remove);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.close_round();
+    assert_eq!(setup.client.get_consecutive_defaults(&user3), 1);
+    assert!(setup.client.is_member(&user3));
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.env.ledger().with_mut(|li| li.timestamp = 7202);
+    setup.client.close_round();
+
+    assert!(!setup.client.is_member(&user3));
+    assert!(!setup.client.get_payout_order().contains(&user3));
+    assert_eq!(setup.client.get_consecutive_defaults(&user1), 0);
+}
+
+#[test]
+fn test_snapshot_matches_individual_getters() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.env.ledger().with_mut(|li| li.timestamp = 600);
+
+    let snapshot = setup.client.snapshot();
+    assert_eq!(snapshot.state, setup.client.get_full_state());
+    assert_eq!(snapshot.stats, setup.client.get_stats());
+    assert_eq!(snapshot.recipient, setup.client.current_recipient());
+    assert_eq!(snapshot.time_remaining, 3000);
+    assert_eq!(snapshot.paid.len(), 3);
+    assert!(snapshot.paid.get(user1).unwrap());
+    assert!(!snapshot.paid.get(user2).unwrap());
+}
+
+#[test]
+fn test_close_round_at_rejects_repeat_close() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.close_round_at(&0);
+
+    let res = setup.client.try_close_round_at(&0);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::RoundAlreadyClosed.into());
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 7202);
+    setup.client.close_round_at(&1);
+    let (round, _, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 2);
+}
+
+#[test]
+fn test_close_round_at_after_cycle_rollover() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.set_max_rounds(&2);
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.close_round_at(&0);
+    while !setup.client.is_complete() {
+        for member in setup.members.iter() {
+            setup.client.contribute(&member, &setup.token_admin, &100);
+        }
+    }
+    setup.client.start_new_cycle();
+
+    // Round 0 of the new cycle has not been closed yet.
+    let (round, _, deadline, _, _) = setup.client.get_state();
+    assert_eq!(round, 0);
+    setup.env.ledger().with_mut(|li| li.timestamp = deadline + 1);
+    setup.client.close_round_at(&0);
+    let (round, _, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+}
+
+#[test]
+fn test_close_round_at_requires_quorum() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let co_admin = Address::generate(&setup.env);
+    setup
+        .client
+        .init_multisig(&vec![&setup.env, co_admin.clone()], &2);
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    let res = setup.client.try_close_round_at(&0);
+    assert_eq!(res.unwrap_err().unwrap(), Error::InsufficientApprovals.into());
+    let (round, _, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 0);
+}
+
+#[test]
+fn test_round_contributions_maps_every_member() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &30);
+
+    let contributions = setup.client.round_contributions();
+    assert_eq!(contributions.len(), 3);
+    assert_eq!(contributions.get(user1).unwrap(), 100);
+    assert_eq!(contributions.get(user2).unwrap(), 30);
+    assert_eq!(contributions.get(user3).unwrap(), 0);
+}
+
+#[test]
+fn test_frozen_clock_extends_the_deadline() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 1000);
+    setup.client.freeze_clock();
+
+    // Past the original deadline, but the clock stopped at 1000.
+    setup.env.ledger().with_mut(|li| li.timestamp = 5000);
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    let res = setup.client.try_close_round();
+    assert_eq!(res.unwrap_err().unwrap(), Error::DeadlineNotPassed.into());
+
+    setup.client.unfreeze_clock();
+    let (_, _, deadline, _, _) = setup.client.get_state();
+    assert_eq!(deadline, 7600);
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 7000);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+}
+
+#[test]
+fn test_payout_goes_to_configured_payout_address() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let cold_wallet = Address::generate(&setup.env);
+    setup.client.set_payout_address(&user1, &cold_wallet);
+    assert_eq!(setup.client.get_payout_address(&user1), cold_wallet);
+
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+
+    assert_eq!(setup.token_client.balance(&cold_wallet), 300);
+    assert_eq!(setup.token_client.balance(&user1), 900);
+}
+
+#[test]
+fn test_single_member_round_pays_back_exact_contribution() {
+    let setup = setup_with_members(1, 1000);
+    init_with_fee(&setup, 500);
+    let user = setup.members.get(0).unwrap();
+    assert_eq!(setup.client.expected_pot(), 100);
+
+    setup.client.contribute(&user, &setup.token_admin, &100);
+
+    let (round, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+    assert!(paid.is_empty());
+    assert_eq!(setup.token_client.balance(&user), 1000);
+    assert_eq!(setup.token_client.balance(&setup.client.address), 0);
+}
+
+#[test]
+fn test_round_state_lives_in_temporary_storage() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+
+    setup.env.as_contract(&setup.client.address, || {
+        let storage = setup.env.storage();
+        assert!(storage.temporary().has(&DataKey::PaidMembers));
+        assert!(!storage.instance().has(&DataKey::PaidMembers));
+        assert!(!storage.persistent().has(&DataKey::PaidMembers));
+        assert!(storage.temporary().has(&DataKey::RoundDeadline));
+        assert!(!storage.instance().has(&DataKey::RoundDeadline));
+        assert!(storage.persistent().has(&DataKey::Members));
+    });
+    let (_, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(paid, vec![&setup.env, user1]);
+}
+
+#[test]
+fn test_durable_state_survives_round_state_expiry() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+
+    // Outlive the paid list's TTL (round duration + 120k ledgers) while the
+    // instance and persistent entries are kept alive.
+    setup.client.bump_ttl(&500_000);
+    setup
+        .env
+        .ledger()
+        .with_mut(|li| li.sequence_number += 400_000);
+
+    setup.env.as_contract(&setup.client.address, || {
+        let storage = setup.env.storage();
+        assert!(!storage.temporary().has(&DataKey::PaidMembers));
+        assert!(!storage.temporary().has(&DataKey::RoundDeadline));
+        assert!(storage.persistent().has(&DataKey::Members));
+    });
+    assert_eq!(setup.client.get_payout_order(), setup.members);
+    let (round, paid, deadline, _, _) = setup.client.get_state();
+    assert_eq!(round, 0);
+    // The deadline is recomputed from the round's start; the paid list
+    // expired with the round and is not kept anywhere else.
+    assert_eq!(deadline, 3600);
+    assert!(paid.is_empty());
+    setup.env.as_contract(&setup.client.address, || {
+        assert!(!setup.env.storage().persistent().has(&DataKey::PaidMembers));
+    });
+}
+
+#[test]
+fn test_round_state_outlives_the_grace_period() {
+    let setup = setup_with_members(3, 1000);
+    let config = RoscaConfig {
+        grace_period_ledgers: 200_000,
+        ..default_config()
+    };
+    setup.client.init(
+        &setup.admin,
+        &setup.members,
+        &100,
+        &setup.token_admin,
+        &3600,
+        &config,
+        &None,
+    );
+    let user1 = setup.members.get(0).unwrap();
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+
+    // Past the round and the default margin, but inside the grace period.
+    setup.client.bump_ttl(&500_000);
+    setup
+        .env
+        .ledger()
+        .with_mut(|li| li.sequence_number += 300_000);
+
+    let (_, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(paid, vec![&setup.env, user1]);
+}
+
+#[test]
+fn test_migrate_storage_moves_legacy_round_state() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    setup.env.as_contract(&setup.client.address, || {
+        let storage = setup.env.storage();
+        storage.temporary().remove(&DataKey::PaidMembers);
+        storage.temporary().remove(&DataKey::RoundDeadline);
+        storage
+            .instance()
+            .set(&DataKey::PaidMembers, &vec![&setup.env, user1.clone()]);
+        storage.instance().set(&DataKey::RoundDeadline, &3600u64);
+    });
+
+    // Legacy instance values are still honoured before migrating.
+    let (_, paid, deadline, _, _) = setup.client.get_state();
+    assert_eq!(paid, vec![&setup.env, user1.clone()]);
+    assert_eq!(deadline, 3600);
+
+    assert_eq!(setup.client.migrate_storage(), 2);
+    assert_eq!(setup.client.migrate_storage(), 0);
+    setup.env.as_contract(&setup.client.address, || {
+        let storage = setup.env.storage();
+        assert!(!storage.instance().has(&DataKey::PaidMembers));
+        assert!(storage.temporary().has(&DataKey::PaidMembers));
+        assert!(!storage.instance().has(&DataKey::RoundDeadline));
+        assert!(storage.temporary().has(&DataKey::RoundDeadline));
+    });
+    let (_, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(paid, vec![&setup.env, user1]);
+}
+
+#[test]
+fn test_projected_payout_nets_fee_and_debt() {
+    let setup = setup_with_members(3, 1000);
+    init_with_fee(&setup, 200);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    assert_eq!(setup.client.projected_payout(&user2), 294);
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &40);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.finalize_round();
+
+    assert_eq!(setup.client.get_debt(&user2), 60);
+    assert_eq!(
+        setup.client.projected_payout(&user2),
+        setup.client.expected_pot() - 60
+    );
+}
+
+#[test]
+fn test_recover_admin_requires_guardian() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    let new_admin = Address::generate(&setup.env);
+
+    let res = setup.client.try_recover_admin(&new_admin);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::NoGuardianConfigured.into());
+}
+
+#[test]
+fn test_init_configures_guardian() {
+    let setup = setup_with_members(2, 1000);
+    let guardian = Address::generate(&setup.env);
+    let new_admin = Address::generate(&setup.env);
+    let config = RoscaConfig {
+        guardian: Some(guardian.clone()),
+        recovery_delay: MIN_RECOVERY_DELAY_SECONDS - 1,
+        ..default_config()
+    };
+    let res = setup.client.try_init(
+        &setup.admin,
+        &setup.members,
+        &100,
+        &setup.token_admin,
+        &3600,
+        &config,
+        &None,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ExtError::InvalidAmount.into());
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let config = RoscaConfig {
+        recovery_delay: MIN_RECOVERY_DELAY_SECONDS,
+        ..config
+    };
+    setup.client.init(
+        &setup.admin,
+        &setup.members,
+        &100,
+        &setup.token_admin,
+        &3600,
+        &config,
+        &None,
+    );
+    assert_eq!(
+        setup.client.get_guardian(),
+        Some((guardian, MIN_RECOVERY_DELAY_SECONDS))
+    );
+
+    // The delay runs from `init`, the admin's first action.
+    setup
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = 1_000 + MIN_RECOVERY_DELAY_SECONDS - 1);
+    let res = setup.client.try_recover_admin(&new_admin);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::RecoveryDelayNotElapsed.into());
+
+    setup
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = 1_000 + MIN_RECOVERY_DELAY_SECONDS);
+    setup.client.recover_admin(&new_admin);
+    assert_eq!(setup.client.get_admin(), new_admin);
+}
+
+#[test]
+fn test_set_guardian_enforces_min_recovery_delay() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    let guardian = Address::generate(&setup.env);
+
+    let res = setup
+        .client
+        .try_set_guardian(&Some(guardian.clone()), &(MIN_RECOVERY_DELAY_SECONDS - 1));
+    assert_eq!(res.unwrap_err().unwrap(), ExtError::InvalidAmount.into());
+    assert_eq!(setup.client.get_guardian(), None);
+
+    setup
+        .client
+        .set_guardian(&Some(guardian.clone()), &MIN_RECOVERY_DELAY_SECONDS);
+    assert_eq!(
+        setup.client.get_guardian(),
+        Some((guardian, MIN_RECOVERY_DELAY_SECONDS))
+    );
+
+    // Clearing the guardian ignores the delay.
+    setup.client.set_guardian(&None, &0);
+    assert_eq!(setup.client.get_guardian(), None);
+}
+
+#[test]
+fn test_recover_admin_waits_for_admin_inactivity() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    let guardian = Address::generate(&setup.env);
+    let new_admin = Address::generate(&setup.env);
+    setup.client.set_guardian(&Some(guardian.clone()), &86_400);
+    assert_eq!(setup.client.get_guardian(), Some((guardian, 86_400)));
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 50_000);
+    setup.client.set_late_fee(&setup.admin, &5);
+
+    // The admin acted at 50_000, so the delay runs until 136_400.
+    setup.env.ledger().with_mut(|li| li.timestamp = 100_000);
+    let res = setup.client.try_recover_admin(&new_admin);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::RecoveryDelayNotElapsed.into());
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 136_400);
+    setup.client.recover_admin(&new_admin);
+    assert_eq!(setup.client.get_admin(), new_admin);
+}
+
+#[test]
+fn test_can_contribute_reports_reason() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let env = &setup.env;
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let stranger = Address::generate(env);
+
+    assert_eq!(setup.client.can_contribute(&user1), (true, Symbol::new(env, "ok")));
+    assert_eq!(
+        setup.client.can_contribute(&stranger),
+        (false, Symbol::new(env, "not_member"))
+    );
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    assert_eq!(
+        setup.client.can_contribute(&user1),
+        (false, Symbol::new(env, "already_paid"))
+    );
+
+    setup.client.set_contributions_paused(&true);
+    assert_eq!(setup.client.can_contribute(&user2), (false, Symbol::new(env, "paused")));
+    setup.client.set_contributions_paused(&false);
+
+    env.ledger().with_mut(|li| li.timestamp = 3601);
+    assert_eq!(
+        setup.client.can_contribute(&user2),
+        (false, Symbol::new(env, "deadline_passed"))
+    );
+    let res = setup.client.try_contribute(&user2, &setup.token_admin, &100);
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_underfilled_round_carries_pot_into_next_payout() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    setup.client.set_carry_over(&true);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.finalize_round();
+
+    // Nothing was paid out; the round is re-run with its pot carried.
+    assert_eq!(setup.client.get_carry_over(), 200);
+    let (round, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 0);
+    assert!(paid.is_empty());
+    assert_eq!(setup.token_client.balance(&setup.client.address), 200);
+
+    for member in [&user1, &user2, &user3] {
+        setup.client.contribute(member, &setup.token_admin, &100);
+    }
+
+    assert_eq!(setup.client.get_carry_over(), 0);
+    assert_eq!(setup.token_client.balance(&user1), 1000 - 200 + 500);
+    assert_eq!(setup.token_client.balance(&setup.client.address), 0);
+}
+
+#[test]
+fn test_dissolve_refunds_carried_pot_to_its_contributors() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    setup.client.set_carry_over(&true);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.finalize_round();
+    assert_eq!(setup.client.get_carry_over(), 200);
+
+    // user1 pays into the re-run round before the group is dissolved.
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.dissolve();
+    assert_eq!(setup.client.get_carry_over(), 0);
+    assert_eq!(setup.token_client.balance(&user1), 1000);
+    assert_eq!(setup.token_client.balance(&user2), 1000);
+    assert_eq!(setup.token_client.balance(&setup.client.address), 0);
+}
+
+#[test]
+fn test_excused_absence_is_not_a_default() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    setup.client.set_excuse_allowance(&1);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+    assert_eq!(setup.client.excuses_remaining(&user3), 1);
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.close_round();
+    assert!(setup.client.get_defaulters().is_empty());
+    assert_eq!(setup.client.excuses_remaining(&user3), 0);
+    assert_eq!(setup.client.get_debt(&user3), 0);
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    let (_, _, deadline, _, _) = setup.client.get_state();
+    setup.env.ledger().with_mut(|li| li.timestamp = deadline + 1);
+    setup.client.close_round();
+    assert_eq!(setup.client.get_defaulters(), vec![&setup.env, user3.clone()]);
+    assert_eq!(setup.client.get_debt(&user3), 100);
+}
+
+#[test]
+fn test_quick_poll_tallies_votes() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let topic = symbol_short!("newmem");
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    setup.client.quick_poll(&topic, &1_000);
+    setup.client.cast(&user1, &topic, &true);
+    setup.client.cast(&user2, &topic, &false);
+    setup.client.cast(&user3, &topic, &true);
+    assert_eq!(setup.client.poll_result(&topic), (2, 1));
+
+    let res = setup.client.try_cast(&user1, &topic, &false);
+    assert_eq!(res.unwrap_err().unwrap(), Error::AlreadyVoted.into());
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 1_001);
+    let outsider = Address::generate(&setup.env);
+    let res = setup.client.try_cast(&outsider, &topic, &true);
+    assert_eq!(res.unwrap_err().unwrap(), Error::OnlyMembersAllowed.into());
+    assert_eq!(setup.client.poll_result(&topic), (2, 1));
+}
+
+/// Price feed whose quote the test sets directly.
+#[soroban_sdk::contract]
+pub struct MockPriceOracle;
+
+#[soroban_sdk::contractimpl]
+impl MockPriceOracle {
+    pub fn set_price(env: Env, price: i128, published_at: u64) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("price"), &(price, published_at));
+    }
+
+    pub fn price(env: Env) -> (i128, u64) {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("price"))
+            .unwrap()
+    }
+}
+
+#[test]
+fn test_pegged_contribution_follows_oracle_price() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let oracle_id = setup.env.register(MockPriceOracle, ());
+    let oracle = MockPriceOracleClient::new(&setup.env, &oracle_id);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    // A zero max age would disable the staleness check.
+    oracle.set_price(&20_000_000, &0);
+    let res = setup.client.try_set_price_peg(&Some(oracle_id.clone()), &100, &0);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError::InvalidAmount.into());
+
+    // Peg to 0.00001 unit; the token is worth 2.0 units, so 50 base units.
+    setup.client.set_price_peg(&Some(oracle_id.clone()), &100, &60);
+    setup.client.contribute(&user1, &setup.token_admin, &50);
+    assert_eq!(setup.token_client.balance(&user1), 950);
+    assert!(setup.client.contribution_status(&vec![&setup.env, user1.clone()]).get(0).unwrap());
+
+    // Re-pegging mid-round would change what user1 already settled.
+    let res = setup.client.try_set_price_peg(&Some(oracle_id.clone()), &200, &60);
+    assert_eq!(res.unwrap_err().unwrap(), Error::CannotChangeMidRound.into());
+
+    // The token halves in value twice; the same peg now costs four times as much.
+    oracle.set_price(&5_000_000, &0);
+    setup.client.contribute(&user2, &setup.token_admin, &50);
+    assert!(!setup.client.contribution_status(&vec![&setup.env, user2.clone()]).get(0).unwrap());
+    setup.client.contribute(&user2, &setup.token_admin, &150);
+    assert_eq!(setup.token_client.balance(&user2), 800);
+    assert!(setup.client.contribution_status(&vec![&setup.env, user2.clone()]).get(0).unwrap());
+
+    setup.env.ledger().with_mut(|li| li.timestamp = 61);
+    let res = setup.client.try_contribute(&user3, &setup.token_admin, &200);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::StalePrice.into());
+}
+
+#[test]
+fn test_pegged_contribution_scales_to_token_decimals() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let oracle_id = setup.env.register(MockPriceOracle, ());
+    let oracle = MockPriceOracleClient::new(&setup.env, &oracle_id);
+    let user1 = setup.members.get(0).unwrap();
+
+    // A 2-decimal token: 1.0 unit at 2.0 units per token is 0.5 token, 50 base units.
+    setup.env.as_contract(&setup.client.address, || {
+        setup
+            .env
+            .storage()
+            .instance()
+            .set(&DataKey4::TokenDecimals, &2u32);
+    });
+    oracle.set_price(&20_000_000, &0);
+    setup.client.set_price_peg(&Some(oracle_id), &10_000_000, &60);
+    assert_eq!(setup.client.required_balance(&user1), 50);
+}
+
+#[test]
+fn test_member_report_tracks_each_member() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    for member in [&user1, &user2, &user3] {
+        setup.client.contribute(member, &setup.token_admin, &100);
+    }
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    let (_, _, deadline, _, _) = setup.client.get_state();
+    setup.env.ledger().with_mut(|li| li.timestamp = deadline + 1);
+    setup.client.finalize_round();
+
+    let report = setup.client.member_report();
+    assert_eq!(report.len(), 3);
+    let r1 = report.get(0).unwrap();
+    assert_eq!(r1.member, user1);
+    assert!(!r1.has_paid_this_round);
+    assert_eq!(r1.lifetime_contributions, 200);
+    assert_eq!(r1.on_time_streak, 2);
+    assert_eq!(r1.payouts_received, 1);
+    let r3 = report.get(2).unwrap();
+    assert_eq!(r3.member, user3);
+    assert_eq!(r3.lifetime_contributions, 100);
+    assert_eq!(r3.default_count, 1);
+    assert_eq!(r3.debt, 100);
+    assert_eq!(r3.deposit, 0);
+    assert_eq!(r3.on_time_streak, 0);
+    assert_eq!(r3.payouts_received, 0);
+    assert_eq!(report.get(1).unwrap().payouts_received, 1);
+}
+
+/// Payout hook that remembers the last notification, or panics once broken.
+#[soroban_sdk::contract]
+pub struct MockPayoutHook;
+
+#[soroban_sdk::contractimpl]
+impl MockPayoutHook {
+    pub fn on_payout(env: Env, recipient: Address, round: u32, amount: i128) {
+        if env.storage().instance().has(&symbol_short!("broken")) {
+            panic!("hook unavailable");
+        }
+        env.storage()
+            .instance()
+            .set(&symbol_short!("last"), &(recipient, round, amount));
+    }
+
+    pub fn break_hook(env: Env) {
+        env.storage().instance().set(&symbol_short!("broken"), &true);
+    }
+
+    pub fn last(env: Env) -> Option<(Address, u32, i128)> {
+        env.storage().instance().get(&symbol_short!("last"))
+    }
+}
+
+#[test]
+fn test_payout_hook_is_notified_and_cannot_block_payouts() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let hook_id = setup.env.register(MockPayoutHook, ());
+    let hook = MockPayoutHookClient::new(&setup.env, &hook_id);
+    setup.client.set_payout_hook(&Some(hook_id.clone()));
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert_eq!(hook.last(), Some((user1.clone(), 0, 300)));
+
+    hook.break_hook();
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert!(emitted_event_names(&setup.env)
+        .contains(Symbol::new(&setup.env, "payout_hook_failed")));
+    assert_eq!(setup.token_client.balance(&user2), 1000 - 200 + 300);
+    assert_eq!(hook.last(), Some((user1, 0, 300)));
+}
+
+#[test]
+fn test_reconcile_drops_paid_entries_for_removed_members() {
+    let setup = setup_with_members(4, 1000);
+    default_init(&setup);
+    let user3 = setup.members.get(2).unwrap();
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+
+    // Healthy contract: nothing to fix.
+    assert_eq!(setup.client.reconcile(), 0);
+    assert_eq!(setup.client.get_contribution_balance(), 100);
+
+    // Simulate a removal that forgot to clean up the round state.
+    setup.env.as_contract(&setup.client.address, || {
+        internals::drop_member(&setup.env, &user3);
+    });
+    assert!(!setup.client.check_invariants().paid_members_are_members);
+
+    assert_eq!(setup.client.reconcile(), 1);
+    let report = setup.client.check_invariants();
+    assert!(report.paid_members_are_members);
+    assert!(report.balance_covers_accounted);
+    assert_eq!(setup.client.get_contribution_balance(), 0);
+    assert_eq!(setup.token_client.balance(&user3), 1000);
+    assert_eq!(setup.client.reconcile(), 0);
+}
+
+#[test]
+fn test_recipient_deferring_short_round_gets_combined_pot() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+    setup.client.defer_if_short(&user1, &true);
+    assert!(setup.client.get_defer_if_short(&user1));
+    assert!(!setup.client.get_defer_if_short(&user2));
+
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.finalize_round();
+
+    // user1's turn rolled forward instead of paying a 200 pot.
+    let (round, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 0);
+    assert!(paid.is_empty());
+    assert_eq!(setup.client.get_carry_over(), 200);
+    assert_eq!(setup.token_client.balance(&user1), 1000);
+
+    for member in [&user1, &user2, &user3] {
+        setup.client.contribute(member, &setup.token_admin, &100);
+    }
+
+    assert_eq!(setup.token_client.balance(&user1), 1000 - 100 + 500);
+    assert_eq!(setup.client.get_carry_over(), 0);
+    let (round, _, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+}
+
+#[test]
+fn test_setup_group_helper_yields_usable_group() {
+    let env = Env::default();
+    let (client, members, token_admin) = testutils::setup_group(&env, 3, 100, 3600);
+    let token_client = TokenClient::new(&env, &token_admin.address);
+    assert_eq!(members.len(), 3);
+    for member in members.iter() {
+        assert_eq!(token_client.balance(&member), 300);
+    }
+
+    for member in members.iter() {
+        client.contribute(&member, &token_admin.address, &100);
+    }
+    let recipient = members.get(0).unwrap();
+    assert_eq!(token_client.balance(&recipient), 300 - 100 + 300);
+    let (round, _, _, _, _) = client.get_state();
+    assert_eq!(round, 1);
+}
+
+#[test]
+fn test_set_token_between_cycles() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    setup.client.set_max_rounds(&0);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    let new_token = setup
+        .env
+        .register_stellar_asset_contract_v2(setup.admin.clone())
+        .address();
+    let res = setup.client.try_set_token(&new_token);
+    assert_eq!(res.unwrap_err().unwrap(), Error::CannotChangeMidRound.into());
+
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    for member in [&user1, &user2] {
+        setup.client.contribute(member, &setup.token_admin, &100);
+    }
+
+    let not_a_token = Address::generate(&setup.env);
+    let res = setup.client.try_set_token(&not_a_token);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::InvalidToken.into());
+
+    setup.client.set_token(&new_token);
+    assert_eq!(setup.client.get_token_decimals(), 7);
+    let new_admin = TokenAdminClient::new(&setup.env, &new_token);
+    let new_client = TokenClient::new(&setup.env, &new_token);
+    new_admin.mint(&user1, &100);
+    new_admin.mint(&user2, &100);
+
+    let res = setup.client.try_contribute(&user1, &setup.token_admin, &100);
+    assert!(res.is_err());
+    setup.client.contribute(&user1, &new_token, &100);
+    setup.client.contribute(&user2, &new_token, &100);
+    assert_eq!(new_client.balance(&user1), 200);
+    assert_eq!(setup.token_client.balance(&setup.client.address), 0);
+}
+
+#[test]
+fn test_required_balance_reflects_member_position() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    assert_eq!(setup.client.required_balance(&user2), 100);
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    assert_eq!(setup.client.required_balance(&user1), 0);
+
+    setup.client.set_late_fee(&setup.admin, &15);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    assert_eq!(setup.client.required_balance(&user2), 115);
+}
+
+/// `FundFlow` records from the last invocation as `(kind, from, to, amount, round)`.
+fn fund_flows(env: &Env) -> alloc::vec::Vec<(Symbol, Address, Address, i128, u32)> {
+    let mut flows = alloc::vec::Vec::new();
+    for ev in env.events().all().iter() {
+        let name: Symbol = ev.1.get(0).unwrap().into_val(env);
+        if name != Symbol::new(env, "fund_flow") {
+            continue;
+        }
+        let kind: Symbol = ev.1.get(2).unwrap().into_val(env);
+        let data: soroban_sdk::Map<Symbol, soroban_sdk::Val> = ev.2.into_val(env);
+        let field = |key: &str| data.get(Symbol::new(env, key)).unwrap();
+        flows.push((
+            kind,
+            field("from").into_val(env),
+            field("to").into_val(env),
+            field("amount").into_val(env),
+            field("round").into_val(env),
+        ));
+    }
+    flows
+}
+
+#[test]
+fn test_contribution_and_payout_emit_fund_flows() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let contract = setup.client.address.clone();
+
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    let flows = fund_flows(&setup.env);
+    assert_eq!(flows.len(), 1);
+    assert_eq!(
+        flows[0],
+        (
+            Symbol::new(&setup.env, "contribution"),
+            user2.clone(),
+            contract.clone(),
+            100,
+            0
+        )
+    );
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    let flows = fund_flows(&setup.env);
+    assert_eq!(
+        flows[0],
+        (
+            Symbol::new(&setup.env, "contribution"),
+            user1.clone(),
+            contract.clone(),
+            100,
+            0
+        )
+    );
+    assert!(flows.contains(&(symbol_short!("payout"), contract, user1, 200, 0)));
+}
+
+#[test]
+fn test_declined_turn_passes_pot_and_reschedules_decliner() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    let res = setup.client.try_decline_turn(&user2);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::NotScheduledRecipient.into());
+
+    setup.client.decline_turn(&user1);
+    assert_eq!(setup.client.current_recipient(), user2);
+    assert_eq!(
+        setup.client.get_payout_order(),
+        vec![&setup.env, user2.clone(), user3.clone(), user1.clone()]
+    );
+
+    for member in [&user1, &user2, &user3] {
+        setup.client.contribute(member, &setup.token_admin, &100);
+    }
+    assert_eq!(setup.token_client.balance(&user2), 1000 - 100 + 300);
+    assert_eq!(setup.token_client.balance(&user1), 900);
+
+    for _ in 0..2 {
+        for member in [&user1, &user2, &user3] {
+            setup.client.contribute(member, &setup.token_admin, &100);
+        }
+    }
+    assert_eq!(setup.token_client.balance(&user1), 1000 - 300 + 300);
+}
+
+#[test]
+fn test_tvl_cap_rejects_inflow_above_cap() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    setup.client.set_max_tvl(&300);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.post_deposit(&user2, &100);
+    setup.client.prepay(&user3, &1);
+    assert_eq!(setup.client.current_tvl(), 300);
+
+    let res = setup.client.try_contribute(&user2, &setup.token_admin, &100);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::TvlCapExceeded.into());
+    let res = setup.client.try_post_deposit(&user1, &1);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::TvlCapExceeded.into());
+
+    setup.client.set_max_tvl(&0);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+}
+
+#[test]
+fn test_member_timeline_lists_actions_by_round() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    setup.client.set_excuse_allowance(&1);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    // Round 0: user3 misses it on their excused absence.
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.close_round();
+
+    // Rounds 1 and 2: everyone pays; round 2 is user3's payout.
+    for _ in 0..2 {
+        for member in [&user1, &user2, &user3] {
+            setup.client.contribute(member, &setup.token_admin, &100);
+        }
+    }
+
+    // Round 3: user3 misses it with no excuses left.
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    let (_, _, deadline, _, _) = setup.client.get_state();
+    setup.env.ledger().with_mut(|li| li.timestamp = deadline + 1);
+    setup.client.close_round();
+
+    let entry = |round: u32, action: &str| TimelineEntry {
+        round,
+        action: Symbol::new(&setup.env, action),
+    };
+    assert_eq!(
+        setup.client.member_timeline(&user3),
+        vec![
+            &setup.env,
+            entry(0, "excused"),
+            entry(1, "paid"),
+            entry(2, "paid"),
+            entry(2, "payout"),
+            entry(3, "defaulted"),
+        ]
+    );
+    assert_eq!(setup.client.get_round_record(&0).excused, vec![&setup.env, user3]);
+}
+
+#[test]
+fn test_payout_and_close_advance_deadline_identically() {
+    for fixed in [false, true] {
+        let paid_out = setup_with_members(2, 1000);
+        let closed = setup_with_members(2, 1000);
+        for setup in [&paid_out, &closed] {
+            default_init(setup);
+            setup.client.set_fixed_schedule(&fixed);
+            setup.env.ledger().with_mut(|li| li.timestamp = 1000);
+        }
+
+        for member in paid_out.members.iter() {
+            paid_out.client.contribute(&member, &paid_out.token_admin, &100);
+        }
+        closed.client.contribute(&closed.members.get(0).unwrap(), &closed.token_admin, &100);
+        closed.env.ledger().with_mut(|li| li.timestamp = 3601);
+        closed.client.close_round();
+
+        // Both paths advance the same way: from the round's close time on a
+        // rolling schedule, from the group's start on a fixed one.
+        let (round_a, _, deadline_a, _, _) = paid_out.client.get_state();
+        let (round_b, _, deadline_b, _, _) = closed.client.get_state();
+        assert_eq!(round_a, 1);
+        assert_eq!(round_b, 1);
+        if fixed {
+            assert_eq!(deadline_a, 7200);
+            assert_eq!(deadline_b, 7200);
+        } else {
+            assert_eq!(deadline_a, 1000 + 3600);
+            assert_eq!(deadline_b, 3601 + 3600);
+        }
+    }
+}
+
+#[test]
+fn test_close_expired_closes_every_passed_round() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    setup.client.set_fixed_schedule(&true);
+
+    // Rounds 0, 1 and 2 end at 3600, 7200 and 10800.
+    setup.env.ledger().with_mut(|li| li.timestamp = 10_801);
+    assert_eq!(setup.client.close_expired(&10), 3);
+
+    let (round, _, deadline, _, _) = setup.client.get_state();
+    assert_eq!(round, 3);
+    assert_eq!(deadline, 14_400);
+    for r in 0..3 {
+        assert_eq!(setup.client.get_round_defaulters(&r), setup.members);
+    }
+    assert_eq!(setup.client.close_expired(&10), 0);
+}
+
+#[test]
+fn test_pull_limit_blocks_auto_contribute_once_reached() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    setup.client.set_auto_contribute(&user1, &5);
+    setup.client.set_pull_limit(&user1, &150);
+
+    setup.client.trigger_auto(&user1);
+    assert_eq!(setup.client.get_pull_limit(&user1), (150, 100));
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+
+    // A second 100 pull would bring the total to 200.
+    let res = setup.client.try_trigger_auto(&user1);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::PullLimitExceeded.into());
+    assert_eq!(setup.client.get_pull_limit(&user1), (150, 100));
+
+    // Paying directly is unaffected.
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+}
+
+#[test]
+fn test_pull_limit_counts_insurance_cut() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    // A 10% insurance cut makes each pull 110.
+    setup.env.as_contract(&setup.client.address, || {
+        setup
+            .env
+            .storage()
+            .instance()
+            .set(&DataKey2::InsuranceContributionBps, &1000u32);
+    });
+    setup.client.set_auto_contribute(&user1, &5);
+    setup.client.set_pull_limit(&user1, &105);
+
+    let res = setup.client.try_trigger_auto(&user1);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::PullLimitExceeded.into());
+    assert_eq!(setup.client.get_pull_limit(&user1), (105, 0));
+    assert_eq!(setup.token_client.balance(&user1), 1000);
+
+    setup.client.set_pull_limit(&user1, &110);
+    setup.client.trigger_auto(&user1);
+    assert_eq!(setup.client.get_pull_limit(&user1), (110, 110));
+    assert_eq!(setup.token_client.balance(&user1), 890);
+}
+
+#[test]
+fn test_last_round_outcome_reports_latest_settled_round() {
+    let setup = setup_with_members(2, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    let res = setup.client.try_last_round_outcome();
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::RoundNotFound.into());
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+
+    let outcome = setup.client.last_round_outcome();
+    assert_eq!(outcome, setup.client.get_round_record(&0));
+    assert_eq!(outcome.recipient, Some(user1));
+    assert_eq!(outcome.pot, 200);
+    assert!(outcome.defaulters.is_empty());
+}
+
+#[test]
+fn test_boost_adds_to_recipient_payout() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    setup.client.boost(&user3, &50);
+    assert_eq!(setup.client.current_tvl(), 50);
+    // The boost does not count as user3's contribution.
+    assert_eq!(setup.client.required_balance(&user3), 100);
+
+    for member in [&user1, &user2, &user3] {
+        setup.client.contribute(member, &setup.token_admin, &100);
+    }
+    assert_eq!(setup.token_client.balance(&user1), 1000 - 100 + 350);
+    assert_eq!(setup.token_client.balance(&user3), 1000 - 150);
+    assert_eq!(setup.client.get_round_record(&0).boosted, 50);
+    assert_eq!(setup.client.current_tvl(), 0);
+}
+
+#[test]
+fn test_preview_payout_includes_boost() {
+    let setup = setup_with_members(3, 1000);
+    init_with_fee(&setup, 500);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.client.boost(&user3, &50);
+
+    let (recipient, amount) = setup.client.preview_payout();
+    assert_eq!(recipient, user1);
+    assert_eq!(amount, 250 - 12);
+
+    // The preview is exactly what the recipient then receives.
+    let before = setup.token_client.balance(&user1);
+    setup.client.claim_early(&user1);
+    assert_eq!(setup.token_client.balance(&user1) - before, amount);
+}
+
+#[test]
+fn test_boost_carries_past_close_and_is_refunded_on_dissolve() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    setup.client.boost(&user3, &50);
+    setup.env.ledger().with_mut(|li| li.timestamp = 3601);
+    setup.client.close_round();
+
+    // No payout happened, so the boost is still owed and not sweepable.
+    assert_eq!(setup.client.get_round_record(&0).boosted, 0);
+    assert_eq!(setup.client.current_tvl(), 50);
+    let res = setup.client.try_sweep_dust(&setup.admin);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::NoSurplusBalance.into());
+
+    setup.client.boost(&user1, &20);
+    setup.client.dissolve();
+    assert_eq!(setup.token_client.balance(&user3), 1000);
+    assert_eq!(setup.token_client.balance(&user1), 1000);
+    assert_eq!(setup.token_client.balance(&setup.client.address), 0);
+}
+
+#[test]
+fn test_rotation_complete_after_every_member_paid() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    assert!(!setup.client.rotation_complete());
+
+    for _ in 0..3 {
+        for member in setup.members.iter() {
+            setup.client.contribute(&member, &setup.token_admin, &100);
+        }
+    }
+    assert!(setup.client.rotation_complete());
+}
+
+#[test]
+fn test_rotation_incomplete_blocks_new_cycle() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    setup.client.set_max_rounds(&2);
+
+    for _ in 0..2 {
+        for member in setup.members.iter() {
+            setup.client.contribute(&member, &setup.token_admin, &100);
+        }
+    }
+    assert!(setup.client.is_complete());
+    assert!(!setup.client.rotation_complete());
+
+    let res = setup.client.try_start_new_cycle();
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::RotationIncomplete.into());
+}
+
+#[test]
+fn test_overpayment_refund_policy_takes_only_what_is_owed() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user2 = setup.members.get(1).unwrap();
+    assert_eq!(setup.client.get_overpay_policy(), OverpayPolicy::Reject);
+
+    setup.client.set_overpay_policy(&OverpayPolicy::Refund);
+    setup.client.contribute(&user2, &setup.token_admin, &150);
+    assert!(emitted_event_names(&setup.env).contains(Symbol::new(&setup.env, "overpayment")));
+    assert_eq!(setup.token_client.balance(&user2), 900);
+    assert_eq!(setup.client.get_contribution_balance(), 100);
+    assert_eq!(setup.client.get_prepaid(&user2), 0);
+}
+
+#[test]
+fn test_overpayment_credit_policy_funds_prepaid_escrow() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user2 = setup.members.get(1).unwrap();
+
+    setup.client.set_overpay_policy(&OverpayPolicy::Credit);
+    setup.client.contribute(&user2, &setup.token_admin, &250);
+    assert_eq!(setup.token_client.balance(&user2), 750);
+    assert_eq!(setup.client.get_contribution_balance(), 100);
+    assert_eq!(setup.client.get_prepaid(&user2), 150);
+    assert_eq!(setup.client.required_balance(&user2), 0);
+}
+
+#[test]
+fn test_get_flags_reflects_configured_modes() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let flags = setup.client.get_flags();
+    assert!(!flags.paused);
+    assert!(!flags.fixed_schedule);
+    assert!(!flags.whitelist_enabled);
+    // `init` caps the cycle at the member count.
+    assert!(flags.finite);
+    assert_eq!(flags.overpay_policy, OverpayPolicy::Reject);
+
+    setup.client.set_fixed_schedule(&true);
+    setup.client.set_whitelist_enabled(&true);
+    setup.client.set_max_rounds(&0);
+    setup.client.set_overpay_policy(&OverpayPolicy::Credit);
+    let flags = setup.client.get_flags();
+    assert!(flags.fixed_schedule);
+    assert!(flags.whitelist_enabled);
+    assert!(!flags.finite);
+    assert_eq!(flags.overpay_policy, OverpayPolicy::Credit);
+    assert!(!flags.paused);
+    assert!(!flags.carry_over_enabled);
+}
+
+#[test]
+fn test_admin_refund_returns_one_members_contribution() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+    let user3 = setup.members.get(2).unwrap();
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    assert_eq!(setup.client.admin_refund(&user2), 100);
+
+    assert_eq!(setup.token_client.balance(&user2), 1000);
+    assert_eq!(setup.token_client.balance(&user1), 900);
+    let (_, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(paid, vec![&setup.env, user1.clone()]);
+    assert_eq!(setup.client.get_contribution_balance(), 100);
+
+    let res = setup.client.try_admin_refund(&user2);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::NothingToRefund.into());
+
+    // Once the round pays out the contributions belong to the recipient.
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.client.contribute(&user3, &setup.token_admin, &100);
+    let res = setup.client.try_admin_refund(&user3);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::NothingToRefund.into());
+}
+
+#[test]
+fn test_prune_history_removes_old_round_records() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    assert_eq!(setup.client.history_size(), 0);
+
+    for _ in 0..3 {
+        for member in setup.members.iter() {
+            setup.client.contribute(&member, &setup.token_admin, &100);
+        }
+    }
+    assert_eq!(setup.client.history_size(), 3);
+
+    assert_eq!(setup.client.prune_history(&2), 2);
+    assert_eq!(setup.client.history_size(), 1);
+    assert!(setup.client.try_get_round_record(&1).is_err());
+    assert_eq!(setup.client.get_round_record(&2).pot, 300);
+
+    // Already pruned rounds are skipped.
+    assert_eq!(setup.client.prune_history(&2), 0);
+    let res = setup.client.try_prune_history(&4);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError2::RoundNotFound.into());
+}
+
+#[test]
+fn test_min_payout_pot_carries_until_threshold_reached() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    setup.client.set_min_payout_pot(&500);
+
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    // 300 is short of the threshold: the round re-runs with the pot carried.
+    let (round, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 0);
+    assert_eq!(paid.len(), 0);
+    assert_eq!(setup.client.get_carry_over(), 300);
+    assert_eq!(setup.token_client.balance(&user1), 900);
+
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    let (round, _, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 1);
+    assert_eq!(setup.client.get_carry_over(), 0);
+    assert_eq!(setup.token_client.balance(&user1), 1000 - 200 + 600);
+}
+
+#[test]
+fn test_verify_member_auth_checks_without_contributing() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let env = &setup.env;
+    let user1 = setup.members.get(0).unwrap();
+    let stranger = Address::generate(env);
+
+    assert_eq!(setup.client.verify_member_auth(&user1), (true, Symbol::new(env, "ok")));
+    assert_eq!(env.auths()[0].0, user1);
+    assert_eq!(setup.token_client.balance(&user1), 1000);
+    assert_eq!(setup.client.get_contribution_balance(), 0);
+
+    assert_eq!(
+        setup.client.verify_member_auth(&stranger),
+        (false, Symbol::new(env, "not_member"))
+    );
+}
+
+#[test]
+fn test_slot_beneficiaries_split_payout() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let env = &setup.env;
+    let partner_a = Address::generate(env);
+    let partner_b = Address::generate(env);
+
+    let uneven = vec![
+        env,
+        SlotBeneficiary { beneficiary: partner_a.clone(), share_bps: 6_000 },
+        SlotBeneficiary { beneficiary: partner_b.clone(), share_bps: 3_000 },
+    ];
+    let res = setup.client.try_set_slot_beneficiaries(&0, &uneven);
+    assert_eq!(res.unwrap_err().unwrap(), ExtError3::InvalidShares.into());
+
+    let shares = vec![
+        env,
+        SlotBeneficiary { beneficiary: partner_a.clone(), share_bps: 6_000 },
+        SlotBeneficiary { beneficiary: partner_b.clone(), share_bps: 4_000 },
+    ];
+    setup.client.set_slot_beneficiaries(&0, &shares);
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert_eq!(setup.token_client.balance(&partner_a), 180);
+    assert_eq!(setup.token_client.balance(&partner_b), 120);
+    assert_eq!(setup.token_client.balance(&setup.members.get(0).unwrap()), 900);
+}
+
+#[test]
+fn test_retry_round_refunds_and_keeps_recipient() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    setup.client.contribute(&user1, &setup.token_admin, &100);
+    setup.client.contribute(&user2, &setup.token_admin, &100);
+    setup.env.ledger().with_mut(|li| li.timestamp += 100);
+    let deadline = setup.client.retry_round();
+
+    assert_eq!(setup.client.get_retry_count(), 1);
+    assert_eq!(setup.token_client.balance(&user1), 1000);
+    assert_eq!(setup.token_client.balance(&user2), 1000);
+    assert_eq!(setup.client.get_contribution_balance(), 0);
+    let (round, paid, _, _, _) = setup.client.get_state();
+    assert_eq!(round, 0);
+    assert_eq!(paid.len(), 0);
+    assert_eq!(setup.client.current_recipient(), user1);
+    assert_eq!(deadline, setup.env.ledger().timestamp() + 3600);
+
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert_eq!(setup.token_client.balance(&user1), 1000 - 100 + 300);
+}
+
+#[soroban_sdk::contract]
+pub struct MockSwapRouter;
+
+#[soroban_sdk::contractimpl]
+impl MockSwapRouter {
+    /// Fills at exactly twice the input, or fails once `min_out` asks more.
+    pub fn swap(
+        env: Env,
+        from: Address,
+        token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        min_out: i128,
+        to: Address,
+    ) -> i128 {
+        let amount_out = amount_in * 2;
+        if amount_out < min_out {
+            panic!("slippage");
+        }
+        let router = env.current_contract_address();
+        TokenClient::new(&env, &token_in).transfer(&from, &router, &amount_in);
+        TokenClient::new(&env, &token_out).transfer(&router, &to, &amount_out);
+        amount_out
+    }
+}
+
+#[test]
+fn test_payout_swapped_into_preferred_token() {
+    let setup = setup_with_members(3, 1000);
+    default_init(&setup);
+    let env = &setup.env;
+    let user1 = setup.members.get(0).unwrap();
+    let user2 = setup.members.get(1).unwrap();
+
+    let other_token = env
+        .register_stellar_asset_contract_v2(setup.admin.clone())
+        .address();
+    let router_id = env.register(MockSwapRouter, ());
+    TokenAdminClient::new(env, &other_token).mint(&router_id, &10_000);
+    let other_client = TokenClient::new(env, &other_token);
+
+    // 0.5 pool tokens per unit of `other_token`: 300 swaps to 600.
+    setup.client.set_exchange_rate(&other_token, &5_000_000);
+    setup.client.set_swap_router(&Some(router_id.clone()), &100);
+    setup.client.set_payout_token(&user1, &Some(other_token.clone()));
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert_eq!(other_client.balance(&user1), 600);
+    assert_eq!(setup.token_client.balance(&user1), 900);
+    assert_eq!(setup.token_client.balance(&router_id), 300);
+
+    // A rate the router cannot meet fails the swap; the pool token is paid.
+    setup.client.set_exchange_rate(&other_token, &2_500_000);
+    setup.client.set_payout_token(&user2, &Some(other_token.clone()));
+    for member in setup.members.iter() {
+        setup.client.contribute(&member, &setup.token_admin, &100);
+    }
+    assert_eq!(other_client.balance(&user2), 0);
+    assert_eq!(setup.token_client.balance(&user2), 1000 - 200 + 300);
+}
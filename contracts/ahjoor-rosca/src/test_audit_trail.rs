@@ -1,6 +1,6 @@
 #![cfg(test)]
 
-use crate::{AhjoorContract, AhjoorContractClient, ContributionEntry, CycleRecord, PayoutStrategy, RoscaConfig, VotingMode};
+use crate::{AhjoorContract, AhjoorContractClient, ContributionEntry, CycleRecord, DefaultingRecipientPolicy, PayoutStrategy, RoscaConfig, VotingMode};
 use soroban_sdk::{testutils::{Address as _, Ledger as _, StellarAssetContract as _}, token, Address, Env, Vec};
 
 fn create_test_contract(env: &Env) -> (AhjoorContractClient, Address, Vec<Address>) {
@@ -48,6 +48,10 @@ fn create_test_contract(env: &Env) -> (AhjoorContractClient, Address, Vec<Addres
     randomize_payout_order: false,
     reserve_enabled: false,
     reserve_contribution_bps: 0,
+    member_amounts: None,
+    defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+    guardian: None,
+    recovery_delay: 0,
     };
 
     client.init(
@@ -3,7 +3,7 @@ use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, Vec};
 use soroban_sdk::token::StellarAssetClient as TokenAdminClient;
 use soroban_sdk::token::Client as TokenClient;
 
-use crate::{AhjoorContract, AhjoorContractClient, RoscaConfig, PayoutStrategy, VotingMode};
+use crate::{AhjoorContract, AhjoorContractClient, DefaultingRecipientPolicy, RoscaConfig, PayoutStrategy, VotingMode};
 
 fn make_config(env: &Env) -> RoscaConfig {
     RoscaConfig {
@@ -30,6 +30,10 @@ fn make_config(env: &Env) -> RoscaConfig {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
     }
 }
 
@@ -70,6 +70,10 @@ fn setup_insurance<'a>(
             randomize_payout_order: false,
             reserve_enabled: false,
             reserve_contribution_bps: 0,
+            member_amounts: None,
+            defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+            guardian: None,
+            recovery_delay: 0,
         },
         &None,
     );
@@ -56,6 +56,10 @@ fn setup_freeze_test<'a>() -> (Env, AhjoorContractClient<'a>, Address, Address,
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
         },
         &None,
     );
@@ -190,8 +194,10 @@ fn test_member_freeze_proposal_executes_and_freezes_group() {
     let contribute_res = client.try_contribute(&member1, &_token_admin, &100);
     assert!(contribute_res.is_err());
 
-    // Existing admin unfreeze path still works
+    // Existing admin unfreeze path still works; the vote outlasted round 0,
+    // so close it and contribute to the next one.
     client.unfreeze_group(&admin, &0, &resolution_hash(&env));
+    client.close_round();
     client.contribute(&member1, &_token_admin, &100);
 
     let log = client.get_freeze_log();
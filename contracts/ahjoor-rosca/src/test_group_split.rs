@@ -4,7 +4,7 @@ use soroban_sdk::token::StellarAssetClient as TokenAdminClient;
 use soroban_sdk::token::Client as TokenClient;
 
 use crate::{
-    AhjoorContract, AhjoorContractClient, RoscaConfig, PayoutStrategy, VotingMode,
+    AhjoorContract, AhjoorContractClient, DefaultingRecipientPolicy, RoscaConfig, PayoutStrategy, VotingMode,
     GroupStatus, SplitProposalStatus,
 };
 
@@ -33,6 +33,10 @@ fn make_config(env: &Env) -> RoscaConfig {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
     }
 }
 
@@ -1,6 +1,6 @@
 #![cfg(test)]
 
-use crate::{AhjoorContract, AhjoorContractClient, PayoutStrategy, RoscaConfig, VotingMode, MIGRATION_TIMEOUT_SECONDS};
+use crate::{AhjoorContract, AhjoorContractClient, DefaultingRecipientPolicy, PayoutStrategy, RoscaConfig, VotingMode, MIGRATION_TIMEOUT_SECONDS};
 use soroban_sdk::{testutils::{Address as _, Ledger as _}, Address, Env, Vec};
 
 fn default_config(fee_recipient: &Address) -> RoscaConfig {
@@ -28,6 +28,10 @@ fn default_config(fee_recipient: &Address) -> RoscaConfig {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
     }
 }
 
@@ -77,6 +77,10 @@ fn test_protocol_fee_deducted_from_payout() {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
         },
         &None,
     );
@@ -135,6 +139,10 @@ fn test_protocol_fee_max_cap_enforced() {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
         },
         &None,
     );
@@ -179,6 +187,10 @@ fn test_update_fee_function() {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
         },
         &None,
     );
@@ -229,6 +241,10 @@ fn test_no_fee_when_fee_bps_zero() {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
         },
         &None,
     );
@@ -282,6 +298,10 @@ fn test_partial_contribution_installments() {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
         },
         &None,
     );
@@ -355,6 +375,10 @@ fn test_partial_contribution_events_emitted() {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
         },
         &None,
     );
@@ -401,6 +425,10 @@ fn test_cannot_exceed_remaining_contribution() {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
         },
         &None,
     );
@@ -451,6 +479,10 @@ fn test_get_member_contribution_status() {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
         },
         &None,
     );
@@ -505,7 +537,7 @@ fn test_timestamp_based_scheduling() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -569,7 +601,7 @@ fn test_max_members_enforcement() {
         
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -610,7 +642,7 @@ fn test_update_max_members() {
         
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -658,7 +690,7 @@ fn test_max_members_boundary() {
         
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -701,7 +733,7 @@ fn test_max_members_proposal() {
         
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -759,7 +791,7 @@ fn test_configurable_max_defaults() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -821,6 +853,10 @@ fn test_suspension_threshold_set_event() {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
         },
         &None,
     );
@@ -855,7 +891,7 @@ fn test_max_defaults_must_be_at_least_one() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -891,7 +927,7 @@ fn test_penalise_defaulter_uses_max_defaults() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -959,7 +995,7 @@ fn test_all_features_integrated() {
             max_members: None,
             skip_fee: 0,
             max_skips_per_cycle: 0,
-            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,},
+            voting_mode: VotingMode::Equal, late_fee_bps: 0, grace_period_seconds: 0, auction_enabled: false, auction_window_ledgers: 0, randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0, member_amounts: None, defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive, guardian: None, recovery_delay: 0,},
         &None,
     );
 
@@ -1031,6 +1067,10 @@ fn test_round_reset_clears_contributions() {
             randomize_payout_order: false,
             reserve_enabled: false,
             reserve_contribution_bps: 0,
+            member_amounts: None,
+            defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+            guardian: None,
+            recovery_delay: 0,
         },
         &None,
     );
@@ -1103,6 +1143,10 @@ fn test_credit_score_oracle_readable_cross_contract() {
             randomize_payout_order: false,
             reserve_enabled: false,
             reserve_contribution_bps: 0,
+            member_amounts: None,
+            defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+            guardian: None,
+            recovery_delay: 0,
         },
         &None,
     );
@@ -1112,7 +1156,9 @@ fn test_credit_score_oracle_readable_cross_contract() {
     assert_eq!(score_before, 0);
     assert_eq!(ledger_before, 0);
 
-    // 2. Both members contribute on time (earns +10 credit score each)
+    // 2. Both members contribute on time (earns +10 credit score each).
+    //    The test ledger starts at sequence 0, so move past it first.
+    env.ledger().with_mut(|l| l.sequence_number = 1);
     env.ledger().set_timestamp(100);
     client.contribute(&m1, &token_admin, &100);
     client.contribute(&m2, &token_admin, &100);
@@ -1130,10 +1176,7 @@ fn test_credit_score_oracle_readable_cross_contract() {
     // 3. A second round: advance ledger then contribute again
     let ledger_after_first = oracle_ledger;
     env.ledger().with_mut(|l| l.sequence_number += 10);
-    env.ledger().set_timestamp(4000);
-    client.close_round();
-
-    env.ledger().set_timestamp(4100);
+    env.ledger().set_timestamp(200);
     client.contribute(&m1, &token_admin, &100);
     client.contribute(&m2, &token_admin, &100);
 
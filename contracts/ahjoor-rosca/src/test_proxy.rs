@@ -68,6 +68,10 @@ fn setup_proxy<'a>() -> (
             randomize_payout_order: false,
             reserve_enabled: false,
             reserve_contribution_bps: 0,
+            member_amounts: None,
+            defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+            guardian: None,
+            recovery_delay: 0,
         },
         &None,
     );
@@ -124,7 +128,7 @@ fn test_proxy_authorization_expires_after_max_rounds() {
         .try_contribute_as_proxy(&proxy, &0, &member, &token_addr, &100)
         .unwrap_err()
         .unwrap();
-    assert_eq!(err, errors::ExtError2::ProxyRoundsExhausted.into());
+    assert_eq!(err, errors::ExtError::ProxyRoundsExhausted.into());
 }
 
 #[test]
@@ -148,7 +152,7 @@ fn test_proxy_round_limit_enforced() {
         .try_contribute_as_proxy(&proxy, &0, &member, &token_addr, &100)
         .unwrap_err()
         .unwrap();
-    assert_eq!(err, errors::ExtError2::ProxyRoundsExhausted.into());
+    assert_eq!(err, errors::ExtError::ProxyRoundsExhausted.into());
 }
 
 #[test]
@@ -59,6 +59,10 @@ fn test_per_type_quorum_enforced() {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
         },
         &None,
     );
@@ -157,6 +161,10 @@ fn test_proposal_respects_quorum_at_creation() {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
         }, &None);
 
     let creator = members.get(0).unwrap();
@@ -193,6 +201,10 @@ fn test_treasury_round_requires_quorum() {
         skip_fee: 0, max_skips_per_cycle: 0, voting_mode: VotingMode::Equal,
         late_fee_bps: 0, auction_enabled: false, auction_window_ledgers: 0,
         randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
     }, &None);
 
     let proposer = members.get(0).unwrap();
@@ -234,6 +246,10 @@ fn test_treasury_round_vote_non_member_rejected() {
         skip_fee: 0, max_skips_per_cycle: 0, voting_mode: VotingMode::Equal,
         late_fee_bps: 0, auction_enabled: false, auction_window_ledgers: 0,
         randomize_payout_order: false, reserve_enabled: false, reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
     }, &None);
 
     let proposer = members.get(0).unwrap();
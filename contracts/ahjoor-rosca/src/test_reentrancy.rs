@@ -0,0 +1,131 @@
+#![cfg(test)]
+use super::*;
+use soroban_sdk::{contract, contractimpl, contracttype, testutils::Address as _, Address, Env};
+
+/// Minimal token whose transfers out of a blocked address revert, standing
+/// in for a token that fails while the ROSCA pays out.
+#[contract]
+pub struct FailingToken;
+
+#[contracttype]
+#[derive(Clone)]
+enum MockKey {
+    Balance(Address),
+    Blocked,
+}
+
+#[contractimpl]
+impl FailingToken {
+    pub fn decimals(_env: Env) -> u32 {
+        7
+    }
+
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage()
+            .instance()
+            .set(&MockKey::Balance(to), &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&MockKey::Balance(id))
+            .unwrap_or(0)
+    }
+
+    /// Makes every later transfer out of `from` revert.
+    pub fn block(env: Env, from: Address) {
+        env.storage().instance().set(&MockKey::Blocked, &from);
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        let blocked: Option<Address> = env.storage().instance().get(&MockKey::Blocked);
+        if blocked == Some(from.clone()) {
+            panic!("transfer blocked");
+        }
+        let from_balance = Self::balance(env.clone(), from.clone());
+        let to_balance = Self::balance(env.clone(), to.clone());
+        env.storage()
+            .instance()
+            .set(&MockKey::Balance(from), &(from_balance - amount));
+        env.storage()
+            .instance()
+            .set(&MockKey::Balance(to), &(to_balance + amount));
+    }
+}
+
+#[test]
+fn test_round_state_committed_before_payout_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(AhjoorContract, ());
+    let client = AhjoorContractClient::new(&env, &contract_id);
+    let token_id = env.register(FailingToken, ());
+    let token = FailingTokenClient::new(&env, &token_id);
+
+    let admin = Address::generate(&env);
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    token.mint(&user1, &1000);
+    token.mint(&user2, &1000);
+    let members = soroban_sdk::vec![&env, user1.clone(), user2.clone()];
+
+    client.init(
+        &admin,
+        &members,
+        &100,
+        &token_id,
+        &3600,
+        &RoscaConfig {
+            strategy: PayoutStrategy::RoundRobin,
+            custom_order: None,
+            penalty_amount: 0,
+            exit_penalty_bps: 0,
+            collective_goal: None,
+            member_goals: None,
+            fee_bps: 0,
+            fee_recipient: None,
+            max_defaults: 3,
+            grace_period_ledgers: 0,
+            use_timestamp_schedule: false,
+            round_duration_seconds: 0,
+            max_members: None,
+            skip_fee: 0,
+            max_skips_per_cycle: 0,
+            voting_mode: VotingMode::Equal,
+            late_fee_bps: 0,
+            grace_period_seconds: 0,
+            auction_enabled: false,
+            auction_window_ledgers: 0,
+            randomize_payout_order: false,
+            reserve_enabled: false,
+            reserve_contribution_bps: 0,
+            member_amounts: None,
+            defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+            guardian: None,
+            recovery_delay: 0,
+        },
+        &None,
+    );
+
+    client.contribute(&user1, &token_id, &100);
+    token.block(&contract_id);
+    client.contribute(&user2, &token_id, &100);
+
+    // The payout transfer reverted, yet the round was already reset and
+    // advanced: only the transfer itself is left, held for a retry.
+    let (round, paid, _, _, _) = client.get_state();
+    assert_eq!(round, 1);
+    assert_eq!(paid.len(), 0);
+    assert_eq!(client.get_member_contribution_status(&user2).0, 0);
+    let pending = client.get_pending_payouts();
+    assert_eq!(pending.len(), 1);
+    let held = pending.get(0).unwrap();
+    assert_eq!(held.round, 0);
+    assert_eq!(held.recipient, user1);
+    assert_eq!(held.amounts.get(token_id.clone()), Some(200));
+    assert_eq!(token.balance(&user1), 900);
+    assert_eq!(token.balance(&contract_id), 200);
+}
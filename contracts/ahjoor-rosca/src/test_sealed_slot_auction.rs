@@ -12,7 +12,7 @@
 //! - Valid reveal, invalid reveal, no-reserve-met, and sniping prevention.
 
 use crate::{
-    AhjoorContract, AhjoorContractClient, PayoutStrategy, RoscaConfig, VotingMode,
+    AhjoorContract, AhjoorContractClient, DefaultingRecipientPolicy, PayoutStrategy, RoscaConfig, VotingMode,
 };
 use soroban_sdk::{
     testutils::{Address as _, Ledger},
@@ -58,6 +58,10 @@ fn base_config() -> RoscaConfig {
         randomize_payout_order: false,
         reserve_enabled: false,
         reserve_contribution_bps: 0,
+        member_amounts: None,
+        defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+        guardian: None,
+        recovery_delay: 0,
     }
 }
 
@@ -166,7 +170,7 @@ fn test_sealed_auction_highest_valid_bid_wins() {
 }
 
 #[test]
-#[should_panic(expected = "Revealed values do not match commitment")]
+#[should_panic(expected = "HostError: Error(Contract, #180)")] // CommitmentMismatch
 fn test_reveal_with_wrong_values_rejected() {
     let hx = setup(100);
     let m1 = hx.members.get(1).unwrap();
@@ -181,7 +185,7 @@ fn test_reveal_with_wrong_values_rejected() {
 }
 
 #[test]
-#[should_panic(expected = "Reveal phase has not opened yet")]
+#[should_panic(expected = "HostError: Error(Contract, #177)")] // RevealNotOpen
 fn test_reveal_before_reveal_phase_rejected() {
     // Sniping prevention: bids cannot be acted on while the commit phase is
     // still open.
@@ -197,7 +201,7 @@ fn test_reveal_before_reveal_phase_rejected() {
 }
 
 #[test]
-#[should_panic(expected = "Commit phase has closed")]
+#[should_panic(expected = "HostError: Error(Contract, #103)")] // AuctionWindowClosed
 fn test_commit_after_commit_phase_rejected() {
     // Sniping prevention: no new bids once the commit phase closes.
     let hx = setup(100);
@@ -86,6 +86,10 @@ fn init_with_auction(
             randomize_payout_order: false,
             reserve_enabled: false,
             reserve_contribution_bps: 0,
+            member_amounts: None,
+            defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+            guardian: None,
+            recovery_delay: 0,
         },
         &None,
     );
@@ -129,6 +133,10 @@ fn init_without_auction(
             randomize_payout_order: false,
             reserve_enabled: false,
             reserve_contribution_bps: 0,
+            member_amounts: None,
+            defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+            guardian: None,
+            recovery_delay: 0,
         },
         &None,
     );
@@ -151,7 +159,7 @@ fn test_single_bidder_wins_auction() {
     // auction_window_ledgers = 500 seconds
     init_with_auction(&env, &client, &admin, &members, &token_addr, 500);
 
-    // Complete round 0 so reset_round_state fires and opens auction at cycle start (round 1 = cycle start for 3-member group? No — cycle start is when new_round % len == 0, i.e. round 0 is cycle start)
+    // Complete round 0 so advance_round fires and opens auction at cycle start (round 1 = cycle start for 3-member group? No — cycle start is when new_round % len == 0, i.e. round 0 is cycle start)
     // Actually round 0 is the first round. After completing round 0, new_round = 1.
     // Cycle start: new_round % 3 == 0 → round 3, 6, 9...
     // So we need to complete 3 rounds to get to cycle start.
@@ -49,6 +49,10 @@ fn create_basic_config() -> RoscaConfig {
     randomize_payout_order: false,
     reserve_enabled: false,
     reserve_contribution_bps: 0,
+    member_amounts: None,
+    defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+    guardian: None,
+    recovery_delay: 0,
     }
 }
 
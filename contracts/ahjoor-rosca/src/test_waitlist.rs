@@ -53,6 +53,10 @@ fn setup_waitlist<'a>() -> (Env, AhjoorContractClient<'a>, Address, Address, Vec
             randomize_payout_order: false,
             reserve_enabled: false,
             reserve_contribution_bps: 0,
+            member_amounts: None,
+            defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+            guardian: None,
+            recovery_delay: 0,
         },
         &None,
     );
@@ -0,0 +1,44 @@
+//! Setup helpers for tests written against this contract, including by
+//! integrators outside the crate. Enabled by the `testutils` feature.
+
+use crate::{AhjoorContract, AhjoorContractClient, RoscaConfig};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{Address, Env, Vec};
+
+/// Round-robin config with every optional feature off.
+pub fn default_config() -> RoscaConfig {
+    RoscaConfig::default()
+}
+
+/// Deploys the contract and a Stellar asset token, generates `n_members`
+/// members each minted enough for a full cycle (`amount * n_members`), and
+/// initializes the group with `default_config`. Mocks all auths on `env`.
+///
+/// Returns the contract client, the members in payout order and the token's
+/// admin client for minting more funds.
+pub fn setup_group<'a>(
+    env: &Env,
+    n_members: u32,
+    amount: i128,
+    duration: u64,
+) -> (AhjoorContractClient<'a>, Vec<Address>, StellarAssetClient<'a>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register(AhjoorContract, ());
+    let client = AhjoorContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let token_admin = StellarAssetClient::new(env, &token);
+
+    let mut members = Vec::new(env);
+    for _ in 0..n_members {
+        let member = Address::generate(env);
+        token_admin.mint(&member, &(amount * n_members as i128));
+        members.push_back(member);
+    }
+
+    client.init(&admin, &members, &amount, &token, &duration, &default_config(), &None);
+    (client, members, token_admin)
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, BytesN, Map, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Map, String, Symbol, Vec};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[contracttype]
@@ -33,7 +33,7 @@ pub struct RoscaConfig {
     pub member_goals: Option<Map<Address, i128>>,
     /// Protocol fee in basis points (e.g., 100 = 1%, 500 = 5%). Max 500 bps.
     pub fee_bps: u32,
-    /// Address that receives protocol fees
+    /// Address that receives protocol fees (defaults to the admin when `None`)
     pub fee_recipient: Option<Address>,
     /// Number of consecutive missed rounds before suspension (default: 3)
     pub max_defaults: u32,
@@ -64,6 +64,54 @@ pub struct RoscaConfig {
     pub reserve_enabled: bool,
     /// Surcharge percentage (bps) on each contribution routed to emergency reserve (#313)
     pub reserve_contribution_bps: u32,
+    /// Per-member contribution amounts, parallel to `members`. When `None`,
+    /// every member owes the flat `contribution_amount`.
+    pub member_amounts: Option<Vec<i128>>,
+    /// What happens to the pot when the scheduled recipient has not paid.
+    pub defaulting_recipient_policy: DefaultingRecipientPolicy,
+    /// Guardian who may take over the admin role after `recovery_delay`
+    /// seconds of admin inactivity. `None` = no recovery.
+    pub guardian: Option<Address>,
+    /// Seconds of admin inactivity before the guardian may recover the group.
+    /// At least `MIN_RECOVERY_DELAY_SECONDS` when a guardian is set.
+    pub recovery_delay: u64,
+}
+
+/// Round-robin payouts with every optional feature off: no fees, penalties,
+/// goals, grace periods, reserve or guardian, and suspension after three
+/// defaults.
+impl Default for RoscaConfig {
+    fn default() -> Self {
+        RoscaConfig {
+            strategy: PayoutStrategy::RoundRobin,
+            custom_order: None,
+            penalty_amount: 0,
+            exit_penalty_bps: 0,
+            collective_goal: None,
+            member_goals: None,
+            fee_bps: 0,
+            fee_recipient: None,
+            max_defaults: 3,
+            grace_period_ledgers: 0,
+            use_timestamp_schedule: false,
+            round_duration_seconds: 0,
+            max_members: None,
+            skip_fee: 0,
+            max_skips_per_cycle: 0,
+            voting_mode: VotingMode::Equal,
+            late_fee_bps: 0,
+            grace_period_seconds: 0,
+            auction_enabled: false,
+            auction_window_ledgers: 0,
+            randomize_payout_order: false,
+            reserve_enabled: false,
+            reserve_contribution_bps: 0,
+            member_amounts: None,
+            defaulting_recipient_policy: DefaultingRecipientPolicy::StillReceive,
+            guardian: None,
+            recovery_delay: 0,
+        }
+    }
 }
 
 #[contracttype]
@@ -87,6 +135,155 @@ pub struct PayoutRecord {
     pub amount: i128,
 }
 
+/// Persisted outcome of a finished round. `recipient` is `None` when the round
+/// was closed without a payout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundRecord {
+    pub recipient: Option<Address>,
+    pub pot: i128,
+    pub defaulters: Vec<Address>,
+    /// Members who paid in full.
+    pub paid: Vec<Address>,
+    /// Members whose absence was excused rather than counted as a default.
+    pub excused: Vec<Address>,
+    /// Voluntary top-ups added to the pot with `boost`.
+    pub boosted: i128,
+}
+
+/// Pot held back by the payout delay until `release_at`. `amounts` maps each
+/// token to the amount owed to `recipient`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingPayout {
+    pub round: u32,
+    pub recipient: Address,
+    pub amounts: Map<Address, i128>,
+    pub release_at: u64,
+}
+
+/// Admin action awaiting `multisig_threshold` approvals from the admin and
+/// co-admins. `action_type` uses the `propose_admin_action` numbering, plus
+/// 5 for an upgrade opened with `propose_upgrade`, which sets `wasm_hash`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposal {
+    pub action_type: u32,
+    pub target_member: Option<Address>,
+    pub payload: Option<i128>,
+    pub approvals: Vec<Address>,
+    pub executed: bool,
+    pub wasm_hash: Option<BytesN<32>>,
+}
+
+/// Result of `check_invariants`; every field is true on a healthy contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvariantReport {
+    /// Token balance covers contributions, deposits and every tracked reserve.
+    pub balance_covers_accounted: bool,
+    /// Every paid member of the current round is a member.
+    pub paid_members_are_members: bool,
+    /// In a finite cycle, no more than `MaxRounds` rounds have paid out.
+    pub round_within_max: bool,
+}
+
+/// Returned by `contribute`: round progress right after the contribution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributeResult {
+    /// Members who have fully paid this round, including the caller.
+    pub paid_count: u32,
+    pub total_members: u32,
+    /// True when this contribution completed the round and ran its payout.
+    pub triggered_payout: bool,
+}
+
+/// Per-round summary published with the `round_closed` event.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundSummary {
+    pub round: u32,
+    pub paid: u32,
+    pub defaulted: u32,
+    /// Total contributed during the round.
+    pub pot: i128,
+}
+
+/// Snapshot of the group's configuration and current round state, returned by
+/// `get_full_state` so frontends can load everything in one call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FullState {
+    pub round: u32,
+    pub paid_members: Vec<Address>,
+    pub deadline: u64,
+    pub members: Vec<Address>,
+    pub contribution_amount: i128,
+    pub token: Address,
+    pub admin: Address,
+    pub defaulters: Vec<Address>,
+}
+
+/// Current-round view returned by `snapshot`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Snapshot {
+    pub state: FullState,
+    /// Whether each member has paid in full this round.
+    pub paid: Map<Address, bool>,
+    pub recipient: Address,
+    /// Seconds until the round deadline, 0 once it has passed.
+    pub time_remaining: u64,
+    pub stats: GroupStats,
+}
+
+/// Aggregate figures for a group summary card, returned by `get_stats`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupStats {
+    pub total_members: u32,
+    pub current_round: u32,
+    pub total_distributed: i128,
+    pub reserve_balance: i128,
+    /// Missed contributions summed over every recorded round.
+    pub total_defaults: u32,
+    /// Share of the current cycle's rounds already finished, 0-100.
+    pub completion_pct: u32,
+}
+
+/// Ledgers left before storage expires, returned by `storage_ttl`. Both are
+/// floors: entry points extend TTLs further without updating them.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageTtl {
+    pub instance: u32,
+    /// Member list, payout order and round history.
+    pub persistent: u32,
+}
+
+/// Lightweight yes/no poll opened with `quick_poll`. Tallies are one vote per
+/// member and carry no on-chain effect.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuickPoll {
+    pub deadline: u64,
+    pub yes: u32,
+    pub no: u32,
+    pub voters: Vec<Address>,
+}
+
+/// Early payout taken by the scheduled recipient before every member had paid.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EarlyClaim {
+    pub recipient: Address,
+    /// Amount contributed at the time of the claim.
+    pub claimed: i128,
+    /// Shortfall against the full pot that the recipient gave up.
+    pub forgone: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ExitRequest {
@@ -111,6 +308,31 @@ pub struct MemberStatus {
     pub claimable_rewards: i128,
 }
 
+/// Something a member did or received in a finished round, as listed by
+/// `member_timeline`. `action` is one of `paid`, `payout`, `defaulted` or
+/// `excused`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimelineEntry {
+    pub round: u32,
+    pub action: Symbol,
+}
+
+/// One member's full state, as exported by `member_report`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberReport {
+    pub member: Address,
+    pub has_paid_this_round: bool,
+    pub lifetime_contributions: i128,
+    pub default_count: u32,
+    pub debt: i128,
+    pub deposit: i128,
+    pub on_time_streak: u32,
+    /// Number of rounds in which the member received the pot.
+    pub payouts_received: u32,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[contracttype]
 pub enum ProposalType {
@@ -120,6 +342,8 @@ pub enum ProposalType {
     MaxMembersUpdate = 3,
     Reinstatement = 4, // #218
     MemberFreeze = 5,  // Member-initiated emergency freeze
+    ContributionAmountChange = 6, // execution_data: new flat contribution amount
+    MemberAddition = 7,           // target_member: address to add
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -151,28 +375,33 @@ pub struct Proposal {
 /// Storage key classification:
 ///
 /// INSTANCE (config + active round state — bounded, shared TTL):
-///   Admin, Members, PayoutOrder, Strategy, ContributionAmt, Token,
-///   CurrentRound, PaidMembers, RoundDuration, RoundDeadline, Defaulters,
+///   Admin, Strategy, ContributionAmt, Token,
+///   CurrentRound, RoundDuration, Defaulters,
 ///   PenaltyAmount, DefaultCount, SuspendedMembers, ApprovedTokens,
 ///   RewardPool, TotalParticipations, MemberParticipation, ClaimedRewards,
 ///   RewardWeights, RewardDistType, ExitedMembers, ExitPenaltyBps,
 ///   IsPaused, PauseReason, PauseTimestamp, CollectiveGoal, TotalCollected,
 ///   MemberGoals, MemberCollected, MilestonesReached, ExchangeRates,
 ///   TokenLimits, ProposalCounter, Proposals, ProposalVotes,
-///   VotingDeadline, QuorumPercentage, MemberContributions
+///   VotingDeadline, QuorumPercentage, MemberContributions, MemberContributionAmt
 ///
 /// PERSISTENT (unbounded growth — individual TTL per key):
 ///   RoundHistory — appended every round; must outlive instance TTL
+///   Members, PayoutOrder — large, rarely written; TTL extended on hot paths
 ///
 /// TEMPORARY (short-lived in-progress state — auto-expires):\
 ///   ExitRequests — pending admin approval; no long-term retention needed
+///   PaidMembers — rewritten every round; TTL covers the round, its
+///   late-contribution grace period and a further margin
+///   RoundDeadline, RoundDeadlineTimestamp — same TTL; recomputed from the
+///   round's start if they lapse
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
     // --- Instance ---
     Admin,                   // Address
-    Members,                 // Vec<Address>
-    PayoutOrder,             // Vec<Address>
+    Members,                 // Vec<Address> (persistent)
+    PayoutOrder,             // Vec<Address> (persistent)
     Strategy,                // PayoutStrategy
     ContributionAmt,         // i128
     Token,                   // Address
@@ -214,6 +443,11 @@ pub enum DataKey {
     MaxDefaults,             // u32 — suspension threshold
     RoundDeadlineTimestamp,  // u64
     MaxMembers,              // u32
+    MemberContributionAmt,   // Map<Address, i128> — explicit per-member amount (overrides ContributionAmt)
+    LateFee,                 // i128 — flat fee charged by contribute_late
+    AutoContribute(Address), // u32 (persistent) — auto-contribute rounds remaining
+    Prepaid(Address),        // i128 (persistent) — contributions escrowed for upcoming rounds
+    RoundSettled(u32),       // bool (persistent) — round's pot has been settled
 }
 
 /// Overflow key enum — DataKey is capped at 50 variants by the soroban XDR limit.
@@ -285,6 +519,90 @@ pub enum DataKey4 {
     PendingRoundDuration = 90,
     MinRoundDuration = 91,
     MaxRoundDuration = 92,
+    LateFeePot = 100,         // i128 — late fees collected this round (paid out with the pot)
+    ReservePool = 101,        // i128 — fee-funded reserve that tops up short pots
+    FeeToReserve = 102,       // bool — keep payout fees in ReservePool instead of transferring them
+    MemberDeposits = 103,     // Map<Address, i128> — security deposits posted by members
+    SlashBps = 104,           // u32 — share of a defaulter's deposit slashed into ReservePool
+    MaxRounds = 105,          // u32 — payouts after which the group completes (member count at init; unset = open-ended)
+    KeeperReward = 106,       // i128 — paid from ReservePool to whoever pokes an expired round
+    AdminSuspended = 107,     // Vec<Address> — members the admin has temporarily excluded
+    DissolvedAt = 108,        // u64 — ledger timestamp of the last `dissolve`
+    MemberWhitelist = 109,    // Vec<Address> — addresses pre-approved to join
+    WhitelistEnabled = 110,   // bool — require MemberWhitelist for add_member/join
+    OpenEnrollment = 111,     // bool — allow `join` after the first payout
+    JoinDeposit = 112,        // i128 — security deposit collected by `join`
+    InterestRateBps = 113,    // u32 — daily interest (bps) charged by `contribute_late`
+    MemberDebt = 114,         // Map<Address, i128> — missed contributions owed by defaulters
+    DefaultingRecipientPolicy = 115, // DefaultingRecipientPolicy — payout rule for a defaulting recipient
+    ContributionsPaused = 116, // bool — admin has paused contributions only
+    DepositsPaused = 117,     // bool — admin has paused security deposits only
+    TotalDistributed = 118,   // i128 — running total of pots paid to recipients
+    MinParticipationBps = 119, // u32 — share of active members who must pay before a close pays out
+    CreatedAt = 120,          // u64 — ledger timestamp of `init`
+    RoundStartedAt = 121,     // u64 — when the current round began
+    TokenDecimals = 122,      // u32 — decimals reported by the base token at init
+    PayoutDelay = 123,        // u64 — seconds a completed round's pot is held before release
+    PendingPayouts = 124,     // Vec<PendingPayout> — held pots, oldest first
+    Disputed = 125,           // Address — member disputing the oldest held pot
+    AdminProposals = 126,     // Map<u32, AdminProposal> — quorum-gated admin actions
+    NextAdminProposalId = 127, // u32 — id assigned to the next admin proposal
+    CycleNumber = 128,        // u32 — finite cycles started after the first (0 = first cycle)
+    FailedPayouts = 129,      // u32 — consecutive payout transfers that reverted
+    PayoutFailureThreshold = 130, // u32 — failures that trip the circuit breaker (default 3)
+    Weights = 131,            // Map<Address, u32> — payout weights; non-empty makes payouts weighted
+    EndOfCyclePolicy = 132,   // EndOfCyclePolicy — what `finalize_cycle` does with leftover funds
+    FixedSchedule = 133,      // bool — deadlines follow start + (round + 1) * duration instead of rolling
+    Operators = 134,          // Vec<Address> — may run non-financial upkeep (closing rounds, snapshots)
+    OnTimeStreaks = 135,      // Map<Address, u32> — consecutive rounds paid in full before the deadline
+    StreakReward = 136,       // i128 — reward per streak round, paid from RewardPool
+    StreakClaims = 137,       // Map<Address, u32> — round of each member's last streak claim
+    FeeToRewards = 138,       // bool — route payout fees into RewardPool
+    TtlFloor = 139,           // (u32, u32) — ledgers instance / persistent storage is known to live until
+    WindowOpen = 140,         // u64 — seconds after round start when contributions open
+    WindowClose = 141,        // u64 — seconds after round start when contributions close
+    MinContribution = 142,    // i128 — smallest single contribution accepted (0 = no floor)
+}
+
+/// Overflow key enum — DataKey4 is capped at 50 variants by the soroban XDR
+/// limit. Discriminants continue DataKey4's so no two keys share a value.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey5 {
+    MaxContribution = 143,    // i128 — largest single contribution accepted (0 = no cap)
+    MaxConsecutiveDefaults = 144, // u32 — missed rounds in a row before ejection (0 = off)
+    ConsecutiveDefaults = 145, // Map<Address, u32> — rounds in a row each member has missed
+    EjectionPolicy = 146,     // EjectionPolicy — what happens to an ejected member
+    LastClosedRound = 147,    // u32 — last round ended by close_round
+    ClockFrozenAt = 148,      // u64 — when the admin froze the round clock
+    Guardian = 149,           // Address — may recover the admin role after inactivity
+    RecoveryDelay = 150,      // u64 — seconds of admin inactivity before recovery
+    LastAdminAction = 151,    // u64 — timestamp of the admin's last authorized call
+    CarryOverEnabled = 152,   // bool — re-run under-subscribed rounds instead of paying out
+    CarryOver = 153,          // i128 — pot carried forward to the next completed round
+    ExcuseAllowance = 154,    // u32 — excused absences each member starts with
+    Oracle = 155,             // Address — price feed the contribution is pegged through
+    PeggedValue = 156,        // i128 — contribution in the pegged unit (7 decimals)
+    OracleMaxAge = 157,       // u64 — oldest oracle price accepted, in seconds
+    PegPrice = 158,           // i128 — last oracle price read (7 decimals)
+    PayoutHook = 159,         // Address — contract notified via on_payout after each payout
+    DeferredRound = 160,      // u32 — round whose short payout was already deferred once
+    MaxTvl = 161,             // i128 — cap on tracked funds the contract may hold (0 = no cap)
+    RoundExcused = 162,       // Vec<Address> — members excused this round, until its record is written
+    RoundBoost = 163,         // i128 — voluntary top-ups to the current round's pot
+    OverpayPolicy = 164,      // OverpayPolicy — handling of contributions above what is owed
+    HistorySize = 165,        // u32 — number of RoundRecord entries currently stored
+    MinPayoutPot = 166,       // i128 — smallest accumulated pot that may be paid out (0 = any)
+    RetryCount = 167,         // u32 — rounds voided and re-run with retry_round
+    SwapRouter = 168,         // Address — DEX router for payouts in a member's preferred token
+    SwapSlippageBps = 169,    // u32 — max shortfall of a payout swap below the exchange rate
+    RoundRefunded = 170,      // Vec<Address> — members who took their contribution back this round
+    RoundBoosts = 171,        // Map<Address, i128> — each donor's part of RoundBoost, refunded if never paid out
+    CarryOverShares = 172,    // Map<Address, i128> — each contributor's part of CarryOver
+    Admins = 173,             // Vec<Address> — co-admins who propose and approve quorum-gated actions
+    AdminThreshold = 174,     // u32 — approvals a quorum-gated action needs (1 = the admin alone)
+    RoundsPaidOut = 175,      // u32 — rounds this cycle that ended in a payout; completes at MaxRounds
+    RoundForeignPaid = 176,   // Vec<Address> — members who paid part of this round in a non-base token
 }
 
 /// Waitlist ordering mode (#456).
@@ -431,6 +749,30 @@ pub enum PersistentKey {
     CycleSnapshot(u32),        // cycle_number → CycleSnapshotData
     /// #457: Ledger at which a member's credit score was last updated (cross-contract oracle)
     CreditScoreUpdatedAt(Address), // u32 — ledger sequence of last credit score update
+    /// Outcome of a finished round, keyed by round number
+    RoundRecord(u32),          // round → RoundRecord
+    /// Rounds in which a member received the pot
+    PayoutRounds(Address),     // Vec<u32>
+    /// Off-chain contact hint attached by a member
+    MemberMeta(Address),       // Bytes
+    /// Address a member's payouts are sent to instead of their own
+    PayoutAddress(Address),    // Address
+    /// Excused absences a member has left before a miss counts as a default
+    ExcusedRemaining(Address), // u32
+    /// Quick poll opened under a topic
+    Poll(Symbol),              // QuickPoll
+    /// Member would rather wait a round than take a short pot
+    DeferIfShort(Address),     // bool
+    /// Cap a member set on what the contract may ever pull from them
+    PullLimit(Address),        // i128
+    /// Total the contract has pulled from a member under their allowance
+    Pulled(Address),           // i128
+    /// Addresses sharing the payout of a rotation slot
+    SlotBeneficiaries(u32),    // Vec<SlotBeneficiary>
+    /// Token a member wants their payouts swapped into
+    PayoutToken(Address),      // Address
+    /// Early payout claim taken in a round
+    ClaimLog(u32),             // EarlyClaim
 }
 
 /// #364: Immutable point-in-time snapshot of group state at cycle end.
@@ -567,6 +909,8 @@ pub enum GroupStatus {
     Merged = 2,
     /// Group was split into two sub-groups; no further operations permitted.
     Split = 3,
+    /// The configured number of rounds has been paid out; the ROSCA has ended.
+    Completed = 4,
 }
 
 #[contracttype]
@@ -612,6 +956,56 @@ pub struct SlotSwap {
     pub admin_approved: bool,
 }
 
+/// Payout handling when the scheduled recipient defaulted on the round.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum DefaultingRecipientPolicy {
+    /// The recipient is paid as usual.
+    StillReceive = 0,
+    /// The next member in the payout order who paid takes the turn, and the
+    /// defaulter moves into that member's later slot.
+    SkipToNext = 1,
+    /// The recipient's payout is moved into the reserve.
+    ForfeitToReserve = 2,
+}
+
+/// What `finalize_cycle` does with funds left over once a finite cycle ends.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum EndOfCyclePolicy {
+    /// Split equally among members who have not exited.
+    DistributeEqually = 0,
+    /// Send everything to the admin.
+    SendToAdmin = 1,
+    /// Keep the funds as reserve for the next cycle.
+    RolloverToNextCycle = 2,
+}
+
+/// What `close_round` does to a member who has missed
+/// `MaxConsecutiveDefaults` rounds in a row.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum EjectionPolicy {
+    /// Suspend the member; the admin can reinstate them later.
+    Suspend = 0,
+    /// Remove the member from the group and the payout order.
+    Remove = 1,
+}
+
+/// What `contribute` does with the part of a payment above what the member
+/// still owes for the round.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum OverpayPolicy {
+    /// Reject the contribution with `ExceedsRemainingContribution`.
+    Reject = 0,
+    /// Take only what is owed; the excess stays with the member.
+    Refund = 1,
+    /// Take the excess as well and credit it to the member's prepaid escrow.
+    /// Applies to base-token payments; other tokens fall back to `Refund`.
+    Credit = 2,
+}
+
 // #214: Insurance Coverage Mode & Claims
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[contracttype]
@@ -824,6 +1218,14 @@ pub struct CoPayerSplit {
     pub amount: i128,
 }
 
+/// One co-owner of a rotation slot and their share of its payout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlotBeneficiary {
+    pub beneficiary: Address,
+    pub share_bps: u32,
+}
+
 /// NFT-style on-chain receipt minted when a round completes for each contributing member.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -854,3 +1256,25 @@ pub struct GroupAnalytics {
     pub avg_reputation_score: i128,
     pub fee_bps: u32,
 }
+
+/// Every optional mode switch and its current value, returned by `get_flags`.
+/// Unset switches report their defaults.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Flags {
+    pub paused: bool,
+    pub contributions_paused: bool,
+    pub deposits_paused: bool,
+    pub frozen: bool,
+    pub timestamp_schedule: bool,
+    pub fixed_schedule: bool,
+    /// `MaxRounds` is set, so the group completes after that many rounds.
+    pub finite: bool,
+    pub whitelist_enabled: bool,
+    pub open_enrollment: bool,
+    pub randomize_payout_order: bool,
+    pub carry_over_enabled: bool,
+    pub fee_to_reserve: bool,
+    pub fee_to_rewards: bool,
+    pub overpay_policy: OverpayPolicy,
+}